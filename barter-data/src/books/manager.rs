@@ -17,26 +17,73 @@ use fnv::FnvHashMap;
 use futures::Stream;
 use futures_util::StreamExt;
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
     sync::Arc,
 };
+use tokio::sync::mpsc;
 use tracing::warn;
 
 /// Maintains a set of local L2 [`OrderBook`]s by applying streamed [`OrderBookEvent`]s to the
 /// associated [`OrderBook`] in the [`OrderBookMap`].
+///
+/// If `max_depth` is configured, each [`OrderBook`] is truncated to that many levels per side
+/// after every update.
+///
+/// If a [`SequenceGapDetector`] is configured, an [`OrderBookEvent::Update`] whose sequence does
+/// not follow on from its [`OrderBook`] flags that book as desynchronised, and further updates are
+/// ignored until the next snapshot. If a `resync_tx` is configured, the key of the desynchronised
+/// book is sent so the caller can resubscribe for a fresh snapshot.
 #[derive(Debug)]
-pub struct OrderBookL2Manager<St, BookMap> {
+pub struct OrderBookL2Manager<St, BookMap>
+where
+    BookMap: OrderBookMap,
+{
     pub stream: St,
     pub books: BookMap,
+    pub max_depth: Option<usize>,
+    pub sequence_gap: Option<SequenceGapDetector>,
+    pub resync_tx: Option<mpsc::UnboundedSender<BookMap::Key>>,
+}
+
+impl<St, BookMap> OrderBookL2Manager<St, BookMap>
+where
+    BookMap: OrderBookMap,
+{
+    /// Cap the depth retained in each managed [`OrderBook`] to `depth` levels per side.
+    pub fn with_max_depth(self, depth: usize) -> Self {
+        Self {
+            max_depth: Some(depth),
+            ..self
+        }
+    }
+
+    /// Flag [`OrderBook`]s as desynchronised when the provided [`SequenceGapDetector`] detects a
+    /// gap in their update sequence.
+    pub fn with_sequence_gap_detector(self, detector: SequenceGapDetector) -> Self {
+        Self {
+            sequence_gap: Some(detector),
+            ..self
+        }
+    }
+
+    /// Send the key of every [`OrderBook`] that becomes desynchronised via the provided
+    /// `resync_tx`, so a resubscription can be triggered.
+    pub fn with_resync_tx(self, resync_tx: mpsc::UnboundedSender<BookMap::Key>) -> Self {
+        Self {
+            resync_tx: Some(resync_tx),
+            ..self
+        }
+    }
 }
 
 impl<St, BookMap> OrderBookL2Manager<St, BookMap>
 where
     St: Stream<Item = MarketStreamEvent<BookMap::Key, OrderBookEvent>> + Unpin,
     BookMap: OrderBookMap,
-    BookMap::Key: Debug,
+    BookMap::Key: Debug + Clone,
 {
     /// Manage local L2 [`OrderBook`]s.
     pub async fn run(mut self) {
@@ -60,11 +107,60 @@ where
             };
 
             let mut book_lock = book.write();
+
+            if let (Some(detector), OrderBookEvent::Update(update)) =
+                (&self.sequence_gap, &event.kind)
+                && !book_lock.is_desynchronised()
+                && detector.is_gap(book_lock.sequence(), update.sequence())
+            {
+                warn!(
+                    exchange = %event.exchange,
+                    instrument = ?event.instrument,
+                    sequence = book_lock.sequence(),
+                    update_sequence = update.sequence(),
+                    "OrderBook sequence gap detected - desynchronised until next snapshot"
+                );
+                book_lock.mark_desynchronised();
+
+                if let Some(resync_tx) = &self.resync_tx
+                    && resync_tx.send(event.instrument.clone()).is_err()
+                {
+                    warn!(
+                        instrument = ?event.instrument,
+                        "OrderBook manager resync receiver dropped"
+                    );
+                }
+                continue;
+            }
+
             book_lock.update(&event.kind);
+            if let Some(depth) = self.max_depth {
+                book_lock.truncate(depth);
+            }
         }
     }
 }
 
+/// Detects gaps between the sequence numbers of consecutive L2 [`OrderBookEvent::Update`]s.
+///
+/// Exchanges differ in how they number updates, so the maximum permitted increment between an
+/// [`OrderBook`] sequence and the next update sequence is configurable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct SequenceGapDetector {
+    pub max_step: u64,
+}
+
+impl SequenceGapDetector {
+    /// Detector requiring every update sequence to directly follow the previous one.
+    pub const CONTIGUOUS: Self = Self { max_step: 1 };
+
+    /// Returns `true` if an update with `update_sequence` skips updates following an
+    /// [`OrderBook`] at `sequence`.
+    pub fn is_gap(&self, sequence: u64, update_sequence: u64) -> bool {
+        update_sequence > sequence.saturating_add(self.max_step)
+    }
+}
+
 /// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
 /// [`Subscription`]s.
 ///
@@ -122,5 +218,8 @@ where
     Ok(OrderBookL2Manager {
         stream,
         books: OrderBookMapMulti::new(books),
+        max_depth: None,
+        sequence_gap: None,
+        resync_tx: None,
     })
 }
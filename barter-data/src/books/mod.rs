@@ -20,6 +20,8 @@ pub struct OrderBook {
     time_engine: Option<DateTime<Utc>>,
     bids: OrderBookSide<Bids>,
     asks: OrderBookSide<Asks>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    desynchronised: bool,
 }
 
 impl OrderBook {
@@ -42,6 +44,7 @@ impl OrderBook {
             time_engine,
             bids: OrderBookSide::bids(bids),
             asks: OrderBookSide::asks(asks),
+            desynchronised: false,
         }
     }
 
@@ -62,14 +65,43 @@ impl OrderBook {
             time_engine: self.time_engine,
             bids: OrderBookSide::bids(self.bids.levels.iter().take(depth).copied()),
             asks: OrderBookSide::asks(self.asks.levels.iter().take(depth).copied()),
+            desynchronised: self.desynchronised,
         }
     }
 
+    /// Drop all but the best `depth` [`Level`]s on each side of the [`OrderBook`], in place.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+
+    /// Returns `true` if the [`OrderBook`] missed updates and no longer reflects the exchange
+    /// book. It is resynchronised by the next [`OrderBookEvent::Snapshot`].
+    pub fn is_desynchronised(&self) -> bool {
+        self.desynchronised
+    }
+
+    /// Flag the [`OrderBook`] as desynchronised, ignoring [`OrderBookEvent::Update`]s until the
+    /// next [`OrderBookEvent::Snapshot`] is applied.
+    pub fn mark_desynchronised(&mut self) {
+        self.desynchronised = true;
+    }
+
     /// Update the local [`OrderBook`] from a new [`OrderBookEvent`].
+    ///
+    /// Updates are ignored while the [`OrderBook`] is desynchronised, since applying them on top
+    /// of missed updates would corrupt the book.
     pub fn update(&mut self, event: &OrderBookEvent) {
         match event {
             OrderBookEvent::Snapshot(snapshot) => {
                 *self = snapshot.clone();
+                self.desynchronised = false;
+            }
+            OrderBookEvent::Update(update) if self.desynchronised => {
+                debug!(
+                    sequence = update.sequence,
+                    "ignoring OrderBook update while desynchronised"
+                );
             }
             OrderBookEvent::Update(update) => {
                 self.sequence = update.sequence;
@@ -210,6 +242,11 @@ where
         &self.levels
     }
 
+    /// Drop all but the best `depth` [`Level`]s of this [`OrderBookSide`].
+    pub fn truncate(&mut self, depth: usize) {
+        self.levels.truncate(depth);
+    }
+
     /// Upsert a single [`Level`] into this [`OrderBookSide`].
     ///
     /// ### Upsert Scenarios
@@ -606,6 +643,71 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_truncate() {
+            let mut book = OrderBook::new(
+                1,
+                None,
+                vec![
+                    Level::new(dec!(100.0), dec!(1.0)),
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(1.0)),
+                ],
+                vec![Level::new(dec!(101.0), dec!(1.0))],
+            );
+
+            book.truncate(2);
+
+            assert_eq!(
+                book.bids().levels(),
+                &[
+                    Level::new(dec!(100.0), dec!(1.0)),
+                    Level::new(dec!(99.0), dec!(1.0)),
+                ]
+            );
+            assert_eq!(book.asks().levels(), &[Level::new(dec!(101.0), dec!(1.0))]);
+        }
+    }
+
+    mod order_book_desynchronised {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_update_ignored_until_snapshot() {
+            let mut book = OrderBook::new(
+                1,
+                None,
+                vec![Level::new(dec!(100.0), dec!(1.0))],
+                vec![Level::new(dec!(101.0), dec!(1.0))],
+            );
+            book.mark_desynchronised();
+
+            let update = OrderBook::new(
+                2,
+                None,
+                vec![Level::new(dec!(100.0), dec!(5.0))],
+                Vec::<Level>::new(),
+            );
+            book.update(&OrderBookEvent::Update(update.clone()));
+            assert!(book.is_desynchronised());
+            assert_eq!(book.sequence(), 1);
+            assert_eq!(book.bids().levels(), &[Level::new(dec!(100.0), dec!(1.0))]);
+
+            let snapshot = OrderBook::new(
+                5,
+                None,
+                vec![Level::new(dec!(99.0), dec!(2.0))],
+                vec![Level::new(dec!(102.0), dec!(2.0))],
+            );
+            book.update(&OrderBookEvent::Snapshot(snapshot.clone()));
+            assert!(!book.is_desynchronised());
+            assert_eq!(book, snapshot);
+
+            book.update(&OrderBookEvent::Update(update));
+            assert_eq!(book.sequence(), 2);
+        }
     }
 
     mod order_book_side {
@@ -677,6 +779,20 @@ mod tests {
                         Level::new(dec!(100), dec!(1)),
                     ]),
                 },
+                TestCase {
+                    // TC4: Level exists with a different decimal scale => replace Level
+                    book_side: OrderBookSide::bids(vec![
+                        Level::new(dec!(80), dec!(1)),
+                        Level::new(dec!(90), dec!(1)),
+                        Level::new(dec!(100.1), dec!(1)),
+                    ]),
+                    new_level: Level::new(dec!(100.100), dec!(5)),
+                    expected: OrderBookSide::bids(vec![
+                        Level::new(dec!(80), dec!(1)),
+                        Level::new(dec!(90), dec!(1)),
+                        Level::new(dec!(100.1), dec!(5)),
+                    ]),
+                },
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
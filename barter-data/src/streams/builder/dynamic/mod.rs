@@ -20,11 +20,12 @@ use crate::{
     instrument::InstrumentData,
     streams::{
         consumer::{MarketStreamResult, STREAM_RECONNECTION_POLICY, init_market_stream},
-        reconnect::stream::ReconnectingStream,
+        reconnect::stream::{ReconnectingStream, ReconnectionBackoffPolicy},
     },
     subscription::{
         SubKind, Subscription,
         book::{OrderBookEvent, OrderBookL1, OrderBooksL1, OrderBooksL2},
+        candle::Candle,
         liquidation::{Liquidation, Liquidations},
         trade::{PublicTrade, PublicTrades},
     },
@@ -60,6 +61,8 @@ pub struct DynamicStreams<InstrumentKey> {
     >,
     pub liquidations:
         VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    pub candles:
+        VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Candle>>>,
 }
 
 impl<InstrumentKey> DynamicStreams<InstrumentKey> {
@@ -75,6 +78,49 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     pub async fn init<SubBatchIter, SubIter, Sub, Instrument>(
         subscription_batches: SubBatchIter,
     ) -> Result<Self, DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        InstrumentKey: Debug + Clone + Send + 'static,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+        Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+        Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+    {
+        Self::init_with_policy(subscription_batches, STREAM_RECONNECTION_POLICY).await
+    }
+
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, using the
+    /// provided [`ReconnectionBackoffPolicy`] for every underlying reconnecting `Stream`.
+    ///
+    /// See [`Self::init`] for how batches are split into WebSocket `Stream`s.
+    pub async fn init_with_policy<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+        policy: ReconnectionBackoffPolicy,
+    ) -> Result<Self, DataError>
     where
         SubBatchIter: IntoIterator<Item = SubIter>,
         SubIter: IntoIterator<Item = Sub>,
@@ -129,7 +175,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 match (exchange, sub_kind) {
                                     (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -149,7 +195,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -169,7 +215,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -189,7 +235,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -209,7 +255,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -229,7 +275,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -249,7 +295,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -269,7 +315,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -289,7 +335,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::Bitmex, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -309,7 +355,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -329,7 +375,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitSpot, SubKind::OrderBooksL1) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -349,7 +395,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -369,7 +415,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -389,7 +435,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL1) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -409,7 +455,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -429,7 +475,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::Coinbase, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -449,7 +495,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -469,7 +515,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -489,7 +535,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -509,7 +555,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -529,7 +575,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -549,7 +595,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -569,7 +615,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::Kraken, SubKind::PublicTrades) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -589,7 +635,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     }
                                     (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
                                         init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
+                                            policy,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -608,7 +654,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         })
                                     }
                                     (ExchangeId::Okx, SubKind::PublicTrades) => init_market_stream(
-                                        STREAM_RECONNECTION_POLICY,
+                                        policy,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(Okx, sub.instrument, PublicTrades)
@@ -660,6 +706,12 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
+            candles: channels
+                .rxs
+                .candles
+                .into_iter()
+                .map(|(exchange, rx)| (exchange, rx.into_stream()))
+                .collect(),
         })
     }
 
@@ -737,6 +789,26 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         )
     }
 
+    /// Remove an exchange [`Candle`] `Stream` from the [`DynamicStreams`] collection.
+    ///
+    /// Note that calling this method will permanently remove this `Stream` from [`Self`].
+    pub fn select_candles(
+        &mut self,
+        exchange: ExchangeId,
+    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Candle>>> {
+        self.candles.remove(&exchange)
+    }
+
+    /// Select and merge every exchange [`Candle`] `Stream` using
+    /// [`SelectAll`](futures_util::stream::select_all::select_all).
+    pub fn select_all_candles(
+        &mut self,
+    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Candle>>> {
+        futures_util::stream::select_all::select_all(
+            std::mem::take(&mut self.candles).into_values(),
+        )
+    }
+
     /// Select and merge every exchange `Stream` for every data type using [`select_all`](futures_util::stream::select_all::select_all)
     ///
     /// Note that using [`MarketStreamResult<Instrument, DataKind>`] as the `Output` is suitable for most
@@ -749,12 +821,14 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         MarketStreamResult<InstrumentKey, OrderBookL1>: Into<Output>,
         MarketStreamResult<InstrumentKey, OrderBookEvent>: Into<Output>,
         MarketStreamResult<InstrumentKey, Liquidation>: Into<Output>,
+        MarketStreamResult<InstrumentKey, Candle>: Into<Output>,
     {
         let Self {
             trades,
             l1s,
             l2s,
             liquidations,
+            candles,
         } = self;
 
         let trades = trades
@@ -773,7 +847,15 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
             .into_values()
             .map(|stream| stream.map(MarketStreamResult::into).boxed());
 
-        let all = trades.chain(l1s).chain(l2s).chain(liquidations);
+        let candles = candles
+            .into_values()
+            .map(|stream| stream.map(MarketStreamResult::into).boxed());
+
+        let all = trades
+            .chain(l1s)
+            .chain(l2s)
+            .chain(liquidations)
+            .chain(candles);
 
         futures_util::stream::select_all::select_all(all)
     }
@@ -870,6 +952,16 @@ where
                         rxs.liquidations.insert(sub.exchange, rx);
                     }
                 }
+                SubKind::Candles => {
+                    if let (None, None) = (
+                        txs.candles.get(&sub.exchange),
+                        rxs.candles.get(&sub.exchange),
+                    ) {
+                        let (tx, rx) = mpsc_unbounded();
+                        txs.candles.insert(sub.exchange, tx);
+                        rxs.candles.insert(sub.exchange, rx);
+                    }
+                }
                 unsupported => return Err(DataError::UnsupportedSubKind(unsupported)),
             }
         }
@@ -887,6 +979,7 @@ struct Txs<InstrumentKey> {
     l2s: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
         FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    candles: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, Candle>>>,
 }
 
 impl<InstrumentKey> Default for Txs<InstrumentKey> {
@@ -896,6 +989,7 @@ impl<InstrumentKey> Default for Txs<InstrumentKey> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            candles: Default::default(),
         }
     }
 }
@@ -906,6 +1000,7 @@ struct Rxs<InstrumentKey> {
     l2s: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
         FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    candles: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, Candle>>>,
 }
 
 impl<InstrumentKey> Default for Rxs<InstrumentKey> {
@@ -915,6 +1010,7 @@ impl<InstrumentKey> Default for Rxs<InstrumentKey> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            candles: Default::default(),
         }
     }
 }
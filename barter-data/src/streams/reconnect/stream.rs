@@ -1,6 +1,5 @@
 use crate::streams::{consumer::StreamKey, reconnect::Event};
 use barter_integration::channel::Tx;
-use derive_more::Constructor;
 use futures::Stream;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
@@ -35,6 +34,16 @@ where
                         futures::future::Either::Left(future::ready(Some(Ok(stream))))
                     }
                     Err(error) => {
+                        if state.attempts_exhausted() {
+                            error!(
+                                attempt,
+                                ?stream_key,
+                                ?error,
+                                "failed to re-initialise Stream within max_attempts, terminating"
+                            );
+                            return futures::future::Either::Left(future::ready(None));
+                        }
+
                         warn!(
                             attempt,
                             ?stream_key,
@@ -155,9 +164,7 @@ where
 }
 
 /// Reconnection backoff policy for a [`ReconnectingStream::with_reconnect_backoff`].
-#[derive(
-    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, Constructor,
-)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct ReconnectionBackoffPolicy {
     /// Initial backoff millisecond duration after the first `Stream` disconnection.
     ///
@@ -171,18 +178,45 @@ pub struct ReconnectionBackoffPolicy {
 
     /// Maximum possible backoff duration between reconnection attempts.
     pub backoff_ms_max: u64,
+
+    /// Maximum number of consecutive failed reconnection attempts before the `Stream` is
+    /// terminated. `None` retries indefinitely.
+    #[serde(default)]
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectionBackoffPolicy {
+    /// Construct a new [`Self`] that retries reconnecting indefinitely.
+    pub fn new(backoff_ms_initial: u64, backoff_multiplier: u8, backoff_ms_max: u64) -> Self {
+        Self {
+            backoff_ms_initial,
+            backoff_multiplier,
+            backoff_ms_max,
+            max_attempts: None,
+        }
+    }
+
+    /// Terminate the `Stream` after `max_attempts` consecutive failed reconnection attempts.
+    pub fn with_max_attempts(self, max_attempts: u32) -> Self {
+        Self {
+            max_attempts: Some(max_attempts),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 struct ReconnectionState {
     policy: ReconnectionBackoffPolicy,
     backoff_ms_current: u64,
+    failed_attempts: u32,
 }
 
 impl From<ReconnectionBackoffPolicy> for ReconnectionState {
     fn from(policy: ReconnectionBackoffPolicy) -> Self {
         Self {
             backoff_ms_current: policy.backoff_ms_initial,
+            failed_attempts: 0,
             policy,
         }
     }
@@ -191,6 +225,16 @@ impl From<ReconnectionBackoffPolicy> for ReconnectionState {
 impl ReconnectionState {
     fn reset_backoff(&mut self) {
         self.backoff_ms_current = self.policy.backoff_ms_initial;
+        self.failed_attempts = 0;
+    }
+
+    /// Record a failed reconnection attempt, returning true if the policy's `max_attempts` has
+    /// now been reached.
+    fn attempts_exhausted(&mut self) -> bool {
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        self.policy
+            .max_attempts
+            .is_some_and(|max_attempts| self.failed_attempts >= max_attempts)
     }
 
     fn multiply_backoff(&mut self) {
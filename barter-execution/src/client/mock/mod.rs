@@ -16,23 +16,104 @@ use barter_instrument::{
     exchange::ExchangeId,
     instrument::name::InstrumentNameExchange,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
 use derive_more::Constructor;
 use futures::stream::BoxStream;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tracing::error;
 
-#[derive(
-    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
-)]
+/// Configuration of a `MockExchange`.
+///
+/// `Market` orders fill immediately at their requested price. `Limit` orders rest until a market
+/// price crosses them, filling in full at their limit price and paying the `maker_fee`. Within a
+/// trading system the `Engine` forwards the price of every market event it processes to its
+/// execution clients, while a standalone [`MockExecution`] is supplied prices via
+/// [`ExecutionClient::update_market_price`]. Resting orders also end when cancelled or when their
+/// time in force elapses (see `day_boundary`).
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub struct MockExecutionConfig {
     pub mocked_exchange: ExchangeId,
     pub initial_state: UnindexedAccountSnapshot,
     pub latency_ms: u64,
+    /// Upper bound of the simulated latency. If set, each request is delayed by a latency
+    /// sampled uniformly from `latency_ms..=latency_max_ms`, otherwise `latency_ms` is fixed.
+    #[serde(default)]
+    pub latency_max_ms: Option<u64>,
     pub fees_percent: Decimal,
+    /// Fee rate, as a fraction of the fill value, charged on resting (maker) fills, ie/ `Limit`
+    /// orders crossed by a market price. Falls back to `fees_percent` if unset.
+    #[serde(default)]
+    pub maker_fee: Option<Decimal>,
+    /// Fee rate, as a fraction of the fill value, charged on market and immediate-or-cancel
+    /// (taker) fills. Falls back to `fees_percent` if unset.
+    #[serde(default)]
+    pub taker_fee: Option<Decimal>,
+    /// Maximum quantity filled per tick for each instrument. Orders larger than their
+    /// instrument's cap are filled by a sequence of partial fill trades, one latency apart.
+    #[serde(default)]
+    pub max_fill_quantity: BTreeMap<InstrumentNameExchange, Decimal>,
+    /// UTC time of day at which `GoodUntilEndOfDay` orders expire. Defaults to midnight.
+    #[serde(default)]
+    pub day_boundary: NaiveTime,
+}
+
+impl MockExecutionConfig {
+    /// Construct a `MockExecutionConfig` with a fixed latency, a single fee rate, no partial
+    /// fills and a midnight day boundary.
+    pub fn new(
+        mocked_exchange: ExchangeId,
+        initial_state: UnindexedAccountSnapshot,
+        latency_ms: u64,
+        fees_percent: Decimal,
+    ) -> Self {
+        Self {
+            mocked_exchange,
+            initial_state,
+            latency_ms,
+            latency_max_ms: None,
+            fees_percent,
+            maker_fee: None,
+            taker_fee: None,
+            max_fill_quantity: BTreeMap::new(),
+            day_boundary: NaiveTime::default(),
+        }
+    }
+
+    pub fn with_latency_max_ms(self, latency_max_ms: Option<u64>) -> Self {
+        Self {
+            latency_max_ms,
+            ..self
+        }
+    }
+
+    pub fn with_maker_fee(self, maker_fee: Option<Decimal>) -> Self {
+        Self { maker_fee, ..self }
+    }
+
+    pub fn with_taker_fee(self, taker_fee: Option<Decimal>) -> Self {
+        Self { taker_fee, ..self }
+    }
+
+    pub fn with_max_fill_quantity(
+        self,
+        max_fill_quantity: BTreeMap<InstrumentNameExchange, Decimal>,
+    ) -> Self {
+        Self {
+            max_fill_quantity,
+            ..self
+        }
+    }
+
+    pub fn with_day_boundary(self, day_boundary: NaiveTime) -> Self {
+        Self {
+            day_boundary,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Constructor)]
@@ -86,6 +167,34 @@ where
     pub fn time_request(&self) -> DateTime<Utc> {
         (self.clock)()
     }
+
+    /// Advance the `MockExchange` clock by `delta`, returning the new exchange time.
+    ///
+    /// Open orders whose time in force elapses are expired by the `MockExchange`.
+    pub async fn advance_time(
+        &self,
+        delta: TimeDelta,
+    ) -> Result<DateTime<Utc>, UnindexedClientError> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.request_tx
+            .send(MockExchangeRequest::advance_time(
+                self.time_request(),
+                response_tx,
+                delta,
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })?;
+
+        response_rx.await.map_err(|_| {
+            UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                self.mocked_exchange,
+            ))
+        })
+    }
 }
 
 impl<FnTime> ExecutionClient for MockExecution<FnTime>
@@ -310,6 +419,26 @@ where
             ))
         })
     }
+
+    /// Supply the latest market `price` of an `instrument` to the `MockExchange`, filling any
+    /// resting `Limit` orders it crosses.
+    fn update_market_price(
+        &self,
+        instrument: &InstrumentNameExchange,
+        price: Decimal,
+    ) -> Result<(), UnindexedClientError> {
+        self.request_tx
+            .send(MockExchangeRequest::market_price(
+                self.time_request(),
+                instrument.clone(),
+                price,
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })
+    }
 }
 
 fn into_owned_request<Kind>(
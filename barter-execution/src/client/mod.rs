@@ -16,6 +16,7 @@ use barter_instrument::{
 };
 use chrono::{DateTime, Utc};
 use futures::Stream;
+use rust_decimal::Decimal;
 use std::future::Future;
 
 mod binance;
@@ -96,4 +97,16 @@ where
         &self,
         time_since: DateTime<Utc>,
     ) -> impl Future<Output = Result<Vec<Trade<QuoteAsset, InstrumentNameExchange>>, UnindexedClientError>>;
+
+    /// Supply the latest market `price` of an `instrument`, as observed by the trading system.
+    ///
+    /// Live exchanges match orders against their own order books, so this is a no-op by default.
+    /// Simulated exchanges use it to fill the resting `Limit` orders the price crosses.
+    fn update_market_price(
+        &self,
+        _instrument: &InstrumentNameExchange,
+        _price: Decimal,
+    ) -> Result<(), UnindexedClientError> {
+        Ok(())
+    }
 }
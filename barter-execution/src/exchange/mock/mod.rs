@@ -8,30 +8,34 @@ use crate::{
         request::{MockExchangeRequest, MockExchangeRequestKind},
     },
     order::{
-        Order, OrderKind, UnindexedOrder,
-        id::OrderId,
-        request::{OrderRequestCancel, OrderRequestOpen},
-        state::{Cancelled, Open},
+        Order, OrderEvent, OrderKind, TimeInForce, UnindexedOrder,
+        id::{ClientOrderId, OrderId},
+        request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
+        state::{Cancelled, Open, OrderState},
     },
     trade::{AssetFees, Trade, TradeId},
 };
 use barter_instrument::{
-    Side,
+    Side, Underlying,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
     instrument::{Instrument, name::InstrumentNameExchange},
 };
 use barter_integration::snapshot::Snapshot;
-use chrono::{DateTime, TimeDelta, Utc};
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
 use fnv::FnvHashMap;
 use futures::stream::BoxStream;
 use itertools::Itertools;
+use rand::Rng;
 use rust_decimal::Decimal;
-use smol_str::ToSmolStr;
-use std::fmt::Debug;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use smol_str::{ToSmolStr, format_smolstr};
+use std::{collections::BTreeMap, fmt::Debug};
+use tokio::{
+    sync::{broadcast, mpsc, oneshot},
+    time::Instant,
+};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 pub mod account;
 pub mod request;
@@ -40,13 +44,22 @@ pub mod request;
 pub struct MockExchange {
     pub exchange: ExchangeId,
     pub latency_ms: u64,
+    pub latency_max_ms: Option<u64>,
     pub fees_percent: Decimal,
+    pub maker_fee: Option<Decimal>,
+    pub taker_fee: Option<Decimal>,
+    pub max_fill_quantity: BTreeMap<InstrumentNameExchange, Decimal>,
+    pub day_boundary: NaiveTime,
     pub request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
     pub event_tx: broadcast::Sender<UnindexedAccountEvent>,
     pub instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     pub account: AccountState,
     pub order_sequence: u64,
     pub time_exchange_latest: DateTime<Utc>,
+    /// Wall clock [`Instant`] at which `time_exchange_latest` was last updated.
+    pub instant_exchange_latest: Instant,
+    pub time_offset: TimeDelta,
+    pub order_expiries: FnvHashMap<ClientOrderId, DateTime<Utc>>,
 }
 
 impl MockExchange {
@@ -59,24 +72,52 @@ impl MockExchange {
         Self {
             exchange: config.mocked_exchange,
             latency_ms: config.latency_ms,
+            latency_max_ms: config.latency_max_ms,
             fees_percent: config.fees_percent,
+            maker_fee: config.maker_fee,
+            taker_fee: config.taker_fee,
+            max_fill_quantity: config.max_fill_quantity,
+            day_boundary: config.day_boundary,
             request_rx,
             event_tx,
             instruments,
             account: AccountState::from(config.initial_state),
             order_sequence: 0,
             time_exchange_latest: Default::default(),
+            instant_exchange_latest: Instant::now(),
+            time_offset: TimeDelta::zero(),
+            order_expiries: FnvHashMap::default(),
         }
     }
 
     pub async fn run(mut self) {
-        while let Some(request) = self.request_rx.recv().await {
-            self.update_time_exchange(request.time_request);
+        loop {
+            // Wake at the next order expiry so resting orders expire without further requests
+            let next = match self.instant_next_expiry() {
+                Some(deadline) => tokio::select! {
+                    request = self.request_rx.recv() => Some(request),
+                    _ = tokio::time::sleep_until(deadline) => None,
+                },
+                None => Some(self.request_rx.recv().await),
+            };
+
+            let Some(request) = next else {
+                self.update_time_exchange_elapsed();
+                self.expire_orders(self.latency_ms);
+                continue;
+            };
+
+            let Some(request) = request else {
+                break;
+            };
+
+            let latency_ms = self.sample_latency_ms();
+            self.update_time_exchange(request.time_request, latency_ms);
 
             match request.kind {
                 MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
                     let snapshot = self.account_snapshot();
-                    self.respond_with_latency(response_tx, snapshot);
+                    self.respond_with_latency(response_tx, snapshot, latency_ms);
                 }
                 MockExchangeRequestKind::FetchBalances {
                     response_tx,
@@ -88,7 +129,7 @@ impl MockExchange {
                         .filter(|balance| assets.contains(&balance.asset))
                         .cloned()
                         .collect();
-                    self.respond_with_latency(response_tx, balances);
+                    self.respond_with_latency(response_tx, balances, latency_ms);
                 }
                 MockExchangeRequestKind::FetchOrdersOpen {
                     response_tx,
@@ -100,53 +141,98 @@ impl MockExchange {
                         .filter(|order| instruments.contains(&order.key.instrument))
                         .cloned()
                         .collect();
-                    self.respond_with_latency(response_tx, orders_open);
+                    self.respond_with_latency(response_tx, orders_open, latency_ms);
                 }
                 MockExchangeRequestKind::FetchTrades {
                     response_tx,
                     time_since,
                 } => {
                     let trades = self.account.trades(time_since).cloned().collect();
-                    self.respond_with_latency(response_tx, trades);
+                    self.respond_with_latency(response_tx, trades, latency_ms);
                 }
                 MockExchangeRequestKind::CancelOrder {
-                    response_tx: _,
+                    response_tx,
                     request,
                 } => {
-                    error!(
-                        exchange = %self.exchange,
-                        ?request,
-                        "MockExchange received cancel request but only Market orders are supported"
-                    );
+                    let response = self.cancel_order(request);
+                    self.respond_with_latency(response_tx, response, latency_ms);
                 }
                 MockExchangeRequestKind::OpenOrder {
                     response_tx,
                     request,
                 } => {
                     let (response, notifications) = self.open_order(request);
-                    self.respond_with_latency(response_tx, response);
+                    self.respond_with_latency(response_tx, response, latency_ms);
 
                     if let Some(notifications) = notifications {
-                        self.account.ack_trade(notifications.trade.clone());
-                        self.send_notifications_with_latency(notifications);
+                        self.settle_notifications(notifications, latency_ms);
+                    }
+                }
+                MockExchangeRequestKind::MarketPrice { instrument, price } => {
+                    for notifications in self.match_limit_orders(&instrument, price) {
+                        self.settle_notifications(notifications, latency_ms);
                     }
                 }
+                MockExchangeRequestKind::AdvanceTime { response_tx, delta } => {
+                    self.time_offset += delta;
+                    self.update_time_exchange(request.time_request, latency_ms);
+                    self.respond_with_latency(response_tx, self.time_exchange(), latency_ms);
+                }
             }
+
+            self.expire_orders(latency_ms);
         }
 
         info!(exchange = %self.exchange, "MockExchange shutting down");
     }
 
-    fn update_time_exchange(&mut self, time_request: DateTime<Utc>) {
-        let client_to_exchange_latency = self.latency_ms / 2;
+    /// Sample the simulated latency of a single request, uniformly from
+    /// `latency_ms..=latency_max_ms` if a range is configured.
+    fn sample_latency_ms(&self) -> u64 {
+        sample_latency_ms(self.latency_ms, self.latency_max_ms, &mut rand::rng())
+    }
+
+    fn update_time_exchange(&mut self, time_request: DateTime<Utc>, latency_ms: u64) {
+        let client_to_exchange_latency = latency_ms / 2;
+
+        let time_request = time_request
+            .checked_add_signed(self.time_offset)
+            .unwrap_or(time_request);
 
         self.time_exchange_latest = time_request
             .checked_add_signed(TimeDelta::milliseconds(client_to_exchange_latency as i64))
             .unwrap_or(time_request);
 
+        self.instant_exchange_latest = Instant::now();
+        self.account.update_time_exchange(self.time_exchange_latest)
+    }
+
+    /// Advance the exchange clock by the wall clock time elapsed since it was last updated.
+    fn update_time_exchange_elapsed(&mut self) {
+        let elapsed = TimeDelta::from_std(self.instant_exchange_latest.elapsed())
+            .unwrap_or_else(|_| TimeDelta::zero());
+
+        self.time_exchange_latest = self
+            .time_exchange_latest
+            .checked_add_signed(elapsed)
+            .unwrap_or(self.time_exchange_latest);
+
+        self.instant_exchange_latest = Instant::now();
         self.account.update_time_exchange(self.time_exchange_latest)
     }
 
+    /// Wall clock [`Instant`] at which the earliest open order expiry is reached, assuming the
+    /// exchange clock advances in real time, or `None` if no orders are due to expire.
+    fn instant_next_expiry(&self) -> Option<Instant> {
+        let time_expiry = self.order_expiries.values().min()?;
+        let until_expiry = time_expiry
+            .signed_duration_since(self.time_exchange_latest)
+            .to_std()
+            .unwrap_or_default();
+
+        Some(self.instant_exchange_latest + until_expiry)
+    }
+
     pub fn time_exchange(&self) -> DateTime<Utc> {
         self.time_exchange_latest
     }
@@ -193,11 +279,12 @@ impl MockExchange {
         &self,
         response_tx: oneshot::Sender<Response>,
         response: Response,
+        latency_ms: u64,
     ) where
         Response: Send + 'static,
     {
         let exchange = self.exchange;
-        let latency = std::time::Duration::from_millis(self.latency_ms);
+        let latency = std::time::Duration::from_millis(latency_ms);
 
         tokio::spawn(async move {
             tokio::time::sleep(latency).await;
@@ -211,17 +298,51 @@ impl MockExchange {
         });
     }
 
+    /// Sends the provided [`UnindexedAccountEvent`] via the `MockExchanges`
+    /// `broadcast::Sender<UnindexedAccountEvent>` after waiting for the latency [`Duration`].
+    fn send_event_with_latency(&self, event: UnindexedAccountEvent, latency_ms: u64) {
+        let exchange = self.exchange;
+        let latency = std::time::Duration::from_millis(latency_ms);
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(latency).await;
+            if tx.send(event).is_err() {
+                error!(
+                    %exchange,
+                    "MockExchange failed to send AccountEvent notification to client"
+                );
+            }
+        });
+    }
+
     /// Sends the provided `OpenOrderNotifications` via the `MockExchanges`
     /// `broadcast::Sender<UnindexedAccountEvent>` after waiting for the latency
     /// [`Duration`].
     ///
+    /// Partial fills are sent one latency [`Duration`] apart, each trade followed by the
+    /// resulting order snapshot.
+    ///
     /// Used to simulate network latency between the exchange and client.
-    fn send_notifications_with_latency(&self, notifications: OpenOrderNotifications) {
+    fn send_notifications_with_latency(
+        &self,
+        notifications: OpenOrderNotifications,
+        latency_ms: u64,
+    ) {
         let balance = self.build_account_event(notifications.balance);
-        let trade = self.build_account_event(notifications.trade);
+        let fills = notifications
+            .fills
+            .into_iter()
+            .map(|fill| {
+                (
+                    self.build_account_event(fill.trade),
+                    fill.order
+                        .map(|order| self.build_account_event(Snapshot(order))),
+                )
+            })
+            .collect::<Vec<_>>();
 
         let exchange = self.exchange;
-        let latency = std::time::Duration::from_millis(self.latency_ms);
+        let latency = std::time::Duration::from_millis(latency_ms);
         let tx = self.event_tx.clone();
         tokio::spawn(async move {
             tokio::time::sleep(latency).await;
@@ -234,16 +355,40 @@ impl MockExchange {
                 );
             }
 
-            if tx.send(trade).is_err() {
-                error!(
-                    %exchange,
-                    kind = "Trade<QuoteAsset, InstrumentNameExchange>",
-                    "MockExchange failed to send AccountEvent notification to client"
-                );
+            for (index, (trade, order)) in fills.into_iter().enumerate() {
+                if index > 0 {
+                    tokio::time::sleep(latency).await;
+                }
+
+                if tx.send(trade).is_err() {
+                    error!(
+                        %exchange,
+                        kind = "Trade<QuoteAsset, InstrumentNameExchange>",
+                        "MockExchange failed to send AccountEvent notification to client"
+                    );
+                }
+
+                if let Some(order) = order
+                    && tx.send(order).is_err()
+                {
+                    error!(
+                        %exchange,
+                        kind = "Snapshot<Order<ExchangeId, InstrumentNameExchange, OrderState>>",
+                        "MockExchange failed to send AccountEvent notification to client"
+                    );
+                }
             }
         });
     }
 
+    /// Record the trades of the provided `OpenOrderNotifications` and send them to the client.
+    fn settle_notifications(&mut self, notifications: OpenOrderNotifications, latency_ms: u64) {
+        for fill in &notifications.fills {
+            self.account.ack_trade(fill.trade.clone());
+        }
+        self.send_notifications_with_latency(notifications, latency_ms);
+    }
+
     pub fn account_stream(&self) -> BoxStream<'static, UnindexedAccountEvent> {
         futures::StreamExt::boxed(BroadcastStream::new(self.event_tx.subscribe()).map_while(
             |result| match result {
@@ -259,11 +404,48 @@ impl MockExchange {
         ))
     }
 
+    /// Cancel a resting order, identified by its [`ClientOrderId`].
     pub fn cancel_order(
         &mut self,
-        _: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
-    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Cancelled, UnindexedOrderError>> {
-        unimplemented!()
+        request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
+    ) -> UnindexedOrderResponseCancel {
+        let Some(order) = self.account.remove_order_open(&request.key.cid) else {
+            let error = if self.account.is_order_cancelled(&request.key.cid) {
+                ApiError::OrderAlreadyCancelled
+            } else {
+                ApiError::OrderRejected(format!(
+                    "MockExchange has no open order with cid: {}",
+                    request.key.cid
+                ))
+            };
+
+            return OrderEvent {
+                key: request.key,
+                state: Err(UnindexedOrderError::Rejected(error)),
+            };
+        };
+
+        self.order_expiries.remove(&request.key.cid);
+
+        let cancelled = Cancelled {
+            id: order.state.id,
+            time_exchange: self.time_exchange(),
+        };
+
+        self.account.insert_order_cancelled(Order {
+            key: order.key,
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            kind: order.kind,
+            time_in_force: order.time_in_force,
+            state: cancelled.clone(),
+        });
+
+        OrderEvent {
+            key: request.key,
+            state: Ok(cancelled),
+        }
     }
 
     pub fn open_order(
@@ -273,8 +455,8 @@ impl MockExchange {
         Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
         Option<OpenOrderNotifications>,
     ) {
-        if let Err(error) = self.validate_order_kind_supported(request.state.kind) {
-            return (build_open_order_err_response(request, error), None);
+        if request.state.kind == OrderKind::Limit {
+            return (self.open_limit_order(request), None);
         }
 
         let underlying = match self.find_instrument_data(&request.key.instrument) {
@@ -282,9 +464,98 @@ impl MockExchange {
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
+        let fee_rate = self.fee_rate(request.state.kind, request.state.time_in_force);
+        let balance_change_result = self.apply_fill_to_balance(
+            &underlying,
+            request.state.side,
+            request.state.price,
+            request.state.quantity,
+            fee_rate,
+        );
+
+        let (balance_snapshot, fees) = match balance_change_result {
+            Ok((balance_snapshot, fees)) => (Snapshot(balance_snapshot), fees),
+            Err(error) => return (build_open_order_err_response(request, error), None),
+        };
+
+        let order_id = self.order_id_sequence_fetch_add();
+        let trade_id = TradeId(order_id.0.clone());
+
+        let trade = Trade {
+            id: trade_id,
+            order_id: order_id.clone(),
+            instrument: request.key.instrument.clone(),
+            strategy: request.key.strategy.clone(),
+            time_exchange: self.time_exchange(),
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            fees,
+        };
+
+        let max_fill_quantity = self
+            .max_fill_quantity
+            .get(&request.key.instrument)
+            .copied()
+            .filter(|max| *max > Decimal::ZERO && *max < request.state.quantity);
+
+        let (filled_quantity, fills) = match max_fill_quantity {
+            Some(max_fill_quantity) => {
+                let order = Order {
+                    key: request.key.clone(),
+                    side: request.state.side,
+                    price: request.state.price,
+                    quantity: request.state.quantity,
+                    kind: request.state.kind,
+                    time_in_force: request.state.time_in_force,
+                    state: (),
+                };
+                (
+                    Decimal::ZERO,
+                    split_into_partial_fills(order, trade, max_fill_quantity),
+                )
+            }
+            None => (
+                request.state.quantity,
+                vec![MockFill { trade, order: None }],
+            ),
+        };
+
+        let order_response = Order {
+            key: request.key,
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            state: Ok(Open {
+                id: order_id,
+                time_exchange: self.time_exchange(),
+                filled_quantity,
+            }),
+        };
+
+        let notifications = OpenOrderNotifications {
+            balance: balance_snapshot,
+            fills,
+        };
+
+        (order_response, Some(notifications))
+    }
+
+    /// Debit the balance required to fill `quantity` at `price`, including fees charged at
+    /// `fee_rate`, returning the updated balance and the fees in the quote asset.
+    fn apply_fill_to_balance(
+        &mut self,
+        underlying: &Underlying<AssetNameExchange>,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        fee_rate: Decimal,
+    ) -> Result<(AssetBalance<AssetNameExchange>, AssetFees<QuoteAsset>), UnindexedApiError> {
         let time_exchange = self.time_exchange();
 
-        let balance_change_result = match request.state.side {
+        match side {
             Side::Buy => {
                 // Buying Instrument requires sufficient QuoteAsset Balance
                 let current = self
@@ -292,11 +563,11 @@ impl MockExchange {
                     .balance_mut(&underlying.quote)
                     .expect("MockExchange has Balance for all configured Instrument assets");
 
-                // Currently we only supported MarketKind orders, so they should be identical
+                // Resting Limit orders do not reserve balance, so they should be identical
                 assert_eq!(current.balance.total, current.balance.free);
 
-                let order_value_quote = request.state.price * request.state.quantity.abs();
-                let order_fees_quote = order_value_quote * self.fees_percent;
+                let order_value_quote = price * quantity.abs();
+                let order_fees_quote = order_value_quote * fee_rate;
                 let quote_required = order_value_quote + order_fees_quote;
 
                 let maybe_new_balance = current.balance.free - quote_required;
@@ -309,7 +580,7 @@ impl MockExchange {
                     Ok((current.clone(), AssetFees::quote_fees(order_fees_quote)))
                 } else {
                     Err(ApiError::BalanceInsufficient(
-                        underlying.quote,
+                        underlying.quote.clone(),
                         format!(
                             "Available Balance: {}, Required Balance inc. fees: {}",
                             current.balance.free, quote_required
@@ -324,11 +595,11 @@ impl MockExchange {
                     .balance_mut(&underlying.quote)
                     .expect("MockExchange has Balance for all configured Instrument assets");
 
-                // Currently we only supported MarketKind orders, so they should be identical
+                // Resting Limit orders do not reserve balance, so they should be identical
                 assert_eq!(current.balance.total, current.balance.free);
 
-                let order_value_base = request.state.quantity.abs();
-                let order_fees_base = order_value_base * self.fees_percent;
+                let order_value_base = quantity.abs();
+                let order_fees_base = order_value_base * fee_rate;
                 let base_required = order_value_base + order_fees_base;
 
                 let maybe_new_balance = current.balance.free - base_required;
@@ -338,12 +609,12 @@ impl MockExchange {
                     current.balance.total = maybe_new_balance;
                     current.time_exchange = time_exchange;
 
-                    let fees_quote = order_fees_base * request.state.price;
+                    let fees_quote = order_fees_base * price;
 
                     Ok((current.clone(), AssetFees::quote_fees(fees_quote)))
                 } else {
                     Err(ApiError::BalanceInsufficient(
-                        underlying.quote,
+                        underlying.quote.clone(),
                         format!(
                             "Available Balance: {}, Required Balance inc. fees: {}",
                             current.balance.free, base_required
@@ -351,61 +622,224 @@ impl MockExchange {
                     ))
                 }
             }
-        };
+        }
+    }
 
-        let (balance_snapshot, fees) = match balance_change_result {
-            Ok((balance_snapshot, fees)) => (Snapshot(balance_snapshot), fees),
-            Err(error) => return (build_open_order_err_response(request, error), None),
+    /// Open a `Limit` order.
+    ///
+    /// `Limit` orders rest until a market price supplied via [`Self::match_limit_orders`]
+    /// crosses them, or until they are cancelled or expire: immediate-or-cancel and fill-or-kill
+    /// orders expire at once, `GoodUntilEndOfDay` orders expire at the next `day_boundary`, and
+    /// `GoodUntilCancelled` orders never expire.
+    fn open_limit_order(
+        &mut self,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>> {
+        if let Err(error) = self.find_instrument_data(&request.key.instrument) {
+            return build_open_order_err_response(request, error);
+        }
+
+        let time_exchange = self.time_exchange();
+        let time_expiry = match request.state.time_in_force {
+            TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => Some(time_exchange),
+            TimeInForce::GoodUntilEndOfDay => Some(self.next_day_boundary(time_exchange)),
+            TimeInForce::GoodUntilCancelled { .. } => None,
         };
 
-        let order_id = self.order_id_sequence_fetch_add();
-        let trade_id = TradeId(order_id.0.clone());
+        let open = Open {
+            id: self.order_id_sequence_fetch_add(),
+            time_exchange,
+            filled_quantity: Decimal::ZERO,
+        };
 
-        let order_response = Order {
+        if let Some(time_expiry) = time_expiry {
+            self.order_expiries
+                .insert(request.key.cid.clone(), time_expiry);
+        }
+        self.account.insert_order_open(Order {
             key: request.key.clone(),
             side: request.state.side,
             price: request.state.price,
             quantity: request.state.quantity,
             kind: request.state.kind,
             time_in_force: request.state.time_in_force,
-            state: Ok(Open {
-                id: order_id.clone(),
-                time_exchange: self.time_exchange(),
-                filled_quantity: request.state.quantity,
-            }),
+            state: open.clone(),
+        });
+
+        Order {
+            key: request.key,
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            state: Ok(open),
+        }
+    }
+
+    /// Fill every resting `Limit` order for the `instrument` crossed by the market `price`, ie/
+    /// buys priced at or above it and sells priced at or below it.
+    ///
+    /// Orders are filled in full at their limit price and charged the maker fee. Orders the
+    /// account cannot afford keep resting.
+    pub fn match_limit_orders(
+        &mut self,
+        instrument: &InstrumentNameExchange,
+        price: Decimal,
+    ) -> Vec<OpenOrderNotifications> {
+        let underlying = match self.find_instrument_data(instrument) {
+            Ok(instrument) => instrument.underlying.clone(),
+            Err(error) => {
+                warn!(exchange = %self.exchange, %error, "MockExchange ignoring market price");
+                return Vec::new();
+            }
         };
 
-        let notifications = OpenOrderNotifications {
-            balance: balance_snapshot,
-            trade: Trade {
-                id: trade_id,
-                order_id: order_id.clone(),
-                instrument: request.key.instrument,
-                strategy: request.key.strategy,
-                time_exchange: self.time_exchange(),
-                side: request.state.side,
-                price: request.state.price,
-                quantity: request.state.quantity,
-                fees,
-            },
+        let crossed = self
+            .account
+            .orders_open()
+            .filter(|order| &order.key.instrument == instrument && order.kind == OrderKind::Limit)
+            .filter(|order| match order.side {
+                Side::Buy => price <= order.price,
+                Side::Sell => price >= order.price,
+            })
+            .map(|order| order.key.cid.clone())
+            .sorted_unstable()
+            .collect::<Vec<_>>();
+
+        crossed
+            .into_iter()
+            .filter_map(|cid| self.fill_limit_order(&underlying, &cid))
+            .collect()
+    }
+
+    /// Fill the resting `Limit` order with the provided `cid` in full at its limit price.
+    fn fill_limit_order(
+        &mut self,
+        underlying: &Underlying<AssetNameExchange>,
+        cid: &ClientOrderId,
+    ) -> Option<OpenOrderNotifications> {
+        let order = self.account.remove_order_open(cid)?;
+
+        let fee_rate = self.fee_rate(order.kind, order.time_in_force);
+        let (balance, fees) = match self.apply_fill_to_balance(
+            underlying,
+            order.side,
+            order.price,
+            order.quantity,
+            fee_rate,
+        ) {
+            Ok(balance_and_fees) => balance_and_fees,
+            Err(error) => {
+                warn!(
+                    exchange = %self.exchange,
+                    %cid,
+                    %error,
+                    "MockExchange cannot fill crossed Limit order - leaving it resting"
+                );
+                self.account.insert_order_open(order);
+                return None;
+            }
         };
 
-        (order_response, Some(notifications))
+        self.order_expiries.remove(cid);
+
+        let trade = Trade {
+            id: TradeId(order.state.id.0.clone()),
+            order_id: order.state.id.clone(),
+            instrument: order.key.instrument.clone(),
+            strategy: order.key.strategy.clone(),
+            time_exchange: self.time_exchange(),
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            fees,
+        };
+
+        Some(OpenOrderNotifications {
+            balance: Snapshot(balance),
+            fills: vec![MockFill {
+                trade,
+                order: Some(Order {
+                    key: order.key,
+                    side: order.side,
+                    price: order.price,
+                    quantity: order.quantity,
+                    kind: order.kind,
+                    time_in_force: order.time_in_force,
+                    state: OrderState::fully_filled(),
+                }),
+            }],
+        })
     }
 
-    pub fn validate_order_kind_supported(
-        &self,
-        order_kind: OrderKind,
-    ) -> Result<(), UnindexedOrderError> {
-        if order_kind == OrderKind::Market {
-            Ok(())
+    /// First `day_boundary` strictly after the provided `time`.
+    fn next_day_boundary(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let boundary = time.date_naive().and_time(self.day_boundary).and_utc();
+        if boundary > time {
+            boundary
         } else {
-            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
-                format!("MockExchange does not supported OrderKind: {order_kind}"),
-            )))
+            boundary + TimeDelta::days(1)
+        }
+    }
+
+    /// Expire every open order whose expiry time has been reached by the exchange clock,
+    /// notifying the client with an `Expired` order snapshot.
+    fn expire_orders(&mut self, latency_ms: u64) {
+        let time_exchange = self.time_exchange();
+        let expired = self
+            .order_expiries
+            .iter()
+            .filter(|(_, time_expiry)| **time_expiry <= time_exchange)
+            .map(|(cid, time_expiry)| (*time_expiry, cid.clone()))
+            .sorted_unstable()
+            .collect::<Vec<_>>();
+
+        for (_, cid) in expired {
+            self.order_expiries.remove(&cid);
+
+            let Some(order) = self.account.remove_order_open(&cid) else {
+                continue;
+            };
+
+            let snapshot: Order<
+                ExchangeId,
+                InstrumentNameExchange,
+                OrderState<AssetNameExchange, InstrumentNameExchange>,
+            > = Order {
+                key: order.key,
+                side: order.side,
+                price: order.price,
+                quantity: order.quantity,
+                kind: order.kind,
+                time_in_force: order.time_in_force,
+                state: OrderState::expired(),
+            };
+
+            self.send_event_with_latency(self.build_account_event(Snapshot(snapshot)), latency_ms);
         }
     }
 
+    /// Fee rate applied to a fill of an order with the provided `kind` and `time_in_force`.
+    ///
+    /// Market and immediate-or-cancel orders take liquidity and pay the taker fee, while resting
+    /// orders pay the maker fee. Either falls back to `fees_percent` if unset.
+    pub fn fee_rate(&self, kind: OrderKind, time_in_force: TimeInForce) -> Decimal {
+        let is_taker = kind == OrderKind::Market
+            || matches!(
+                time_in_force,
+                TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill
+            );
+
+        let rate = if is_taker {
+            self.taker_fee
+        } else {
+            self.maker_fee
+        };
+
+        rate.unwrap_or(self.fees_percent)
+    }
+
     pub fn find_instrument_data(
         &self,
         instrument: &InstrumentNameExchange,
@@ -435,6 +869,20 @@ impl MockExchange {
     }
 }
 
+/// Sample a latency uniformly from `latency_ms..=latency_max_ms`, or `latency_ms` if no
+/// (non-empty) range is configured.
+fn sample_latency_ms<R>(latency_ms: u64, latency_max_ms: Option<u64>, rng: &mut R) -> u64
+where
+    R: Rng,
+{
+    match latency_max_ms {
+        Some(latency_max_ms) if latency_max_ms > latency_ms => {
+            rng.random_range(latency_ms..=latency_max_ms)
+        }
+        _ => latency_ms,
+    }
+}
+
 fn build_open_order_err_response<E>(
     request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     error: E,
@@ -456,5 +904,575 @@ where
 #[derive(Debug)]
 pub struct OpenOrderNotifications {
     pub balance: Snapshot<AssetBalance<AssetNameExchange>>,
+    pub fills: Vec<MockFill>,
+}
+
+/// Single fill of a [`MockExchange`] order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockFill {
     pub trade: Trade<QuoteAsset, InstrumentNameExchange>,
+    /// Order state after a partial fill, or `None` if the order was filled in a single trade.
+    pub order: Option<
+        Order<
+            ExchangeId,
+            InstrumentNameExchange,
+            OrderState<AssetNameExchange, InstrumentNameExchange>,
+        >,
+    >,
+}
+
+/// Split a `trade` for the full `order` quantity into partial fills of at most
+/// `max_fill_quantity`, each paired with the resulting `Open` order state, with the final fill
+/// transitioning the order to `FullyFilled`.
+///
+/// Fees are apportioned by fill quantity, with any rounding remainder charged on the final fill.
+fn split_into_partial_fills(
+    order: Order<ExchangeId, InstrumentNameExchange, ()>,
+    trade: Trade<QuoteAsset, InstrumentNameExchange>,
+    max_fill_quantity: Decimal,
+) -> Vec<MockFill> {
+    let mut fills = Vec::new();
+    let mut filled_quantity = Decimal::ZERO;
+    let mut fees_charged = Decimal::ZERO;
+
+    while filled_quantity < trade.quantity {
+        let quantity = max_fill_quantity.min(trade.quantity - filled_quantity);
+        filled_quantity += quantity;
+
+        let is_final = filled_quantity >= trade.quantity;
+        let fees = if is_final {
+            trade.fees.fees - fees_charged
+        } else {
+            trade.fees.fees * quantity / trade.quantity
+        };
+        fees_charged += fees;
+
+        let state = if is_final {
+            OrderState::fully_filled()
+        } else {
+            OrderState::active(Open {
+                id: trade.order_id.clone(),
+                time_exchange: trade.time_exchange,
+                filled_quantity,
+            })
+        };
+
+        fills.push(MockFill {
+            trade: Trade {
+                id: TradeId(format_smolstr!("{}-{}", trade.id.0, fills.len())),
+                quantity,
+                fees: AssetFees::quote_fees(fees),
+                ..trade.clone()
+            },
+            order: Some(Order {
+                key: order.key.clone(),
+                side: order.side,
+                price: order.price,
+                quantity: order.quantity,
+                kind: order.kind,
+                time_in_force: order.time_in_force,
+                state,
+            }),
+        });
+    }
+
+    fills
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountEventKind, balance::Balance, order::OrderKey, order::id::StrategyId,
+        order::request::RequestCancel, order::request::RequestOpen,
+        order::state::InactiveOrderState,
+    };
+    use barter_instrument::Underlying;
+    use chrono::TimeZone;
+    use rand::{SeedableRng, rngs::StdRng};
+    use std::str::FromStr;
+
+    fn time(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn instrument() -> InstrumentNameExchange {
+        InstrumentNameExchange::from("btc_usdt")
+    }
+
+    fn mock_exchange(
+        config: impl FnOnce(MockExecutionConfig) -> MockExecutionConfig,
+    ) -> (MockExchange, mpsc::UnboundedSender<MockExchangeRequest>) {
+        let initial_state = UnindexedAccountSnapshot {
+            exchange: ExchangeId::Mock,
+            balances: vec![AssetBalance::new(
+                AssetNameExchange::from("usdt"),
+                Balance::new(Decimal::from(10_000), Decimal::from(10_000)),
+                time(0, 0),
+            )],
+            instruments: vec![],
+        };
+        let config = config(MockExecutionConfig::new(
+            ExchangeId::Mock,
+            initial_state,
+            0,
+            Decimal::ZERO,
+        ));
+
+        let instruments = FnvHashMap::from_iter([(
+            instrument(),
+            Instrument::spot(
+                ExchangeId::Mock,
+                "mock_btc_usdt",
+                instrument(),
+                Underlying::new(
+                    AssetNameExchange::from("btc"),
+                    AssetNameExchange::from("usdt"),
+                ),
+                None,
+            ),
+        )]);
+
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, _) = broadcast::channel(16);
+
+        (
+            MockExchange::new(config, request_rx, event_tx, instruments),
+            request_tx,
+        )
+    }
+
+    fn request_open(
+        cid: &str,
+        kind: OrderKind,
+        time_in_force: TimeInForce,
+    ) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeId::Mock,
+                instrument: instrument(),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: Decimal::from(100),
+                quantity: Decimal::from(2),
+                kind,
+                time_in_force,
+            },
+        }
+    }
+
+    fn request_cancel(cid: &str) -> OrderRequestCancel<ExchangeId, InstrumentNameExchange> {
+        OrderRequestCancel {
+            key: OrderKey {
+                exchange: ExchangeId::Mock,
+                instrument: instrument(),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestCancel { id: None },
+        }
+    }
+
+    #[test]
+    fn test_next_day_boundary() {
+        let (mut exchange, _) = mock_exchange(|config| config);
+
+        // Strictly after the provided time, so a time on the boundary rolls to the next day
+        assert_eq!(
+            exchange.next_day_boundary(time(12, 0)),
+            time(0, 0) + TimeDelta::days(1)
+        );
+        assert_eq!(
+            exchange.next_day_boundary(time(0, 0)),
+            time(0, 0) + TimeDelta::days(1)
+        );
+
+        exchange.day_boundary = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        assert_eq!(exchange.next_day_boundary(time(10, 0)), time(17, 0));
+        assert_eq!(
+            exchange.next_day_boundary(time(17, 30)),
+            time(17, 0) + TimeDelta::days(1)
+        );
+    }
+
+    #[test]
+    fn test_open_limit_order() {
+        let (mut exchange, _) = mock_exchange(|config| config);
+        exchange.update_time_exchange(time(12, 0), 0);
+
+        let response = exchange.open_limit_order(request_open(
+            "gtd",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilEndOfDay,
+        ));
+        let open = response.state.unwrap();
+        assert_eq!(open.filled_quantity, Decimal::ZERO);
+        assert_eq!(open.time_exchange, time(12, 0));
+        assert_eq!(exchange.account.orders_open().count(), 1);
+        assert_eq!(
+            exchange.order_expiries.get(&ClientOrderId::new("gtd")),
+            Some(&(time(0, 0) + TimeDelta::days(1)))
+        );
+
+        let response = exchange.open_limit_order(request_open(
+            "ioc",
+            OrderKind::Limit,
+            TimeInForce::ImmediateOrCancel,
+        ));
+        assert!(response.state.is_ok());
+        assert_eq!(
+            exchange.order_expiries.get(&ClientOrderId::new("ioc")),
+            Some(&time(12, 0))
+        );
+
+        let response = exchange.open_limit_order(request_open(
+            "gtc",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilCancelled { post_only: false },
+        ));
+        assert!(response.state.is_ok());
+        assert!(
+            !exchange
+                .order_expiries
+                .contains_key(&ClientOrderId::new("gtc"))
+        );
+        assert_eq!(exchange.account.orders_open().count(), 3);
+
+        let mut unknown = request_open("unknown", OrderKind::Limit, TimeInForce::FillOrKill);
+        unknown.key.instrument = InstrumentNameExchange::from("eth_usdt");
+        assert!(matches!(
+            exchange.open_limit_order(unknown).state,
+            Err(UnindexedOrderError::Rejected(ApiError::InstrumentInvalid(
+                ..
+            )))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expire_orders() {
+        let (mut exchange, _) = mock_exchange(|config| config);
+        let mut events = exchange.event_tx.subscribe();
+        exchange.update_time_exchange(time(12, 0), 0);
+
+        exchange.open_limit_order(request_open(
+            "ioc",
+            OrderKind::Limit,
+            TimeInForce::ImmediateOrCancel,
+        ));
+        exchange.open_limit_order(request_open(
+            "gtd",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilEndOfDay,
+        ));
+
+        // Immediate-or-cancel orders expire at once, end-of-day orders keep resting
+        exchange.expire_orders(0);
+        assert_eq!(
+            exchange
+                .account
+                .orders_open()
+                .map(|order| order.key.cid.clone())
+                .collect::<Vec<_>>(),
+            vec![ClientOrderId::new("gtd")]
+        );
+
+        let event = events.recv().await.unwrap();
+        let AccountEventKind::OrderSnapshot(Snapshot(order)) = event.kind else {
+            panic!("expected OrderSnapshot, got: {event:?}");
+        };
+        assert_eq!(order.key.cid, ClientOrderId::new("ioc"));
+        assert_eq!(
+            order.state,
+            OrderState::Inactive(InactiveOrderState::Expired)
+        );
+
+        // Crossing the day boundary expires the end-of-day order
+        exchange.update_time_exchange(time(0, 0) + TimeDelta::days(1), 0);
+        exchange.expire_orders(0);
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert!(exchange.order_expiries.is_empty());
+
+        let event = events.recv().await.unwrap();
+        assert!(matches!(
+            event.kind,
+            AccountEventKind::OrderSnapshot(Snapshot(Order { ref key, .. })) if key.cid == ClientOrderId::new("gtd")
+        ));
+    }
+
+    #[test]
+    fn test_cancel_resting_limit_order() {
+        let (mut exchange, _) = mock_exchange(|config| config);
+        exchange.update_time_exchange(time(12, 0), 0);
+
+        let open = exchange
+            .open_limit_order(request_open(
+                "gtd",
+                OrderKind::Limit,
+                TimeInForce::GoodUntilEndOfDay,
+            ))
+            .state
+            .unwrap();
+
+        let cancelled = exchange.cancel_order(request_cancel("gtd")).state.unwrap();
+        assert_eq!(cancelled, Cancelled::new(open.id, time(12, 0)));
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert_eq!(exchange.account.orders_cancelled().count(), 1);
+        assert!(exchange.order_expiries.is_empty());
+
+        assert_eq!(
+            exchange.cancel_order(request_cancel("gtd")).state,
+            Err(UnindexedOrderError::Rejected(
+                ApiError::OrderAlreadyCancelled
+            ))
+        );
+        assert!(matches!(
+            exchange.cancel_order(request_cancel("unknown")).state,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(_)))
+        ));
+    }
+
+    #[test]
+    fn test_match_limit_orders_charges_maker_fee() {
+        let (mut exchange, _) = mock_exchange(|config| {
+            config
+                .with_maker_fee(Some(Decimal::from_str("0.0002").unwrap()))
+                .with_taker_fee(Some(Decimal::from_str("0.001").unwrap()))
+        });
+        exchange.update_time_exchange(time(12, 0), 0);
+
+        exchange.open_limit_order(request_open(
+            "gtd",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilEndOfDay,
+        ));
+
+        // Market price above the buy limit does not cross
+        assert!(
+            exchange
+                .match_limit_orders(&instrument(), Decimal::from(101))
+                .is_empty()
+        );
+        assert_eq!(exchange.account.orders_open().count(), 1);
+
+        let notifications = exchange.match_limit_orders(&instrument(), Decimal::from(99));
+        assert_eq!(notifications.len(), 1);
+
+        // Filled at the limit price, charged the maker fee: 100 * 2 * 0.0002
+        let fill = &notifications[0].fills[0];
+        assert_eq!(fill.trade.price, Decimal::from(100));
+        assert_eq!(fill.trade.quantity, Decimal::from(2));
+        assert_eq!(fill.trade.fees.fees, Decimal::from_str("0.04").unwrap());
+        assert_eq!(
+            fill.order.as_ref().unwrap().state,
+            OrderState::Inactive(InactiveOrderState::FullyFilled)
+        );
+        assert_eq!(
+            notifications[0].balance.0.balance.free,
+            Decimal::from_str("9799.96").unwrap()
+        );
+
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert!(exchange.order_expiries.is_empty());
+    }
+
+    fn split(quantity: &str, fees: &str, max_fill_quantity: &str) -> Vec<MockFill> {
+        let request = request_open("split", OrderKind::Market, TimeInForce::ImmediateOrCancel);
+        let order = Order {
+            key: request.key.clone(),
+            side: request.state.side,
+            price: request.state.price,
+            quantity: Decimal::from_str(quantity).unwrap(),
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            state: (),
+        };
+        let trade = Trade {
+            id: TradeId::new("7"),
+            order_id: OrderId::new("7"),
+            instrument: instrument(),
+            strategy: request.key.strategy,
+            time_exchange: time(12, 0),
+            side: Side::Buy,
+            price: request.state.price,
+            quantity: order.quantity,
+            fees: AssetFees::quote_fees(Decimal::from_str(fees).unwrap()),
+        };
+
+        split_into_partial_fills(order, trade, Decimal::from_str(max_fill_quantity).unwrap())
+    }
+
+    #[test]
+    fn test_sample_latency_ms() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let samples = (0..1_000)
+            .map(|_| sample_latency_ms(20, Some(50), &mut rng))
+            .collect::<Vec<_>>();
+        assert!(samples.iter().all(|latency| (20..=50).contains(latency)));
+        assert!(samples.iter().any(|latency| *latency != samples[0]));
+
+        // Missing or empty ranges fall back to the fixed latency
+        assert_eq!(sample_latency_ms(20, None, &mut rng), 20);
+        assert_eq!(sample_latency_ms(20, Some(20), &mut rng), 20);
+        assert_eq!(sample_latency_ms(20, Some(10), &mut rng), 20);
+    }
+
+    #[test]
+    fn test_split_into_partial_fills() {
+        let fills = split("0.1", "0.03", "0.04");
+
+        let quantities = fills
+            .iter()
+            .map(|fill| fill.trade.quantity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            quantities,
+            vec![
+                Decimal::from_str("0.04").unwrap(),
+                Decimal::from_str("0.04").unwrap(),
+                Decimal::from_str("0.02").unwrap(),
+            ]
+        );
+        assert_eq!(
+            quantities.iter().sum::<Decimal>(),
+            Decimal::from_str("0.1").unwrap()
+        );
+
+        // Fees apportioned by quantity, summing to the full trade fees
+        let fees = fills
+            .iter()
+            .map(|fill| fill.trade.fees.fees)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fees,
+            vec![
+                Decimal::from_str("0.012").unwrap(),
+                Decimal::from_str("0.012").unwrap(),
+                Decimal::from_str("0.006").unwrap(),
+            ]
+        );
+
+        let trade_ids = fills
+            .iter()
+            .map(|fill| fill.trade.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trade_ids,
+            vec![
+                TradeId::new("7-0"),
+                TradeId::new("7-1"),
+                TradeId::new("7-2")
+            ]
+        );
+
+        let states = fills
+            .iter()
+            .map(|fill| fill.order.as_ref().unwrap().state.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            states,
+            vec![
+                OrderState::active(Open::new(
+                    OrderId::new("7"),
+                    time(12, 0),
+                    Decimal::from_str("0.04").unwrap()
+                )),
+                OrderState::active(Open::new(
+                    OrderId::new("7"),
+                    time(12, 0),
+                    Decimal::from_str("0.08").unwrap()
+                )),
+                OrderState::fully_filled(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_into_partial_fills_remainder() {
+        // Exact multiple leaves no remainder fill
+        let fills = split("0.08", "0.02", "0.04");
+        assert_eq!(
+            fills
+                .iter()
+                .map(|fill| fill.trade.quantity)
+                .collect::<Vec<_>>(),
+            vec![
+                Decimal::from_str("0.04").unwrap(),
+                Decimal::from_str("0.04").unwrap()
+            ]
+        );
+        assert_eq!(
+            fills.last().unwrap().order.as_ref().unwrap().state,
+            OrderState::fully_filled()
+        );
+
+        // Fee rounding remainder is charged on the final fill
+        let fills = split("3", "1", "1");
+        let fees = fills
+            .iter()
+            .map(|fill| fill.trade.fees.fees)
+            .collect::<Vec<_>>();
+        assert_eq!(fees[0], fees[1]);
+        assert_eq!(fees[2], Decimal::ONE - fees[0] - fees[1]);
+        assert_eq!(fees.iter().sum::<Decimal>(), Decimal::ONE);
+    }
+
+    #[tokio::test]
+    async fn test_run_expires_orders_without_further_requests() {
+        let (exchange, request_tx) = mock_exchange(|config| config);
+        let mut events = exchange.event_tx.subscribe();
+        tokio::spawn(exchange.run());
+
+        // Open an end-of-day order 50ms before the day boundary, then send no more requests
+        let time_request = time(0, 0) + TimeDelta::days(1) - TimeDelta::milliseconds(50);
+        let (response_tx, response_rx) = oneshot::channel();
+        request_tx
+            .send(MockExchangeRequest::open_order(
+                time_request,
+                response_tx,
+                request_open("gtd", OrderKind::Limit, TimeInForce::GoodUntilEndOfDay),
+            ))
+            .unwrap();
+        assert!(response_rx.await.unwrap().state.is_ok());
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(1), events.recv())
+            .await
+            .expect("order should expire at the day boundary")
+            .unwrap();
+        let AccountEventKind::OrderSnapshot(Snapshot(order)) = event.kind else {
+            panic!("expected OrderSnapshot, got: {event:?}");
+        };
+        assert_eq!(order.key.cid, ClientOrderId::new("gtd"));
+        assert_eq!(
+            order.state,
+            OrderState::Inactive(InactiveOrderState::Expired)
+        );
+
+        drop(request_tx);
+    }
+
+    #[test]
+    fn test_mock_execution_config_builder() {
+        let (exchange, _) = mock_exchange(|config| {
+            config
+                .with_latency_max_ms(Some(10))
+                .with_maker_fee(Some(Decimal::from_str("0.001").unwrap()))
+                .with_day_boundary(NaiveTime::from_hms_opt(17, 0, 0).unwrap())
+        });
+
+        assert_eq!(exchange.latency_max_ms, Some(10));
+        assert_eq!(
+            exchange.maker_fee,
+            Some(Decimal::from_str("0.001").unwrap())
+        );
+        assert_eq!(exchange.taker_fee, None);
+        assert!(exchange.max_fill_quantity.is_empty());
+        assert_eq!(
+            exchange.day_boundary,
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap()
+        );
+    }
 }
@@ -14,7 +14,8 @@ use barter_instrument::{
     exchange::ExchangeId,
     instrument::name::InstrumentNameExchange,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
 use tokio::sync::oneshot;
 
 #[derive(Debug)]
@@ -109,6 +110,28 @@ impl MockExchangeRequest {
             },
         )
     }
+
+    pub fn market_price(
+        time_request: DateTime<Utc>,
+        instrument: InstrumentNameExchange,
+        price: Decimal,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::MarketPrice { instrument, price },
+        )
+    }
+
+    pub fn advance_time(
+        time_request: DateTime<Utc>,
+        response_tx: oneshot::Sender<DateTime<Utc>>,
+        delta: TimeDelta,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::AdvanceTime { response_tx, delta },
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -138,4 +161,14 @@ pub enum MockExchangeRequestKind {
         >,
         request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     },
+    /// Latest market `price` of an instrument, filling any resting `Limit` orders it crosses.
+    MarketPrice {
+        instrument: InstrumentNameExchange,
+        price: Decimal,
+    },
+    /// Advance the `MockExchange` clock by `delta`, responding with the new exchange time.
+    AdvanceTime {
+        response_tx: oneshot::Sender<DateTime<Utc>>,
+        delta: TimeDelta,
+    },
 }
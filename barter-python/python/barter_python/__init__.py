@@ -53,6 +53,7 @@ __all__.append("TradingSummaryGenerator")
 OrderId = execution.OrderId
 StrategyId = execution.StrategyId
 ClientOrderId = execution.ClientOrderId
+ClientOrderIdGenerator = execution.ClientOrderIdGenerator
 OrderKey = execution.OrderKey
 OrderKind = execution.OrderKind
 TimeInForce = execution.TimeInForce
@@ -82,7 +83,7 @@ AccountEvent = execution.AccountEvent
 AccountEventKind = execution.AccountEventKind
 
 __all__.extend([
-    "OrderId", "StrategyId", "ClientOrderId", "OrderKey", "OrderKind", "TimeInForce",
+    "OrderId", "StrategyId", "ClientOrderId", "ClientOrderIdGenerator", "OrderKey", "OrderKind", "TimeInForce",
     "TradeId", "Trade", "AssetFees", "Order", "InstrumentAccountSnapshot", "AccountSnapshot", "OrderEvent",
     "OpenInFlight", "Open", "CancelInFlight", "Cancelled", "OrderError", "InactiveOrderState", "OrderState",
     "RequestOpen", "OrderResponseCancel",
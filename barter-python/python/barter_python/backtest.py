@@ -33,6 +33,9 @@ from .barter_python import (
 from .barter_python import (
     backtest as _backtest,
 )
+from .barter_python import (
+    build_comparison_table as _build_comparison_table,
+)
 from .barter_python import (
     run_backtests as _run_backtests,
 )
@@ -72,6 +75,18 @@ class MarketDataInMemory:
             _inner=inner,
         )
 
+    def filter_kinds(self, kinds: Iterable[str]) -> MarketDataInMemory:
+        """Return a copy retaining only events whose DataKind matches one of ``kinds``."""
+        if self._inner is None:
+            raise ValueError("filter_kinds requires Rust-backed market data")
+
+        inner = self._inner.filter_kinds(list(kinds))
+        return MarketDataInMemory(
+            _time_first_event=inner.time_first_event,
+            events=inner.events(),
+            _inner=inner,
+        )
+
     def stream(self) -> AsyncIterable[MarketEvent[int, DataKind]]:
         """Provide an async iterator over the buffered market events."""
 
@@ -112,6 +127,17 @@ def run_backtests(
     return _run_backtests(args_constant, list(args_dynamics))
 
 
+def build_comparison_table(
+    summaries: Iterable[BacktestSummary],
+    labels: Iterable[str] | None = None,
+) -> list[dict[str, object]]:
+    """Flatten summaries into one row of headline metrics per run."""
+
+    return _build_comparison_table(
+        list(summaries), None if labels is None else list(labels)
+    )
+
+
 __all__ = [
     "BacktestArgsConstant",
     "BacktestArgsDynamic",
@@ -122,5 +148,6 @@ __all__ = [
     "MultiBacktestSummary",
     "MockExecutionConfig",
     "backtest",
+    "build_comparison_table",
     "run_backtests",
 ]
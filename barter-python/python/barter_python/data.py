@@ -600,24 +600,44 @@ class MarketStreamItem(MarketStreamEvent):
 
 
 class MarketStreamReconnecting(MarketStreamEvent):
-    """Notification that a stream is reconnecting for the given exchange."""
+    """Notification that a stream is reconnecting for the given exchange.
 
-    def __init__(self, exchange: str) -> None:
+    ``attempt`` counts consecutive reconnections for the exchange since it last yielded a
+    market event, and ``policy`` is the ``ReconnectPolicy`` governing the backoff, if known.
+    """
+
+    def __init__(
+        self,
+        exchange: str,
+        attempt: int | None = None,
+        policy: Any | None = None,
+    ) -> None:
         self.kind = "reconnecting"
         self.exchange = exchange
+        self.attempt = attempt
+        self.policy = policy
 
     def __repr__(self) -> str:
-        return f"MarketStreamReconnecting(exchange={self.exchange!r})"
+        if self.attempt is None:
+            return f"MarketStreamReconnecting(exchange={self.exchange!r})"
+        return (
+            f"MarketStreamReconnecting(exchange={self.exchange!r}, "
+            f"attempt={self.attempt!r})"
+        )
 
     def __eq__(self, other: object) -> bool:
         if not isinstance(other, MarketStreamReconnecting):
             return NotImplemented
-        return self.exchange == other.exchange
+        return self.exchange == other.exchange and self.attempt == other.attempt
 
     def __hash__(self) -> int:
-        return hash((self.kind, self.exchange))
+        return hash((self.kind, self.exchange, self.attempt))
 
     def __getitem__(self, key: str) -> Any:
         if key == "exchange":
             return self.exchange
+        if key == "attempt":
+            return self.attempt
+        if key == "policy":
+            return self.policy
         return super().__getitem__(key)
@@ -13,6 +13,7 @@ from .instrument import Side
 
 # Placeholders for classes added by Rust extension
 ClientOrderId = None
+ClientOrderIdGenerator = None
 OrderId = None
 StrategyId = None
 TradeId = None
@@ -42,6 +43,7 @@ TimeInForce = None
 
 _asset_balance_new = _core.asset_balance_new
 _balance_new = _core.balance_new
+aggregate_trades = _core.aggregate_trades
 
 try:
     _execution_bindings = _core.execution
@@ -54,6 +56,8 @@ else:
         TimeInForce = _execution_bindings.TimeInForce
     if hasattr(_execution_bindings, "ClientOrderId"):
         ClientOrderId = _execution_bindings.ClientOrderId
+    if hasattr(_execution_bindings, "ClientOrderIdGenerator"):
+        ClientOrderIdGenerator = _execution_bindings.ClientOrderIdGenerator
     if hasattr(_execution_bindings, "OrderId"):
         OrderId = _execution_bindings.OrderId
     if hasattr(_execution_bindings, "StrategyId"):
@@ -212,8 +216,17 @@ class MockExecutionClient:
             client_order_id=client_order_id,
         )
 
-    def poll_event(self, timeout=None):
-        return self._inner.poll_event(timeout_secs=timeout)
+    def poll_event(self, timeout=None, raw=False):
+        return self._inner.poll_event(timeout_secs=timeout, raw=raw)
+
+    def advance_time(self, delta):
+        return self._inner.advance_time(delta)
+
+    def update_market_price(self, instrument, price):
+        return self._inner.update_market_price(instrument, price)
+
+    def cancel_order(self, instrument, client_order_id, strategy=None):
+        return self._inner.cancel_order(instrument, client_order_id, strategy=strategy)
 
     def open_limit_order(
         self,
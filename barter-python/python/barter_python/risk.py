@@ -6,6 +6,8 @@ from collections.abc import Iterable
 from typing import Protocol, TypeVar, Union
 
 from .barter_python import (
+    CallbackRiskManager,
+    ConsecutiveLossTracker,
     DefaultRiskManager,
     RiskApproved,
     RiskRefused,
@@ -45,6 +47,8 @@ __all__ = [
     "RiskRefused",
     "RiskManager",
     "DefaultRiskManager",
+    "CallbackRiskManager",
+    "ConsecutiveLossTracker",
     "calculate_quote_notional",
     "calculate_abs_percent_difference",
     "calculate_delta",
@@ -208,6 +208,18 @@ class Trade(Generic[AssetKey, InstrumentKey]):  # type: ignore
     def value_quote(self) -> Decimal:
         return self._inner.value_quote()
 
+    def approx_eq(
+        self,
+        other: Trade[Any, Any],
+        price_tol: float = 0.0,
+        qty_tol: float = 0.0,
+        fees_tol: float = 0.0,
+    ) -> bool:
+        other_inner = other._inner if isinstance(other, Trade) else other
+        return self._inner.approx_eq(
+            other_inner, price_tol=price_tol, qty_tol=qty_tol, fees_tol=fees_tol
+        )
+
     def __str__(self) -> str:
         return str(self._inner)
 
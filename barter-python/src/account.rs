@@ -1,6 +1,9 @@
 use crate::{
     command::{PyOrderSnapshot, parse_decimal},
-    execution::{PyExecutionAssetBalance, PyOrderResponseCancel, PyTrade, serialize_to_json},
+    execution::{
+        PyExecutionAssetBalance, PyOrderResponseCancel, PyTrade, serialize_to_json,
+        serialize_to_py_dict,
+    },
     instrument::PyExchangeIndex,
     integration::PySnapshot,
 };
@@ -229,10 +232,74 @@ impl PyAccountEvent {
         PyAccountEventKind::from_inner(self.inner.kind.clone())
     }
 
+    /// Variant name of the event [`kind`](Self::kind), eg/ `"trade"`.
+    #[getter]
+    pub fn variant(&self) -> &'static str {
+        PyAccountEventKind::from_inner(self.inner.kind.clone()).variant_str()
+    }
+
+    /// Full account snapshot payload, or `None` for other event kinds.
+    #[getter]
+    pub fn snapshot(&self) -> Option<PyAccountSnapshot> {
+        match &self.inner.kind {
+            ExecutionAccountEventKind::Snapshot(snapshot) => {
+                Some(PyAccountSnapshot::from_inner(snapshot.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Asset balance payload of a balance snapshot, or `None` for other event kinds.
+    #[getter]
+    pub fn balance(&self) -> Option<PyExecutionAssetBalance> {
+        match &self.inner.kind {
+            ExecutionAccountEventKind::BalanceSnapshot(balance) => {
+                Some(PyExecutionAssetBalance::from_inner(balance.value().clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Order payload of an order snapshot, or `None` for other event kinds.
+    #[getter]
+    pub fn order(&self) -> Option<PyOrderSnapshot> {
+        match &self.inner.kind {
+            ExecutionAccountEventKind::OrderSnapshot(order) => {
+                Some(PyOrderSnapshot::from_inner(order.value().clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Cancel response payload, or `None` for other event kinds.
+    #[getter]
+    pub fn cancelled(&self) -> Option<PyOrderResponseCancel> {
+        match &self.inner.kind {
+            ExecutionAccountEventKind::OrderCancelled(response) => {
+                Some(PyOrderResponseCancel::from_inner(response.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Trade payload, or `None` for other event kinds.
+    #[getter]
+    pub fn trade(&self) -> Option<PyTrade> {
+        match &self.inner.kind {
+            ExecutionAccountEventKind::Trade(trade) => Some(PyTrade::from_inner(trade.clone())),
+            _ => None,
+        }
+    }
+
     pub fn to_json(&self) -> PyResult<String> {
         serialize_to_json(&self.inner)
     }
 
+    /// Untyped `dict` representation of the event, for payloads without a typed accessor.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        serialize_to_py_dict(py, &self.inner)
+    }
+
     #[classmethod]
     pub fn from_json(_cls: &Bound<'_, PyType>, data: &str) -> PyResult<Self> {
         let inner = serde_json::from_str::<
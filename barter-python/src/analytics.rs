@@ -1,5 +1,9 @@
 use crate::{
     command::parse_decimal,
+    common::timestamp_to_py,
+    error::UndefinedMetricError,
+    execution::{PyOrderKind, PyTrade},
+    instrument::PySide,
     summary::{PyDrawdown, PyMeanDrawdown, PyMetricWithInterval, decimal_to_py},
 };
 use barter::{
@@ -19,18 +23,21 @@ use barter::{
             sortino::SortinoRatio,
             win_rate::WinRate,
         },
+        summary::dataset::DataSetSummary,
         time::{Annual252, Annual365, Daily, TimeInterval},
     },
 };
+use barter_execution::{order::OrderKind, trade::Trade};
+use barter_instrument::{Side, asset::QuoteAsset, instrument::InstrumentIndex};
 use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone, Utc};
 use pyo3::{
     Bound, PyObject,
     exceptions::PyValueError,
     prelude::*,
-    types::{PyAny, PyDelta, PySequence},
+    types::{PyAny, PyDelta, PyDict, PySequence, PyTuple},
 };
-use rust_decimal::Decimal;
-use std::str::FromStr;
+use rust_decimal::{Decimal, MathematicalOps, prelude::ToPrimitive};
+use std::{collections::HashMap, str::FromStr};
 
 #[derive(Debug, Copy, Clone)]
 enum IntervalChoice {
@@ -40,6 +47,156 @@ enum IntervalChoice {
     Duration(TimeDelta),
 }
 
+impl IntervalChoice {
+    fn time_delta(self) -> TimeDelta {
+        match self {
+            Self::Daily => Daily.interval(),
+            Self::Annual252 => Annual252.interval(),
+            Self::Annual365 => Annual365.interval(),
+            Self::Duration(delta) => delta,
+        }
+    }
+
+    fn name(self) -> String {
+        match self {
+            Self::Daily => Daily.name().to_string(),
+            Self::Annual252 => Annual252.name().to_string(),
+            Self::Annual365 => Annual365.name().to_string(),
+            Self::Duration(delta) => delta.name().to_string(),
+        }
+    }
+}
+
+/// Number of `base` intervals contained in `interval`.
+fn interval_periods(interval: IntervalChoice, base: IntervalChoice) -> PyResult<Decimal> {
+    let interval_secs = Decimal::from(interval.time_delta().num_seconds()).abs();
+    let base_secs = Decimal::from(base.time_delta().num_seconds()).abs();
+
+    interval_secs
+        .checked_div(base_secs)
+        .ok_or_else(|| PyValueError::new_err("base_interval must be a non-zero duration"))
+}
+
+/// How analytics functions treat results that are mathematically undefined, such as a
+/// Sharpe ratio computed from zero-variance returns.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum UndefinedPolicy {
+    /// Return the sentinel value produced by the core metric (e.g. `Decimal::MAX`).
+    Sentinel,
+    /// Return `None`.
+    None,
+    /// Raise an `UndefinedMetricError`.
+    Raise,
+}
+
+impl UndefinedPolicy {
+    /// Resolve an undefined result, returning `true` if the sentinel value should be produced.
+    fn resolve(self, metric: &str, reason: &str) -> PyResult<bool> {
+        match self {
+            Self::Sentinel => Ok(true),
+            Self::None => Ok(false),
+            Self::Raise => Err(UndefinedMetricError::new_err(format!(
+                "{metric} is undefined: {reason}"
+            ))),
+        }
+    }
+}
+
+fn parse_undefined_policy(value: Option<&str>) -> PyResult<UndefinedPolicy> {
+    match value {
+        None => Ok(UndefinedPolicy::Sentinel),
+        Some(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+            "sentinel" => Ok(UndefinedPolicy::Sentinel),
+            "none" => Ok(UndefinedPolicy::None),
+            "raise" => Ok(UndefinedPolicy::Raise),
+            _ => Err(PyValueError::new_err(format!(
+                "on_undefined must be 'sentinel', 'none' or 'raise', got {raw}"
+            ))),
+        },
+    }
+}
+
+/// Return convention applied when converting, scaling and comparing returns.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Compounding {
+    /// Simple returns that scale linearly with time (the core `RateOfReturn` convention).
+    Simple,
+    /// Continuously compounded returns, `ln(1 + r)`, which also scale linearly with time.
+    Log,
+    /// Discretely compounded returns, `(1 + r)^n - 1`.
+    Compound,
+}
+
+impl Compounding {
+    /// Scale a per-period simple return over `periods` periods using this convention.
+    fn scale_return(self, value: Decimal, periods: Decimal, field: &str) -> PyResult<Decimal> {
+        let overflow = || PyValueError::new_err(format!("{field} overflowed during compounding"));
+
+        match self {
+            Self::Simple => value.checked_mul(periods).ok_or_else(overflow),
+            Self::Log => {
+                let growth = Self::growth(value, field)?;
+                growth
+                    .checked_ln()
+                    .and_then(|log| log.checked_mul(periods))
+                    .ok_or_else(overflow)
+            }
+            Self::Compound => {
+                let growth = Self::growth(value, field)?;
+                growth
+                    .checked_powd(periods)
+                    .and_then(|compounded| compounded.checked_sub(Decimal::ONE))
+                    .ok_or_else(overflow)
+            }
+        }
+    }
+
+    /// Express a single-period simple return in this convention.
+    fn convert_return(self, value: Decimal, field: &str) -> PyResult<Decimal> {
+        self.scale_return(value, Decimal::ONE, field)
+    }
+
+    /// Express a fractional drawdown, `(peak - trough) / peak`, in this convention.
+    ///
+    /// Log drawdowns are `-ln(trough / peak)`; simple and compound drawdowns are identical.
+    fn convert_drawdown(self, value: Decimal) -> PyResult<Decimal> {
+        match self {
+            Self::Simple | Self::Compound => Ok(value),
+            Self::Log => Self::growth(-value, "drawdown")?
+                .checked_ln()
+                .map(|log| -log)
+                .ok_or_else(|| PyValueError::new_err("drawdown overflowed during compounding")),
+        }
+    }
+
+    fn growth(value: Decimal, field: &str) -> PyResult<Decimal> {
+        match Decimal::ONE.checked_add(value) {
+            Some(growth) if growth.is_sign_positive() && !growth.is_zero() => Ok(growth),
+            _ => Err(PyValueError::new_err(format!(
+                "{field} must be greater than -1 for log or compound returns"
+            ))),
+        }
+    }
+}
+
+fn parse_compounding(value: Option<&str>) -> PyResult<Compounding> {
+    match value {
+        None => Ok(Compounding::Simple),
+        Some(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+            "simple" => Ok(Compounding::Simple),
+            "log" => Ok(Compounding::Log),
+            "compound" => Ok(Compounding::Compound),
+            _ => Err(PyValueError::new_err(format!(
+                "compounding must be 'simple', 'log' or 'compound', got {raw}"
+            ))),
+        },
+    }
+}
+
+fn insufficient_samples(sample_count: Option<u64>, min_samples: u64) -> bool {
+    sample_count.is_some_and(|count| count < min_samples)
+}
+
 fn ratio_to_metric<Interval>(
     py: Python<'_>,
     value: Decimal,
@@ -121,21 +278,52 @@ where
     }
 }
 
+/// Calculate the Sharpe ratio for the provided return statistics.
+///
+/// A zero `std_dev_returns` leaves the ratio undefined; `on_undefined` selects whether the
+/// core sentinel value is returned (`"sentinel"`, default), `None` is returned (`"none"`) or
+/// an `UndefinedMetricError` is raised (`"raise"`).
+///
+/// When `sample_count` is provided and is below `min_samples`, `None` is returned since a
+/// ratio computed from so few returns is not meaningful.
+///
+/// `compounding` converts the simple `mean_return` and `risk_free_return` into the chosen
+/// convention (`"simple"`, default, `"log"` or `"compound"`) before the ratio is computed;
+/// `std_dev_returns` is expected to already be expressed in that convention.
+#[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (risk_free_return, mean_return, std_dev_returns, interval))]
+#[pyo3(signature = (risk_free_return, mean_return, std_dev_returns, interval, on_undefined = None, sample_count = None, min_samples = 2, compounding = None))]
 pub fn calculate_sharpe_ratio(
     py: Python<'_>,
     risk_free_return: f64,
     mean_return: f64,
     std_dev_returns: f64,
     interval: &Bound<'_, PyAny>,
-) -> PyResult<Py<PyMetricWithInterval>> {
-    let risk_free = parse_decimal(risk_free_return, "risk_free_return")?;
-    let mean = parse_decimal(mean_return, "mean_return")?;
+    on_undefined: Option<&str>,
+    sample_count: Option<u64>,
+    min_samples: u64,
+    compounding: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let compounding = parse_compounding(compounding)?;
+    let risk_free = compounding.convert_return(
+        parse_decimal(risk_free_return, "risk_free_return")?,
+        "risk_free_return",
+    )?;
+    let mean =
+        compounding.convert_return(parse_decimal(mean_return, "mean_return")?, "mean_return")?;
     let deviation = parse_decimal(std_dev_returns, "std_dev_returns")?;
     let choice = parse_interval_choice(interval)?;
+    let policy = parse_undefined_policy(on_undefined)?;
+
+    if insufficient_samples(sample_count, min_samples) {
+        return Ok(None);
+    }
+
+    if deviation.is_zero() && !policy.resolve("sharpe_ratio", "std_dev_returns is zero")? {
+        return Ok(None);
+    }
 
-    match choice {
+    let metric = match choice {
         IntervalChoice::Daily => sharpe_metric(
             py,
             SharpeRatio::calculate(risk_free, mean, deviation, Daily),
@@ -152,24 +340,49 @@ pub fn calculate_sharpe_ratio(
             py,
             SharpeRatio::calculate(risk_free, mean, deviation, delta),
         ),
-    }
+    }?;
+
+    Ok(Some(metric))
 }
 
+/// Calculate the Sortino ratio for the provided return statistics.
+///
+/// A zero `std_dev_loss_returns` leaves the ratio undefined; see [`calculate_sharpe_ratio`]
+/// for the supported `on_undefined` policies, the `sample_count` guard and `compounding`.
+#[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (risk_free_return, mean_return, std_dev_loss_returns, interval))]
+#[pyo3(signature = (risk_free_return, mean_return, std_dev_loss_returns, interval, on_undefined = None, sample_count = None, min_samples = 2, compounding = None))]
 pub fn calculate_sortino_ratio(
     py: Python<'_>,
     risk_free_return: f64,
     mean_return: f64,
     std_dev_loss_returns: f64,
     interval: &Bound<'_, PyAny>,
-) -> PyResult<Py<PyMetricWithInterval>> {
-    let risk_free = parse_decimal(risk_free_return, "risk_free_return")?;
-    let mean = parse_decimal(mean_return, "mean_return")?;
+    on_undefined: Option<&str>,
+    sample_count: Option<u64>,
+    min_samples: u64,
+    compounding: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let compounding = parse_compounding(compounding)?;
+    let risk_free = compounding.convert_return(
+        parse_decimal(risk_free_return, "risk_free_return")?,
+        "risk_free_return",
+    )?;
+    let mean =
+        compounding.convert_return(parse_decimal(mean_return, "mean_return")?, "mean_return")?;
     let deviation = parse_decimal(std_dev_loss_returns, "std_dev_loss_returns")?;
     let choice = parse_interval_choice(interval)?;
+    let policy = parse_undefined_policy(on_undefined)?;
 
-    match choice {
+    if insufficient_samples(sample_count, min_samples) {
+        return Ok(None);
+    }
+
+    if deviation.is_zero() && !policy.resolve("sortino_ratio", "std_dev_loss_returns is zero")? {
+        return Ok(None);
+    }
+
+    let metric = match choice {
         IntervalChoice::Daily => sortino_metric(
             py,
             SortinoRatio::calculate(risk_free, mean, deviation, Daily),
@@ -186,7 +399,149 @@ pub fn calculate_sortino_ratio(
             py,
             SortinoRatio::calculate(risk_free, mean, deviation, delta),
         ),
+    }?;
+
+    Ok(Some(metric))
+}
+
+/// Calculate the Sharpe ratio directly from an equity curve.
+///
+/// The curve is first converted into simple period returns (see [`equity_curve_returns`]),
+/// whose mean & standard deviation are then passed to [`calculate_sharpe_ratio`].
+///
+/// With `compounding="log"` each period return is converted into a log return,
+/// `ln(equity[i] / equity[i - 1])`, before the mean & standard deviation are taken, so the
+/// ratio is computed entirely from log returns.
+#[pyfunction]
+#[pyo3(signature = (equity_curve, risk_free_return, interval, on_undefined = None, compounding = None))]
+pub fn calculate_sharpe_from_equity(
+    py: Python<'_>,
+    equity_curve: &Bound<'_, PyAny>,
+    risk_free_return: f64,
+    interval: &Bound<'_, PyAny>,
+    on_undefined: Option<&str>,
+    compounding: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let compounding = parse_compounding(compounding)?;
+    let risk_free = compounding.convert_return(
+        parse_decimal(risk_free_return, "risk_free_return")?,
+        "risk_free_return",
+    )?;
+    let (returns, _) = equity_curve_summaries(equity_curve, compounding)?;
+
+    calculate_sharpe_ratio(
+        py,
+        decimal_to_f64(risk_free, "risk_free_return")?,
+        decimal_to_f64(returns.mean, "mean_return")?,
+        decimal_to_f64(returns.dispersion.std_dev, "std_dev_returns")?,
+        interval,
+        on_undefined,
+        None,
+        2,
+        None,
+    )
+}
+
+/// Calculate the Sortino ratio directly from an equity curve.
+///
+/// Mirrors [`calculate_sharpe_from_equity`], using the standard deviation of the negative
+/// period returns only, as the core trading summary does.
+#[pyfunction]
+#[pyo3(signature = (equity_curve, risk_free_return, interval, on_undefined = None, compounding = None))]
+pub fn calculate_sortino_from_equity(
+    py: Python<'_>,
+    equity_curve: &Bound<'_, PyAny>,
+    risk_free_return: f64,
+    interval: &Bound<'_, PyAny>,
+    on_undefined: Option<&str>,
+    compounding: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let compounding = parse_compounding(compounding)?;
+    let risk_free = compounding.convert_return(
+        parse_decimal(risk_free_return, "risk_free_return")?,
+        "risk_free_return",
+    )?;
+    let (returns, losses) = equity_curve_summaries(equity_curve, compounding)?;
+
+    calculate_sortino_ratio(
+        py,
+        decimal_to_f64(risk_free, "risk_free_return")?,
+        decimal_to_f64(returns.mean, "mean_return")?,
+        decimal_to_f64(losses.dispersion.std_dev, "std_dev_loss_returns")?,
+        interval,
+        on_undefined,
+        None,
+        2,
+        None,
+    )
+}
+
+/// Convert an equity curve into simple period returns, ie/ `equity[i] / equity[i - 1] - 1`.
+///
+/// A return relative to zero or negative equity is meaningless, so every point used as the
+/// base of a return must be strictly positive.
+fn equity_curve_returns(equity_curve: &Bound<'_, PyAny>) -> PyResult<Vec<Decimal>> {
+    let sequence = equity_curve
+        .downcast::<PySequence>()
+        .map_err(|_| PyValueError::new_err("equity_curve must be a sequence of numeric values"))?;
+
+    let equity = (0..sequence.len()?)
+        .map(|index| {
+            parse_numeric_value(
+                &sequence.get_item(index)?,
+                &format!("equity_curve[{index}]"),
+            )
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    if equity.len() < 2 {
+        return Err(PyValueError::new_err(
+            "equity_curve must contain at least two points",
+        ));
+    }
+
+    equity
+        .windows(2)
+        .enumerate()
+        .map(|(index, window)| {
+            let (prev, next) = (window[0], window[1]);
+            if prev <= Decimal::ZERO {
+                return Err(PyValueError::new_err(format!(
+                    "equity_curve[{index}] must be positive to derive a period return",
+                )));
+            }
+
+            (next - prev).checked_div(prev).ok_or_else(|| {
+                PyValueError::new_err(format!("equity_curve[{}] return overflowed", index + 1))
+            })
+        })
+        .collect()
+}
+
+/// Summarise the period returns of an equity curve, expressed in the `compounding` convention,
+/// alongside the negative returns only.
+fn equity_curve_summaries(
+    equity_curve: &Bound<'_, PyAny>,
+    compounding: Compounding,
+) -> PyResult<(DataSetSummary, DataSetSummary)> {
+    let mut returns = DataSetSummary::default();
+    let mut losses = DataSetSummary::default();
+
+    for value in equity_curve_returns(equity_curve)? {
+        let value = compounding.convert_return(value, "equity_curve return")?;
+        returns.update(value);
+        if value.is_sign_negative() {
+            losses.update(value);
+        }
     }
+
+    Ok((returns, losses))
+}
+
+fn decimal_to_f64(value: Decimal, field: &str) -> PyResult<f64> {
+    value
+        .to_f64()
+        .ok_or_else(|| PyValueError::new_err(format!("{field} cannot be represented as a float")))
 }
 
 fn parse_interval_choice(value: &Bound<'_, PyAny>) -> PyResult<IntervalChoice> {
@@ -225,21 +580,45 @@ fn parse_interval_choice(value: &Bound<'_, PyAny>) -> PyResult<IntervalChoice> {
     ))
 }
 
+/// Calculate the Calmar ratio for the provided return statistics.
+///
+/// A zero `max_drawdown` leaves the ratio undefined; see [`calculate_sharpe_ratio`] for the
+/// supported `on_undefined` policies, the `sample_count` guard and `compounding`. With
+/// `compounding="log"` the `max_drawdown` is also converted to a log drawdown.
+#[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (risk_free_return, mean_return, max_drawdown, interval))]
+#[pyo3(signature = (risk_free_return, mean_return, max_drawdown, interval, on_undefined = None, sample_count = None, min_samples = 2, compounding = None))]
 pub fn calculate_calmar_ratio(
     py: Python<'_>,
     risk_free_return: f64,
     mean_return: f64,
     max_drawdown: f64,
     interval: &Bound<'_, PyAny>,
-) -> PyResult<Py<PyMetricWithInterval>> {
-    let risk_free = parse_decimal(risk_free_return, "risk_free_return")?;
-    let mean = parse_decimal(mean_return, "mean_return")?;
-    let drawdown = parse_decimal(max_drawdown, "max_drawdown")?;
+    on_undefined: Option<&str>,
+    sample_count: Option<u64>,
+    min_samples: u64,
+    compounding: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let compounding = parse_compounding(compounding)?;
+    let risk_free = compounding.convert_return(
+        parse_decimal(risk_free_return, "risk_free_return")?,
+        "risk_free_return",
+    )?;
+    let mean =
+        compounding.convert_return(parse_decimal(mean_return, "mean_return")?, "mean_return")?;
+    let drawdown = compounding.convert_drawdown(parse_decimal(max_drawdown, "max_drawdown")?)?;
     let choice = parse_interval_choice(interval)?;
+    let policy = parse_undefined_policy(on_undefined)?;
 
-    match choice {
+    if insufficient_samples(sample_count, min_samples) {
+        return Ok(None);
+    }
+
+    if drawdown.is_zero() && !policy.resolve("calmar_ratio", "max_drawdown is zero")? {
+        return Ok(None);
+    }
+
+    let metric = match choice {
         IntervalChoice::Daily => {
             calmar_metric(py, CalmarRatio::calculate(risk_free, mean, drawdown, Daily))
         }
@@ -254,18 +633,31 @@ pub fn calculate_calmar_ratio(
         IntervalChoice::Duration(delta) => {
             calmar_metric(py, CalmarRatio::calculate(risk_free, mean, drawdown, delta))
         }
-    }
+    }?;
+
+    Ok(Some(metric))
 }
 
+/// Calculate the profit factor from gross profits and losses.
+///
+/// Zero gross losses leave the factor undefined; see [`calculate_sharpe_ratio`] for the
+/// supported `on_undefined` policies. When both inputs are zero `None` is returned unless
+/// `on_undefined="raise"`.
 #[pyfunction]
-#[pyo3(signature = (profits_gross_abs, losses_gross_abs))]
+#[pyo3(signature = (profits_gross_abs, losses_gross_abs, on_undefined = None))]
 pub fn calculate_profit_factor(
     py: Python<'_>,
     profits_gross_abs: f64,
     losses_gross_abs: f64,
+    on_undefined: Option<&str>,
 ) -> PyResult<Option<PyObject>> {
     let profits = parse_decimal(profits_gross_abs, "profits_gross_abs")?;
     let losses = parse_decimal(losses_gross_abs, "losses_gross_abs")?;
+    let policy = parse_undefined_policy(on_undefined)?;
+
+    if losses.is_zero() && !policy.resolve("profit_factor", "losses_gross_abs is zero")? {
+        return Ok(None);
+    }
 
     let factor = ProfitFactor::calculate(profits, losses);
     factor
@@ -292,19 +684,33 @@ pub fn calculate_win_rate(py: Python<'_>, wins: f64, total: f64) -> PyResult<Opt
         .transpose()
 }
 
+/// Calculate the rate of return for `mean_return`, optionally scaled to `target_interval`.
+///
+/// `compounding` selects how the return is scaled: `"simple"` (default) scales linearly,
+/// `"log"` scales the continuously compounded return `ln(1 + r)` linearly, and `"compound"`
+/// compounds the return as `(1 + r)^n - 1`.
 #[pyfunction]
-#[pyo3(signature = (mean_return, interval, target_interval=None))]
+#[pyo3(signature = (mean_return, interval, target_interval=None, compounding=None))]
 pub fn calculate_rate_of_return(
     py: Python<'_>,
     mean_return: f64,
     interval: &Bound<'_, PyAny>,
     target_interval: Option<&Bound<'_, PyAny>>,
+    compounding: Option<&str>,
 ) -> PyResult<Py<PyMetricWithInterval>> {
     let mean = parse_decimal(mean_return, "mean_return")?;
     let base_choice = parse_interval_choice(interval)?;
     let target_choice = target_interval
         .map(|value| parse_interval_choice(value))
         .transpose()?;
+    let compounding = parse_compounding(compounding)?;
+
+    if compounding != Compounding::Simple {
+        let target = target_choice.unwrap_or(base_choice);
+        let periods = interval_periods(target, base_choice)?;
+        let value = compounding.scale_return(mean, periods, "mean_return")?;
+        return PyMetricWithInterval::from_components(py, value, target.name());
+    }
 
     match base_choice {
         IntervalChoice::Daily => {
@@ -322,6 +728,29 @@ pub fn calculate_rate_of_return(
     }
 }
 
+/// Return the number of `base_interval` periods contained in `interval`.
+///
+/// With the default daily base this is the periods-per-year constant of the annual intervals
+/// (`252` for `"annual_252"`, `365` for `"annual_365"`). Ratio metrics scale between intervals
+/// by the square root of this factor, whereas rates of return scale linearly.
+#[pyfunction]
+#[pyo3(signature = (interval, base_interval=None))]
+pub fn annualization_factor(
+    py: Python<'_>,
+    interval: &Bound<'_, PyAny>,
+    base_interval: Option<&Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    let interval = parse_interval_choice(interval)?;
+    let base = base_interval
+        .map(parse_interval_choice)
+        .transpose()?
+        .unwrap_or(IntervalChoice::Daily);
+
+    let factor = interval_periods(interval, base)?;
+
+    decimal_to_py(py, factor.normalize())
+}
+
 fn parse_datetime_point(value: &Bound<'_, PyAny>, index: usize) -> PyResult<DateTime<Utc>> {
     if let Ok(datetime) = value.extract::<DateTime<Utc>>() {
         return Ok(datetime);
@@ -403,22 +832,39 @@ fn build_drawdown_series(points: Vec<Timed<Decimal>>) -> Vec<Drawdown> {
     drawdowns
 }
 
-fn drawdown_series_from_py(points: &Bound<'_, PyAny>) -> PyResult<Vec<Drawdown>> {
+fn drawdown_series_from_py(
+    points: &Bound<'_, PyAny>,
+    compounding: Option<&str>,
+) -> PyResult<Vec<Drawdown>> {
+    let compounding = parse_compounding(compounding)?;
     let parsed = parse_equity_points(points)?;
     if parsed.is_empty() {
         return Ok(Vec::new());
     }
 
-    Ok(build_drawdown_series(parsed))
+    build_drawdown_series(parsed)
+        .into_iter()
+        .map(|drawdown| {
+            Ok(Drawdown {
+                value: compounding.convert_drawdown(drawdown.value)?,
+                ..drawdown
+            })
+        })
+        .collect()
 }
 
+/// Generate every drawdown period in the `(datetime, value)` equity `points`.
+///
+/// Drawdown values are fractional, `(peak - trough) / peak`, unless `compounding="log"`
+/// selects log drawdowns, `-ln(trough / peak)`.
 #[pyfunction]
-#[pyo3(signature = (points))]
+#[pyo3(signature = (points, compounding = None))]
 pub fn generate_drawdown_series(
     py: Python<'_>,
     points: &Bound<'_, PyAny>,
+    compounding: Option<&str>,
 ) -> PyResult<Vec<Py<PyDrawdown>>> {
-    let drawdowns = drawdown_series_from_py(points)?;
+    let drawdowns = drawdown_series_from_py(points, compounding)?;
 
     drawdowns
         .into_iter()
@@ -427,12 +873,13 @@ pub fn generate_drawdown_series(
 }
 
 #[pyfunction]
-#[pyo3(signature = (points))]
+#[pyo3(signature = (points, compounding = None))]
 pub fn calculate_max_drawdown(
     py: Python<'_>,
     points: &Bound<'_, PyAny>,
+    compounding: Option<&str>,
 ) -> PyResult<Option<Py<PyDrawdown>>> {
-    let drawdowns = drawdown_series_from_py(points)?;
+    let drawdowns = drawdown_series_from_py(points, compounding)?;
 
     if drawdowns.is_empty() {
         return Ok(None);
@@ -453,12 +900,13 @@ pub fn calculate_max_drawdown(
 }
 
 #[pyfunction]
-#[pyo3(signature = (points))]
+#[pyo3(signature = (points, compounding = None))]
 pub fn calculate_mean_drawdown(
     py: Python<'_>,
     points: &Bound<'_, PyAny>,
+    compounding: Option<&str>,
 ) -> PyResult<Option<Py<PyMeanDrawdown>>> {
-    let drawdowns = drawdown_series_from_py(points)?;
+    let drawdowns = drawdown_series_from_py(points, compounding)?;
 
     if drawdowns.is_empty() {
         return Ok(None);
@@ -478,6 +926,574 @@ pub fn calculate_mean_drawdown(
     }
 }
 
+/// Calculate the Ulcer Index of the `(datetime, value)` equity `points`.
+///
+/// The Ulcer Index is the root mean square of the percentage drawdown from the running peak at
+/// every point, so a monotonically rising curve has an Ulcer Index of zero. Returns `None` for
+/// an empty curve.
+#[pyfunction]
+#[pyo3(signature = (equity_curve))]
+pub fn calculate_ulcer_index(
+    py: Python<'_>,
+    equity_curve: &Bound<'_, PyAny>,
+) -> PyResult<Option<PyObject>> {
+    let points = parse_equity_points(equity_curve)?;
+    if points.is_empty() {
+        return Ok(None);
+    }
+
+    let count = Decimal::from(points.len());
+    let sum_squares = point_drawdowns(points)
+        .into_iter()
+        .map(|drawdown| {
+            let percent = drawdown * Decimal::ONE_HUNDRED;
+            percent * percent
+        })
+        .sum::<Decimal>();
+
+    let ulcer_index = (sum_squares / count)
+        .sqrt()
+        .ok_or_else(|| PyValueError::new_err("ulcer index could not be calculated"))?;
+
+    decimal_to_py(py, ulcer_index.normalize()).map(Some)
+}
+
+/// Calculate the Pain Ratio of the `(datetime, value)` equity `points`.
+///
+/// The simple return between the first & last points is annualised to `interval` (default
+/// `annual_365`), and its excess over `risk_free_return` is divided by the mean of the
+/// drawdown periods from [`generate_drawdown_series`]. Zero mean drawdown is handled as for
+/// [`calculate_calmar_ratio`]. Returns `None` if the curve has fewer than two points or spans
+/// no time.
+#[pyfunction]
+#[pyo3(signature = (equity_curve, risk_free_return, interval = None, on_undefined = None))]
+pub fn calculate_pain_ratio(
+    py: Python<'_>,
+    equity_curve: &Bound<'_, PyAny>,
+    risk_free_return: f64,
+    interval: Option<&Bound<'_, PyAny>>,
+    on_undefined: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let risk_free = parse_decimal(risk_free_return, "risk_free_return")?;
+    let choice = interval
+        .map(parse_interval_choice)
+        .transpose()?
+        .unwrap_or(IntervalChoice::Annual365);
+    let policy = parse_undefined_policy(on_undefined)?;
+
+    let points = parse_equity_points(equity_curve)?;
+    let Some(annualised_return) = annualised_return(&points, choice)? else {
+        return Ok(None);
+    };
+
+    let drawdowns = build_drawdown_series(points);
+    let mean_drawdown = if drawdowns.is_empty() {
+        Decimal::ZERO
+    } else {
+        drawdowns
+            .iter()
+            .map(|drawdown| drawdown.value)
+            .sum::<Decimal>()
+            / Decimal::from(drawdowns.len())
+    };
+
+    if mean_drawdown.is_zero() && !policy.resolve("pain_ratio", "mean drawdown is zero")? {
+        return Ok(None);
+    }
+
+    drawdown_ratio_metric(py, choice, risk_free, annualised_return, mean_drawdown).map(Some)
+}
+
+/// Calculate the Sterling Ratio of the `(datetime, value)` equity `points`.
+///
+/// Like [`calculate_pain_ratio`], the simple return of the curve is annualised to `interval`
+/// (default `annual_365`), but its excess over `risk_free_return` is divided by the average of
+/// the `largest_drawdowns` (default 3) deepest drawdown periods from [`generate_drawdown_series`].
+/// Curves with fewer drawdown periods average every period. Zero drawdown is handled as for
+/// [`calculate_calmar_ratio`]. Returns `None` if the curve has fewer than two points or spans
+/// no time.
+#[pyfunction]
+#[pyo3(signature = (equity_curve, risk_free_return, interval = None, largest_drawdowns = 3, on_undefined = None))]
+pub fn calculate_sterling_ratio(
+    py: Python<'_>,
+    equity_curve: &Bound<'_, PyAny>,
+    risk_free_return: f64,
+    interval: Option<&Bound<'_, PyAny>>,
+    largest_drawdowns: usize,
+    on_undefined: Option<&str>,
+) -> PyResult<Option<Py<PyMetricWithInterval>>> {
+    let risk_free = parse_decimal(risk_free_return, "risk_free_return")?;
+    let choice = interval
+        .map(parse_interval_choice)
+        .transpose()?
+        .unwrap_or(IntervalChoice::Annual365);
+    let policy = parse_undefined_policy(on_undefined)?;
+    if largest_drawdowns == 0 {
+        return Err(PyValueError::new_err("largest_drawdowns must be positive"));
+    }
+
+    let points = parse_equity_points(equity_curve)?;
+    let Some(annualised_return) = annualised_return(&points, choice)? else {
+        return Ok(None);
+    };
+
+    let mut drawdowns = build_drawdown_series(points)
+        .into_iter()
+        .map(|drawdown| drawdown.value)
+        .collect::<Vec<_>>();
+    drawdowns.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    drawdowns.truncate(largest_drawdowns);
+
+    let average_drawdown = if drawdowns.is_empty() {
+        Decimal::ZERO
+    } else {
+        drawdowns.iter().sum::<Decimal>() / Decimal::from(drawdowns.len())
+    };
+
+    if average_drawdown.is_zero()
+        && !policy.resolve("sterling_ratio", "average drawdown is zero")?
+    {
+        return Ok(None);
+    }
+
+    drawdown_ratio_metric(py, choice, risk_free, annualised_return, average_drawdown).map(Some)
+}
+
+/// Simple return between the first & last `points`, annualised to `interval`.
+///
+/// Returns `None` if there are fewer than two points or they span no time.
+fn annualised_return(
+    points: &[Timed<Decimal>],
+    interval: IntervalChoice,
+) -> PyResult<Option<Decimal>> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Ok(None);
+    };
+
+    let span = last.time.signed_duration_since(first.time);
+    if points.len() < 2 || span <= TimeDelta::zero() {
+        return Ok(None);
+    }
+
+    let total_return = (last.value - first.value)
+        .checked_div(first.value)
+        .ok_or_else(|| PyValueError::new_err("equity_curve must start with a non-zero value"))?;
+
+    Ok(Some(
+        total_return * interval_periods(interval, IntervalChoice::Duration(span))?,
+    ))
+}
+
+/// Build a [`CalmarRatio`] style metric dividing the excess `annualised_return` by `drawdown`.
+fn drawdown_ratio_metric(
+    py: Python<'_>,
+    interval: IntervalChoice,
+    risk_free: Decimal,
+    annualised_return: Decimal,
+    drawdown: Decimal,
+) -> PyResult<Py<PyMetricWithInterval>> {
+    match interval {
+        IntervalChoice::Daily => calmar_metric(
+            py,
+            CalmarRatio::calculate(risk_free, annualised_return, drawdown, Daily),
+        ),
+        IntervalChoice::Annual252 => calmar_metric(
+            py,
+            CalmarRatio::calculate(risk_free, annualised_return, drawdown, Annual252),
+        ),
+        IntervalChoice::Annual365 => calmar_metric(
+            py,
+            CalmarRatio::calculate(risk_free, annualised_return, drawdown, Annual365),
+        ),
+        IntervalChoice::Duration(delta) => calmar_metric(
+            py,
+            CalmarRatio::calculate(risk_free, annualised_return, drawdown, delta),
+        ),
+    }
+}
+
+/// Fractional drawdown from the running peak at every point, tracked with the same
+/// [`DrawdownGenerator`] used by [`generate_drawdown_series`].
+fn point_drawdowns(points: Vec<Timed<Decimal>>) -> Vec<Decimal> {
+    let mut generator = DrawdownGenerator::default();
+
+    points
+        .into_iter()
+        .map(|point| {
+            let value = point.value;
+            generator.update(point);
+            generator
+                .peak
+                .and_then(|peak| (peak - value).checked_div(peak))
+                .unwrap_or_default()
+                .max(Decimal::ZERO)
+        })
+        .collect()
+}
+
+/// Calculate the historical Value-at-Risk of a return series at the given `confidence`.
+///
+/// Returns are sorted ascending and the VaR is the return at the `1 - confidence` percentile,
+/// ie/ the 5th worst of 100 returns at `0.95` confidence. The result is expressed as a positive
+/// loss magnitude, so a negative VaR means even the tail return was a gain.
+#[pyfunction]
+#[pyo3(signature = (returns, confidence))]
+pub fn calculate_historical_var(
+    py: Python<'_>,
+    returns: &Bound<'_, PyAny>,
+    confidence: f64,
+) -> PyResult<PyObject> {
+    let (sorted, tail_index) = sorted_returns_tail(returns, confidence)?;
+    decimal_to_py(py, -sorted[tail_index])
+}
+
+/// Calculate the historical Conditional Value-at-Risk (expected shortfall) of a return series.
+///
+/// Averages every sorted return up to & including the [`calculate_historical_var`] percentile,
+/// expressed as a positive loss magnitude.
+#[pyfunction]
+#[pyo3(signature = (returns, confidence))]
+pub fn calculate_conditional_var(
+    py: Python<'_>,
+    returns: &Bound<'_, PyAny>,
+    confidence: f64,
+) -> PyResult<PyObject> {
+    let (sorted, tail_index) = sorted_returns_tail(returns, confidence)?;
+    let tail = &sorted[..=tail_index];
+    let mean = tail.iter().sum::<Decimal>() / Decimal::from(tail.len());
+    decimal_to_py(py, -mean)
+}
+
+/// Parse & sort a return series ascending, returning it alongside the index of the return at
+/// the `1 - confidence` percentile.
+fn sorted_returns_tail(
+    returns: &Bound<'_, PyAny>,
+    confidence: f64,
+) -> PyResult<(Vec<Decimal>, usize)> {
+    let confidence = parse_decimal(confidence, "confidence")?;
+    if confidence <= Decimal::ZERO || confidence >= Decimal::ONE {
+        return Err(PyValueError::new_err(
+            "confidence must be between 0 and 1 (exclusive)",
+        ));
+    }
+
+    let sequence = returns
+        .downcast::<PySequence>()
+        .map_err(|_| PyValueError::new_err("returns must be a sequence of numeric values"))?;
+
+    let mut sorted = (0..sequence.len()?)
+        .map(|index| parse_numeric_value(&sequence.get_item(index)?, &format!("returns[{index}]")))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    if sorted.is_empty() {
+        return Err(PyValueError::new_err("returns must not be empty"));
+    }
+    sorted.sort();
+
+    let tail_count = ((Decimal::ONE - confidence) * Decimal::from(sorted.len()))
+        .ceil()
+        .to_usize()
+        .unwrap_or(0);
+    let tail_index = tail_count.saturating_sub(1).min(sorted.len() - 1);
+
+    Ok((sorted, tail_index))
+}
+
+/// Merge timestamped event lists into a single time-ordered timeline.
+///
+/// Each event's time is read from its `time_exchange` or `time` attribute (or key, for dicts),
+/// falling back to `context.time` for audit ticks, and may be a `datetime`, an RFC3339 string or
+/// epoch nanoseconds. Entries are dicts with `time`, `source`, `kind` & `event`, where `source`
+/// is the name from `sources` (or the position of the event list) and `kind` is the event's
+/// type name. The merge is stable, so events sharing a timestamp keep their relative order.
+#[pyfunction]
+#[pyo3(signature = (*event_lists, sources = None))]
+pub fn merge_timeline(
+    py: Python<'_>,
+    event_lists: &Bound<'_, PyTuple>,
+    sources: Option<Vec<String>>,
+) -> PyResult<Vec<PyObject>> {
+    if let Some(sources) = &sources
+        && sources.len() != event_lists.len()
+    {
+        return Err(PyValueError::new_err(format!(
+            "sources must contain one name per event list: expected {}, got {}",
+            event_lists.len(),
+            sources.len()
+        )));
+    }
+
+    let mut timeline = Vec::new();
+    for (list_index, events) in event_lists.iter().enumerate() {
+        let source = match &sources {
+            Some(sources) => sources[list_index].clone().into_py(py),
+            None => list_index.into_py(py),
+        };
+
+        for (event_index, event) in events.iter()?.enumerate() {
+            let event = event?;
+            let time = event_time(&event)?.ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "event_lists[{list_index}][{event_index}] has no time_exchange or time"
+                ))
+            })?;
+            timeline.push((time, source.clone_ref(py), event));
+        }
+    }
+
+    timeline.sort_by_key(|(time, _, _)| *time);
+
+    timeline
+        .into_iter()
+        .map(|(time, source, event)| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("time", timestamp_to_py(py, time)?)?;
+            dict.set_item("source", source)?;
+            dict.set_item("kind", event.get_type().getattr("__name__")?)?;
+            dict.set_item("event", event)?;
+            Ok(dict.into_py(py))
+        })
+        .collect()
+}
+
+fn event_time(event: &Bound<'_, PyAny>) -> PyResult<Option<DateTime<Utc>>> {
+    for field in ["time_exchange", "time"] {
+        if let Some(value) = event_field(event, field)?
+            && !value.is_none()
+        {
+            return parse_event_timestamp(&value).map(Some);
+        }
+    }
+
+    match event_field(event, "context")? {
+        Some(context) if !context.is_none() => event_time(&context),
+        _ => Ok(None),
+    }
+}
+
+fn event_field<'py>(event: &Bound<'py, PyAny>, field: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Ok(dict) = event.downcast::<PyDict>() {
+        return dict.get_item(field);
+    }
+
+    Ok(event.getattr(field).ok())
+}
+
+fn parse_event_timestamp(value: &Bound<'_, PyAny>) -> PyResult<DateTime<Utc>> {
+    if let Ok(datetime) = value.extract::<DateTime<Utc>>() {
+        return Ok(datetime);
+    }
+
+    if let Ok(naive) = value.extract::<NaiveDateTime>() {
+        return Ok(Utc.from_utc_datetime(&naive));
+    }
+
+    if let Ok(nanos) = value.extract::<i64>() {
+        return Ok(Utc.timestamp_nanos(nanos));
+    }
+
+    if let Ok(text) = value.extract::<String>() {
+        return DateTime::parse_from_rfc3339(&text)
+            .map(|datetime| datetime.with_timezone(&Utc))
+            .map_err(|err| {
+                PyValueError::new_err(format!("invalid event timestamp '{text}': {err}"))
+            });
+    }
+
+    Err(PyValueError::new_err(
+        "event timestamps must be a datetime, RFC3339 string or epoch nanoseconds",
+    ))
+}
+
+/// Report the slippage of each trade relative to the mid price at order arrival.
+///
+/// `arrival_mids` holds one mid price per trade, in the same order as `trades`. Slippage is
+/// signed so that positive values are a cost: buys filled above the arrival mid and sells
+/// filled below it. Each report entry contains `slippage_bps` and the equivalent
+/// `slippage_quote` cost for the traded quantity.
+#[pyfunction]
+#[pyo3(signature = (trades, arrival_mids))]
+pub fn slippage_report(
+    py: Python<'_>,
+    trades: &Bound<'_, PyAny>,
+    arrival_mids: &Bound<'_, PyAny>,
+) -> PyResult<Vec<PyObject>> {
+    let trades = trades
+        .downcast::<PySequence>()
+        .map_err(|_| PyValueError::new_err("trades must be a sequence of Trade instances"))?;
+    let mids = arrival_mids
+        .downcast::<PySequence>()
+        .map_err(|_| PyValueError::new_err("arrival_mids must be a sequence of prices"))?;
+
+    let length = trades.len()?;
+    if mids.len()? != length {
+        return Err(PyValueError::new_err(format!(
+            "arrival_mids must contain one mid price per trade: expected {length}, got {}",
+            mids.len()?
+        )));
+    }
+
+    (0..length)
+        .map(|index| {
+            let trade = extract_trade(&trades.get_item(index)?, index)?;
+            let field = format!("arrival_mids[{index}]");
+            let mid = parse_numeric_value(&mids.get_item(index)?, &field)?;
+            if mid <= Decimal::ZERO {
+                return Err(PyValueError::new_err(format!("{field} must be positive")));
+            }
+
+            slippage_entry(py, &trade, mid)
+        })
+        .collect()
+}
+
+pub(crate) fn extract_trade(
+    value: &Bound<'_, PyAny>,
+    index: usize,
+) -> PyResult<Trade<QuoteAsset, InstrumentIndex>> {
+    // Wrappers from barter_python.trade_bindings hold the native trade in `_inner`.
+    let native = value.getattr("_inner").unwrap_or_else(|_| value.clone());
+
+    native
+        .extract::<PyRef<'_, PyTrade>>()
+        .map(|trade| trade.clone_inner())
+        .map_err(|_| PyValueError::new_err(format!("trades[{index}] must be a Trade instance")))
+}
+
+fn slippage_entry(
+    py: Python<'_>,
+    trade: &Trade<QuoteAsset, InstrumentIndex>,
+    arrival_mid: Decimal,
+) -> PyResult<PyObject> {
+    let price_diff = price_slippage(trade, arrival_mid);
+    let slippage_bps = price_diff / arrival_mid * Decimal::from(10_000);
+    let slippage_quote = price_diff * trade.quantity.abs();
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("trade_id", trade.id.0.as_str())?;
+    dict.set_item("instrument", trade.instrument.index())?;
+    dict.set_item("side", Py::new(py, PySide::from_side(trade.side))?)?;
+    dict.set_item("time_exchange", timestamp_to_py(py, trade.time_exchange)?)?;
+    dict.set_item("price", decimal_to_py(py, trade.price)?)?;
+    dict.set_item("quantity", decimal_to_py(py, trade.quantity)?)?;
+    dict.set_item("arrival_mid", decimal_to_py(py, arrival_mid)?)?;
+    dict.set_item("slippage_bps", decimal_to_py(py, slippage_bps)?)?;
+    dict.set_item("slippage_quote", decimal_to_py(py, slippage_quote)?)?;
+    Ok(dict.into_py(py))
+}
+
+/// Signed price slippage of a trade relative to `arrival_mid`, positive when it is a cost.
+fn price_slippage(trade: &Trade<QuoteAsset, InstrumentIndex>, arrival_mid: Decimal) -> Decimal {
+    match trade.side {
+        Side::Buy => trade.price - arrival_mid,
+        Side::Sell => arrival_mid - trade.price,
+    }
+}
+
+/// Aggregate fill statistics per order kind, for comparing aggressive & passive execution.
+///
+/// Trades do not record the kind of order that produced them, so each entry of
+/// `trades_with_kind` is a `(trade, kind, arrival_mid, order_quantity)` tuple, where `kind` is an
+/// `OrderKind` or its name and `order_quantity` is the quantity requested by the trade's order.
+///
+/// Returns a dict keyed by order kind name, each holding the trade `count`, the mean
+/// `average_slippage_bps` (signed as in [`slippage_report`]) and the `fill_rate`, ie/ the traded
+/// quantity over the quantity requested by the distinct orders filled.
+#[pyfunction]
+#[pyo3(signature = (trades_with_kind))]
+pub fn fill_stats_by_kind(
+    py: Python<'_>,
+    trades_with_kind: &Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    #[derive(Default)]
+    struct KindStats {
+        count: usize,
+        slippage_bps: Decimal,
+        filled: Decimal,
+        requested: HashMap<String, Decimal>,
+    }
+
+    let entries = trades_with_kind.downcast::<PySequence>().map_err(|_| {
+        PyValueError::new_err(
+            "trades_with_kind must be a sequence of (trade, kind, arrival_mid, order_quantity)",
+        )
+    })?;
+
+    let mut stats: Vec<(OrderKind, KindStats)> = Vec::new();
+    for index in 0..entries.len()? {
+        let entry = entries.get_item(index)?;
+        let fields = entry
+            .downcast::<PySequence>()
+            .ok()
+            .filter(|fields| fields.len().is_ok_and(|len| len == 4))
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "trades_with_kind[{index}] must be a (trade, kind, arrival_mid, order_quantity) tuple",
+                ))
+            })?;
+
+        let trade = extract_trade(&fields.get_item(0)?, index)?;
+        let kind = PyOrderKind::coerce(&fields.get_item(1)?)?;
+        let mid_field = format!("trades_with_kind[{index}].arrival_mid");
+        let mid = parse_numeric_value(&fields.get_item(2)?, &mid_field)?;
+        if mid <= Decimal::ZERO {
+            return Err(PyValueError::new_err(format!(
+                "{mid_field} must be positive"
+            )));
+        }
+        let quantity_field = format!("trades_with_kind[{index}].order_quantity");
+        let order_quantity = parse_numeric_value(&fields.get_item(3)?, &quantity_field)?.abs();
+        if order_quantity.is_zero() {
+            return Err(PyValueError::new_err(format!(
+                "{quantity_field} must be non-zero"
+            )));
+        }
+
+        let position = match stats.iter().position(|(existing, _)| *existing == kind) {
+            Some(position) => position,
+            None => {
+                stats.push((kind, KindStats::default()));
+                stats.len() - 1
+            }
+        };
+        let kind_stats = &mut stats[position].1;
+
+        kind_stats.count += 1;
+        kind_stats.slippage_bps += price_slippage(&trade, mid) / mid * Decimal::from(10_000);
+        kind_stats.filled += trade.quantity.abs();
+        kind_stats
+            .requested
+            .entry(trade.order_id.0.to_string())
+            .or_insert(order_quantity);
+    }
+
+    let output = PyDict::new_bound(py);
+    for (kind, kind_stats) in stats {
+        let requested = kind_stats.requested.values().sum::<Decimal>();
+        let entry = PyDict::new_bound(py);
+        entry.set_item("count", kind_stats.count)?;
+        entry.set_item(
+            "average_slippage_bps",
+            decimal_to_py(
+                py,
+                kind_stats.slippage_bps / Decimal::from(kind_stats.count),
+            )?,
+        )?;
+        entry.set_item(
+            "fill_rate",
+            decimal_to_py(py, kind_stats.filled / requested)?,
+        )?;
+
+        let name = match kind {
+            OrderKind::Market => "market",
+            OrderKind::Limit => "limit",
+        };
+        output.set_item(name, entry)?;
+    }
+
+    Ok(output.into_py(py))
+}
+
 /// Calculate the next mean using Welford's online algorithm.
 ///
 /// This function computes the updated mean after adding a new value to a dataset.
@@ -587,6 +1603,99 @@ pub fn welford_calculate_population_variance(
     decimal_to_py(py, result)
 }
 
+/// Streaming accumulator of the mean & variance of a series, using Welford's online algorithm.
+///
+/// Values are folded in one at a time via `update`, so statistics can be tracked over a stream
+/// without retaining it. Accumulators of disjoint streams can be combined with `merge`, which
+/// uses the parallel form of the algorithm (Chan et al.).
+#[pyclass(module = "barter_python", name = "Welford")]
+#[derive(Debug, Clone, Default)]
+pub struct PyWelford {
+    count: u64,
+    mean: Decimal,
+    recurrence_relation_m: Decimal,
+}
+
+#[pymethods]
+impl PyWelford {
+    #[new]
+    pub fn __new__() -> Self {
+        Self::default()
+    }
+
+    /// Incorporate the next value of the series.
+    #[pyo3(signature = (value))]
+    pub fn update(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let value = parse_numeric_value(value, "value")?;
+
+        self.count += 1;
+        let prev_mean = self.mean;
+        self.mean = welford_online::calculate_mean(prev_mean, value, Decimal::from(self.count));
+        self.recurrence_relation_m = welford_online::calculate_recurrence_relation_m(
+            self.recurrence_relation_m,
+            prev_mean,
+            value,
+            self.mean,
+        );
+
+        Ok(())
+    }
+
+    /// Fold the statistics of `other`, accumulated over a disjoint series, into this accumulator.
+    #[pyo3(signature = (other))]
+    pub fn merge(&mut self, other: &PyWelford) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let count_self = Decimal::from(self.count);
+        let count_other = Decimal::from(other.count);
+        let count = count_self + count_other;
+        let delta = other.mean - self.mean;
+
+        self.mean += delta * count_other / count;
+        self.recurrence_relation_m +=
+            other.recurrence_relation_m + delta * delta * count_self * count_other / count;
+        self.count += other.count;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self, py: Python<'_>) -> PyResult<PyObject> {
+        decimal_to_py(py, self.mean)
+    }
+
+    pub fn sample_variance(&self, py: Python<'_>) -> PyResult<PyObject> {
+        decimal_to_py(
+            py,
+            welford_online::calculate_sample_variance(
+                self.recurrence_relation_m,
+                Decimal::from(self.count),
+            ),
+        )
+    }
+
+    pub fn population_variance(&self, py: Python<'_>) -> PyResult<PyObject> {
+        decimal_to_py(
+            py,
+            welford_online::calculate_population_variance(
+                self.recurrence_relation_m,
+                Decimal::from(self.count),
+            ),
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Welford(count={}, mean={})", self.count, self.mean)
+    }
+}
+
 fn parse_interval_from_str(label: &str) -> PyResult<IntervalChoice> {
     let normalised = label.trim().to_ascii_lowercase();
     match normalised.as_str() {
@@ -162,6 +162,29 @@ impl PyMarketDataInMemory {
         self.time_first_event
     }
 
+    /// Return a new `MarketDataInMemory` retaining only the market events whose `DataKind`
+    /// matches one of `kinds` (eg/ `["trade"]`). Reconnecting events are always retained.
+    pub fn filter_kinds(&self, kinds: Vec<String>) -> PyResult<Self> {
+        let kinds = kinds
+            .iter()
+            .map(|kind| parse_data_kind_name(kind))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let events = self
+            .events
+            .iter()
+            .filter(|event| match event {
+                MarketStreamEvent::Item(item) => {
+                    kinds.iter().any(|kind| *kind == item.kind.kind_name())
+                }
+                MarketStreamEvent::Reconnecting(_) => true,
+            })
+            .cloned()
+            .collect();
+
+        Self::from_events_vec(events)
+    }
+
     pub fn events(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
         let data_module = Self::data_module(py)?;
         let instrument_module = Self::instrument_module(py)?;
@@ -243,6 +266,7 @@ impl PyBacktestArgsConstant {
         })
         .trading_state(TradingState::Enabled)
         .time_engine_start(time_first_event)
+        .tear_sheet_history(true)
         .balances(self._initial_balances.clone())
         .build();
 
@@ -647,6 +671,22 @@ fn parse_market_event_from_py(
     })
 }
 
+/// Parse a `DataKind` name into its [`DataKind::kind_name`], accepting both the Python
+/// `DataKind.kind` spelling (eg/ "order_book_l1") and the `kind_name` itself (eg/ "l1").
+fn parse_data_kind_name(name: &str) -> PyResult<&'static str> {
+    match name.to_ascii_lowercase().as_str() {
+        "trade" | "public_trade" => Ok("public_trade"),
+        "order_book_l1" | "l1" => Ok("l1"),
+        "order_book" | "l2" => Ok("l2"),
+        "candle" => Ok("candle"),
+        "liquidation" => Ok("liquidation"),
+        other => Err(PyValueError::new_err(format!(
+            "unknown DataKind '{other}', expected one of 'trade', 'order_book_l1', \
+             'order_book', 'candle' or 'liquidation'"
+        ))),
+    }
+}
+
 fn data_kind_from_py(
     py: Python<'_>,
     obj: &PyObject,
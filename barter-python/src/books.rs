@@ -1,10 +1,16 @@
 #![allow(unused_imports)]
 
-use barter_data::books::{
-    Asks, Bids, Level, OrderBook, OrderBookSide, mid_price, volume_weighted_mid_price,
+use barter_data::{
+    books::{Asks, Bids, Level, OrderBook, OrderBookSide, mid_price, volume_weighted_mid_price},
+    subscription::book::OrderBookEvent,
 };
-use pyo3::prelude::*;
-use rust_decimal::{Decimal, prelude::FromPrimitive};
+use pyo3::{prelude::*, types::PyDict};
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
+};
+
+use crate::summary::decimal_to_py;
 
 /// Wrapper around [`Level`] for Python exposure.
 #[pyclass(module = "barter_python", name = "Level", unsendable)]
@@ -68,18 +74,70 @@ impl PyLevel {
 #[derive(Debug, Clone)]
 pub struct PyOrderBook {
     inner: OrderBook,
+    price_scale: Option<u32>,
+}
+
+/// Round `levels` prices to `price_scale` decimal places, so equal prices quoted with
+/// inconsistent precision resolve to the same level.
+///
+/// Levels that collapse onto the same price are merged, with the latest amount taking precedence.
+fn normalise_prices(levels: Vec<Level>, price_scale: Option<u32>) -> Vec<Level> {
+    let Some(scale) = price_scale else {
+        return levels;
+    };
+
+    let mut normalised: Vec<Level> = Vec::with_capacity(levels.len());
+    for mut level in levels {
+        level.price = level.price.round_dp(scale).normalize();
+        match normalised
+            .iter_mut()
+            .find(|existing| existing.price == level.price)
+        {
+            Some(existing) => existing.amount = level.amount,
+            None => normalised.push(level),
+        }
+    }
+    normalised
+}
+
+impl PyOrderBook {
+    /// Best bid and ask prices, if both sides of the book are populated.
+    fn best_bid_ask(&self) -> Option<(Decimal, Decimal)> {
+        let bid = self.inner.bids().best()?;
+        let ask = self.inner.asks().best()?;
+        Some((bid.price, ask.price))
+    }
+
+    /// Apply an incremental `update`, with its level prices normalised to this book's
+    /// `price_scale` first so they resolve to the same levels as on insert.
+    fn merge_update(&mut self, update: &OrderBook) {
+        let update = match self.price_scale {
+            None => update.clone(),
+            Some(_) => OrderBook::new(
+                update.sequence(),
+                update.time_engine(),
+                normalise_prices(update.bids().levels().to_vec(), self.price_scale),
+                normalise_prices(update.asks().levels().to_vec(), self.price_scale),
+            ),
+        };
+        self.inner.update(&OrderBookEvent::Update(update));
+    }
 }
 
 #[pymethods]
 impl PyOrderBook {
     /// Create a new [`OrderBook`].
     #[new]
-    #[pyo3(signature = (sequence, bids, asks, time_engine=None))]
+    ///
+    /// When `price_scale` is provided, level prices are rounded to that many decimal places on
+    /// insert.
+    #[pyo3(signature = (sequence, bids, asks, time_engine=None, price_scale=None))]
     fn new(
         sequence: i64,
         bids: Vec<(f64, f64)>,
         asks: Vec<(f64, f64)>,
         time_engine: Option<chrono::DateTime<chrono::Utc>>,
+        price_scale: Option<u32>,
     ) -> PyResult<Self> {
         let bids_levels: Vec<Level> = bids
             .into_iter()
@@ -128,16 +186,39 @@ impl PyOrderBook {
             .collect::<PyResult<Vec<_>>>()?;
 
         Ok(Self {
-            inner: OrderBook::new(sequence as u64, time_engine, bids_levels, asks_levels),
+            inner: OrderBook::new(
+                sequence as u64,
+                time_engine,
+                normalise_prices(bids_levels, price_scale),
+                normalise_prices(asks_levels, price_scale),
+            ),
+            price_scale,
         })
     }
 
+    /// Get the number of decimal places prices are rounded to on insert, if any.
+    #[getter]
+    fn price_scale(&self) -> Option<u32> {
+        self.price_scale
+    }
+
     /// Get the sequence number.
     #[getter]
     fn sequence(&self) -> u64 {
         self.inner.sequence()
     }
 
+    /// Whether the book missed updates and must be resynchronised from a snapshot.
+    #[getter]
+    fn desynchronised(&self) -> bool {
+        self.inner.is_desynchronised()
+    }
+
+    /// Flag the book as desynchronised, eg/ after detecting a sequence gap.
+    fn mark_desynchronised(&mut self) {
+        self.inner.mark_desynchronised();
+    }
+
     /// Get the time engine.
     #[getter]
     fn time_engine(&self) -> Option<chrono::DateTime<chrono::Utc>> {
@@ -169,11 +250,164 @@ impl PyOrderBook {
         self.inner.mid_price().map(|p| p.to_string())
     }
 
+    /// Best ask minus best bid price, or `None` if either side of the book is empty.
+    #[getter]
+    fn spread(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.best_bid_ask()
+            .map(|(bid, ask)| decimal_to_py(py, ask - bid))
+            .transpose()
+    }
+
+    /// Spread in basis points of the mid-price, or `None` if either side of the book is empty.
+    #[getter]
+    fn spread_bps(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let Some((bid, ask)) = self.best_bid_ask() else {
+            return Ok(None);
+        };
+
+        let mid = mid_price(bid, ask);
+        if mid.is_zero() {
+            return Ok(None);
+        }
+
+        decimal_to_py(py, (ask - bid) / mid * Decimal::from(10_000)).map(Some)
+    }
+
     /// Calculate the volume weighted mid-price.
     fn volume_weighted_mid_price(&self) -> Option<String> {
         self.inner.volume_weighed_mid_price().map(|p| p.to_string())
     }
 
+    /// Return the book as a list of `{"side", "level", "price", "amount"}` records, bids first,
+    /// suitable for constructing a `pandas.DataFrame`.
+    ///
+    /// `level` is the zero-based depth index on each side, and prices & amounts are `Decimal`s.
+    fn to_records(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let sides = [
+            ("bid", self.inner.bids().levels()),
+            ("ask", self.inner.asks().levels()),
+        ];
+
+        let mut records = Vec::new();
+        for (side, levels) in sides {
+            for (index, level) in levels.iter().enumerate() {
+                let record = PyDict::new_bound(py);
+                record.set_item("side", side)?;
+                record.set_item("level", index)?;
+                record.set_item("price", decimal_to_py(py, level.price)?)?;
+                record.set_item("amount", decimal_to_py(py, level.amount)?)?;
+                records.push(record.into_py(py));
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Return the book as `{"bid_price", "bid_qty", "ask_price", "ask_qty"}` lists of floats,
+    /// best level first, suitable for `pandas.DataFrame(book.to_arrays())`.
+    ///
+    /// Each side is truncated to `depth` levels if provided, and the shorter side is padded with
+    /// `NaN` so that all lists have equal length.
+    #[pyo3(signature = (depth=None))]
+    fn to_arrays<'py>(
+        &self,
+        py: Python<'py>,
+        depth: Option<usize>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let bids = self.inner.bids().levels();
+        let asks = self.inner.asks().levels();
+        let len = bids.len().max(asks.len()).min(depth.unwrap_or(usize::MAX));
+
+        let column = |levels: &[Level], value: fn(&Level) -> Decimal| -> Vec<f64> {
+            (0..len)
+                .map(|index| {
+                    levels
+                        .get(index)
+                        .and_then(|level| value(level).to_f64())
+                        .unwrap_or(f64::NAN)
+                })
+                .collect()
+        };
+
+        let arrays = PyDict::new_bound(py);
+        arrays.set_item("bid_price", column(bids, |level| level.price))?;
+        arrays.set_item("bid_qty", column(bids, |level| level.amount))?;
+        arrays.set_item("ask_price", column(asks, |level| level.price))?;
+        arrays.set_item("ask_qty", column(asks, |level| level.amount))?;
+        Ok(arrays)
+    }
+
+    /// Return a new book with the levels of `update` merged into this one.
+    ///
+    /// Update levels with a zero amount are removed, others are inserted or replace the existing
+    /// level at that price, maintaining bid-descending and ask-ascending ordering. The new book
+    /// takes the sequence and engine time of the `update`, unless this book is desynchronised, in
+    /// which case the update is ignored. Update prices are rounded to this book's `price_scale`.
+    fn apply_update(&self, update: &PyOrderBook) -> PyOrderBook {
+        let mut book = self.clone();
+        book.merge_update(&update.inner);
+        book
+    }
+
+    /// Drop all but the top `levels` price levels on each side of the book, in place.
+    fn truncate(&mut self, levels: usize) {
+        self.inner.truncate(levels);
+    }
+
+    /// Check the book for integrity violations, returning a description of each one found.
+    ///
+    /// Bids must be strictly descending and asks strictly ascending by price, the best bid must
+    /// be below the best ask, and no level may have a negative amount.
+    fn validate(&self) -> Vec<String> {
+        let bids = self.inner.bids().levels();
+        let asks = self.inner.asks().levels();
+        let mut violations = Vec::new();
+
+        for (index, pair) in bids.windows(2).enumerate() {
+            if pair[0].price <= pair[1].price {
+                violations.push(format!(
+                    "bids not strictly descending at level {}: {} <= {}",
+                    index + 1,
+                    pair[0].price,
+                    pair[1].price
+                ));
+            }
+        }
+
+        for (index, pair) in asks.windows(2).enumerate() {
+            if pair[0].price >= pair[1].price {
+                violations.push(format!(
+                    "asks not strictly ascending at level {}: {} >= {}",
+                    index + 1,
+                    pair[0].price,
+                    pair[1].price
+                ));
+            }
+        }
+
+        if let (Some(best_bid), Some(best_ask)) = (bids.first(), asks.first())
+            && best_bid.price >= best_ask.price
+        {
+            violations.push(format!(
+                "crossed book: best bid {} >= best ask {}",
+                best_bid.price, best_ask.price
+            ));
+        }
+
+        for (side, levels) in [("bid", bids), ("ask", asks)] {
+            for (index, level) in levels.iter().enumerate() {
+                if level.amount < Decimal::ZERO {
+                    violations.push(format!(
+                        "negative {side} amount at level {index}: {}",
+                        level.amount
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!(
             "OrderBook(sequence={}, bids={}, asks={})",
@@ -184,6 +418,56 @@ impl PyOrderBook {
     }
 }
 
+/// Convert a full `book` snapshot into the equivalent sequence of single-level updates, one per
+/// bid then ask level from best to worst, each carrying the snapshot sequence and engine time.
+///
+/// Applying the updates in order to an empty book with [`updates_to_snapshot`] reproduces `book`.
+#[pyfunction]
+pub fn snapshot_to_updates(book: &PyOrderBook) -> Vec<PyOrderBook> {
+    let sequence = book.inner.sequence();
+    let time_engine = book.inner.time_engine();
+    let update = |bids: Vec<Level>, asks: Vec<Level>| PyOrderBook {
+        inner: OrderBook::new(sequence, time_engine, bids, asks),
+        price_scale: book.price_scale,
+    };
+
+    let bids = book
+        .inner
+        .bids()
+        .levels()
+        .iter()
+        .map(|level| update(vec![*level], vec![]));
+    let asks = book
+        .inner
+        .asks()
+        .levels()
+        .iter()
+        .map(|level| update(vec![], vec![*level]));
+
+    bids.chain(asks).collect()
+}
+
+/// Apply `updates` in order to the `base` book (empty if not provided) and return the resulting
+/// snapshot.
+///
+/// Update levels are upserted, with a zero amount removing the level and prices rounded to the
+/// `price_scale` of `base`, and the snapshot takes the sequence and engine time of the final
+/// update.
+#[pyfunction]
+#[pyo3(signature = (updates, base=None))]
+pub fn updates_to_snapshot(updates: Vec<PyOrderBook>, base: Option<&PyOrderBook>) -> PyOrderBook {
+    let mut snapshot = base.cloned().unwrap_or_else(|| PyOrderBook {
+        inner: OrderBook::default(),
+        price_scale: None,
+    });
+
+    for update in updates {
+        snapshot.merge_update(&update.inner);
+    }
+
+    snapshot
+}
+
 /// Calculate the mid-price from best bid and ask prices.
 #[pyfunction]
 pub fn calculate_mid_price(best_bid_price: f64, best_ask_price: f64) -> PyResult<String> {
@@ -1,5 +1,10 @@
 use chrono::{DateTime, Utc};
-use pyo3::{PyObject, exceptions::PyValueError, prelude::*, types::PyModule};
+use pyo3::{
+    PyObject,
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PyModule},
+};
 use rust_decimal::prelude::ToPrimitive;
 use serde_json::Value as JsonValue;
 
@@ -24,12 +29,18 @@ use barter_data::{
 use barter_instrument::{exchange::ExchangeId, instrument::InstrumentIndex};
 use barter_integration::Terminal;
 
-use crate::command::{
-    PyInstrumentFilter, PyOrderRequestCancel, PyOrderRequestOpen, collect_cancel_requests,
-    collect_open_requests,
+use crate::{
+    command::{
+        PyInstrumentFilter, PyOrderRequestCancel, PyOrderRequestOpen, collect_cancel_requests,
+        collect_open_requests,
+    },
+    data::PyCandle,
 };
 
 /// Wrapper around [`EngineEvent`] value for Python.
+///
+/// Market event constructors accept an `index_offset` which is subtracted from `instrument`,
+/// allowing callers to pass instrument numbers from a 1-based or otherwise offset scheme.
 #[pyclass(module = "barter_python", name = "EngineEvent", unsendable)]
 #[derive(Debug, Clone)]
 pub struct PyEngineEvent {
@@ -54,6 +65,14 @@ impl PyEngineEvent {
         Ok(Self { inner })
     }
 
+    /// Construct an [`EngineEvent`] from MessagePack bytes produced by [`Self::to_bytes`].
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let inner = rmp_serde::from_slice(data)
+            .map_err(|err| PyValueError::new_err(format!("invalid EngineEvent bytes: {err}")))?;
+        Ok(Self { inner })
+    }
+
     /// Construct an [`EngineEvent`] from a Python dictionary-like object.
     #[staticmethod]
     pub fn from_dict(py: Python<'_>, value: PyObject) -> PyResult<Self> {
@@ -151,7 +170,7 @@ impl PyEngineEvent {
     /// Construct an [`EngineEvent::Market`] wrapping a public trade.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, trade_id, price, amount, side, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, trade_id, price, amount, side, time_exchange=None, time_received=None, index_offset=0))]
     pub fn market_trade(
         exchange: &str,
         instrument: usize,
@@ -161,9 +180,10 @@ impl PyEngineEvent {
         side: &str,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
         let side = parse_side_local(side)?;
         let time_exchange = time_exchange.unwrap_or(Utc::now());
         let time_received = time_received.unwrap_or(time_exchange);
@@ -206,7 +226,7 @@ impl PyEngineEvent {
     /// Construct an [`EngineEvent::Market`] wrapping a candle.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, open, high, low, close, volume, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, open, high, low, close, volume, time_exchange=None, time_received=None, trade_count=0, index_offset=0))]
     pub fn market_candle(
         exchange: &str,
         instrument: usize,
@@ -217,9 +237,11 @@ impl PyEngineEvent {
         volume: f64,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        trade_count: u64,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
         let time_exchange = time_exchange.unwrap_or(Utc::now());
         let time_received = time_received.unwrap_or(time_exchange);
 
@@ -266,7 +288,7 @@ impl PyEngineEvent {
             low: low.to_f64().unwrap(),
             close: close.to_f64().unwrap(),
             volume: volume.to_f64().unwrap(),
-            trade_count: 0, // Default value since not provided
+            trade_count,
         };
 
         let event = MarketEvent {
@@ -282,10 +304,41 @@ impl PyEngineEvent {
         })
     }
 
+    /// Construct an [`EngineEvent::Market`] wrapping a [`PyCandle`].
+    ///
+    /// `time_exchange` defaults to the candle close time.
+    #[staticmethod]
+    #[pyo3(signature = (exchange, instrument, candle, time_exchange=None, time_received=None, index_offset=0))]
+    pub fn market_candle_from(
+        exchange: &str,
+        instrument: usize,
+        candle: &PyCandle,
+        time_exchange: Option<DateTime<Utc>>,
+        time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
+    ) -> PyResult<Self> {
+        let exchange_id = parse_exchange_id(exchange)?;
+        let candle = candle.as_inner();
+        let time_exchange = time_exchange.unwrap_or(candle.close_time);
+        let time_received = time_received.unwrap_or(time_exchange);
+
+        let event = MarketEvent {
+            time_exchange,
+            time_received,
+            exchange: exchange_id,
+            instrument: offset_instrument_index(instrument, index_offset)?,
+            kind: DataKind::Candle(candle),
+        };
+
+        Ok(Self {
+            inner: EngineEvent::Market(MarketStreamEvent::Item(event)),
+        })
+    }
+
     /// Construct an [`EngineEvent::Market`] wrapping a liquidation.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, price, amount, side, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, price, amount, side, time_exchange=None, time_received=None, index_offset=0))]
     pub fn market_liquidation(
         exchange: &str,
         instrument: usize,
@@ -294,9 +347,10 @@ impl PyEngineEvent {
         side: &str,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
         let side = parse_side_local(side)?;
         let time_exchange = time_exchange.unwrap_or(Utc::now());
         let time_received = time_received.unwrap_or(time_exchange);
@@ -339,7 +393,7 @@ impl PyEngineEvent {
     /// Construct an [`EngineEvent::Market`] wrapping an order book snapshot.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, sequence, time_engine, bids, asks, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, sequence, time_engine, bids, asks, time_exchange=None, time_received=None, index_offset=0))]
     pub fn market_order_book_snapshot(
         exchange: &str,
         instrument: usize,
@@ -349,9 +403,10 @@ impl PyEngineEvent {
         asks: Vec<(f64, f64)>,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
 
         let bids_levels: Vec<Level> = bids
             .into_iter()
@@ -383,7 +438,7 @@ impl PyEngineEvent {
     /// Construct an [`EngineEvent::Market`] wrapping an order book update.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, sequence, time_engine, bids, asks, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, sequence, time_engine, bids, asks, time_exchange=None, time_received=None, index_offset=0))]
     pub fn market_order_book_update(
         exchange: &str,
         instrument: usize,
@@ -393,9 +448,10 @@ impl PyEngineEvent {
         asks: Vec<(f64, f64)>,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
 
         let bids_levels: Vec<Level> = bids
             .into_iter()
@@ -427,7 +483,7 @@ impl PyEngineEvent {
     /// Construct an [`EngineEvent::Market`] wrapping an order book L1 snapshot.
     #[allow(clippy::too_many_arguments)]
     #[staticmethod]
-    #[pyo3(signature = (exchange, instrument, bid_price, bid_amount, ask_price, ask_amount, time_exchange=None, time_received=None))]
+    #[pyo3(signature = (exchange, instrument, bid_price, bid_amount, ask_price, ask_amount, time_exchange=None, time_received=None, index_offset=0))]
     pub fn market_order_book_l1(
         exchange: &str,
         instrument: usize,
@@ -437,9 +493,10 @@ impl PyEngineEvent {
         ask_amount: Option<f64>,
         time_exchange: Option<DateTime<Utc>>,
         time_received: Option<DateTime<Utc>>,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let exchange_id = parse_exchange_id(exchange)?;
-        let instrument_index = InstrumentIndex(instrument);
+        let instrument_index = offset_instrument_index(instrument, index_offset)?;
 
         let time_exchange_unwrapped = time_exchange.unwrap_or(Utc::now());
         let time_received = time_received.unwrap_or(time_exchange_unwrapped);
@@ -494,6 +551,14 @@ impl PyEngineEvent {
         serde_json::to_string(&self.inner).map_err(|err| PyValueError::new_err(err.to_string()))
     }
 
+    /// Serialize the [`EngineEvent`] to compact MessagePack bytes, for recording large event
+    /// streams more efficiently than [`Self::to_json`].
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        rmp_serde::to_vec_named(&self.inner)
+            .map(|bytes| PyBytes::new_bound(py, &bytes))
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
     /// Convert the [`EngineEvent`] into a Python dictionary via JSON round-trip.
     pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
         let json = self.to_json()?;
@@ -508,6 +573,21 @@ impl PyEngineEvent {
     }
 }
 
+/// Translate an externally numbered `instrument` into the engine's 0-based [`InstrumentIndex`].
+pub(crate) fn offset_instrument_index(
+    instrument: usize,
+    index_offset: usize,
+) -> PyResult<InstrumentIndex> {
+    instrument
+        .checked_sub(index_offset)
+        .map(InstrumentIndex)
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "instrument {instrument} is below the index offset {index_offset}"
+            ))
+        })
+}
+
 fn parse_exchange_id(value: &str) -> PyResult<ExchangeId> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -12,7 +12,7 @@ use barter_execution::order::request::{
 use barter_execution::order::{
     OrderKey, OrderKind, OrderSnapshot, TimeInForce,
     id::{ClientOrderId, OrderId, StrategyId},
-    state::{ActiveOrderState, Open, OpenInFlight, OrderState},
+    state::{ActiveOrderState, InactiveOrderState, Open, OpenInFlight, OrderState},
 };
 use barter_instrument::{
     Side, Underlying, asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex,
@@ -187,7 +187,7 @@ fn parse_exchange_index(value: &Bound<'_, PyAny>) -> PyResult<ExchangeIndex> {
     ))
 }
 
-fn parse_instrument_index(value: &Bound<'_, PyAny>) -> PyResult<InstrumentIndex> {
+pub(crate) fn parse_instrument_index(value: &Bound<'_, PyAny>) -> PyResult<InstrumentIndex> {
     if let Ok(index) = value.extract::<usize>() {
         return Ok(InstrumentIndex(index));
     }
@@ -289,6 +289,23 @@ impl PyOrderRequestOpen {
         }
     }
 
+    /// Compare with another request, allowing `price` and `quantity` to differ within tolerances.
+    ///
+    /// All other fields must be equal.
+    #[pyo3(signature = (other, price_tol=0.0, qty_tol=0.0))]
+    pub fn approx_eq(&self, other: &Self, price_tol: f64, qty_tol: f64) -> PyResult<bool> {
+        let price_tol = parse_tolerance(price_tol, "price_tol")?;
+        let qty_tol = parse_tolerance(qty_tol, "qty_tol")?;
+        let (lhs, rhs) = (&self.inner, &other.inner);
+
+        Ok(lhs.key == rhs.key
+            && lhs.state.side == rhs.state.side
+            && lhs.state.kind == rhs.state.kind
+            && lhs.state.time_in_force == rhs.state.time_in_force
+            && within_tolerance(lhs.state.price, rhs.state.price, price_tol)
+            && within_tolerance(lhs.state.quantity, rhs.state.quantity, qty_tol))
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!(
             "OrderRequestOpen(side='{}', price={}, quantity={}, kind='{}', time_in_force='{}')",
@@ -427,6 +444,74 @@ impl PyOrderSnapshot {
         Ok(Self { inner: order })
     }
 
+    #[getter]
+    pub fn key(&self) -> PyOrderKey {
+        PyOrderKey::from_inner(self.inner.key.clone())
+    }
+
+    #[getter]
+    pub fn side(&self) -> &'static str {
+        match self.inner.side {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        }
+    }
+
+    #[getter]
+    pub fn price(&self) -> String {
+        self.inner.price.to_string()
+    }
+
+    #[getter]
+    pub fn quantity(&self) -> String {
+        self.inner.quantity.to_string()
+    }
+
+    /// Name of the order state, eg/ "open_in_flight", "open", or "cancelled".
+    #[getter]
+    pub fn state(&self) -> &'static str {
+        match &self.inner.state {
+            OrderState::Active(ActiveOrderState::OpenInFlight(_)) => "open_in_flight",
+            OrderState::Active(ActiveOrderState::Open(_)) => "open",
+            OrderState::Active(ActiveOrderState::CancelInFlight(_)) => "cancel_in_flight",
+            OrderState::Inactive(InactiveOrderState::Cancelled(_)) => "cancelled",
+            OrderState::Inactive(InactiveOrderState::FullyFilled) => "fully_filled",
+            OrderState::Inactive(InactiveOrderState::OpenFailed(_)) => "open_failed",
+            OrderState::Inactive(InactiveOrderState::Expired) => "expired",
+        }
+    }
+
+    /// Compare with another snapshot, allowing `price`, `quantity` and the filled quantity of
+    /// open orders to differ within tolerances.
+    ///
+    /// All other fields must be equal.
+    #[pyo3(signature = (other, price_tol=0.0, qty_tol=0.0))]
+    pub fn approx_eq(&self, other: &Self, price_tol: f64, qty_tol: f64) -> PyResult<bool> {
+        let price_tol = parse_tolerance(price_tol, "price_tol")?;
+        let qty_tol = parse_tolerance(qty_tol, "qty_tol")?;
+        let (lhs, rhs) = (&self.inner, &other.inner);
+
+        let state_eq = match (&lhs.state, &rhs.state) {
+            (
+                OrderState::Active(ActiveOrderState::Open(lhs_open)),
+                OrderState::Active(ActiveOrderState::Open(rhs_open)),
+            ) => {
+                lhs_open.id == rhs_open.id
+                    && lhs_open.time_exchange == rhs_open.time_exchange
+                    && within_tolerance(lhs_open.filled_quantity, rhs_open.filled_quantity, qty_tol)
+            }
+            (lhs_state, rhs_state) => lhs_state == rhs_state,
+        };
+
+        Ok(state_eq
+            && lhs.key == rhs.key
+            && lhs.side == rhs.side
+            && lhs.kind == rhs.kind
+            && lhs.time_in_force == rhs.time_in_force
+            && within_tolerance(lhs.price, rhs.price, price_tol)
+            && within_tolerance(lhs.quantity, rhs.quantity, qty_tol))
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         let order = &self.inner;
         let side = match order.side {
@@ -581,6 +666,22 @@ pub(crate) fn parse_decimal(value: f64, field: &str) -> PyResult<Decimal> {
         .ok_or_else(|| PyValueError::new_err(format!("{field} must be a finite numeric value")))
 }
 
+/// Parse a non-negative tolerance used by `approx_eq` comparisons.
+pub(crate) fn parse_tolerance(value: f64, field: &str) -> PyResult<Decimal> {
+    let tolerance = parse_decimal(value, field)?;
+    if tolerance.is_sign_negative() {
+        return Err(PyValueError::new_err(format!(
+            "{field} must be a non-negative numeric value"
+        )));
+    }
+    Ok(tolerance)
+}
+
+/// Returns `true` if `lhs` and `rhs` differ by no more than `tolerance`.
+pub(crate) fn within_tolerance(lhs: Decimal, rhs: Decimal, tolerance: Decimal) -> bool {
+    (lhs - rhs).abs() <= tolerance
+}
+
 pub(crate) fn parse_time_in_force(
     value: Option<&Bound<'_, PyAny>>,
     post_only: Option<bool>,
@@ -5,11 +5,22 @@ use barter_instrument::{
     asset::{ExchangeAsset, name::AssetNameInternal},
     exchange::ExchangeId,
 };
+use chrono::{DateTime, Utc};
 use pyo3::{
-    Bound, PyObject, PyResult, Python,
+    Bound, IntoPy, PyAny, PyObject, PyResult, Python,
     exceptions::PyValueError,
-    types::{PyAnyMethods, PyDict, PyDictMethods},
+    pyfunction,
+    types::{PyAnyMethods, PyDict, PyDictMethods, PyStringMethods},
 };
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+/// Whether serialized timestamps are rendered as epoch nanosecond integers.
+static TIMESTAMP_EPOCH_NS: AtomicBool = AtomicBool::new(false);
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum SummaryInterval {
@@ -18,6 +29,15 @@ pub enum SummaryInterval {
     Annual365,
 }
 
+/// Convert a non-negative number of seconds into a [`Duration`], erroring rather than panicking
+/// if it is too large to represent or to offset the current [`tokio::time::Instant`] by.
+pub fn duration_from_secs(secs: f64, field: &str) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(secs)
+        .ok()
+        .filter(|duration| tokio::time::Instant::now().checked_add(*duration).is_some())
+        .ok_or_else(|| PyValueError::new_err(format!("{field} is out of range")))
+}
+
 pub fn parse_summary_interval(value: Option<&str>) -> PyResult<SummaryInterval> {
     match value {
         None => Ok(SummaryInterval::Daily),
@@ -42,6 +62,121 @@ pub fn parse_summary_interval(value: Option<&str>) -> PyResult<SummaryInterval>
     }
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TimestampFormat {
+    Native,
+    EpochNs,
+}
+
+impl TimestampFormat {
+    fn current() -> Self {
+        if TIMESTAMP_EPOCH_NS.load(Ordering::Relaxed) {
+            Self::EpochNs
+        } else {
+            Self::Native
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::EpochNs => "epoch_ns",
+        }
+    }
+}
+
+pub fn parse_timestamp_format(value: &str) -> PyResult<TimestampFormat> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "native" | "rfc3339" | "datetime" => Ok(TimestampFormat::Native),
+        "epoch_ns" | "ns" | "nanos" => Ok(TimestampFormat::EpochNs),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid timestamp format '{value}'. valid values are: native, epoch_ns",
+            value = value.trim()
+        ))),
+    }
+}
+
+/// Set how timestamps are rendered by `to_dict` methods & timestamp getters.
+///
+/// `"native"` (the default) keeps each path's existing `datetime` or RFC3339 string output,
+/// while `"epoch_ns"` renders every timestamp as integer nanoseconds since the Unix epoch,
+/// matching the `Metric.time` convention.
+#[pyfunction]
+pub fn set_timestamp_format(format: &str) -> PyResult<()> {
+    let format = parse_timestamp_format(format)?;
+    TIMESTAMP_EPOCH_NS.store(format == TimestampFormat::EpochNs, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Return the active timestamp format, either `"native"` or `"epoch_ns"`.
+#[pyfunction]
+pub fn timestamp_format() -> &'static str {
+    TimestampFormat::current().as_str()
+}
+
+/// Render a timestamp as a `datetime`, or as epoch nanoseconds if configured.
+pub fn timestamp_to_py(py: Python<'_>, time: DateTime<Utc>) -> PyResult<PyObject> {
+    match TimestampFormat::current() {
+        TimestampFormat::Native => Ok(time.into_py(py)),
+        TimestampFormat::EpochNs => epoch_ns_to_py(py, time),
+    }
+}
+
+/// Render a timestamp as an RFC3339 string, or as epoch nanoseconds if configured.
+pub fn timestamp_str_to_py(py: Python<'_>, time: DateTime<Utc>) -> PyResult<PyObject> {
+    match TimestampFormat::current() {
+        TimestampFormat::Native => Ok(time.to_rfc3339().into_py(py)),
+        TimestampFormat::EpochNs => epoch_ns_to_py(py, time),
+    }
+}
+
+/// Convert a list of decimals (`decimal.Decimal`, `str` or `int`) to floats in a single pass.
+#[pyfunction]
+pub fn decimals_to_floats(values: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<f64>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            decimal_from_value(value, index)?.to_f64().ok_or_else(|| {
+                PyValueError::new_err(format!("values[{index}] cannot be represented as a float"))
+            })
+        })
+        .collect()
+}
+
+/// Convert a list of decimals (`decimal.Decimal`, `str` or `int`) to their canonical string
+/// representation in a single pass.
+#[pyfunction]
+pub fn decimals_to_strings(values: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<String>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| decimal_from_value(value, index).map(|value| value.to_string()))
+        .collect()
+}
+
+fn decimal_from_value(value: &Bound<'_, PyAny>, index: usize) -> PyResult<Decimal> {
+    let text = value.str()?;
+    let text = text.to_str()?.trim();
+
+    Decimal::from_str(text)
+        .or_else(|_| Decimal::from_scientific(text))
+        .map_err(|err| {
+            PyValueError::new_err(format!("values[{index}] is not a valid decimal: {err}"))
+        })
+}
+
+fn epoch_ns_to_py(py: Python<'_>, time: DateTime<Utc>) -> PyResult<PyObject> {
+    time.timestamp_nanos_opt()
+        .map(|nanos| nanos.into_py(py))
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "timestamp {} cannot be represented as epoch nanoseconds",
+                time.to_rfc3339()
+            ))
+        })
+}
+
 pub fn parse_initial_balances(
     py: Python<'_>,
     values: Option<PyObject>,
@@ -1,10 +1,14 @@
-use crate::{command::parse_decimal, data::PyExchangeId};
+use crate::{command::parse_decimal, data::PyExchangeId, execution::serialize_to_py_dict};
 use barter::system::config::{
-    ExecutionConfig, RiskConfiguration, RiskInstrumentLimits, RiskLimits, RiskLimitsError,
-    SystemConfig,
+    ExecutionConfig, InstrumentAliasError, InstrumentConfig, RiskConfiguration,
+    RiskInstrumentLimits, RiskLimits, RiskLimitsError, SystemConfig,
 };
 use barter_execution::{UnindexedAccountSnapshot, client::mock::MockExecutionConfig};
-use barter_instrument::exchange::ExchangeId;
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::name::{InstrumentNameExchange, InstrumentNameInternal},
+};
+use chrono::NaiveTime;
 use pyo3::{
     Bound, Py, PyObject,
     exceptions::PyValueError,
@@ -12,7 +16,10 @@ use pyo3::{
     types::{PyAny, PyDict, PyList, PyModule, PyType},
 };
 use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
 use std::{
+    collections::{BTreeMap, HashSet},
     fs::File,
     io::{BufReader, BufWriter},
     path::Path,
@@ -60,6 +67,57 @@ impl PyMockExecutionConfig {
         }
     }
 
+    fn parse_fee_rate(value: Option<&Bound<'_, PyAny>>, field: &str) -> PyResult<Option<Decimal>> {
+        let Some(value) = value.filter(|value| !value.is_none()) else {
+            return Ok(None);
+        };
+
+        let rate = decimal_from_py(value)
+            .map_err(|_| PyValueError::new_err(format!("{field} must be a decimal value")))?;
+        if rate < Decimal::ZERO || rate >= Decimal::ONE {
+            return Err(PyValueError::new_err(format!(
+                "{field} must be a fraction in the range [0, 1)"
+            )));
+        }
+
+        Ok(Some(rate))
+    }
+
+    fn parse_max_fill_quantity(
+        value: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<BTreeMap<InstrumentNameExchange, Decimal>> {
+        let Some(value) = value else {
+            return Ok(BTreeMap::new());
+        };
+
+        value
+            .iter()
+            .map(|(instrument, quantity)| {
+                let instrument = instrument.extract::<String>().map_err(|_| {
+                    PyValueError::new_err("max_fill_quantity keys must be instrument names")
+                })?;
+                let quantity = decimal_from_py(&quantity)
+                    .ok()
+                    .filter(|quantity| *quantity > Decimal::ZERO)
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!(
+                            "max_fill_quantity[{instrument}] must be a positive decimal value"
+                        ))
+                    })?;
+                Ok((InstrumentNameExchange::new(instrument), quantity))
+            })
+            .collect()
+    }
+
+    fn validate_latency_range(latency_ms: u64, latency_max_ms: Option<u64>) -> PyResult<()> {
+        match latency_max_ms {
+            Some(latency_max_ms) if latency_max_ms < latency_ms => Err(PyValueError::new_err(
+                "latency_max_ms must be greater than or equal to latency_ms",
+            )),
+            _ => Ok(()),
+        }
+    }
+
     fn snapshot_to_py(py: Python<'_>, snapshot: &UnindexedAccountSnapshot) -> PyResult<PyObject> {
         let serialized = serde_json::to_string(snapshot)
             .map_err(|err| PyValueError::new_err(err.to_string()))?;
@@ -72,13 +130,29 @@ impl PyMockExecutionConfig {
 #[pymethods]
 impl PyMockExecutionConfig {
     #[new]
-    #[pyo3(signature = (mocked_exchange=None, initial_state=None, latency_ms=0, fees_percent=0.0))]
+    #[pyo3(signature = (
+        mocked_exchange=None,
+        initial_state=None,
+        latency_ms=0,
+        fees_percent=0.0,
+        latency_max_ms=None,
+        maker_fee=None,
+        taker_fee=None,
+        max_fill_quantity=None,
+        day_boundary=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn __new__(
         py: Python<'_>,
         mocked_exchange: Option<&PyExchangeId>,
         initial_state: Option<PyObject>,
         latency_ms: u64,
         fees_percent: f64,
+        latency_max_ms: Option<u64>,
+        maker_fee: Option<&Bound<'_, PyAny>>,
+        taker_fee: Option<&Bound<'_, PyAny>>,
+        max_fill_quantity: Option<&Bound<'_, PyDict>>,
+        day_boundary: Option<NaiveTime>,
     ) -> PyResult<Self> {
         if !fees_percent.is_finite() || fees_percent < 0.0 {
             return Err(PyValueError::new_err(
@@ -90,11 +164,21 @@ impl PyMockExecutionConfig {
             .map(|value| value.as_inner())
             .unwrap_or(ExchangeId::Mock);
 
+        Self::validate_latency_range(latency_ms, latency_max_ms)?;
+
         let snapshot = Self::parse_snapshot(py, initial_state, exchange)?;
         let fees_percent = parse_decimal(fees_percent, "fees_percent")?;
+        let maker_fee = Self::parse_fee_rate(maker_fee, "maker_fee")?;
+        let taker_fee = Self::parse_fee_rate(taker_fee, "taker_fee")?;
+        let max_fill_quantity = Self::parse_max_fill_quantity(max_fill_quantity)?;
 
         Ok(Self {
-            inner: MockExecutionConfig::new(exchange, snapshot, latency_ms, fees_percent),
+            inner: MockExecutionConfig::new(exchange, snapshot, latency_ms, fees_percent)
+                .with_latency_max_ms(latency_max_ms)
+                .with_maker_fee(maker_fee)
+                .with_taker_fee(taker_fee)
+                .with_max_fill_quantity(max_fill_quantity)
+                .with_day_boundary(day_boundary.unwrap_or_default()),
         })
     }
 
@@ -116,8 +200,23 @@ impl PyMockExecutionConfig {
     }
 
     #[setter]
-    pub fn set_latency_ms(&mut self, value: u64) {
+    pub fn set_latency_ms(&mut self, value: u64) -> PyResult<()> {
+        Self::validate_latency_range(value, self.inner.latency_max_ms)?;
         self.inner.latency_ms = value;
+        Ok(())
+    }
+
+    /// Upper bound of the uniformly sampled latency, or `None` if `latency_ms` is fixed.
+    #[getter]
+    pub fn latency_max_ms(&self) -> Option<u64> {
+        self.inner.latency_max_ms
+    }
+
+    #[setter]
+    pub fn set_latency_max_ms(&mut self, value: Option<u64>) -> PyResult<()> {
+        Self::validate_latency_range(self.inner.latency_ms, value)?;
+        self.inner.latency_max_ms = value;
+        Ok(())
     }
 
     #[getter]
@@ -137,6 +236,65 @@ impl PyMockExecutionConfig {
         Ok(())
     }
 
+    /// Fee rate charged on resting (maker) fills, or `None` to fall back to `fees_percent`.
+    #[getter]
+    pub fn maker_fee(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.inner
+            .maker_fee
+            .map(|rate| decimal_to_py(py, rate))
+            .transpose()
+    }
+
+    #[setter]
+    pub fn set_maker_fee(&mut self, value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        self.inner.maker_fee = Self::parse_fee_rate(value, "maker_fee")?;
+        Ok(())
+    }
+
+    /// Fee rate charged on market and immediate-or-cancel (taker) fills, or `None` to fall back
+    /// to `fees_percent`.
+    #[getter]
+    pub fn taker_fee(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.inner
+            .taker_fee
+            .map(|rate| decimal_to_py(py, rate))
+            .transpose()
+    }
+
+    #[setter]
+    pub fn set_taker_fee(&mut self, value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        self.inner.taker_fee = Self::parse_fee_rate(value, "taker_fee")?;
+        Ok(())
+    }
+
+    /// Maximum quantity filled per tick, keyed by exchange instrument name. Larger orders are
+    /// filled by a sequence of partial fills.
+    #[getter]
+    pub fn max_fill_quantity(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        for (instrument, quantity) in &self.inner.max_fill_quantity {
+            dict.set_item(instrument.name().as_str(), decimal_to_py(py, *quantity)?)?;
+        }
+        Ok(dict.into_py(py))
+    }
+
+    #[setter]
+    pub fn set_max_fill_quantity(&mut self, value: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.inner.max_fill_quantity = Self::parse_max_fill_quantity(value)?;
+        Ok(())
+    }
+
+    /// UTC time of day at which good-until-end-of-day orders expire.
+    #[getter]
+    pub fn day_boundary(&self) -> NaiveTime {
+        self.inner.day_boundary
+    }
+
+    #[setter]
+    pub fn set_day_boundary(&mut self, value: NaiveTime) {
+        self.inner.day_boundary = value;
+    }
+
     #[getter]
     pub fn initial_state(&self, py: Python<'_>) -> PyResult<PyObject> {
         Self::snapshot_to_py(py, &self.inner.initial_state)
@@ -165,9 +323,12 @@ impl PyMockExecutionConfig {
 
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!(
-            "MockExecutionConfig(exchange={}, latency_ms={}, fees_percent={})",
+            "MockExecutionConfig(exchange={}, latency_ms={}, latency_max_ms={}, fees_percent={})",
             self.inner.mocked_exchange.as_str(),
             self.inner.latency_ms,
+            self.inner
+                .latency_max_ms
+                .map_or_else(|| "None".to_string(), |value| value.to_string()),
             self.inner.fees_percent
         ))
     }
@@ -227,8 +388,8 @@ impl PyExecutionConfig {
 }
 
 /// Python wrapper around [`SystemConfig`].
-#[pyclass(module = "barter_python", name = "SystemConfig")]
-#[derive(Clone)]
+#[pyclass(module = "barter_python", name = "SystemConfig", eq)]
+#[derive(Clone, PartialEq)]
 pub struct PySystemConfig {
     pub(crate) inner: SystemConfig,
 }
@@ -264,18 +425,80 @@ impl PySystemConfig {
     }
 
     /// Construct a [`SystemConfig`] from a Python dictionary-like object.
+    ///
+    /// Malformed instruments or executions are reported with the offending field, eg/
+    /// `instruments[1]: unknown variant ...`.
     #[staticmethod]
     pub fn from_dict(py: Python<'_>, value: PyObject) -> PyResult<Self> {
         let json_module = PyModule::import_bound(py, "json")?;
         let dumps = json_module.getattr("dumps")?;
         let serialized: String = dumps.call1((value,))?.extract()?;
 
-        let config = serde_json::from_str(&serialized)
+        let value = serde_json::from_str::<JsonValue>(&serialized)
             .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
+        if !value.is_object() {
+            return Err(PyValueError::new_err("system config must be a dictionary"));
+        }
+        validate_items::<InstrumentConfig>(&value, "instruments")?;
+        validate_items::<ExecutionConfig>(&value, "executions")?;
+
+        let config = serde_json::from_value(value)
+            .map_err(|err| PyValueError::new_err(format!("invalid system config: {err}")))?;
+
         Ok(Self { inner: config })
     }
 
+    /// Check the configuration for inconsistencies, returning a human-readable description of
+    /// each problem found. An empty list means the configuration is consistent.
+    ///
+    /// Checks that every instrument's exchange has an execution, that each execution is unique
+    /// and its initial state exchange matches the mocked exchange, and that per-instrument risk
+    /// overrides reference a configured instrument.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        let mut execution_exchanges = HashSet::new();
+        for (index, execution) in self.inner.executions.iter().enumerate() {
+            let ExecutionConfig::Mock(mock) = execution;
+            if !execution_exchanges.insert(mock.mocked_exchange) {
+                problems.push(format!(
+                    "executions[{index}]: duplicate execution for exchange '{}'",
+                    mock.mocked_exchange.as_str()
+                ));
+            }
+            if mock.initial_state.exchange != mock.mocked_exchange {
+                problems.push(format!(
+                    "executions[{index}]: initial_state exchange '{}' does not match mocked_exchange '{}'",
+                    mock.initial_state.exchange.as_str(),
+                    mock.mocked_exchange.as_str()
+                ));
+            }
+        }
+
+        for (index, instrument) in self.inner.instruments.iter().enumerate() {
+            if !execution_exchanges.contains(&instrument.exchange) {
+                problems.push(format!(
+                    "instruments[{index}]: no execution configured for exchange '{}' ({})",
+                    instrument.exchange.as_str(),
+                    instrument.name_exchange.name()
+                ));
+            }
+        }
+
+        let instrument_count = self.inner.instruments.len();
+        for limits in &self.inner.risk.instruments {
+            if limits.index >= instrument_count {
+                problems.push(format!(
+                    "risk.instruments: index {} is out of range for {instrument_count} instruments",
+                    limits.index
+                ));
+            }
+        }
+
+        problems
+    }
+
     /// Return a dictionary describing the configured risk limits.
     pub fn risk_limits(&self, py: Python<'_>) -> PyResult<PyObject> {
         risk_configuration_to_py(py, &self.inner.risk)
@@ -338,6 +561,48 @@ impl PySystemConfig {
         }
     }
 
+    /// Define (or replace) a logical instrument alias grouping the provided instruments.
+    ///
+    /// Instruments are identified as `"exchange:base_quote"` (eg/ `"binance_spot:btc_usdt"`) or
+    /// by their internal name (eg/ `"binance_spot-btc_usdt"`).
+    pub fn set_instrument_alias(&mut self, alias: &str, instruments: Vec<String>) -> PyResult<()> {
+        let instruments = instruments
+            .iter()
+            .map(|instrument| parse_instrument_key(instrument))
+            .collect();
+
+        self.inner
+            .set_instrument_alias(alias, instruments)
+            .map_err(alias_error_to_py)
+    }
+
+    /// Remove the instrument alias `alias`, returning whether it was configured.
+    pub fn remove_instrument_alias(&mut self, alias: &str) -> bool {
+        self.inner.remove_instrument_alias(alias).is_some()
+    }
+
+    /// Return a dictionary mapping each alias to the internal names of its instruments.
+    pub fn instrument_aliases(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        for alias in self.inner.instrument_aliases() {
+            let instruments = alias
+                .instruments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            dict.set_item(&alias.name, instruments)?;
+        }
+
+        Ok(dict.into_py(py))
+    }
+
+    /// Return the alias the provided instrument is a member of, if any.
+    pub fn alias_for(&self, instrument: &str) -> Option<String> {
+        self.inner
+            .instrument_alias(&parse_instrument_key(instrument))
+            .map(str::to_string)
+    }
+
     /// Return the configuration as a Python dictionary.
     pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
         let json = serde_json::to_string(&self.inner)
@@ -377,6 +642,77 @@ impl PySystemConfig {
     }
 }
 
+/// Compare the instrument universes of two configs, returning a dictionary with `added`,
+/// `removed` and `modified` lists.
+///
+/// Instruments are matched by exchange and exchange instrument name. Added and removed entries
+/// are instrument dictionaries, while modified entries contain the `old` and `new` instrument.
+#[pyfunction]
+pub fn diff_universes(
+    py: Python<'_>,
+    old_config: &PySystemConfig,
+    new_config: &PySystemConfig,
+) -> PyResult<PyObject> {
+    let key =
+        |instrument: &InstrumentConfig| (instrument.exchange, instrument.name_exchange.clone());
+    let old = old_config
+        .inner
+        .instruments
+        .iter()
+        .map(|instrument| (key(instrument), instrument))
+        .collect::<BTreeMap<_, _>>();
+    let new = new_config
+        .inner
+        .instruments
+        .iter()
+        .map(|instrument| (key(instrument), instrument))
+        .collect::<BTreeMap<_, _>>();
+
+    let added = PyList::empty_bound(py);
+    let modified = PyList::empty_bound(py);
+    for (key, instrument) in &new {
+        match old.get(key) {
+            None => added.append(serialize_to_py_dict(py, *instrument)?)?,
+            Some(previous) if previous != instrument => {
+                let change = PyDict::new_bound(py);
+                change.set_item("old", serialize_to_py_dict(py, *previous)?)?;
+                change.set_item("new", serialize_to_py_dict(py, *instrument)?)?;
+                modified.append(change)?;
+            }
+            Some(_) => {}
+        }
+    }
+
+    let removed = PyList::empty_bound(py);
+    for (key, instrument) in &old {
+        if !new.contains_key(key) {
+            removed.append(serialize_to_py_dict(py, *instrument)?)?;
+        }
+    }
+
+    let diff = PyDict::new_bound(py);
+    diff.set_item("added", added)?;
+    diff.set_item("removed", removed)?;
+    diff.set_item("modified", modified)?;
+    Ok(diff.into_py(py))
+}
+
+/// Deserialize each element of the `field` array individually so errors name the offending item.
+fn validate_items<T: DeserializeOwned>(config: &JsonValue, field: &str) -> PyResult<()> {
+    let Some(items) = config.get(field) else {
+        return Ok(());
+    };
+    let items = items
+        .as_array()
+        .ok_or_else(|| PyValueError::new_err(format!("{field} must be a list")))?;
+
+    items.iter().enumerate().try_for_each(|(index, item)| {
+        T::deserialize(item)
+            .map(drop)
+            .map_err(|err| PyValueError::new_err(format!("{field}[{index}]: {err}")))
+    })
+}
+
 fn risk_configuration_to_py(py: Python<'_>, config: &RiskConfiguration) -> PyResult<PyObject> {
     let dict = PyDict::new_bound(py);
     dict.set_item(
@@ -424,6 +760,10 @@ fn risk_limits_to_py(py: Python<'_>, limits: &RiskLimits) -> PyResult<Py<PyDict>
         "max_exposure_percent",
         option_decimal_to_py(py, limits.max_exposure_percent)?,
     )?;
+    dict.set_item(
+        "exit_cooldown_secs",
+        option_decimal_to_py(py, limits.exit_cooldown_secs)?,
+    )?;
 
     Ok(dict.into())
 }
@@ -441,12 +781,14 @@ fn risk_limits_from_py(dict: &Bound<'_, PyAny>) -> PyResult<RiskLimits> {
     let max_position_quantity = optional_decimal_from_dict(&dict, "max_position_quantity")?;
     let max_leverage = optional_decimal_from_dict(&dict, "max_leverage")?;
     let max_exposure_percent = optional_decimal_from_dict(&dict, "max_exposure_percent")?;
+    let exit_cooldown_secs = optional_decimal_from_dict(&dict, "exit_cooldown_secs")?;
 
     Ok(RiskLimits {
         max_position_notional,
         max_position_quantity,
         max_leverage,
         max_exposure_percent,
+        exit_cooldown_secs,
     })
 }
 
@@ -480,3 +822,12 @@ fn decimal_from_py(value: &Bound<'_, PyAny>) -> PyResult<Decimal> {
 fn risk_error_to_py(error: RiskLimitsError) -> PyErr {
     PyValueError::new_err(error.to_string())
 }
+
+/// Parse an `"exchange:base_quote"` or internal `"exchange-base_quote"` instrument key.
+pub(crate) fn parse_instrument_key(value: &str) -> InstrumentNameInternal {
+    InstrumentNameInternal::new(value.trim().replacen(':', "-", 1))
+}
+
+fn alias_error_to_py(error: InstrumentAliasError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
@@ -1,14 +1,45 @@
 #![allow(unused_imports)]
 
-use crate::{backtest::market_event_to_py, command::parse_decimal};
+use crate::{
+    PyEngineEvent,
+    backtest::market_event_to_py,
+    command::parse_decimal,
+    common::{duration_from_secs, timestamp_to_py},
+};
+use barter::EngineEvent;
 use barter_data::{
+    Identifier,
     event::{DataKind, MarketEvent},
+    exchange::{
+        Connector,
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+        bitfinex::Bitfinex,
+        bitmex::Bitmex,
+        bybit::{futures::BybitPerpetualsUsd, spot::BybitSpot},
+        coinbase::Coinbase,
+        gateio::{
+            future::{GateioFuturesBtc, GateioFuturesUsd},
+            option::GateioOptions,
+            perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+            spot::GateioSpot,
+        },
+        kraken::Kraken,
+        okx::Okx,
+        subscription::ExchangeSub,
+    },
     instrument::InstrumentData,
-    streams::{builder::dynamic::DynamicStreams, consumer::MarketStreamResult, reconnect::Event},
+    streams::{
+        builder::dynamic::DynamicStreams,
+        consumer::{MarketStreamEvent, MarketStreamResult, STREAM_RECONNECTION_POLICY},
+        reconnect::{Event, stream::ReconnectionBackoffPolicy},
+    },
     subscription::{
         SubKind, Subscription,
+        book::{OrderBooksL1, OrderBooksL2},
+        candle::Candle,
         exchange_supports_instrument_kind as rust_exchange_supports_instrument_kind,
-        trade::PublicTrade,
+        liquidation::Liquidations,
+        trade::{PublicTrade, PublicTrades},
     },
 };
 use barter_instrument::{
@@ -24,13 +55,13 @@ use barter_instrument::{
     },
 };
 use barter_integration::subscription::SubscriptionId;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
 use futures::{Stream, StreamExt};
 use pyo3::{
     Bound,
-    exceptions::PyValueError,
+    exceptions::{PyStopAsyncIteration, PyValueError, asyncio::TimeoutError as AsyncTimeoutError},
     prelude::*,
-    types::{PyAny, PyDict, PyModule},
+    types::{PyAny, PyDict, PyModule, PyString, PyType},
 };
 use pyo3_async_runtimes::tokio::future_into_py;
 #[cfg(feature = "python-tests")]
@@ -38,9 +69,15 @@ use serde_json;
 #[cfg(feature = "python-tests")]
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU64, Ordering},
+};
 use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
-use tokio::sync::mpsc::{self, UnboundedReceiver, error::TryRecvError};
+use tokio::sync::{
+    Mutex as AsyncMutex,
+    mpsc::{self, UnboundedReceiver, error::TryRecvError},
+};
 use tokio::time::Duration;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
@@ -62,6 +99,33 @@ impl PyExchangeId {
     }
 }
 
+/// Daily trading session of a venue, as `(open, close)` UTC times of day.
+///
+/// A session with `close` before `open` runs overnight, closing the following day.
+type TradingHours = (NaiveTime, NaiveTime);
+
+/// Daily trading session of the provided `exchange`, or `None` if it trades 24/7.
+///
+/// Most crypto venues never close, but some pause trading for a daily maintenance window.
+fn exchange_trading_hours(exchange: ExchangeId) -> Option<TradingHours> {
+    let time = |hour, min| NaiveTime::from_hms_opt(hour, min, 0).expect("valid time of day");
+
+    match exchange {
+        // Daily maintenance from 04:00 to 04:10 JST (19:00 to 19:10 UTC)
+        ExchangeId::Bitflyer => Some((time(19, 10), time(19, 0))),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the provided time of day falls within the `(open, close)` session.
+fn session_contains((open, close): TradingHours, time: NaiveTime) -> bool {
+    if open <= close {
+        open <= time && time < close
+    } else {
+        time >= open || time < close
+    }
+}
+
 #[pymethods]
 impl PyExchangeId {
     /// Other / unknown exchange.
@@ -316,6 +380,19 @@ impl PyExchangeId {
         inner: ExchangeId::Poloniex,
     };
 
+    /// Daily trading session as `(open, close)` UTC times, or `None` for venues trading 24/7.
+    ///
+    /// A session whose `close` is before its `open` runs overnight into the following day.
+    fn trading_hours(&self) -> Option<TradingHours> {
+        exchange_trading_hours(self.inner)
+    }
+
+    /// Return `True` if the venue is open for trading at the provided time.
+    fn is_open(&self, at: DateTime<Utc>) -> bool {
+        exchange_trading_hours(self.inner)
+            .is_none_or(|session| session_contains(session, at.time()))
+    }
+
     /// Return the string representation.
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -383,6 +460,304 @@ impl PySubKind {
     }
 }
 
+/// Wrapper around [`Candle`] for Python exposure.
+#[pyclass(module = "barter_python", name = "Candle", eq)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PyCandle {
+    pub(crate) inner: Candle,
+}
+
+impl PyCandle {
+    pub(crate) fn as_inner(&self) -> Candle {
+        self.inner
+    }
+}
+
+#[pymethods]
+impl PyCandle {
+    /// Create a new OHLCV [`Candle`].
+    #[new]
+    #[pyo3(signature = (close_time, open, high, low, close, volume, trade_count = 0))]
+    fn new(
+        close_time: DateTime<Utc>,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+        trade_count: u64,
+    ) -> PyResult<Self> {
+        for (field, value) in [
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ] {
+            if !value.is_finite() {
+                return Err(PyValueError::new_err(format!(
+                    "{field} must be a finite numeric value"
+                )));
+            }
+        }
+
+        if high < low {
+            return Err(PyValueError::new_err(format!(
+                "high ({high}) must be greater than or equal to low ({low})"
+            )));
+        }
+
+        if volume < 0.0 {
+            return Err(PyValueError::new_err("volume must be non-negative"));
+        }
+
+        Ok(Self {
+            inner: Candle {
+                close_time,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                trade_count,
+            },
+        })
+    }
+
+    /// Candle close time.
+    #[getter]
+    fn close_time(&self) -> DateTime<Utc> {
+        self.inner.close_time
+    }
+
+    /// Opening price.
+    #[getter]
+    fn open(&self) -> f64 {
+        self.inner.open
+    }
+
+    /// Highest price.
+    #[getter]
+    fn high(&self) -> f64 {
+        self.inner.high
+    }
+
+    /// Lowest price.
+    #[getter]
+    fn low(&self) -> f64 {
+        self.inner.low
+    }
+
+    /// Closing price.
+    #[getter]
+    fn close(&self) -> f64 {
+        self.inner.close
+    }
+
+    /// Traded volume.
+    #[getter]
+    fn volume(&self) -> f64 {
+        self.inner.volume
+    }
+
+    /// Number of trades aggregated into the candle.
+    #[getter]
+    fn trade_count(&self) -> u64 {
+        self.inner.trade_count
+    }
+
+    /// Convert the candle into a Python dictionary.
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("close_time", timestamp_to_py(py, self.inner.close_time)?)?;
+        dict.set_item("open", self.inner.open)?;
+        dict.set_item("high", self.inner.high)?;
+        dict.set_item("low", self.inner.low)?;
+        dict.set_item("close", self.inner.close)?;
+        dict.set_item("volume", self.inner.volume)?;
+        dict.set_item("trade_count", self.inner.trade_count)?;
+        Ok(dict.into_py(py))
+    }
+
+    /// Return the debug representation.
+    fn __repr__(&self) -> String {
+        format!(
+            "Candle(close_time={}, open={}, high={}, low={}, close={}, volume={}, trade_count={})",
+            self.inner.close_time,
+            self.inner.open,
+            self.inner.high,
+            self.inner.low,
+            self.inner.close,
+            self.inner.volume,
+            self.inner.trade_count
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum DataKindVariant {
+    Trade,
+    Candle,
+    OrderBookL1,
+    OrderBook,
+    Liquidation,
+}
+
+impl DataKindVariant {
+    fn from_data_kind(kind: &DataKind) -> Self {
+        match kind {
+            DataKind::Trade(_) => Self::Trade,
+            DataKind::OrderBookL1(_) => Self::OrderBookL1,
+            DataKind::OrderBook(_) => Self::OrderBook,
+            DataKind::Candle(_) => Self::Candle,
+            DataKind::Liquidation(_) => Self::Liquidation,
+        }
+    }
+
+    /// Parse either the Python `DataKind.kind` label or the Rust [`DataKind::kind_name`].
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "trade" | "public_trade" => Some(Self::Trade),
+            "order_book_l1" | "l1" => Some(Self::OrderBookL1),
+            "order_book" | "l2" => Some(Self::OrderBook),
+            "candle" => Some(Self::Candle),
+            "liquidation" => Some(Self::Liquidation),
+            _ => None,
+        }
+    }
+
+    fn attr_name(self) -> &'static str {
+        match self {
+            Self::Trade => "TRADE",
+            Self::Candle => "CANDLE",
+            Self::OrderBookL1 => "ORDER_BOOK_L1",
+            Self::OrderBook => "ORDER_BOOK",
+            Self::Liquidation => "LIQUIDATION",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Trade => "trade",
+            Self::Candle => "candle",
+            Self::OrderBookL1 => "order_book_l1",
+            Self::OrderBook => "order_book",
+            Self::Liquidation => "liquidation",
+        }
+    }
+}
+
+/// Typed kind of a [`MarketEvent`], mirroring the variants of [`DataKind`].
+#[pyclass(module = "barter_python", name = "DataKind", eq, frozen, hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyDataKind {
+    inner: DataKindVariant,
+}
+
+impl PyDataKind {
+    /// Return the shared class attribute for the variant so identity checks (`is`) hold.
+    fn variant_object(py: Python<'_>, variant: DataKindVariant) -> PyResult<PyObject> {
+        let class = py.get_type_bound::<PyDataKind>();
+        Ok(class.getattr(variant.attr_name())?.into_py(py))
+    }
+
+    fn variant_from_py(event: &Bound<'_, PyAny>) -> PyResult<Option<DataKindVariant>> {
+        if let Ok(engine_event) = event.downcast::<PyEngineEvent>() {
+            return Ok(match &engine_event.borrow().inner {
+                EngineEvent::Market(MarketStreamEvent::Item(item)) => {
+                    Some(DataKindVariant::from_data_kind(&item.kind))
+                }
+                _ => None,
+            });
+        }
+
+        if let Ok(kind) = event.downcast::<PyDataKind>() {
+            return Ok(Some(kind.get().inner));
+        }
+
+        // MarketStreamItem wraps the MarketEvent in an `event` attribute.
+        if event.hasattr("event")? {
+            return Self::variant_from_py(&event.getattr("event")?);
+        }
+
+        if !event.hasattr("kind")? {
+            return Ok(None);
+        }
+
+        // MarketEvent.kind is a DataKind whose own `kind` is the variant label.
+        let mut kind = event.getattr("kind")?;
+        if !kind.is_instance_of::<PyString>() && kind.hasattr("kind")? {
+            kind = kind.getattr("kind")?;
+        }
+
+        Ok(kind
+            .extract::<String>()
+            .ok()
+            .and_then(|label| DataKindVariant::from_label(&label)))
+    }
+}
+
+#[pymethods]
+impl PyDataKind {
+    /// Public trade market data.
+    #[classattr]
+    const TRADE: Self = Self {
+        inner: DataKindVariant::Trade,
+    };
+
+    /// OHLCV candle market data.
+    #[classattr]
+    const CANDLE: Self = Self {
+        inner: DataKindVariant::Candle,
+    };
+
+    /// Level 1 order book market data.
+    #[classattr]
+    const ORDER_BOOK_L1: Self = Self {
+        inner: DataKindVariant::OrderBookL1,
+    };
+
+    /// Level 2 order book market data.
+    #[classattr]
+    const ORDER_BOOK: Self = Self {
+        inner: DataKindVariant::OrderBook,
+    };
+
+    /// Liquidation market data.
+    #[classattr]
+    const LIQUIDATION: Self = Self {
+        inner: DataKindVariant::Liquidation,
+    };
+
+    /// Determine the [`PyDataKind`] of a market event.
+    ///
+    /// Accepts an `EngineEvent` wrapping market data, a `MarketStreamItem`, a `MarketEvent`
+    /// or a `barter_python.data.DataKind`.
+    #[staticmethod]
+    fn from_market_event(py: Python<'_>, event: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let variant = Self::variant_from_py(event)?.ok_or_else(|| {
+            PyValueError::new_err("event does not contain a recognised market data kind")
+        })?;
+        Self::variant_object(py, variant)
+    }
+
+    /// Label matching `barter_python.data.DataKind.kind`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.label()
+    }
+
+    /// Return the string representation.
+    fn __str__(&self) -> &'static str {
+        self.inner.label()
+    }
+
+    /// Return the debug representation.
+    fn __repr__(&self) -> String {
+        format!("DataKind.{}", self.inner.attr_name())
+    }
+}
+
 /// Wrapper around [`Subscription`] for Python exposure.
 #[pyclass(module = "barter_python", name = "Subscription", unsendable)]
 #[derive(Debug, Clone)]
@@ -434,6 +809,12 @@ impl PySubscription {
         }
     }
 
+    /// Get the exchange [`SubscriptionId`] that market events for this subscription arrive with.
+    #[getter]
+    fn id(&self) -> PyResult<PySubscriptionId> {
+        subscription_id(&self.inner).map(|inner| PySubscriptionId { inner })
+    }
+
     /// Return the string representation.
     fn __str__(&self) -> String {
         format!(
@@ -585,6 +966,113 @@ fn get_required<'py>(dict: &Bound<'py, PyDict>, field: &str) -> PyResult<Bound<'
     })
 }
 
+/// Derive the [`SubscriptionId`] of a dynamic `subscription` the same way the exchange
+/// [`Connector`] does when mapping incoming messages to subscribed instruments.
+fn subscription_id(
+    subscription: &Subscription<ExchangeId, MarketDataInstrument, SubKind>,
+) -> PyResult<SubscriptionId> {
+    let instrument = subscription.instrument.clone();
+
+    let id = match (subscription.exchange, subscription.kind) {
+        (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
+            exchange_subscription_id(BinanceSpot::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
+            exchange_subscription_id(BinanceSpot::default(), instrument, OrderBooksL1)
+        }
+        (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
+            exchange_subscription_id(BinanceSpot::default(), instrument, OrderBooksL2)
+        }
+        (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
+            exchange_subscription_id(BinanceFuturesUsd::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
+            exchange_subscription_id(BinanceFuturesUsd::default(), instrument, OrderBooksL1)
+        }
+        (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
+            exchange_subscription_id(BinanceFuturesUsd::default(), instrument, OrderBooksL2)
+        }
+        (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
+            exchange_subscription_id(BinanceFuturesUsd::default(), instrument, Liquidations)
+        }
+        (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
+            exchange_subscription_id(Bitfinex, instrument, PublicTrades)
+        }
+        (ExchangeId::Bitmex, SubKind::PublicTrades) => {
+            exchange_subscription_id(Bitmex, instrument, PublicTrades)
+        }
+        (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
+            exchange_subscription_id(BybitSpot::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::BybitSpot, SubKind::OrderBooksL1) => {
+            exchange_subscription_id(BybitSpot::default(), instrument, OrderBooksL1)
+        }
+        (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
+            exchange_subscription_id(BybitSpot::default(), instrument, OrderBooksL2)
+        }
+        (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+            exchange_subscription_id(BybitPerpetualsUsd::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL1) => {
+            exchange_subscription_id(BybitPerpetualsUsd::default(), instrument, OrderBooksL1)
+        }
+        (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
+            exchange_subscription_id(BybitPerpetualsUsd::default(), instrument, OrderBooksL2)
+        }
+        (ExchangeId::Coinbase, SubKind::PublicTrades) => {
+            exchange_subscription_id(Coinbase, instrument, PublicTrades)
+        }
+        (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioSpot::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioFuturesUsd::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioFuturesBtc::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioPerpetualsUsd::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioPerpetualsBtc::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
+            exchange_subscription_id(GateioOptions::default(), instrument, PublicTrades)
+        }
+        (ExchangeId::Kraken, SubKind::PublicTrades) => {
+            exchange_subscription_id(Kraken, instrument, PublicTrades)
+        }
+        (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
+            exchange_subscription_id(Kraken, instrument, OrderBooksL1)
+        }
+        (ExchangeId::Okx, SubKind::PublicTrades) => {
+            exchange_subscription_id(Okx, instrument, PublicTrades)
+        }
+        (exchange, kind) => {
+            return Err(PyValueError::new_err(format!(
+                "{exchange} does not support {kind} subscriptions"
+            )));
+        }
+    };
+
+    Ok(id)
+}
+
+fn exchange_subscription_id<Exchange, Kind>(
+    exchange: Exchange,
+    instrument: MarketDataInstrument,
+    kind: Kind,
+) -> SubscriptionId
+where
+    Exchange: Connector,
+    Subscription<Exchange, MarketDataInstrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subscription = Subscription::new(exchange, instrument, kind);
+    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(&subscription).id()
+}
+
 /// Wrapper around [`SubscriptionId`] for Python exposure.
 #[pyclass(module = "barter_python", name = "SubscriptionId", eq, frozen)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -602,6 +1090,25 @@ impl PySubscriptionId {
         }
     }
 
+    /// Construct the canonical [`SubscriptionId`] the exchange connector derives for a market
+    /// data subscription, eg/ `"@trade|BTCUSDT"` for Binance spot public trades.
+    #[classmethod]
+    #[pyo3(signature = (exchange, base, quote, kind, instrument_kind=None))]
+    fn for_market(
+        _cls: &Bound<'_, PyType>,
+        exchange: &PyExchangeId,
+        base: &str,
+        quote: &str,
+        kind: &PySubKind,
+        instrument_kind: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let instrument_kind = parse_market_data_instrument_kind(instrument_kind)?;
+        let instrument = MarketDataInstrument::from((base, quote, instrument_kind));
+        let subscription = Subscription::new(exchange.inner, instrument, kind.inner);
+
+        subscription_id(&subscription).map(|inner| Self { inner })
+    }
+
     /// Get the string value.
     #[getter]
     fn value(&self) -> &str {
@@ -634,6 +1141,7 @@ impl PySubscriptionId {
 pub struct PyDynamicStreams {
     runtime: Arc<Runtime>,
     inner: Mutex<Option<DynamicStreams<InstrumentIndex>>>,
+    reconnect_policy: Option<PyReconnectPolicy>,
 }
 
 impl PyDynamicStreams {
@@ -644,9 +1152,17 @@ impl PyDynamicStreams {
         Self {
             runtime,
             inner: Mutex::new(Some(streams)),
+            reconnect_policy: None,
         }
     }
 
+    /// Attach the [`PyReconnectPolicy`] the underlying streams were initialised with, so it is
+    /// surfaced on every `MarketStreamReconnecting` event.
+    fn with_reconnect_policy(mut self, policy: Option<PyReconnectPolicy>) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
     fn with_streams<R, T>(&self, func: R) -> PyResult<Option<T>>
     where
         R: FnOnce(&mut DynamicStreams<InstrumentIndex>) -> Option<T>,
@@ -673,7 +1189,8 @@ impl PyDynamicStreams {
         stream
             .map(|stream| {
                 let mapped = stream.map(|event| event.into());
-                Ok(PyMarketStream::new(runtime, mapped))
+                Ok(PyMarketStream::new(runtime, mapped)
+                    .with_reconnect_policy(self.reconnect_policy))
             })
             .transpose()
     }
@@ -692,7 +1209,8 @@ impl PyDynamicStreams {
         stream
             .map(|stream| {
                 let mapped = stream.map(|event| event.into());
-                Ok(PyAsyncMarketStream::new(runtime, mapped))
+                Ok(PyAsyncMarketStream::new(runtime, mapped)
+                    .with_reconnect_policy(self.reconnect_policy))
             })
             .transpose()
     }
@@ -716,7 +1234,9 @@ impl PyDynamicStreams {
                 l1s: VecMap::default(),
                 l2s: VecMap::default(),
                 liquidations: VecMap::default(),
+                candles: VecMap::default(),
             })),
+            reconnect_policy: None,
         })
     }
 
@@ -730,7 +1250,7 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_trades()))?
             .ok_or_else(|| PyValueError::new_err("no trade streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyMarketStream::new(runtime, mapped))
+        Ok(PyMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 
     fn select_l1s(&self, exchange: &PyExchangeId) -> PyResult<Option<PyMarketStream>> {
@@ -743,7 +1263,7 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_l1s()))?
             .ok_or_else(|| PyValueError::new_err("no order book L1 streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyMarketStream::new(runtime, mapped))
+        Ok(PyMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 
     fn select_l2s(&self, exchange: &PyExchangeId) -> PyResult<Option<PyMarketStream>> {
@@ -756,7 +1276,7 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_l2s()))?
             .ok_or_else(|| PyValueError::new_err("no order book streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyMarketStream::new(runtime, mapped))
+        Ok(PyMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 
     fn select_liquidations(&self, exchange: &PyExchangeId) -> PyResult<Option<PyMarketStream>> {
@@ -769,7 +1289,20 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_liquidations()))?
             .ok_or_else(|| PyValueError::new_err("no liquidation streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyMarketStream::new(runtime, mapped))
+        Ok(PyMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
+    }
+
+    fn select_candles(&self, exchange: &PyExchangeId) -> PyResult<Option<PyMarketStream>> {
+        self.select_stream(|streams| streams.select_candles(exchange.inner))
+    }
+
+    fn select_all_candles(&self) -> PyResult<PyMarketStream> {
+        let runtime = Arc::clone(&self.runtime);
+        let stream = self
+            .with_streams(|streams| Some(streams.select_all_candles()))?
+            .ok_or_else(|| PyValueError::new_err("no candle streams available"))?;
+        let mapped = stream.map(|event| event.into());
+        Ok(PyMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 
     /// Select all trade streams as an async iterator.
@@ -779,7 +1312,7 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_trades()))?
             .ok_or_else(|| PyValueError::new_err("no trade streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyAsyncMarketStream::new(runtime, mapped))
+        Ok(PyAsyncMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 
     /// Select all order book L1 streams as an async iterator.
@@ -789,7 +1322,7 @@ impl PyDynamicStreams {
             .with_streams(|streams| Some(streams.select_all_l1s()))?
             .ok_or_else(|| PyValueError::new_err("no order book L1 streams available"))?;
         let mapped = stream.map(|event| event.into());
-        Ok(PyAsyncMarketStream::new(runtime, mapped))
+        Ok(PyAsyncMarketStream::new(runtime, mapped).with_reconnect_policy(self.reconnect_policy))
     }
 }
 
@@ -797,12 +1330,98 @@ impl PyDynamicStreams {
 pub struct PyMarketStream {
     runtime: Arc<Runtime>,
     receiver: Mutex<Option<UnboundedReceiver<MarketStreamResult<InstrumentIndex, DataKind>>>>,
+    reconnects: Mutex<ReconnectAttempts>,
+    stale_filter: Option<StaleEventFilter>,
 }
 
 #[pyclass(module = "barter_python", name = "AsyncMarketStream", unsendable)]
 pub struct PyAsyncMarketStream {
     runtime: Arc<Runtime>,
-    receiver: Option<UnboundedReceiver<MarketStreamResult<InstrumentIndex, DataKind>>>,
+    receiver:
+        Arc<AsyncMutex<Option<UnboundedReceiver<MarketStreamResult<InstrumentIndex, DataKind>>>>>,
+    reconnects: Arc<Mutex<ReconnectAttempts>>,
+}
+
+/// Consecutive reconnections per exchange, reset once that exchange yields a market event.
+#[derive(Debug, Default)]
+struct ReconnectAttempts {
+    policy: Option<PyReconnectPolicy>,
+    attempts: HashMap<ExchangeId, u32>,
+}
+
+impl ReconnectAttempts {
+    /// Record the event, returning the attempt count if it is a reconnection.
+    fn observe(&mut self, event: &MarketStreamResult<InstrumentIndex, DataKind>) -> Option<u32> {
+        match event {
+            Event::Reconnecting(exchange) => {
+                let attempt = self.attempts.entry(*exchange).or_default();
+                *attempt = attempt.saturating_add(1);
+                Some(*attempt)
+            }
+            Event::Item(Ok(event)) => {
+                self.attempts.remove(&event.exchange);
+                None
+            }
+            Event::Item(Err(_)) => None,
+        }
+    }
+}
+
+/// Drops market events whose `time_exchange` lags their `time_received` by more than a maximum
+/// age, such as data replayed by an exchange after a reconnect, counting every event dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct StaleEventFilter {
+    max_age: TimeDelta,
+    dropped: Arc<AtomicU64>,
+}
+
+impl StaleEventFilter {
+    pub(crate) fn new(max_age_secs: f64, field: &str) -> PyResult<Self> {
+        if !max_age_secs.is_finite() || max_age_secs <= 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "{field} must be a positive number"
+            )));
+        }
+
+        let max_age = TimeDelta::from_std(duration_from_secs(max_age_secs, field)?)
+            .map_err(|err| PyValueError::new_err(format!("{field} is out of range: {err}")))?;
+
+        Ok(Self {
+            max_age,
+            dropped: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Returns `true`, and counts the event as dropped, if the `event` is older than the
+    /// maximum age when received.
+    pub(crate) fn is_stale<InstrumentKey, Kind>(
+        &self,
+        event: &MarketEvent<InstrumentKey, Kind>,
+    ) -> bool {
+        let stale = event.time_received - event.time_exchange > self.max_age;
+        if stale {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        stale
+    }
+
+    /// Number of stale events dropped so far.
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+fn parse_timeout(timeout: Option<f64>) -> PyResult<Option<Duration>> {
+    timeout
+        .map(|secs| {
+            if !secs.is_finite() || secs.is_sign_negative() {
+                return Err(PyValueError::new_err(
+                    "timeout must be a finite, non-negative number",
+                ));
+            }
+            duration_from_secs(secs, "timeout")
+        })
+        .transpose()
 }
 
 impl PyMarketStream {
@@ -825,9 +1444,39 @@ impl PyMarketStream {
         Self {
             runtime: runtime_clone,
             receiver: Mutex::new(Some(rx)),
+            reconnects: Mutex::new(ReconnectAttempts::default()),
+            stale_filter: None,
         }
     }
 
+    fn with_reconnect_policy(self, policy: Option<PyReconnectPolicy>) -> Self {
+        if let Ok(mut reconnects) = self.reconnects.lock() {
+            reconnects.policy = policy;
+        }
+        self
+    }
+
+    fn reconnect_policy(&self) -> Option<PyReconnectPolicy> {
+        self.reconnects
+            .lock()
+            .ok()
+            .and_then(|reconnects| reconnects.policy)
+    }
+
+    /// Take ownership of the underlying receiver, leaving this stream closed.
+    fn take_receiver(
+        &self,
+    ) -> PyResult<Option<UnboundedReceiverStream<MarketStreamResult<InstrumentIndex, DataKind>>>>
+    {
+        let receiver = self
+            .receiver
+            .lock()
+            .map_err(|_| PyValueError::new_err("market stream mutex poisoned"))?
+            .take();
+
+        Ok(receiver.map(UnboundedReceiverStream::new))
+    }
+
     fn recv_inner(
         &self,
         timeout: Option<f64>,
@@ -844,12 +1493,7 @@ impl PyMarketStream {
 
         let runtime = Arc::clone(&self.runtime);
 
-        let item = if let Some(secs) = timeout {
-            if secs.is_sign_negative() {
-                return Err(PyValueError::new_err("timeout must be non-negative"));
-            }
-
-            let duration = Duration::from_secs_f64(secs);
+        let item = if let Some(duration) = parse_timeout(timeout)? {
             runtime
                 .block_on(async { tokio::time::timeout(duration, receiver.recv()).await })
                 .map_err(|_| PyValueError::new_err("timeout elapsed awaiting market event"))?
@@ -884,8 +1528,60 @@ impl PyAsyncMarketStream {
 
         Self {
             runtime: runtime_clone,
-            receiver: Some(rx),
+            receiver: Arc::new(AsyncMutex::new(Some(rx))),
+            reconnects: Arc::new(Mutex::new(ReconnectAttempts::default())),
+        }
+    }
+
+    fn with_reconnect_policy(self, policy: Option<PyReconnectPolicy>) -> Self {
+        if let Ok(mut reconnects) = self.reconnects.lock() {
+            reconnects.policy = policy;
         }
+        self
+    }
+
+    /// Build an awaitable resolving to the next market event.
+    ///
+    /// Once the stream is exhausted the awaitable resolves to `None`, or raises
+    /// `StopAsyncIteration` if `stop_iteration` is set.
+    fn recv_awaitable(
+        &self,
+        py: Python<'_>,
+        timeout: Option<f64>,
+        stop_iteration: bool,
+    ) -> PyResult<PyObject> {
+        let timeout = parse_timeout(timeout)?;
+        let receiver = Arc::clone(&self.receiver);
+        let reconnects = Arc::clone(&self.reconnects);
+
+        let future = async move {
+            let mut guard = receiver.lock().await;
+
+            let item = match (guard.as_mut(), timeout) {
+                (None, _) => None,
+                (Some(rx), Some(duration)) => tokio::time::timeout(duration, rx.recv())
+                    .await
+                    .map_err(|_| {
+                        AsyncTimeoutError::new_err("timeout elapsed awaiting market event")
+                    })?,
+                (Some(rx), None) => rx.recv().await,
+            };
+
+            if item.is_none() {
+                *guard = None;
+            }
+            drop(guard);
+
+            Python::with_gil(|py| match item {
+                Some(event) => market_stream_result_to_py(py, event, &reconnects),
+                None if stop_iteration => {
+                    Err(PyStopAsyncIteration::new_err("market stream closed"))
+                }
+                None => Ok(py.None()),
+            })
+        };
+
+        future_into_py(py, future).map(|bound| bound.into())
     }
 }
 
@@ -895,7 +1591,7 @@ impl PyMarketStream {
     pub fn recv(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Option<PyObject>> {
         let event = self.recv_inner(timeout)?;
         match event {
-            Some(event) => market_stream_result_to_py(py, event).map(Some),
+            Some(event) => market_stream_result_to_py(py, event, &self.reconnects).map(Some),
             None => Ok(None),
         }
     }
@@ -912,7 +1608,7 @@ impl PyMarketStream {
         };
 
         match receiver.try_recv() {
-            Ok(event) => market_stream_result_to_py(py, event).map(Some),
+            Ok(event) => market_stream_result_to_py(py, event, &self.reconnects).map(Some),
             Err(TryRecvError::Empty) => Ok(None),
             Err(TryRecvError::Disconnected) => {
                 *guard = None;
@@ -939,6 +1635,69 @@ impl PyMarketStream {
         future_into_py(py, future).map(|bound| bound.into())
     }
 
+    /// Return a new stream forwarding only market events for the provided instrument index.
+    ///
+    /// Reconnecting and error events are forwarded unchanged. Events for other instruments are
+    /// dropped as they arrive. This stream is consumed and reports as closed afterwards.
+    pub fn filter_instrument(&self, instrument: usize) -> PyResult<Self> {
+        let stream = futures::stream::iter(self.take_receiver()?)
+            .flatten()
+            .filter(move |event| {
+                let keep = match event {
+                    Event::Item(Ok(event)) => event.instrument == InstrumentIndex(instrument),
+                    Event::Item(Err(_)) | Event::Reconnecting(_) => true,
+                };
+                futures::future::ready(keep)
+            });
+
+        Ok(Self::new(Arc::clone(&self.runtime), stream)
+            .with_reconnect_policy(self.reconnect_policy()))
+    }
+
+    /// Return a new stream dropping market events whose `time_exchange` is more than
+    /// `max_age_secs` older than their `time_received`, such as data replayed after a reconnect.
+    ///
+    /// Reconnecting and error events are forwarded unchanged. The number of events dropped is
+    /// reported by [`Self::stale_dropped`]. This stream is consumed and reports as closed
+    /// afterwards.
+    pub fn filter_stale(&self, max_age_secs: f64) -> PyResult<Self> {
+        let filter = StaleEventFilter::new(max_age_secs, "max_age_secs")?;
+        let stream_filter = filter.clone();
+        let stream = futures::stream::iter(self.take_receiver()?)
+            .flatten()
+            .filter(move |event| {
+                let keep = match event {
+                    Event::Item(Ok(event)) => !stream_filter.is_stale(event),
+                    Event::Item(Err(_)) | Event::Reconnecting(_) => true,
+                };
+                futures::future::ready(keep)
+            });
+
+        let mut filtered = Self::new(Arc::clone(&self.runtime), stream)
+            .with_reconnect_policy(self.reconnect_policy());
+        filtered.stale_filter = Some(filter);
+        Ok(filtered)
+    }
+
+    /// Number of stale market events dropped by [`Self::filter_stale`], or `0` if this stream
+    /// does not filter stale events.
+    #[getter]
+    pub fn stale_dropped(&self) -> u64 {
+        self.stale_filter
+            .as_ref()
+            .map_or(0, StaleEventFilter::dropped)
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Block until the next market event arrives, raising `StopIteration` once the stream is
+    /// closed.
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.recv(py, None)
+    }
+
     pub fn is_closed(&self) -> PyResult<bool> {
         let guard = self
             .receiver
@@ -961,8 +1720,31 @@ impl PyMarketStream {
 
 #[pymethods]
 impl PyAsyncMarketStream {
+    /// Await the next market event, or `None` once the stream is closed.
+    ///
+    /// Raises `asyncio.TimeoutError` if `timeout` seconds elapse without an event.
+    #[pyo3(signature = (timeout = None))]
+    pub fn recv(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<PyObject> {
+        self.recv_awaitable(py, timeout, false)
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.recv_awaitable(py, None, true)
+    }
+
     fn __repr__(&self) -> PyResult<String> {
-        Ok(if self.receiver.is_none() {
+        // A receiver locked by a pending recv is still open.
+        let closed = self
+            .receiver
+            .try_lock()
+            .map(|guard| guard.is_none())
+            .unwrap_or(false);
+
+        Ok(if closed {
             "AsyncMarketStream(closed=True)".to_string()
         } else {
             "AsyncMarketStream(closed=False)".to_string()
@@ -970,12 +1752,31 @@ impl PyAsyncMarketStream {
     }
 }
 
+/// Initialise [`DynamicStreams`] from batches of subscriptions.
+///
+/// Unless `strict=False`, every subscription is first checked against
+/// [`exchange_supports_instrument_kind`] and a `ValueError` naming the first unsupported
+/// exchange, instrument & kind is raised before any connection is attempted.
+///
+/// `reconnect_policy` configures the backoff between reconnection attempts, defaulting to
+/// [`STREAM_RECONNECTION_POLICY`].
 #[pyfunction]
-#[pyo3(signature = (subscriptions))]
+#[pyo3(signature = (subscriptions, strict = true, reconnect_policy = None))]
 pub fn init_dynamic_streams(
     _py: Python<'_>,
     subscriptions: Vec<Vec<PySubscription>>,
+    strict: bool,
+    reconnect_policy: Option<PyReconnectPolicy>,
 ) -> PyResult<PyDynamicStreams> {
+    if strict
+        && let Some(unsupported) = subscriptions
+            .iter()
+            .flatten()
+            .find(|subscription| !subscription.is_supported())
+    {
+        return Err(unsupported_subscription_error(&unsupported.inner));
+    }
+
     let runtime = Arc::new(
         RuntimeBuilder::new_multi_thread()
             .enable_all()
@@ -1013,23 +1814,172 @@ pub fn init_dynamic_streams(
         })
         .collect::<Vec<_>>();
 
+    let reconnect_policy = reconnect_policy.unwrap_or_default();
     let streams = runtime
-        .block_on(DynamicStreams::init(converted))
+        .block_on(DynamicStreams::init_with_policy(
+            converted,
+            reconnect_policy.inner,
+        ))
         .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
-    Ok(PyDynamicStreams::from_parts(runtime, streams))
+    Ok(
+        PyDynamicStreams::from_parts(runtime, streams)
+            .with_reconnect_policy(Some(reconnect_policy)),
+    )
+}
+
+/// Exponential backoff applied between reconnection attempts of a market data stream.
+///
+/// Delays are in milliseconds and grow by `multiplier` after every failed attempt, capped at
+/// `max_delay_ms`. A `max_attempts` of `None` retries indefinitely.
+#[pyclass(module = "barter_python", name = "ReconnectPolicy", eq, frozen)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PyReconnectPolicy {
+    inner: ReconnectionBackoffPolicy,
+}
+
+impl Default for PyReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            inner: STREAM_RECONNECTION_POLICY,
+        }
+    }
+}
+
+#[pymethods]
+impl PyReconnectPolicy {
+    #[new]
+    #[pyo3(signature = (
+        initial_delay_ms = None,
+        max_delay_ms = None,
+        multiplier = None,
+        max_attempts = None,
+    ))]
+    fn new(
+        initial_delay_ms: Option<i64>,
+        max_delay_ms: Option<i64>,
+        multiplier: Option<i64>,
+        max_attempts: Option<i64>,
+    ) -> PyResult<Self> {
+        let default = STREAM_RECONNECTION_POLICY;
+
+        let initial = non_negative_delay_ms(
+            initial_delay_ms,
+            default.backoff_ms_initial,
+            "initial_delay_ms",
+        )?;
+        let max = non_negative_delay_ms(max_delay_ms, default.backoff_ms_max, "max_delay_ms")?;
+        if max < initial {
+            return Err(PyValueError::new_err(
+                "max_delay_ms must be greater than or equal to initial_delay_ms",
+            ));
+        }
+
+        let multiplier = match multiplier {
+            None => default.backoff_multiplier,
+            Some(value) => u8::try_from(value)
+                .ok()
+                .filter(|value| *value >= 1)
+                .ok_or_else(|| {
+                    PyValueError::new_err("multiplier must be an integer between 1 and 255")
+                })?,
+        };
+
+        let max_attempts = max_attempts
+            .map(|value| {
+                u32::try_from(value)
+                    .ok()
+                    .filter(|value| *value >= 1)
+                    .ok_or_else(|| {
+                        PyValueError::new_err("max_attempts must be a positive integer or None")
+                    })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            inner: ReconnectionBackoffPolicy {
+                backoff_ms_initial: initial,
+                backoff_multiplier: multiplier,
+                backoff_ms_max: max,
+                max_attempts,
+            },
+        })
+    }
+
+    #[getter]
+    fn initial_delay_ms(&self) -> u64 {
+        self.inner.backoff_ms_initial
+    }
+
+    #[getter]
+    fn max_delay_ms(&self) -> u64 {
+        self.inner.backoff_ms_max
+    }
+
+    #[getter]
+    fn multiplier(&self) -> u8 {
+        self.inner.backoff_multiplier
+    }
+
+    #[getter]
+    fn max_attempts(&self) -> Option<u32> {
+        self.inner.max_attempts
+    }
+
+    fn __repr__(&self) -> String {
+        let max_attempts = self
+            .inner
+            .max_attempts
+            .map_or_else(|| "None".to_string(), |attempts| attempts.to_string());
+        format!(
+            "ReconnectPolicy(initial_delay_ms={}, max_delay_ms={}, multiplier={}, max_attempts={})",
+            self.inner.backoff_ms_initial,
+            self.inner.backoff_ms_max,
+            self.inner.backoff_multiplier,
+            max_attempts,
+        )
+    }
+}
+
+fn non_negative_delay_ms(value: Option<i64>, default: u64, field: &str) -> PyResult<u64> {
+    match value {
+        None => Ok(default),
+        Some(value) => u64::try_from(value)
+            .map_err(|_| PyValueError::new_err(format!("{field} must be non-negative"))),
+    }
+}
+
+fn unsupported_subscription_error(
+    subscription: &Subscription<ExchangeId, MarketDataInstrument, SubKind>,
+) -> PyErr {
+    PyValueError::new_err(format!(
+        "{} does not support {} instruments: cannot subscribe to {} {}",
+        subscription.exchange.as_str(),
+        subscription.instrument.kind(),
+        subscription.instrument,
+        subscription.kind
+    ))
 }
 
 fn market_stream_result_to_py(
     py: Python<'_>,
     event: MarketStreamResult<InstrumentIndex, DataKind>,
+    reconnects: &Mutex<ReconnectAttempts>,
 ) -> PyResult<PyObject> {
     let data_module = PyModule::import_bound(py, "barter_python.data")?;
 
+    let (attempt, policy) = {
+        let mut reconnects = reconnects
+            .lock()
+            .map_err(|_| PyValueError::new_err("market stream mutex poisoned"))?;
+        (reconnects.observe(&event), reconnects.policy)
+    };
+
     match event {
         Event::Reconnecting(exchange) => {
             let reconnecting = data_module.getattr("MarketStreamReconnecting")?;
-            let constructed = reconnecting.call1((exchange.as_str(),))?;
+            let policy = policy.map(|policy| policy.into_py(py));
+            let constructed = reconnecting.call1((exchange.as_str(), attempt, policy))?;
             Ok(constructed.into_py(py))
         }
         Event::Item(result) => match result {
@@ -1059,6 +2009,67 @@ pub fn exchange_supports_instrument_kind(
     ))
 }
 
+/// Build the cross product of `symbols` & `kinds` as subscriptions on `exchange`.
+///
+/// `symbols` are `(base, quote)` pairs. Combinations the exchange does not support, as reported
+/// by [`exchange_supports_instrument_kind`], are skipped, or raise a `ValueError` if `strict`.
+#[pyfunction]
+#[pyo3(signature = (exchange, symbols, kinds, instrument_kind = None, strict = false))]
+pub fn subscriptions_for(
+    exchange: &PyExchangeId,
+    symbols: Vec<(String, String)>,
+    kinds: Vec<PySubKind>,
+    instrument_kind: Option<Bound<'_, PyAny>>,
+    strict: bool,
+) -> PyResult<Vec<PySubscription>> {
+    let instrument_kind = parse_market_data_instrument_kind(instrument_kind)?;
+
+    let mut subscriptions = Vec::with_capacity(symbols.len() * kinds.len());
+    for (base, quote) in &symbols {
+        for kind in &kinds {
+            let instrument = MarketDataInstrument::from((
+                base.as_str(),
+                quote.as_str(),
+                instrument_kind.clone(),
+            ));
+            let subscription = PySubscription {
+                inner: Subscription::new(exchange.inner, instrument, kind.inner),
+            };
+
+            if subscription.is_supported() {
+                subscriptions.push(subscription);
+            } else if strict {
+                return Err(unsupported_subscription_error(&subscription.inner));
+            }
+        }
+    }
+
+    Ok(subscriptions)
+}
+
+/// Merge several market streams into a single stream that closes once every input is exhausted.
+///
+/// Each input stream is consumed and reports as closed afterwards.
+#[pyfunction]
+pub fn merge_market_streams(streams: Vec<PyRef<'_, PyMarketStream>>) -> PyResult<PyMarketStream> {
+    let runtime = streams
+        .first()
+        .map(|stream| Arc::clone(&stream.runtime))
+        .ok_or_else(|| {
+            PyValueError::new_err("merge_market_streams requires at least one stream")
+        })?;
+
+    let receivers = streams
+        .iter()
+        .map(|stream| stream.take_receiver())
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let policy = streams.first().and_then(|stream| stream.reconnect_policy());
+    let merged = futures::stream::select_all(receivers.into_iter().flatten());
+
+    Ok(PyMarketStream::new(runtime, merged).with_reconnect_policy(policy))
+}
+
 #[cfg(feature = "python-tests")]
 fn parse_exchange_id(value: &str) -> PyResult<ExchangeId> {
     let serialized = format!("\"{}\"", value);
@@ -1078,10 +2089,9 @@ fn parse_side(value: &str) -> PyResult<Side> {
 }
 
 #[cfg(feature = "python-tests")]
-fn parse_trade_event(
-    _py: Python<'_>,
+fn parse_event_header(
     dict: &Bound<'_, PyDict>,
-) -> PyResult<(ExchangeId, MarketStreamResult<InstrumentIndex, PublicTrade>)> {
+) -> PyResult<(ExchangeId, InstrumentIndex, DateTime<Utc>, DateTime<Utc>)> {
     let exchange_str: String = dict
         .get_item("exchange")?
         .ok_or_else(|| PyValueError::new_err("event missing 'exchange'"))?
@@ -1102,6 +2112,20 @@ fn parse_trade_event(
         .ok_or_else(|| PyValueError::new_err("event missing 'time_received'"))?
         .extract::<DateTime<Utc>>()?;
 
+    Ok((
+        exchange,
+        InstrumentIndex(instrument),
+        time_exchange,
+        time_received,
+    ))
+}
+
+#[cfg(feature = "python-tests")]
+fn parse_trade_event(
+    dict: &Bound<'_, PyDict>,
+) -> PyResult<(ExchangeId, MarketStreamResult<InstrumentIndex, PublicTrade>)> {
+    let (exchange, instrument, time_exchange, time_received) = parse_event_header(dict)?;
+
     let trade_value = dict
         .get_item("trade")?
         .ok_or_else(|| PyValueError::new_err("event missing 'trade'"))?;
@@ -1134,19 +2158,48 @@ fn parse_trade_event(
             time_exchange,
             time_received,
             exchange,
-            instrument: InstrumentIndex(instrument),
+            instrument,
             kind: trade,
         })),
     ))
 }
 
 #[cfg(feature = "python-tests")]
-#[pyfunction]
-pub fn _testing_dynamic_trades(
+fn parse_candle_event(
+    dict: &Bound<'_, PyDict>,
+) -> PyResult<(ExchangeId, MarketStreamResult<InstrumentIndex, Candle>)> {
+    let (exchange, instrument, time_exchange, time_received) = parse_event_header(dict)?;
+
+    let candle = dict
+        .get_item("candle")?
+        .ok_or_else(|| PyValueError::new_err("event missing 'candle'"))?
+        .extract::<PyRef<'_, PyCandle>>()?
+        .as_inner();
+
+    Ok((
+        exchange,
+        Event::Item(Ok(MarketEvent {
+            time_exchange,
+            time_received,
+            exchange,
+            instrument,
+            kind: candle,
+        })),
+    ))
+}
+
+/// Group synthetic `item` / `reconnecting` / `error` event dictionaries into one pre-filled
+/// stream per exchange, parsing `item` events with the provided `parse_item`.
+#[cfg(feature = "python-tests")]
+fn testing_streams<Kind, F>(
     py: Python<'_>,
     events: Vec<PyObject>,
-) -> PyResult<PyDynamicStreams> {
-    let mut grouped: BTreeMap<ExchangeId, Vec<MarketStreamResult<InstrumentIndex, PublicTrade>>> =
+    parse_item: F,
+) -> PyResult<VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentIndex, Kind>>>>
+where
+    F: Fn(&Bound<'_, PyDict>) -> PyResult<(ExchangeId, MarketStreamResult<InstrumentIndex, Kind>)>,
+{
+    let mut grouped: BTreeMap<ExchangeId, Vec<MarketStreamResult<InstrumentIndex, Kind>>> =
         BTreeMap::new();
 
     for obj in events {
@@ -1158,7 +2211,7 @@ pub fn _testing_dynamic_trades(
 
         match event_type.as_str() {
             "item" => {
-                let (exchange, event) = parse_trade_event(py, &dict)?;
+                let (exchange, event) = parse_item(dict)?;
                 grouped.entry(exchange).or_default().push(event);
             }
             "reconnecting" => {
@@ -1195,7 +2248,7 @@ pub fn _testing_dynamic_trades(
         }
     }
 
-    let mut trade_map: VecMap<ExchangeId, UnboundedReceiverStream<_>> = VecMap::default();
+    let mut streams = VecMap::default();
 
     for (exchange, events) in grouped {
         let (tx, rx) = mpsc::unbounded_channel();
@@ -1203,16 +2256,14 @@ pub fn _testing_dynamic_trades(
             tx.send(event).expect("send event");
         }
         drop(tx);
-        trade_map.insert(exchange, UnboundedReceiverStream::new(rx));
+        streams.insert(exchange, UnboundedReceiverStream::new(rx));
     }
 
-    let streams = DynamicStreams {
-        trades: trade_map,
-        l1s: VecMap::default(),
-        l2s: VecMap::default(),
-        liquidations: VecMap::default(),
-    };
+    Ok(streams)
+}
 
+#[cfg(feature = "python-tests")]
+fn testing_dynamic_streams(streams: DynamicStreams<InstrumentIndex>) -> PyResult<PyDynamicStreams> {
     let runtime = Arc::new(
         RuntimeBuilder::new_current_thread()
             .enable_all()
@@ -1223,6 +2274,36 @@ pub fn _testing_dynamic_trades(
     Ok(PyDynamicStreams::from_parts(runtime, streams))
 }
 
+#[cfg(feature = "python-tests")]
+#[pyfunction]
+pub fn _testing_dynamic_trades(
+    py: Python<'_>,
+    events: Vec<PyObject>,
+) -> PyResult<PyDynamicStreams> {
+    testing_dynamic_streams(DynamicStreams {
+        trades: testing_streams(py, events, parse_trade_event)?,
+        l1s: VecMap::default(),
+        l2s: VecMap::default(),
+        liquidations: VecMap::default(),
+        candles: VecMap::default(),
+    })
+}
+
+#[cfg(feature = "python-tests")]
+#[pyfunction]
+pub fn _testing_dynamic_candles(
+    py: Python<'_>,
+    events: Vec<PyObject>,
+) -> PyResult<PyDynamicStreams> {
+    testing_dynamic_streams(DynamicStreams {
+        trades: VecMap::default(),
+        l1s: VecMap::default(),
+        l2s: VecMap::default(),
+        liquidations: VecMap::default(),
+        candles: testing_streams(py, events, parse_candle_event)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1270,6 +2351,40 @@ mod tests {
         }))
     }
 
+    #[test]
+    fn session_contains_handles_overnight_sessions() {
+        let time = |hour, min| NaiveTime::from_hms_opt(hour, min, 0).unwrap();
+
+        let day = (time(9, 0), time(17, 0));
+        assert!(session_contains(day, time(9, 0)));
+        assert!(!session_contains(day, time(17, 0)));
+        assert!(!session_contains(day, time(20, 0)));
+
+        let overnight = (time(19, 10), time(19, 0));
+        assert!(session_contains(overnight, time(23, 0)));
+        assert!(session_contains(overnight, time(3, 0)));
+        assert!(!session_contains(overnight, time(19, 5)));
+    }
+
+    #[test]
+    fn stale_event_filter_drops_and_counts_lagging_events() {
+        let Event::Item(Ok(event)) = sample_trade_event() else {
+            panic!("expected market event");
+        };
+
+        let lenient = StaleEventFilter::new(2.0, "max_age_secs").unwrap();
+        assert!(!lenient.is_stale(&event));
+        assert_eq!(lenient.dropped(), 0);
+
+        let strict = StaleEventFilter::new(0.5, "max_age_secs").unwrap();
+        assert!(strict.is_stale(&event));
+        assert!(strict.clone().is_stale(&event));
+        assert_eq!(strict.dropped(), 2);
+
+        assert!(StaleEventFilter::new(0.0, "max_age_secs").is_err());
+        assert!(StaleEventFilter::new(f64::NAN, "max_age_secs").is_err());
+    }
+
     fn sample_reconnect_event() -> MarketStreamResult<InstrumentIndex, PublicTrade> {
         Event::Reconnecting(ExchangeId::BinanceSpot)
     }
@@ -1295,6 +2410,7 @@ mod tests {
             l1s: VecMap::default(),
             l2s: VecMap::default(),
             liquidations: VecMap::default(),
+            candles: VecMap::default(),
         }
     }
 
@@ -10,6 +10,7 @@ use pyo3::{
 use pyo3::exceptions::PyValueError;
 
 create_exception!(barter_python, SocketError, PyException);
+create_exception!(barter_python, UndefinedMetricError, PyValueError);
 
 #[derive(Debug, Clone)]
 enum SocketErrorDetails {
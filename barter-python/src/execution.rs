@@ -15,7 +15,9 @@ use barter_execution::{
     order::{
         OrderEvent, OrderKey, OrderKind, TimeInForce,
         id::{ClientOrderId, OrderId, StrategyId},
-        request::{OrderRequestOpen, OrderResponseCancel, RequestOpen},
+        request::{
+            OrderRequestCancel, OrderRequestOpen, OrderResponseCancel, RequestCancel, RequestOpen,
+        },
         state::{
             ActiveOrderState, CancelInFlight, Cancelled, InactiveOrderState, Open, OrderState,
         },
@@ -30,7 +32,7 @@ use barter_instrument::{
     index::{IndexedInstruments, error::IndexError},
     instrument::{Instrument, InstrumentIndex, kind::InstrumentKind, name::InstrumentNameExchange},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use fnv::FnvHashMap;
 use futures::{StreamExt, stream::BoxStream};
 use pyo3::{
@@ -38,7 +40,7 @@ use pyo3::{
     basic::CompareOp,
     exceptions::{PyNotImplementedError, PyValueError},
     prelude::*,
-    types::{PyModule, PyType},
+    types::{PyDict, PyModule, PyType},
 };
 use rust_decimal::Decimal;
 use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
@@ -48,7 +50,10 @@ use tokio::time::{Duration, timeout};
 
 use crate::{
     account::PyAccountEvent,
-    command::{PyOrderKey, parse_side, parse_time_in_force},
+    analytics::extract_trade,
+    classes::engine::offset_instrument_index,
+    command::{PyOrderKey, parse_side, parse_time_in_force, parse_tolerance, within_tolerance},
+    common::duration_from_secs,
     config::{PyMockExecutionConfig, PySystemConfig},
     data::PyExchangeId,
     instrument::{PyAssetIndex, PyExchangeIndex, PyInstrumentIndex, PyQuoteAsset, PySide},
@@ -300,6 +305,63 @@ pub fn asset_balance_new(
     })
 }
 
+/// Aggregate fills for a single instrument into totals and a quantity-weighted average price.
+///
+/// Returns a dict containing the `instrument` index, `trade_count`, `total_quantity`,
+/// `total_fees`, `net_quantity` (buys minus sells) and the volume weighted `average_price`.
+#[pyfunction]
+#[pyo3(signature = (trades))]
+pub fn aggregate_trades(py: Python<'_>, trades: Vec<Bound<'_, PyAny>>) -> PyResult<PyObject> {
+    let trades = trades
+        .iter()
+        .enumerate()
+        .map(|(index, trade)| extract_trade(trade, index))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let Some(first) = trades.first() else {
+        return Err(PyValueError::new_err("trades must not be empty"));
+    };
+    let instrument = first.instrument;
+
+    let mut total_quantity = Decimal::ZERO;
+    let mut total_fees = Decimal::ZERO;
+    let mut net_quantity = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+
+    for (index, trade) in trades.iter().enumerate() {
+        if trade.instrument != instrument {
+            return Err(PyValueError::new_err(format!(
+                "trades[{index}] has instrument {} but expected {instrument}",
+                trade.instrument,
+            )));
+        }
+
+        let quantity = trade.quantity.abs();
+        total_quantity += quantity;
+        total_fees += trade.fees.fees;
+        notional += trade.price * quantity;
+        net_quantity += match trade.side {
+            Side::Buy => quantity,
+            Side::Sell => -quantity,
+        };
+    }
+
+    let average_price = if total_quantity.is_zero() {
+        None
+    } else {
+        Some(decimal_to_py(py, notional / total_quantity)?)
+    };
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("instrument", instrument.index())?;
+    dict.set_item("trade_count", trades.len())?;
+    dict.set_item("total_quantity", decimal_to_py(py, total_quantity)?)?;
+    dict.set_item("total_fees", decimal_to_py(py, total_fees)?)?;
+    dict.set_item("net_quantity", decimal_to_py(py, net_quantity)?)?;
+    dict.set_item("average_price", average_price)?;
+    Ok(dict.into_py(py))
+}
+
 #[pymethods]
 impl PyExecutionBalance {
     #[new]
@@ -326,6 +388,27 @@ impl PyExecutionBalance {
         decimal_to_py(py, self.inner.used())
     }
 
+    /// Return the change from `other` to this balance as a dict of `total_delta`, `free_delta`
+    /// and `used_delta`.
+    ///
+    /// Deltas may be negative, so they are returned as plain `Decimal`s rather than a `Balance`.
+    pub fn delta(&self, py: Python<'_>, other: &Self) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item(
+            "total_delta",
+            decimal_to_py(py, self.inner.total - other.inner.total)?,
+        )?;
+        dict.set_item(
+            "free_delta",
+            decimal_to_py(py, self.inner.free - other.inner.free)?,
+        )?;
+        dict.set_item(
+            "used_delta",
+            decimal_to_py(py, self.inner.used() - other.inner.used())?,
+        )?;
+        Ok(dict.into_py(py))
+    }
+
     fn __str__(&self) -> PyResult<String> {
         Python::with_gil(|py| {
             let total = decimal_to_py(py, self.inner.total)?;
@@ -741,6 +824,12 @@ impl PyClientOrderId {
     fn format_repr(&self, label: &str) -> String {
         format!("{label}('{}')", self.inner)
     }
+
+    fn sequential_unchecked(prefix: &str, n: u64) -> Self {
+        Self {
+            inner: ClientOrderId::new(format!("{prefix}-{n:04}")),
+        }
+    }
 }
 
 #[pymethods]
@@ -769,6 +858,14 @@ impl PyClientOrderId {
         }
     }
 
+    /// Deterministic [`ClientOrderId`] for sequence number `n`, formatted as `{prefix}-{n:04}`.
+    #[staticmethod]
+    #[pyo3(signature = (prefix, n))]
+    pub fn sequential(prefix: &str, n: u64) -> PyResult<Self> {
+        ensure_non_empty(prefix, "client order id prefix")?;
+        Ok(Self::sequential_unchecked(prefix, n))
+    }
+
     /// Access the underlying string value.
     #[getter]
     pub fn value(&self) -> String {
@@ -786,6 +883,71 @@ impl PyClientOrderId {
     }
 }
 
+/// Reproducible generator of sequential [`ClientOrderId`]s (`prefix-0000`, `prefix-0001`, ...).
+#[pyclass(module = "barter_python", name = "ClientOrderIdGenerator")]
+#[derive(Debug, Clone)]
+pub struct PyClientOrderIdGenerator {
+    prefix: String,
+    start: u64,
+    next: u64,
+}
+
+#[pymethods]
+impl PyClientOrderIdGenerator {
+    #[new]
+    #[pyo3(signature = (prefix, start = 0))]
+    pub fn __new__(prefix: &str, start: u64) -> PyResult<Self> {
+        ensure_non_empty(prefix, "client order id prefix")?;
+        Ok(Self {
+            prefix: prefix.to_string(),
+            start,
+            next: start,
+        })
+    }
+
+    /// Prefix shared by every generated [`ClientOrderId`].
+    #[getter]
+    pub fn prefix(&self) -> String {
+        self.prefix.clone()
+    }
+
+    /// Number of [`ClientOrderId`]s generated since construction or the last reset.
+    #[getter]
+    pub fn count(&self) -> u64 {
+        self.next - self.start
+    }
+
+    /// Generate the next [`ClientOrderId`] in the sequence.
+    pub fn next_id(&mut self) -> PyResult<PyClientOrderId> {
+        let id = PyClientOrderId::sequential_unchecked(&self.prefix, self.next);
+        self.next = self
+            .next
+            .checked_add(1)
+            .ok_or_else(|| PyValueError::new_err("client order id sequence exhausted"))?;
+        Ok(id)
+    }
+
+    /// Restart the sequence from the initial `start` value.
+    pub fn reset(&mut self) {
+        self.next = self.start;
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<PyClientOrderId> {
+        self.next_id()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ClientOrderIdGenerator(prefix='{}', next={})",
+            self.prefix, self.next
+        )
+    }
+}
+
 /// Wrapper around [`OrderId`] for Python exposure.
 #[pyclass(module = "barter_python", name = "OrderId", eq, hash, frozen)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1842,6 +2004,36 @@ impl PyTrade {
         decimal_to_py(py, self.inner.value_quote())
     }
 
+    /// Compare with another trade, allowing `price`, `quantity` and `fees` to differ within
+    /// tolerances.
+    ///
+    /// Useful when reconciling engine trades against exchange reported trades that round
+    /// differently. All other fields must be equal.
+    #[pyo3(signature = (other, price_tol=0.0, qty_tol=0.0, fees_tol=0.0))]
+    pub fn approx_eq(
+        &self,
+        other: &Self,
+        price_tol: f64,
+        qty_tol: f64,
+        fees_tol: f64,
+    ) -> PyResult<bool> {
+        let price_tol = parse_tolerance(price_tol, "price_tol")?;
+        let qty_tol = parse_tolerance(qty_tol, "qty_tol")?;
+        let fees_tol = parse_tolerance(fees_tol, "fees_tol")?;
+        let (lhs, rhs) = (&self.inner, &other.inner);
+
+        Ok(lhs.id == rhs.id
+            && lhs.order_id == rhs.order_id
+            && lhs.instrument == rhs.instrument
+            && lhs.strategy == rhs.strategy
+            && lhs.time_exchange == rhs.time_exchange
+            && lhs.side == rhs.side
+            && lhs.fees.asset == rhs.fees.asset
+            && within_tolerance(lhs.price, rhs.price, price_tol)
+            && within_tolerance(lhs.quantity, rhs.quantity, qty_tol)
+            && within_tolerance(lhs.fees.fees, rhs.fees.fees, fees_tol))
+    }
+
     fn __str__(&self) -> String {
         format!(
             "Trade(instrument={}, side={}, price={}, quantity={}, time={})",
@@ -1873,10 +2065,16 @@ impl PyTrade {
 #[derive(Debug, Clone)]
 pub struct PyExecutionInstrumentMap {
     inner: ExecutionInstrumentMap,
+    /// Offset between externally numbered instruments and the engine's [`InstrumentIndex`].
+    index_offset: usize,
 }
 
 impl PyExecutionInstrumentMap {
-    fn from_configs(exchange: ExchangeId, configs: Vec<InstrumentConfig>) -> PyResult<Self> {
+    fn from_configs(
+        exchange: ExchangeId,
+        configs: Vec<InstrumentConfig>,
+        index_offset: usize,
+    ) -> PyResult<Self> {
         let instruments = configs
             .into_iter()
             .map(Instrument::from)
@@ -1884,7 +2082,10 @@ impl PyExecutionInstrumentMap {
         let indexed = IndexedInstruments::new(instruments);
         let inner =
             generate_execution_instrument_map(&indexed, exchange).map_err(index_error_to_py)?;
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            index_offset,
+        })
     }
 
     fn collect_asset_names(&self) -> Vec<String> {
@@ -1961,27 +2162,71 @@ impl PyExecutionInstrumentMap {
 #[pymethods]
 impl PyExecutionInstrumentMap {
     #[classmethod]
-    #[pyo3(signature = (exchange, config))]
+    #[pyo3(signature = (exchange, config, index_offset = 0))]
     pub fn from_system_config(
         _cls: &Bound<'_, PyType>,
         exchange: &PyExchangeId,
         config: &PySystemConfig,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let mut system = config.clone_inner();
-        Self::from_configs(exchange.as_inner(), system.instruments.drain(..).collect())
+        Self::from_configs(
+            exchange.as_inner(),
+            system.instruments.drain(..).collect(),
+            index_offset,
+        )
     }
 
     #[classmethod]
-    #[pyo3(signature = (exchange, definitions))]
+    #[pyo3(signature = (exchange, definitions, index_offset = 0))]
     pub fn from_definitions(
         _cls: &Bound<'_, PyType>,
         py: Python<'_>,
         exchange: &PyExchangeId,
         definitions: PyObject,
+        index_offset: usize,
     ) -> PyResult<Self> {
         let value = definitions.bind(py);
         let configs = instrument_configs_from_py(py, &value)?;
-        Self::from_configs(exchange.as_inner(), configs)
+        Self::from_configs(exchange.as_inner(), configs, index_offset)
+    }
+
+    /// Offset added to an [`InstrumentIndex`] to produce the external instrument number.
+    #[getter]
+    pub fn index_offset(&self) -> usize {
+        self.index_offset
+    }
+
+    /// Return a copy of this map using the provided external instrument numbering offset.
+    #[pyo3(signature = (index_offset))]
+    pub fn with_index_offset(&self, index_offset: usize) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            index_offset,
+        }
+    }
+
+    /// Convert an [`InstrumentIndex`] to the external instrument number.
+    #[pyo3(signature = (index))]
+    pub fn to_external_index(&self, index: &PyInstrumentIndex) -> PyResult<usize> {
+        self.inner
+            .find_instrument_name_exchange(index.inner())
+            .map_err(key_error_to_py)?;
+        index
+            .inner()
+            .index()
+            .checked_add(self.index_offset)
+            .ok_or_else(|| PyValueError::new_err("external instrument number overflowed"))
+    }
+
+    /// Convert an external instrument number to the engine's [`InstrumentIndex`].
+    #[pyo3(signature = (number))]
+    pub fn from_external_index(&self, number: usize) -> PyResult<PyInstrumentIndex> {
+        let index = offset_instrument_index(number, self.index_offset)?;
+        self.inner
+            .find_instrument_name_exchange(index)
+            .map_err(key_error_to_py)?;
+        Ok(PyInstrumentIndex::from_inner(index))
     }
 
     #[getter]
@@ -2110,6 +2355,20 @@ impl PyMockExecutionClient {
         let indexed = indexer.account_event(event).map_err(index_error_to_py)?;
         Ok(PyAccountEvent::from_inner(indexed))
     }
+
+    fn account_event_to_py(
+        &self,
+        py: Python<'_>,
+        event: UnindexedAccountEvent,
+        raw: bool,
+    ) -> PyResult<PyObject> {
+        if raw {
+            serialize_to_py_dict(py, &event)
+        } else {
+            let indexed = self.index_account_event(event)?;
+            Py::new(py, indexed).map(|event| event.into_py(py))
+        }
+    }
 }
 
 #[pymethods]
@@ -2377,12 +2636,87 @@ impl PyMockExecutionClient {
         }
     }
 
-    #[pyo3(signature = (timeout_secs=None))]
+    /// Supply the latest market `price` of an `instrument`, filling any resting limit orders it
+    /// crosses at their limit price and charging the maker fee.
+    #[pyo3(signature = (instrument, price))]
+    pub fn update_market_price(&self, instrument: &str, price: &Bound<'_, PyAny>) -> PyResult<()> {
+        let price_decimal = extract_decimal(price, "price")?;
+        if price_decimal <= Decimal::ZERO {
+            return Err(PyValueError::new_err(
+                "price must be a positive numeric value",
+            ));
+        }
+
+        self.clone_client()?
+            .update_market_price(&InstrumentNameExchange::new(instrument), price_decimal)
+            .map_err(unindexed_client_error_to_py)
+    }
+
+    /// Cancel the resting order with the provided `client_order_id`.
+    #[pyo3(signature = (instrument, client_order_id, strategy=None))]
+    pub fn cancel_order(
+        &self,
+        py: Python<'_>,
+        instrument: &str,
+        client_order_id: &Bound<'_, PyAny>,
+        strategy: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<PyObject>> {
+        let strategy_id = match strategy {
+            Some(value) => coerce_strategy_id(value)?,
+            None => StrategyId::unknown(),
+        };
+        let cid = coerce_client_order_id(Some(client_order_id))?;
+
+        let client = self.clone_client()?;
+        let runtime = Arc::clone(&self.runtime);
+        let instrument_name = instrument.to_string();
+        let exchange_id = self.exchange_id;
+
+        let response = py.allow_threads(move || {
+            runtime.block_on(async move {
+                let instrument_exchange = InstrumentNameExchange::new(instrument_name);
+                let key = OrderKey {
+                    exchange: exchange_id,
+                    instrument: &instrument_exchange,
+                    strategy: strategy_id,
+                    cid,
+                };
+                client
+                    .cancel_order(OrderRequestCancel::new(key, RequestCancel::new(None)))
+                    .await
+            })
+        });
+
+        match response {
+            Some(response) => serialize_to_py_dict(py, &response).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Advance the mock exchange clock by `delta`, expiring any open orders whose time in force
+    /// elapses, and return the new exchange time.
+    #[pyo3(signature = (delta))]
+    pub fn advance_time(&self, py: Python<'_>, delta: TimeDelta) -> PyResult<DateTime<Utc>> {
+        if delta < TimeDelta::zero() {
+            return Err(PyValueError::new_err("delta must be non-negative"));
+        }
+
+        let client = self.clone_client()?;
+        let runtime = Arc::clone(&self.runtime);
+
+        let time_exchange = py.allow_threads(move || runtime.block_on(client.advance_time(delta)));
+        time_exchange.map_err(unindexed_client_error_to_py)
+    }
+
+    /// Wait up to `timeout_secs` for the next account event, returned as a typed
+    /// [`PyAccountEvent`], or as the untyped `dict` of the exchange event if `raw` is set.
+    #[pyo3(signature = (timeout_secs=None, raw=false))]
     pub fn poll_event(
         &self,
         py: Python<'_>,
         timeout_secs: Option<f64>,
-    ) -> PyResult<Option<Py<PyAccountEvent>>> {
+        raw: bool,
+    ) -> PyResult<Option<PyObject>> {
         let mut guard = self
             .account_stream
             .lock()
@@ -2414,26 +2748,20 @@ impl PyMockExecutionClient {
                 return Err(PyValueError::new_err("timeout must be non-negative"));
             }
 
-            let duration = Duration::from_secs_f64(secs);
+            let duration = duration_from_secs(secs, "timeout")?;
             let event = py.allow_threads(move || {
                 runtime.block_on(async { timeout(duration, stream.next()).await })
             });
 
             match event {
-                Ok(Some(event)) => {
-                    let indexed = self.index_account_event(event)?;
-                    Py::new(py, indexed).map(Some)
-                }
+                Ok(Some(event)) => self.account_event_to_py(py, event, raw).map(Some),
                 Ok(None) => Ok(None),
                 Err(_) => Ok(None),
             }
         } else {
             let event = py.allow_threads(move || runtime.block_on(stream.next()));
             match event {
-                Some(event) => {
-                    let indexed = self.index_account_event(event)?;
-                    Py::new(py, indexed).map(Some)
-                }
+                Some(event) => self.account_event_to_py(py, event, raw).map(Some),
                 None => Ok(None),
             }
         }
@@ -1,8 +1,11 @@
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    sync::{LazyLock, Mutex},
+};
 
 use barter::system::config::InstrumentConfig;
 use barter_instrument::{
-    Side,
+    Side, Underlying,
     asset::{
         Asset, AssetIndex, QuoteAsset,
         name::{AssetNameExchange, AssetNameInternal},
@@ -11,15 +14,23 @@ use barter_instrument::{
     index::{IndexedInstruments, error::IndexError},
     instrument::{
         InstrumentIndex,
+        kind::{InstrumentKind, perpetual::PerpetualContract},
         name::{InstrumentNameExchange, InstrumentNameInternal},
+        quote::InstrumentQuoteAsset,
         spec::{
             InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
             OrderQuantityUnits,
         },
     },
 };
-use pyo3::{Bound, PyAny, PyResult, Python, exceptions::PyValueError, prelude::*, types::PyType};
-use rust_decimal::{Decimal, prelude::FromPrimitive};
+use barter_integration::collection::FnvIndexMap;
+use pyo3::{
+    Bound, PyAny, PyResult, Python,
+    exceptions::{PyNotImplementedError, PyValueError},
+    prelude::*,
+    types::PyType,
+};
+use rust_decimal::{Decimal, RoundingStrategy, prelude::FromPrimitive};
 
 use crate::{
     config::PySystemConfig,
@@ -409,6 +420,34 @@ impl PySide {
     fn __repr__(&self) -> String {
         format!("Side.{:?}", self.inner)
     }
+
+    /// Encode this side using the wire convention of the provided exchange.
+    ///
+    /// Returns a string for venues that name the side, or `1` / `-1` for venues that
+    /// encode it in the sign of the order amount.
+    #[pyo3(signature = (exchange))]
+    fn to_exchange(&self, py: Python<'_>, exchange: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let encoding = SideEncoding::for_exchange(coerce_exchange_id(exchange)?)?;
+        Ok(match encoding.encode(self.inner) {
+            SideValue::Text(text) => text.into_py(py),
+            SideValue::Signed(sign) => sign.into_py(py),
+        })
+    }
+
+    /// Decode a side from the wire convention of the provided exchange.
+    #[staticmethod]
+    #[pyo3(signature = (exchange, value))]
+    fn from_exchange(exchange: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let exchange = coerce_exchange_id(exchange)?;
+        let encoding = SideEncoding::for_exchange(exchange)?;
+        encoding.decode(value)?.map(Self::from_side).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "invalid side {value} for exchange {}, expected {}",
+                exchange.as_str(),
+                encoding.expected(),
+            ))
+        })
+    }
 }
 
 impl PySide {
@@ -421,6 +460,106 @@ impl PySide {
     }
 }
 
+/// Wire convention an exchange uses to communicate a [`Side`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SideEncoding {
+    /// `"buy"` / `"sell"`.
+    Lowercase,
+    /// `"BUY"` / `"SELL"`.
+    Uppercase,
+    /// `"Buy"` / `"Sell"`.
+    Capitalised,
+    /// `"b"` / `"s"`.
+    Initial,
+    /// Sign of the order amount, positive for buy and negative for sell.
+    Signed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SideValue {
+    Text(&'static str),
+    Signed(i8),
+}
+
+impl SideEncoding {
+    fn for_exchange(exchange: ExchangeId) -> PyResult<Self> {
+        match exchange {
+            ExchangeId::BinanceFuturesCoin
+            | ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceOptions
+            | ExchangeId::BinancePortfolioMargin
+            | ExchangeId::BinanceSpot
+            | ExchangeId::BinanceUs => Ok(Self::Uppercase),
+            ExchangeId::BybitPerpetualsUsd | ExchangeId::BybitSpot | ExchangeId::Bitmex => {
+                Ok(Self::Capitalised)
+            }
+            ExchangeId::Coinbase | ExchangeId::Okx | ExchangeId::GateioSpot => Ok(Self::Lowercase),
+            ExchangeId::Kraken => Ok(Self::Initial),
+            ExchangeId::Bitfinex
+            | ExchangeId::GateioFuturesBtc
+            | ExchangeId::GateioFuturesUsd
+            | ExchangeId::GateioPerpetualsBtc
+            | ExchangeId::GateioPerpetualsUsd => Ok(Self::Signed),
+            other => Err(PyNotImplementedError::new_err(format!(
+                "no side convention defined for exchange {}",
+                other.as_str(),
+            ))),
+        }
+    }
+
+    fn names(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Lowercase => Some(("buy", "sell")),
+            Self::Uppercase => Some(("BUY", "SELL")),
+            Self::Capitalised => Some(("Buy", "Sell")),
+            Self::Initial => Some(("b", "s")),
+            Self::Signed => None,
+        }
+    }
+
+    fn encode(self, side: Side) -> SideValue {
+        match (self.names(), side) {
+            (Some((buy, _)), Side::Buy) => SideValue::Text(buy),
+            (Some((_, sell)), Side::Sell) => SideValue::Text(sell),
+            (None, Side::Buy) => SideValue::Signed(1),
+            (None, Side::Sell) => SideValue::Signed(-1),
+        }
+    }
+
+    /// Decode `value`, returning `None` if it is not a valid side for this convention.
+    fn decode(self, value: &Bound<'_, PyAny>) -> PyResult<Option<Side>> {
+        match self.names() {
+            Some((buy, sell)) => Ok(match value.extract::<&str>() {
+                Ok(text) if text == buy => Some(Side::Buy),
+                Ok(text) if text == sell => Some(Side::Sell),
+                _ => None,
+            }),
+            None => {
+                if value.extract::<&str>().is_ok() {
+                    return Ok(None);
+                }
+                let amount = value.extract::<f64>().map_err(|_| {
+                    PyValueError::new_err("side value must be a signed number for this exchange")
+                })?;
+                Ok(if amount > 0.0 {
+                    Some(Side::Buy)
+                } else if amount < 0.0 {
+                    Some(Side::Sell)
+                } else {
+                    None
+                })
+            }
+        }
+    }
+
+    fn expected(self) -> String {
+        match self.names() {
+            Some((buy, sell)) => format!("'{buy}' or '{sell}'"),
+            None => "a positive (buy) or negative (sell) amount".to_string(),
+        }
+    }
+}
+
 /// Wrapper around [`InstrumentIndex`] for Python exposure.
 #[pyclass(module = "barter_python", name = "InstrumentIndex", eq, hash, frozen)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -826,6 +965,57 @@ impl PyInstrumentSpec {
         }
     }
 
+    /// Snap `price` to a multiple of the price `tick_size`.
+    ///
+    /// `mode` is one of `"toward_zero"` (default), `"nearest"`, `"up"` or `"down"`.
+    #[pyo3(signature = (price, mode = "toward_zero"))]
+    pub fn round_price(
+        &self,
+        py: Python<'_>,
+        price: &Bound<'_, PyAny>,
+        mode: &str,
+    ) -> PyResult<PyObject> {
+        let price = parse_decimal(price, "price")?;
+        let rounded = snap_to_increment(
+            price,
+            self.inner.price.tick_size,
+            parse_spec_rounding(mode)?,
+        );
+        decimal_to_py(py, rounded)
+    }
+
+    /// Snap `quantity` to a multiple of the quantity `increment`.
+    ///
+    /// `mode` is one of `"toward_zero"` (default), `"nearest"`, `"up"` or `"down"`.
+    #[pyo3(signature = (quantity, mode = "toward_zero"))]
+    pub fn round_quantity(
+        &self,
+        py: Python<'_>,
+        quantity: &Bound<'_, PyAny>,
+        mode: &str,
+    ) -> PyResult<PyObject> {
+        let quantity = parse_decimal(quantity, "quantity")?;
+        let rounded = snap_to_increment(
+            quantity,
+            self.inner.quantity.increment,
+            parse_spec_rounding(mode)?,
+        );
+        decimal_to_py(py, rounded)
+    }
+
+    /// Return `True` if an order of `quantity` at `price` meets the minimum notional.
+    ///
+    /// Quantities denominated in the quote asset are already notional values.
+    pub fn min_notional_ok(
+        &self,
+        price: &Bound<'_, PyAny>,
+        quantity: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        let price = parse_decimal(price, "price")?;
+        let quantity = parse_decimal(quantity, "quantity")?;
+        Ok(order_notional(&self.inner, price, quantity) >= self.inner.notional.min)
+    }
+
     fn __repr__(&self) -> String {
         let price = PyInstrumentSpecPrice {
             inner: self.inner.price,
@@ -846,10 +1036,67 @@ impl PyInstrumentSpec {
     }
 }
 
+/// Parse an [`InstrumentSpec`] rounding `mode` into the equivalent [`RoundingStrategy`].
+fn parse_spec_rounding(mode: &str) -> PyResult<RoundingStrategy> {
+    match mode.to_ascii_lowercase().as_str() {
+        "toward_zero" => Ok(RoundingStrategy::ToZero),
+        "nearest" => Ok(RoundingStrategy::MidpointAwayFromZero),
+        "up" => Ok(RoundingStrategy::ToPositiveInfinity),
+        "down" => Ok(RoundingStrategy::ToNegativeInfinity),
+        other => Err(PyValueError::new_err(format!(
+            "mode must be one of 'toward_zero', 'nearest', 'up' or 'down', got '{other}'"
+        ))),
+    }
+}
+
+/// Snap `value` to a multiple of `increment` using the provided [`RoundingStrategy`].
+pub(crate) fn snap_to_increment(
+    value: Decimal,
+    increment: Decimal,
+    strategy: RoundingStrategy,
+) -> Decimal {
+    (value / increment).round_dp_with_strategy(0, strategy) * increment
+}
+
+/// Notional value of an order of `quantity` at `price`, respecting the [`OrderQuantityUnits`] of
+/// the [`InstrumentSpec`].
+pub(crate) fn order_notional<AssetKey>(
+    spec: &InstrumentSpec<AssetKey>,
+    price: Decimal,
+    quantity: Decimal,
+) -> Decimal {
+    match spec.quantity.unit {
+        OrderQuantityUnits::Quote => quantity,
+        _ => price * quantity,
+    }
+}
+
 fn index_error_to_py(error: IndexError) -> PyErr {
     PyValueError::new_err(error.to_string())
 }
 
+/// Maximum number of [`IndexedInstruments`] held by the [`INSTRUMENT_CACHE`].
+const INSTRUMENT_CACHE_CAPACITY: usize = 16;
+
+/// [`IndexedInstruments`] previously built by [`PyIndexedInstruments`], keyed on the content of
+/// the [`InstrumentConfig`]s they were built from.
+///
+/// Entries are ordered from least to most recently used, with the least recently used entry
+/// evicted once [`INSTRUMENT_CACHE_CAPACITY`] is exceeded.
+static INSTRUMENT_CACHE: LazyLock<Mutex<FnvIndexMap<Vec<InstrumentConfig>, IndexedInstruments>>> =
+    LazyLock::new(Mutex::default);
+
+/// Clear the cache of [`IndexedInstruments`] reused by `IndexedInstruments.from_definitions` and
+/// `IndexedInstruments.from_system_config`.
+#[pyfunction]
+pub fn clear_instrument_cache() -> PyResult<()> {
+    INSTRUMENT_CACHE
+        .lock()
+        .map_err(|_| PyValueError::new_err("instrument cache poisoned"))?
+        .clear();
+    Ok(())
+}
+
 #[pyclass(module = "barter_python", name = "IndexedInstruments", unsendable)]
 #[derive(Debug, Clone)]
 pub struct PyIndexedInstruments {
@@ -857,18 +1104,148 @@ pub struct PyIndexedInstruments {
 }
 
 impl PyIndexedInstruments {
-    fn from_configs(configs: Vec<InstrumentConfig>) -> Self {
-        Self {
-            inner: IndexedInstruments::new(configs),
+    /// Build [`IndexedInstruments`] from the provided configs, reusing a cached result if the
+    /// same configs were previously indexed.
+    fn from_configs(configs: Vec<InstrumentConfig>) -> PyResult<Self> {
+        let mut cache = INSTRUMENT_CACHE
+            .lock()
+            .map_err(|_| PyValueError::new_err("instrument cache poisoned"))?;
+
+        // Re-insert hits so the most recently used entry is always last
+        let inner = cache
+            .shift_remove(&configs)
+            .unwrap_or_else(|| IndexedInstruments::new(configs.iter().cloned()));
+        cache.insert(configs, inner.clone());
+
+        if cache.len() > INSTRUMENT_CACHE_CAPACITY {
+            cache.shift_remove_index(0);
         }
+
+        Ok(Self { inner })
     }
 }
 
+const INSTRUMENT_CSV_COLUMNS: [&str; 6] = [
+    "base",
+    "quote",
+    "kind",
+    "price_tick",
+    "quantity_step",
+    "min_notional",
+];
+
+/// Parse instrument CSV contents into [`InstrumentConfig`]s for the provided exchange.
+///
+/// The first non-blank line must be the header; errors reference 1-based file line numbers.
+fn instrument_configs_from_csv(
+    contents: &str,
+    exchange: ExchangeId,
+) -> Result<Vec<InstrumentConfig>, String> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let (header_line, header) = lines
+        .next()
+        .ok_or_else(|| "instrument CSV is empty".to_string())?;
+    let columns = header
+        .split(',')
+        .map(|column| column.trim().to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    if columns != INSTRUMENT_CSV_COLUMNS {
+        return Err(format!(
+            "line {header_line}: expected header '{}'",
+            INSTRUMENT_CSV_COLUMNS.join(",")
+        ));
+    }
+
+    lines
+        .map(|(line_number, line)| {
+            instrument_config_from_csv_row(line, exchange)
+                .map_err(|error| format!("line {line_number}: {error}"))
+        })
+        .collect()
+}
+
+fn instrument_config_from_csv_row(
+    line: &str,
+    exchange: ExchangeId,
+) -> Result<InstrumentConfig, String> {
+    let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+    let [base, quote, kind, price_tick, quantity_step, min_notional] = fields.as_slice() else {
+        return Err(format!(
+            "expected {} columns, found {}",
+            INSTRUMENT_CSV_COLUMNS.len(),
+            fields.len()
+        ));
+    };
+
+    if base.is_empty() || quote.is_empty() {
+        return Err("base and quote must not be empty".to_string());
+    }
+
+    let parse_positive = |label: &str, value: &str| {
+        Decimal::from_str(value)
+            .ok()
+            .filter(|decimal| *decimal > Decimal::ZERO)
+            .ok_or_else(|| format!("{label} must be a positive decimal, got '{value}'"))
+    };
+    let price_tick = parse_positive("price_tick", price_tick)?;
+    let quantity_step = parse_positive("quantity_step", quantity_step)?;
+    let min_notional = Decimal::from_str(min_notional)
+        .ok()
+        .filter(|decimal| !decimal.is_sign_negative())
+        .ok_or_else(|| {
+            format!("min_notional must be a non-negative decimal, got '{min_notional}'")
+        })?;
+
+    let base = base.to_ascii_lowercase();
+    let quote = quote.to_ascii_lowercase();
+
+    let (kind, quantity_unit) = match kind.to_ascii_lowercase().as_str() {
+        "spot" => (
+            InstrumentKind::Spot,
+            OrderQuantityUnits::Asset(AssetNameExchange::new(base.as_str())),
+        ),
+        "perpetual" => (
+            InstrumentKind::Perpetual(PerpetualContract {
+                contract_size: Decimal::ONE,
+                settlement_asset: AssetNameExchange::new(quote.as_str()),
+            }),
+            OrderQuantityUnits::Contract,
+        ),
+        other => {
+            return Err(format!(
+                "unsupported kind '{other}', expected 'spot' or 'perpetual'"
+            ));
+        }
+    };
+
+    Ok(InstrumentConfig {
+        exchange,
+        name_exchange: InstrumentNameExchange::new(format!(
+            "{}{}",
+            base.to_ascii_uppercase(),
+            quote.to_ascii_uppercase()
+        )),
+        underlying: Underlying::new(base.as_str(), quote.as_str()),
+        quote: InstrumentQuoteAsset::UnderlyingQuote,
+        kind,
+        spec: Some(InstrumentSpec::new(
+            InstrumentSpecPrice::new(Decimal::ZERO, price_tick),
+            InstrumentSpecQuantity::new(quantity_unit, Decimal::ZERO, quantity_step),
+            InstrumentSpecNotional::new(min_notional),
+        )),
+    })
+}
+
 #[pymethods]
 impl PyIndexedInstruments {
     #[classmethod]
     #[pyo3(signature = (config))]
-    pub fn from_system_config(_cls: &Bound<'_, PyType>, config: &PySystemConfig) -> Self {
+    pub fn from_system_config(_cls: &Bound<'_, PyType>, config: &PySystemConfig) -> PyResult<Self> {
         let mut config = config.clone_inner();
         Self::from_configs(std::mem::take(&mut config.instruments))
     }
@@ -882,7 +1259,25 @@ impl PyIndexedInstruments {
     ) -> PyResult<Self> {
         let definitions = definitions.bind(py);
         let configs = instrument_configs_from_py(py, &definitions)?;
-        Ok(Self::from_configs(configs))
+        Self::from_configs(configs)
+    }
+
+    /// Build indexed instruments for `exchange` from a CSV file with the columns
+    /// `base,quote,kind,price_tick,quantity_step,min_notional`.
+    #[classmethod]
+    #[pyo3(signature = (path, exchange))]
+    pub fn from_csv(
+        _cls: &Bound<'_, PyType>,
+        path: &str,
+        exchange: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        let exchange = coerce_exchange_id(exchange)?;
+        let contents = std::fs::read_to_string(path).map_err(|error| {
+            PyValueError::new_err(format!("failed to read instrument CSV {path}: {error}"))
+        })?;
+        let configs = instrument_configs_from_csv(&contents, exchange)
+            .map_err(|error| PyValueError::new_err(format!("invalid instrument CSV: {error}")))?;
+        Self::from_configs(configs)
     }
 
     pub fn __len__(&self) -> usize {
@@ -965,6 +1360,28 @@ impl PyIndexedInstruments {
             })
     }
 
+    /// Resolve the [`InstrumentIndex`] of the instrument with the provided internal name
+    /// (eg/ "binance_spot-BTCUSDT").
+    #[pyo3(signature = (name))]
+    pub fn find_index(&self, name: &str) -> PyResult<PyInstrumentIndex> {
+        let name_internal = InstrumentNameInternal::new(name);
+        self.inner
+            .instruments()
+            .iter()
+            .find_map(|keyed| (keyed.value.name_internal == name_internal).then_some(keyed.key))
+            .map(PyInstrumentIndex::from_inner)
+            .ok_or_else(|| PyValueError::new_err(format!("instrument {name} not found")))
+    }
+
+    /// Return the internal name of the instrument at the provided [`InstrumentIndex`].
+    #[pyo3(signature = (index))]
+    pub fn name_for_index(&self, index: &PyInstrumentIndex) -> PyResult<String> {
+        self.inner
+            .find_instrument(index.inner())
+            .map(|instrument| instrument.name_internal.name().to_string())
+            .map_err(index_error_to_py)
+    }
+
     #[pyo3(signature = (index))]
     pub fn instrument(&self, py: Python<'_>, index: &PyInstrumentIndex) -> PyResult<PyObject> {
         let instrument = self
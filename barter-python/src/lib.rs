@@ -31,14 +31,20 @@ mod system;
 
 use account::{PyAccountEvent, PyAccountEventKind, PyAccountSnapshot, PyInstrumentAccountSnapshot};
 use analytics::{
-    calculate_calmar_ratio, calculate_max_drawdown, calculate_mean_drawdown,
-    calculate_profit_factor, calculate_rate_of_return, calculate_sharpe_ratio,
-    calculate_sortino_ratio, calculate_win_rate, generate_drawdown_series, welford_calculate_mean,
-    welford_calculate_population_variance, welford_calculate_recurrence_relation_m,
-    welford_calculate_sample_variance,
+    PyWelford, annualization_factor, calculate_calmar_ratio, calculate_conditional_var,
+    calculate_historical_var, calculate_max_drawdown, calculate_mean_drawdown,
+    calculate_pain_ratio, calculate_profit_factor, calculate_rate_of_return,
+    calculate_sharpe_from_equity, calculate_sharpe_ratio, calculate_sortino_from_equity,
+    calculate_sortino_ratio, calculate_sterling_ratio, calculate_ulcer_index, calculate_win_rate,
+    fill_stats_by_kind, generate_drawdown_series, merge_timeline, slippage_report,
+    welford_calculate_mean, welford_calculate_population_variance,
+    welford_calculate_recurrence_relation_m, welford_calculate_sample_variance,
 };
 use backtest::{PyBacktestArgsConstant, PyBacktestArgsDynamic, PyMarketDataInMemory};
-use books::{PyLevel, PyOrderBook, calculate_mid_price, calculate_volume_weighted_mid_price};
+use books::{
+    PyLevel, PyOrderBook, calculate_mid_price, calculate_volume_weighted_mid_price,
+    snapshot_to_updates, updates_to_snapshot,
+};
 
 use barter_data::books::Level;
 use classes::core::{PySequence, PyTimedF64, shutdown_event, timed_f64};
@@ -51,20 +57,23 @@ use command::{
     PyInstrumentFilter, PyOrderKey, PyOrderRequestCancel, PyOrderRequestOpen, PyOrderSnapshot,
     parse_decimal,
 };
-use config::{PyExecutionConfig, PyMockExecutionConfig, PySystemConfig};
+use config::{PyExecutionConfig, PyMockExecutionConfig, PySystemConfig, diff_universes};
 #[cfg(feature = "python-tests")]
-use data::_testing_dynamic_trades;
+use data::{_testing_dynamic_candles, _testing_dynamic_trades};
 use data::{
-    PyAsyncMarketStream, PyDynamicStreams, PyExchangeId, PyMarketStream, PySubKind, PySubscription,
-    PySubscriptionId, exchange_supports_instrument_kind, init_dynamic_streams,
+    PyAsyncMarketStream, PyCandle, PyDataKind, PyDynamicStreams, PyExchangeId, PyMarketStream,
+    PyReconnectPolicy, PySubKind, PySubscription, PySubscriptionId,
+    exchange_supports_instrument_kind, init_dynamic_streams, merge_market_streams,
+    subscriptions_for,
 };
-use error::{PySocketErrorInfo, SocketError as PySocketErrorExc};
+use error::{PySocketErrorInfo, SocketError as PySocketErrorExc, UndefinedMetricError};
 use execution::{
     PyActiveOrderState, PyAssetFees, PyCancelInFlightState, PyCancelledState, PyClientOrderId,
-    PyExecutionAssetBalance, PyExecutionBalance, PyExecutionInstrumentMap, PyInactiveOrderState,
-    PyMockExecutionClient, PyOpenState, PyOrderError, PyOrderEvent, PyOrderId, PyOrderKind,
-    PyOrderResponseCancel, PyOrderState, PyStrategyId, PyTimeInForce, PyTrade, PyTradeId,
-    asset_balance_new, balance_new,
+    PyClientOrderIdGenerator, PyExecutionAssetBalance, PyExecutionBalance,
+    PyExecutionInstrumentMap, PyInactiveOrderState, PyMockExecutionClient, PyOpenState,
+    PyOrderError, PyOrderEvent, PyOrderId, PyOrderKind, PyOrderResponseCancel, PyOrderState,
+    PyStrategyId, PyTimeInForce, PyTrade, PyTradeId, aggregate_trades, asset_balance_new,
+    balance_new,
 };
 use instrument::{
     PyAsset, PyAssetIndex, PyAssetNameExchange, PyAssetNameInternal, PyExchangeIndex,
@@ -74,11 +83,11 @@ use instrument::{
 };
 use integration::{PySnapUpdates, PySnapshot};
 use logging::{init_json_logging_py, init_tracing};
-use metric::{PyField, PyMetric, PyTag, PyValue};
+use metric::{PyField, PyMetric, PyMetricSink, PyTag, PyValue};
 use pyo3::{Bound, exceptions::PyValueError, prelude::*, types::PyModule};
 use risk::{
-    PyDefaultRiskManager, PyRiskApproved, PyRiskRefused, calculate_abs_percent_difference,
-    calculate_delta, calculate_quote_notional,
+    PyCallbackRiskManager, PyConsecutiveLossTracker, PyDefaultRiskManager, PyRiskApproved,
+    PyRiskRefused, calculate_abs_percent_difference, calculate_delta, calculate_quote_notional,
 };
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
@@ -87,10 +96,12 @@ use strategy::build_ioc_market_order_to_close_position;
 use summary::{
     PyAssetTearSheet, PyBacktestSummary, PyDrawdown, PyInstrumentTearSheet, PyMeanDrawdown,
     PyMetricWithInterval, PyMultiBacktestSummary, PyTradingSummary, PyTradingSummaryGenerator,
+    build_comparison_table, summarize_trades,
 };
 use system::{
     PyActionOutput, PyAuditContext, PyAuditEvent, PyAuditTick, PyAuditUpdates,
-    PyClosePositionsOutput, PyEngineOutput, PyPositionExit, PySendRequestsOutput, PySystemHandle,
+    PyClosePositionsOutput, PyEngineOutput, PyPaperReplay, PyPositionExit, PySendRequestsOutput,
+    PySystemHandle, position_exit_stats, reconstruct_positions, run_backtest_from_events,
     run_historic_backtest, run_historic_backtest_with_generator, start_system,
 };
 
@@ -177,6 +188,7 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Create execution submodule
     let execution = PyModule::new_bound(py, "execution")?;
     execution.add_class::<PyClientOrderId>()?;
+    execution.add_class::<PyClientOrderIdGenerator>()?;
     execution.add_class::<PyOrderId>()?;
     execution.add_class::<PyStrategyId>()?;
     execution.add_class::<PyTradeId>()?;
@@ -205,6 +217,7 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     execution.add_class::<PyAccountEventKind>()?;
     execution.add_class::<PyAccountEvent>()?;
     execution.add_class::<PyTimeInForce>()?;
+    execution.add_function(wrap_pyfunction!(aggregate_trades, &execution)?)?;
     m.add_submodule(&execution)?;
 
     m.add_class::<PySystemConfig>()?;
@@ -215,6 +228,7 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySocketErrorInfo>()?;
     m.add_class::<PySequence>()?;
     m.add_class::<PySystemHandle>()?;
+    m.add_class::<PyPaperReplay>()?;
     m.add_class::<PyInstrumentFilter>()?;
     m.add_class::<PyOrderRequestOpen>()?;
     m.add_class::<PyOrderRequestCancel>()?;
@@ -225,14 +239,18 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMetricWithInterval>()?;
     m.add_class::<PyDrawdown>()?;
     m.add_class::<PyMeanDrawdown>()?;
+    m.add_class::<PyWelford>()?;
 
     m.add_class::<PyExchangeId>()?;
     m.add_class::<PySubKind>()?;
+    m.add_class::<PyCandle>()?;
+    m.add_class::<PyDataKind>()?;
     m.add_class::<PySubscription>()?;
     m.add_class::<PySubscriptionId>()?;
     m.add_class::<PyDynamicStreams>()?;
     m.add_class::<PyMarketStream>()?;
     m.add_class::<PyAsyncMarketStream>()?;
+    m.add_class::<PyReconnectPolicy>()?;
     m.add_class::<PyAssetNameInternal>()?;
     m.add_class::<PyAssetNameExchange>()?;
     m.add_class::<PyInstrumentNameInternal>()?;
@@ -252,7 +270,10 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyRiskApproved>()?;
     m.add_class::<PyRiskRefused>()?;
     m.add_class::<PyDefaultRiskManager>()?;
+    m.add_class::<PyCallbackRiskManager>()?;
+    m.add_class::<PyConsecutiveLossTracker>()?;
     m.add_class::<PyMetric>()?;
+    m.add_class::<PyMetricSink>()?;
     m.add_class::<PyTag>()?;
     m.add_class::<PyBacktestArgsConstant>()?;
     m.add_class::<PyBacktestArgsDynamic>()?;
@@ -283,26 +304,53 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(timed_f64, m)?)?;
     m.add_function(wrap_pyfunction!(run_historic_backtest, m)?)?;
     m.add_function(wrap_pyfunction!(run_historic_backtest_with_generator, m)?)?;
+    m.add_function(wrap_pyfunction!(run_backtest_from_events, m)?)?;
     m.add_function(wrap_pyfunction!(backtest::backtest, m)?)?;
     m.add_function(wrap_pyfunction!(backtest::run_backtests, m)?)?;
+    m.add_function(wrap_pyfunction!(build_comparison_table, m)?)?;
+    m.add_function(wrap_pyfunction!(summarize_trades, m)?)?;
     m.add_function(wrap_pyfunction!(start_system, m)?)?;
+    m.add_function(wrap_pyfunction!(position_exit_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(reconstruct_positions, m)?)?;
     m.add_function(wrap_pyfunction!(init_dynamic_streams, m)?)?;
     m.add_function(wrap_pyfunction!(exchange_supports_instrument_kind, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_market_streams, m)?)?;
+    m.add_function(wrap_pyfunction!(subscriptions_for, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_universes, m)?)?;
+    m.add_function(wrap_pyfunction!(common::set_timestamp_format, m)?)?;
+    m.add_function(wrap_pyfunction!(common::timestamp_format, m)?)?;
+    m.add_function(wrap_pyfunction!(common::decimals_to_floats, m)?)?;
+    m.add_function(wrap_pyfunction!(common::decimals_to_strings, m)?)?;
+    m.add_function(wrap_pyfunction!(instrument::clear_instrument_cache, m)?)?;
     #[cfg(feature = "python-tests")]
     m.add_function(wrap_pyfunction!(_testing_dynamic_trades, m)?)?;
     #[cfg(feature = "python-tests")]
+    m.add_function(wrap_pyfunction!(_testing_dynamic_candles, m)?)?;
+    #[cfg(feature = "python-tests")]
     m.add_function(wrap_pyfunction!(error::_testing_raise_socket_error, m)?)?;
     m.add_function(wrap_pyfunction!(balance_new, m)?)?;
     m.add_function(wrap_pyfunction!(asset_balance_new, m)?)?;
+    m.add_function(wrap_pyfunction!(aggregate_trades, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_sharpe_ratio, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_sortino_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_sharpe_from_equity, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_sortino_from_equity, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_calmar_ratio, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_profit_factor, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_win_rate, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_rate_of_return, m)?)?;
+    m.add_function(wrap_pyfunction!(annualization_factor, m)?)?;
     m.add_function(wrap_pyfunction!(generate_drawdown_series, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_max_drawdown, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_mean_drawdown, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_ulcer_index, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_pain_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_sterling_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_historical_var, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_conditional_var, m)?)?;
+    m.add_function(wrap_pyfunction!(slippage_report, m)?)?;
+    m.add_function(wrap_pyfunction!(fill_stats_by_kind, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_timeline, m)?)?;
     m.add_function(wrap_pyfunction!(welford_calculate_mean, m)?)?;
     m.add_function(wrap_pyfunction!(
         welford_calculate_recurrence_relation_m,
@@ -315,6 +363,8 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(calculate_delta, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_mid_price, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_volume_weighted_mid_price, m)?)?;
+    m.add_function(wrap_pyfunction!(snapshot_to_updates, m)?)?;
+    m.add_function(wrap_pyfunction!(updates_to_snapshot, m)?)?;
     m.add_function(wrap_pyfunction!(
         build_ioc_market_order_to_close_position,
         m
@@ -326,6 +376,8 @@ pub fn barter_python(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     let socket_error_type = py.get_type_bound::<PySocketErrorExc>();
     m.add("SocketError", socket_error_type)?;
+    let undefined_metric_type = py.get_type_bound::<UndefinedMetricError>();
+    m.add("UndefinedMetricError", undefined_metric_type)?;
 
     Ok(())
 }
@@ -422,6 +474,7 @@ mod tests {
             "buy",
             Some(time_exchange),
             Some(time_received),
+            0,
         )
         .unwrap();
 
@@ -459,6 +512,7 @@ mod tests {
             "sell",
             Some(time_exchange),
             None,
+            0,
         )
         .unwrap();
 
@@ -483,6 +537,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn engine_event_market_trade_applies_index_offset() {
+        let event =
+            PyEngineEvent::market_trade("mock", 3, "trade-1", 1.0, 1.0, "buy", None, None, 1)
+                .unwrap();
+
+        match event.inner {
+            EngineEvent::Market(MarketStreamEvent::Item(item)) => {
+                assert_eq!(item.instrument, InstrumentIndex(2));
+            }
+            other => panic!("unexpected event variant: {other:?}"),
+        }
+
+        assert!(
+            PyEngineEvent::market_trade("mock", 0, "trade-2", 1.0, 1.0, "buy", None, None, 1)
+                .is_err()
+        );
+    }
+
     #[test]
     fn engine_event_market_order_book_l1_constructor() {
         let last_update = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
@@ -497,6 +570,7 @@ mod tests {
             Some(1.5),
             Some(time_exchange),
             None,
+            0,
         )
         .unwrap();
 
@@ -540,6 +614,8 @@ mod tests {
             250.5,
             Some(time_exchange),
             None,
+            42,
+            0,
         )
         .unwrap();
 
@@ -558,7 +634,7 @@ mod tests {
                         assert_eq!(candle.low, 95.0);
                         assert_eq!(candle.close, 105.0);
                         assert_eq!(candle.volume, 250.5);
-                        assert_eq!(candle.trade_count, 0);
+                        assert_eq!(candle.trade_count, 42);
                     }
                     other => panic!("unexpected market data kind: {other:?}"),
                 }
@@ -579,6 +655,7 @@ mod tests {
             "sell",
             Some(time_exchange),
             None,
+            0,
         )
         .unwrap();
 
@@ -617,6 +694,7 @@ mod tests {
             vec![(101.0, 1.0), (101.5, 0.5)],
             Some(time_exchange),
             None,
+            0,
         )
         .unwrap();
 
@@ -665,6 +743,7 @@ mod tests {
             vec![(101.0, 1.0), (101.5, 0.5)],
             Some(time_exchange),
             None,
+            0,
         )
         .unwrap();
 
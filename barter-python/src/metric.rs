@@ -1,10 +1,14 @@
 #![allow(clippy::too_many_arguments)]
 
 use barter_integration::metric::Value;
-use pyo3::{prelude::*, pyclass::CompareOp};
+use pyo3::{exceptions::PyValueError, prelude::*, pyclass::CompareOp};
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex, PoisonError},
+};
 
 /// Python wrapper for [`Metric`].
-#[pyclass(module = "barter_python", name = "Metric", unsendable)]
+#[pyclass(module = "barter_python", name = "Metric")]
 #[derive(Debug, Clone)]
 pub struct PyMetric {
     name: String,
@@ -51,6 +55,40 @@ impl PyMetric {
         self.fields.clone()
     }
 
+    /// Render the metric in InfluxDB line protocol.
+    ///
+    /// `time` is rendered unchanged as the timestamp, so write with the matching precision.
+    pub fn to_line_protocol(&self) -> PyResult<String> {
+        if self.fields.is_empty() {
+            return Err(PyValueError::new_err(
+                "line protocol requires at least one field",
+            ));
+        }
+
+        let mut line = escape_line_protocol(&self.name, &[',', ' ']);
+        for tag in &self.tags {
+            line.push(',');
+            line.push_str(&escape_line_protocol(&tag.key, &[',', '=', ' ']));
+            line.push('=');
+            line.push_str(&escape_line_protocol(&tag.value, &[',', '=', ' ']));
+        }
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}={}",
+                    escape_line_protocol(&field.key, &[',', '=', ' ']),
+                    field.value.to_line_protocol()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(format!("{line} {fields} {}", self.time))
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!(
             "Metric(name='{}', time={}, tags=[...], fields=[...])",
@@ -59,8 +97,20 @@ impl PyMetric {
     }
 }
 
+/// Backslash escape every `special` character of a line protocol name, key or tag value.
+fn escape_line_protocol(value: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        if special.contains(&char) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
 /// Python wrapper for [`Tag`].
-#[pyclass(module = "barter_python", name = "Tag", unsendable)]
+#[pyclass(module = "barter_python", name = "Tag")]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PyTag {
     key: String,
@@ -104,7 +154,7 @@ impl PyTag {
 }
 
 /// Python wrapper for [`Field`].
-#[pyclass(module = "barter_python", name = "Field", unsendable)]
+#[pyclass(module = "barter_python", name = "Field")]
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct PyField {
     key: String,
@@ -148,7 +198,7 @@ impl PyField {
 }
 
 /// Python wrapper for [`Value`].
-#[pyclass(module = "barter_python", name = "Value", unsendable)]
+#[pyclass(module = "barter_python", name = "Value")]
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct PyValue {
     inner: Value,
@@ -271,6 +321,19 @@ impl PyValue {
         }
     }
 
+    /// Render the value as a line protocol field value.
+    pub fn to_line_protocol(&self) -> String {
+        match &self.inner {
+            Value::Float(value) => value.to_string(),
+            Value::Int(value) => format!("{value}i"),
+            Value::UInt(value) => format!("{value}u"),
+            Value::Bool(value) => value.to_string(),
+            Value::String(value) => {
+                format!("\"{}\"", escape_line_protocol(value, &['"', '\\']))
+            }
+        }
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{:?}", self.inner))
     }
@@ -286,3 +349,71 @@ impl PyValue {
         }
     }
 }
+
+/// Destination for emitted [`PyMetric`]s.
+pub trait MetricSink: Debug + Send + Sync {
+    /// Emit a [`PyMetric`] to the sink.
+    fn emit(&self, metric: PyMetric);
+
+    /// Drain every [`PyMetric`] emitted since the last flush.
+    fn flush(&self) -> Vec<PyMetric>;
+}
+
+/// [`MetricSink`] collecting emitted [`PyMetric`]s in memory until flushed.
+#[derive(Debug, Default)]
+pub struct InMemoryMetricSink {
+    metrics: Mutex<Vec<PyMetric>>,
+}
+
+impl MetricSink for InMemoryMetricSink {
+    fn emit(&self, metric: PyMetric) {
+        self.metrics
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(metric);
+    }
+
+    fn flush(&self) -> Vec<PyMetric> {
+        std::mem::take(&mut *self.metrics.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+/// Python wrapper for a [`MetricSink`], backed by an [`InMemoryMetricSink`].
+///
+/// Clones share the same underlying sink.
+#[pyclass(module = "barter_python", name = "MetricSink")]
+#[derive(Debug, Clone)]
+pub struct PyMetricSink {
+    inner: Arc<dyn MetricSink>,
+}
+
+impl PyMetricSink {
+    pub(crate) fn emit_metric(&self, metric: PyMetric) {
+        self.inner.emit(metric);
+    }
+}
+
+#[pymethods]
+impl PyMetricSink {
+    /// Create a [`MetricSink`] collecting metrics in memory.
+    #[new]
+    pub fn __new__() -> Self {
+        Self {
+            inner: Arc::new(InMemoryMetricSink::default()),
+        }
+    }
+
+    /// Emit a [`Metric`] to the sink.
+    pub fn emit(&self, metric: PyMetric) {
+        self.emit_metric(metric);
+    }
+
+    /// Return and clear every [`Metric`] emitted since the last flush.
+    pub fn flush(&self) -> Vec<PyMetric> {
+        self.inner.flush()
+    }
+
+    fn __repr__(&self) -> String {
+        "MetricSink()".to_string()
+    }
+}
@@ -1,17 +1,45 @@
 use crate::{
     command::{
         DefaultOrderRequestCancel, DefaultOrderRequestOpen, PyOrderRequestCancel,
-        PyOrderRequestOpen, parse_side,
+        PyOrderRequestOpen, parse_instrument_index, parse_side,
     },
     instrument::PySide,
     summary::decimal_to_py,
+    system::PyPositionExit,
 };
-use barter::risk::{DefaultRiskManager, RiskManager, RiskRefused as RustRiskRefused, check::util};
-use barter_instrument::Side;
+use barter::{
+    engine::{
+        clock::{EngineClock, LiveClock},
+        state::{EngineState, instrument::filter::InstrumentFilter},
+    },
+    risk::{
+        DefaultRiskManager, RiskApproved as RustRiskApproved, RiskManager,
+        RiskRefused as RustRiskRefused, check::util,
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
 use pyo3::{
-    Bound, PyAny, PyObject, PyResult, Python, exceptions::PyValueError, prelude::*, types::PyType,
+    Bound, PyAny, PyObject, PyResult, Python,
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBool, PyDict, PyType},
 };
 use rust_decimal::Decimal;
+use std::{
+    collections::{HashMap, VecDeque},
+    marker::PhantomData,
+    sync::Mutex,
+};
+use tracing::warn;
+
+#[allow(clippy::type_complexity)]
+type CheckOutput = (
+    Vec<PyRiskApproved>,
+    Vec<PyRiskApproved>,
+    Vec<PyRiskRefused>,
+    Vec<PyRiskRefused>,
+);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum RequestVariant {
@@ -168,69 +196,521 @@ impl PyRiskRefused {
 #[pyclass(module = "barter_python", name = "DefaultRiskManager", unsendable)]
 pub struct PyDefaultRiskManager {
     inner: DefaultRiskManager<PyObject>,
+    max_order_notional: Option<Decimal>,
+    max_order_quantity: Option<Decimal>,
+    max_position_quantity: HashMap<InstrumentIndex, Decimal>,
+    max_open_positions: Option<usize>,
+}
+
+impl PyDefaultRiskManager {
+    /// Return the reason an open order request breaches the configured limits, if any.
+    fn limit_breach(&self, request: &DefaultOrderRequestOpen) -> Option<String> {
+        let quantity = request.state.quantity.abs();
+
+        if let Some(max_quantity) = self.max_order_quantity
+            && quantity > max_quantity
+        {
+            return Some(format!(
+                "order quantity {quantity} exceeds max_order_quantity {max_quantity}"
+            ));
+        }
+
+        let max_notional = self.max_order_notional?;
+        let notional = util::calculate_quote_notional(quantity, request.state.price, Decimal::ONE);
+
+        match notional {
+            Some(notional) if notional > max_notional => Some(format!(
+                "order notional {notional} exceeds max_order_notional {max_notional}"
+            )),
+            Some(_) => None,
+            None => Some("order notional could not be calculated".to_string()),
+        }
+    }
 }
 
 #[pymethods]
 impl PyDefaultRiskManager {
     #[new]
-    pub fn __new__() -> Self {
-        Self {
+    #[pyo3(signature = (
+        max_order_notional = None,
+        max_order_quantity = None,
+        max_position_quantity = None,
+        max_open_positions = None
+    ))]
+    pub fn __new__(
+        max_order_notional: Option<&Bound<'_, PyAny>>,
+        max_order_quantity: Option<&Bound<'_, PyAny>>,
+        max_position_quantity: Option<&Bound<'_, PyDict>>,
+        max_open_positions: Option<usize>,
+    ) -> PyResult<Self> {
+        let max_order_notional = max_order_notional
+            .map(|value| positive_decimal_from_py(value, "max_order_notional"))
+            .transpose()?;
+        let max_order_quantity = max_order_quantity
+            .map(|value| positive_decimal_from_py(value, "max_order_quantity"))
+            .transpose()?;
+        let max_position_quantity = max_position_quantity
+            .map(parse_max_position_quantity)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
             inner: DefaultRiskManager::default(),
+            max_order_notional,
+            max_order_quantity,
+            max_position_quantity,
+            max_open_positions: max_open_positions
+                .map(validate_max_open_positions)
+                .transpose()?,
+        })
+    }
+
+    #[getter]
+    pub fn max_order_notional(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.max_order_notional
+            .map(|value| decimal_to_py(py, value))
+            .transpose()
+    }
+
+    #[getter]
+    pub fn max_order_quantity(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        self.max_order_quantity
+            .map(|value| decimal_to_py(py, value))
+            .transpose()
+    }
+
+    #[getter]
+    pub fn max_position_quantity<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let limits = PyDict::new_bound(py);
+        for (instrument, max_quantity) in &self.max_position_quantity {
+            limits.set_item(instrument.index(), decimal_to_py(py, *max_quantity)?)?;
         }
+        Ok(limits)
+    }
+
+    #[getter]
+    pub fn max_open_positions(&self) -> Option<usize> {
+        self.max_open_positions
     }
 
-    #[allow(clippy::type_complexity)]
+    /// Check order requests against the configured limits.
+    ///
+    /// When `max_position_quantity` or `max_open_positions` is configured, `state` must be `None`
+    /// (flat) or a mapping of instrument index to current signed net position quantity. Approved
+    /// open requests are accumulated into the net positions used to evaluate subsequent requests.
     #[pyo3(signature = (state, cancels, opens))]
     pub fn check(
         &self,
-        _py: Python<'_>,
+        py: Python<'_>,
         state: PyObject,
         cancels: &Bound<'_, PyAny>,
         opens: &Bound<'_, PyAny>,
-    ) -> PyResult<(
-        Vec<PyRiskApproved>,
-        Vec<PyRiskApproved>,
-        Vec<PyRiskRefused>,
-        Vec<PyRiskRefused>,
-    )> {
+    ) -> PyResult<CheckOutput> {
         let cancel_requests = collect_cancel_requests(cancels)?;
         let open_requests = collect_open_requests(opens)?;
 
         let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
-            self.inner
-                .check(&state, cancel_requests.clone(), open_requests.clone());
+            self.inner.check(&state, cancel_requests, open_requests);
 
-        let approved_cancels = approved_cancels
-            .into_iter()
-            .map(|approved| {
-                PyRiskApproved::from_variant(RequestVariant::Cancel(approved.into_item()))
-            })
-            .collect();
+        let mut positions =
+            if self.max_position_quantity.is_empty() && self.max_open_positions.is_none() {
+                HashMap::new()
+            } else {
+                positions_from_py(state.bind(py))?
+            };
 
-        let approved_opens = approved_opens
-            .into_iter()
-            .map(|approved| {
-                PyRiskApproved::from_variant(RequestVariant::Open(approved.into_item()))
-            })
-            .collect();
+        let mut limit_approved_opens = Vec::new();
+        let mut limit_refused_opens = Vec::new();
+        for approved in approved_opens {
+            let request = approved.into_item();
+            let breach = self
+                .limit_breach(&request)
+                .or_else(|| {
+                    position_quantity_breach(&request, &positions, &self.max_position_quantity)
+                })
+                .or_else(|| {
+                    self.max_open_positions
+                        .and_then(|max| open_positions_breach(&request, &positions, max))
+                });
 
-        let refused_cancels = refused_cancels
-            .into_iter()
-            .map(|refused: RustRiskRefused<DefaultOrderRequestCancel>| {
-                let RustRiskRefused { item, reason } = refused;
-                PyRiskRefused::from_parts(RequestVariant::Cancel(item), reason)
-            })
-            .collect();
+            match breach {
+                None => {
+                    *positions.entry(request.key.instrument).or_default() +=
+                        signed_quantity(&request);
+                    limit_approved_opens.push(RustRiskApproved::new(request));
+                }
+                Some(reason) => limit_refused_opens.push(RustRiskRefused::new(request, reason)),
+            }
+        }
 
-        let refused_opens = refused_opens
-            .into_iter()
-            .map(|refused: RustRiskRefused<DefaultOrderRequestOpen>| {
-                let RustRiskRefused { item, reason } = refused;
-                PyRiskRefused::from_parts(RequestVariant::Open(item), reason)
+        Ok(check_output_to_py(
+            approved_cancels,
+            limit_approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(limit_refused_opens),
+        ))
+    }
+}
+
+/// Risk manager state exposing the positions of each instrument.
+pub trait PositionState {
+    /// Current signed net position quantity of each instrument with an open position.
+    fn net_positions(&self) -> HashMap<InstrumentIndex, Decimal>;
+
+    /// Time the most recent position in the instrument was exited, if any.
+    fn time_last_exit(&self, instrument: InstrumentIndex) -> Option<DateTime<Utc>>;
+}
+
+impl<GlobalData, InstrumentData> PositionState for EngineState<GlobalData, InstrumentData> {
+    fn net_positions(&self) -> HashMap<InstrumentIndex, Decimal> {
+        self.instruments
+            .instruments(&InstrumentFilter::None)
+            .filter_map(|state| {
+                let position = state.position.current.as_ref()?;
+                let quantity = match position.side {
+                    Side::Buy => position.quantity_abs,
+                    Side::Sell => -position.quantity_abs,
+                };
+                Some((state.key, quantity))
             })
-            .collect();
+            .collect()
+    }
 
-        Ok((
+    fn time_last_exit(&self, instrument: InstrumentIndex) -> Option<DateTime<Utc>> {
+        // The instrument TearSheetGenerator clock only advances on position exits
+        let tear_sheet = &self.instruments.instrument_index(&instrument).tear_sheet;
+        (!tear_sheet.pnl_returns.total.count.is_zero()).then_some(tear_sheet.time_engine_now)
+    }
+}
+
+impl PositionState for PyObject {
+    fn net_positions(&self) -> HashMap<InstrumentIndex, Decimal> {
+        Python::with_gil(|py| positions_from_py(self.bind(py))).unwrap_or_else(|error| {
+            warn!(%error, "risk state is not a mapping of net positions, treating as flat");
+            HashMap::new()
+        })
+    }
+
+    fn time_last_exit(&self, _: InstrumentIndex) -> Option<DateTime<Utc>> {
+        None
+    }
+}
+
+/// [`RiskManager`] delegating checks of open order requests to an optional Python callable.
+///
+/// The callable receives each open order request as a dict and returns `True` to approve it,
+/// `False` to refuse it, or a `str` refusal reason. Cancel requests are always approved, as are
+/// all requests when no callable is configured. If the callable raises or returns anything else,
+/// the request is approved and the error is logged.
+///
+/// If `max_open_positions` is configured, open requests that would open a position while that
+/// many positions are already open are refused before the callable is consulted, as are open
+/// requests that would take the net position of an instrument beyond its `max_position_quantity`
+/// (orders reducing the absolute net position are always permitted). Likewise, open
+/// requests for an instrument within its `exit_cooldowns` duration of a position exit, as measured
+/// by the engine `Clock`, are refused.
+///
+/// If an [`OrderRateLimiter`] is configured, approved open requests exceeding its rate for their
+/// exchange are refused with an [`ORDER_RATE_LIMIT_REASON`] reason.
+#[derive(Debug)]
+pub struct CallbackRiskManager<State, Clock = LiveClock> {
+    callback: Option<PyObject>,
+    max_open_positions: Option<usize>,
+    max_position_quantity: HashMap<InstrumentIndex, Decimal>,
+    exit_cooldowns: HashMap<InstrumentIndex, TimeDelta>,
+    order_rate_limiter: Option<OrderRateLimiter>,
+    clock: Clock,
+    phantom: PhantomData<State>,
+}
+
+impl<State> CallbackRiskManager<State> {
+    pub(crate) fn new(callback: Option<PyObject>) -> Self {
+        Self {
+            callback,
+            max_open_positions: None,
+            max_position_quantity: HashMap::new(),
+            exit_cooldowns: HashMap::new(),
+            order_rate_limiter: None,
+            clock: LiveClock,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<State, Clock> CallbackRiskManager<State, Clock> {
+    pub(crate) fn with_max_open_positions(self, max_open_positions: Option<usize>) -> Self {
+        Self {
+            max_open_positions,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_max_position_quantity(
+        self,
+        max_position_quantity: HashMap<InstrumentIndex, Decimal>,
+    ) -> Self {
+        Self {
+            max_position_quantity,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_exit_cooldowns(
+        self,
+        exit_cooldowns: HashMap<InstrumentIndex, TimeDelta>,
+    ) -> Self {
+        Self {
+            exit_cooldowns,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_order_rate_limiter(
+        self,
+        order_rate_limiter: Option<OrderRateLimiter>,
+    ) -> Self {
+        Self {
+            order_rate_limiter,
+            ..self
+        }
+    }
+
+    /// Measure exit cooldowns & order rates with the provided `clock`, typically the engine's.
+    pub(crate) fn with_clock<NewClock>(
+        self,
+        clock: NewClock,
+    ) -> CallbackRiskManager<State, NewClock> {
+        CallbackRiskManager {
+            callback: self.callback,
+            max_open_positions: self.max_open_positions,
+            max_position_quantity: self.max_position_quantity,
+            exit_cooldowns: self.exit_cooldowns,
+            order_rate_limiter: self.order_rate_limiter,
+            clock,
+            phantom: PhantomData,
+        }
+    }
+
+    fn evaluate(
+        &self,
+        py: Python<'_>,
+        callback: &PyObject,
+        request: &DefaultOrderRequestOpen,
+    ) -> Option<String> {
+        let decision = open_request_to_dict(py, request)
+            .and_then(|dict| callback.call1(py, (dict,)))
+            .and_then(|decision| parse_callback_decision(decision.bind(py)));
+
+        decision.unwrap_or_else(|error| {
+            warn!(
+                %error,
+                ?request,
+                "risk callback failed, approving order request"
+            );
+            None
+        })
+    }
+}
+
+impl<State> Default for CallbackRiskManager<State> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<State, Clock> CallbackRiskManager<State, Clock>
+where
+    State: PositionState,
+    Clock: EngineClock,
+{
+    /// Return the reason an open order request is within the exit cooldown of its instrument,
+    /// if any.
+    fn cooldown_breach(&self, state: &State, request: &DefaultOrderRequestOpen) -> Option<String> {
+        let instrument = request.key.instrument;
+        let cooldown = *self.exit_cooldowns.get(&instrument)?;
+        let time_exit = state.time_last_exit(instrument)?;
+        let time_cooldown_end = time_exit + cooldown;
+
+        (self.clock.time() < time_cooldown_end).then(|| {
+            format!(
+                "instrument {} is in cooldown until {} following position exit at {}",
+                instrument.index(),
+                time_cooldown_end.to_rfc3339(),
+                time_exit.to_rfc3339(),
+            )
+        })
+    }
+}
+
+impl<State, Clock> RiskManager for CallbackRiskManager<State, Clock>
+where
+    State: PositionState,
+    Clock: EngineClock,
+{
+    type State = State;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = DefaultOrderRequestCancel>,
+        opens: impl IntoIterator<Item = DefaultOrderRequestOpen>,
+    ) -> (
+        impl IntoIterator<Item = RustRiskApproved<DefaultOrderRequestCancel>>,
+        impl IntoIterator<Item = RustRiskApproved<DefaultOrderRequestOpen>>,
+        impl IntoIterator<Item = RustRiskRefused<DefaultOrderRequestCancel>>,
+        impl IntoIterator<Item = RustRiskRefused<DefaultOrderRequestOpen>>,
+    ) {
+        let mut approved_opens = Vec::new();
+        let mut refused_opens = Vec::new();
+
+        let tracks_positions =
+            self.max_open_positions.is_some() || !self.max_position_quantity.is_empty();
+        let mut positions = if tracks_positions {
+            state.net_positions()
+        } else {
+            HashMap::new()
+        };
+
+        for request in opens {
+            let refusal = self
+                .cooldown_breach(state, &request)
+                .or_else(|| {
+                    self.max_open_positions
+                        .and_then(|max| open_positions_breach(&request, &positions, max))
+                })
+                .or_else(|| {
+                    position_quantity_breach(&request, &positions, &self.max_position_quantity)
+                })
+                .or_else(|| {
+                    let callback = self.callback.as_ref()?;
+                    Python::with_gil(|py| self.evaluate(py, callback, &request))
+                })
+                .or_else(|| {
+                    self.order_rate_limiter
+                        .as_ref()?
+                        .acquire(request.key.exchange, self.clock.time())
+                });
+
+            match refusal {
+                None => {
+                    if tracks_positions {
+                        *positions.entry(request.key.instrument).or_default() +=
+                            signed_quantity(&request);
+                    }
+                    approved_opens.push(RustRiskApproved::new(request));
+                }
+                Some(reason) => refused_opens.push(RustRiskRefused::new(request, reason)),
+            }
+        }
+
+        (
+            cancels.into_iter().map(RustRiskApproved::new),
+            approved_opens,
+            std::iter::empty(),
+            refused_opens,
+        )
+    }
+}
+
+/// Reason prefix of open order requests refused by an [`OrderRateLimiter`].
+pub const ORDER_RATE_LIMIT_REASON: &str = "order rate limit exceeded";
+
+/// Limits the open order requests approved per exchange to `max_orders_per_second` within any
+/// sliding one second window, as measured by the engine clock.
+#[derive(Debug)]
+pub struct OrderRateLimiter {
+    max_orders_per_second: usize,
+    sent: Mutex<HashMap<ExchangeIndex, VecDeque<DateTime<Utc>>>>,
+}
+
+impl OrderRateLimiter {
+    pub(crate) fn new(max_orders_per_second: usize) -> PyResult<Self> {
+        if max_orders_per_second == 0 {
+            return Err(PyValueError::new_err(
+                "max_orders_per_second must be positive",
+            ));
+        }
+
+        Ok(Self {
+            max_orders_per_second,
+            sent: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Record an order sent to `exchange` at `time`, or return the refusal reason if the
+    /// exchange has already reached its rate for the preceding second.
+    fn acquire(&self, exchange: ExchangeIndex, time: DateTime<Utc>) -> Option<String> {
+        let mut sent = self
+            .sent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let window = sent.entry(exchange).or_default();
+
+        let window_start = time - TimeDelta::seconds(1);
+        while window.front().is_some_and(|sent| *sent <= window_start) {
+            window.pop_front();
+        }
+
+        if window.len() >= self.max_orders_per_second {
+            return Some(format!(
+                "{ORDER_RATE_LIMIT_REASON}: exchange {} already sent {} orders in the last second",
+                exchange.index(),
+                window.len(),
+            ));
+        }
+
+        window.push_back(time);
+        None
+    }
+}
+
+/// Python facing [`CallbackRiskManager`], which may also be provided to `start_system`.
+#[pyclass(module = "barter_python", name = "CallbackRiskManager", unsendable)]
+pub struct PyCallbackRiskManager {
+    callback: PyObject,
+}
+
+impl PyCallbackRiskManager {
+    pub(crate) fn risk_manager<State>(&self, py: Python<'_>) -> CallbackRiskManager<State> {
+        CallbackRiskManager::new(Some(self.callback.clone_ref(py)))
+    }
+}
+
+#[pymethods]
+impl PyCallbackRiskManager {
+    #[new]
+    #[pyo3(signature = (callback))]
+    pub fn __new__(callback: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if !callback.is_callable() {
+            return Err(PyTypeError::new_err("callback must be callable"));
+        }
+
+        Ok(Self {
+            callback: callback.clone().unbind(),
+        })
+    }
+
+    #[getter]
+    pub fn callback(&self, py: Python<'_>) -> PyObject {
+        self.callback.clone_ref(py)
+    }
+
+    #[pyo3(signature = (state, cancels, opens))]
+    pub fn check(
+        &self,
+        py: Python<'_>,
+        state: PyObject,
+        cancels: &Bound<'_, PyAny>,
+        opens: &Bound<'_, PyAny>,
+    ) -> PyResult<CheckOutput> {
+        let cancel_requests = collect_cancel_requests(cancels)?;
+        let open_requests = collect_open_requests(opens)?;
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) = self
+            .risk_manager::<PyObject>(py)
+            .check(&state, cancel_requests, open_requests);
+
+        Ok(check_output_to_py(
             approved_cancels,
             approved_opens,
             refused_cancels,
@@ -239,6 +719,176 @@ impl PyDefaultRiskManager {
     }
 }
 
+/// Tracks streaks of consecutive losing position exits, for halting trading after a losing run.
+///
+/// Exits with negative realised PnL extend the current streak, while any other exit resets it.
+#[pyclass(module = "barter_python", name = "ConsecutiveLossTracker")]
+#[derive(Debug, Clone, Default)]
+pub struct PyConsecutiveLossTracker {
+    threshold: Option<usize>,
+    current_streak: usize,
+    max_streak: usize,
+}
+
+#[pymethods]
+impl PyConsecutiveLossTracker {
+    #[new]
+    #[pyo3(signature = (threshold = None))]
+    pub fn __new__(threshold: Option<usize>) -> PyResult<Self> {
+        Ok(Self {
+            threshold: threshold.map(validate_streak_threshold).transpose()?,
+            ..Self::default()
+        })
+    }
+
+    #[getter]
+    pub fn threshold(&self) -> Option<usize> {
+        self.threshold
+    }
+
+    #[getter]
+    pub fn current_streak(&self) -> usize {
+        self.current_streak
+    }
+
+    #[getter]
+    pub fn max_streak(&self) -> usize {
+        self.max_streak
+    }
+
+    /// Observe a `PositionExit` (or any object with a `pnl_realised`), returning the current
+    /// losing streak.
+    pub fn observe(&mut self, exit: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let pnl = match exit.extract::<PyRef<'_, PyPositionExit>>() {
+            Ok(exit) => exit.realised_pnl(),
+            Err(_) => decimal_from_py(&exit.getattr("pnl_realised")?, "pnl_realised")?,
+        };
+
+        if pnl < Decimal::ZERO {
+            self.current_streak += 1;
+            self.max_streak = self.max_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+        }
+
+        Ok(self.current_streak)
+    }
+
+    /// Whether the current losing streak has reached `threshold`, defaulting to the threshold
+    /// provided at construction.
+    #[pyo3(signature = (threshold = None))]
+    pub fn should_halt(&self, threshold: Option<usize>) -> PyResult<bool> {
+        let threshold = match threshold.or(self.threshold) {
+            Some(threshold) => validate_streak_threshold(threshold)?,
+            None => {
+                return Err(PyValueError::new_err(
+                    "threshold must be provided when the tracker has no default threshold",
+                ));
+            }
+        };
+
+        Ok(self.current_streak >= threshold)
+    }
+
+    /// Reset the current losing streak, eg/ after trading is re-enabled. The max streak is kept.
+    pub fn reset(&mut self) {
+        self.current_streak = 0;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ConsecutiveLossTracker(current_streak={}, max_streak={})",
+            self.current_streak, self.max_streak
+        )
+    }
+}
+
+pub(crate) fn validate_max_open_positions(max_open_positions: usize) -> PyResult<usize> {
+    if max_open_positions == 0 {
+        return Err(PyValueError::new_err("max_open_positions must be positive"));
+    }
+    Ok(max_open_positions)
+}
+
+fn validate_streak_threshold(threshold: usize) -> PyResult<usize> {
+    if threshold == 0 {
+        return Err(PyValueError::new_err("threshold must be positive"));
+    }
+    Ok(threshold)
+}
+
+fn check_output_to_py(
+    approved_cancels: impl IntoIterator<Item = RustRiskApproved<DefaultOrderRequestCancel>>,
+    approved_opens: impl IntoIterator<Item = RustRiskApproved<DefaultOrderRequestOpen>>,
+    refused_cancels: impl IntoIterator<Item = RustRiskRefused<DefaultOrderRequestCancel>>,
+    refused_opens: impl IntoIterator<Item = RustRiskRefused<DefaultOrderRequestOpen>>,
+) -> CheckOutput {
+    let approved_cancels = approved_cancels
+        .into_iter()
+        .map(|approved| PyRiskApproved::from_variant(RequestVariant::Cancel(approved.into_item())))
+        .collect();
+
+    let approved_opens = approved_opens
+        .into_iter()
+        .map(|approved| PyRiskApproved::from_variant(RequestVariant::Open(approved.into_item())))
+        .collect();
+
+    let refused_cancels = refused_cancels
+        .into_iter()
+        .map(|refused: RustRiskRefused<DefaultOrderRequestCancel>| {
+            let RustRiskRefused { item, reason } = refused;
+            PyRiskRefused::from_parts(RequestVariant::Cancel(item), reason)
+        })
+        .collect();
+
+    let refused_opens = refused_opens
+        .into_iter()
+        .map(|refused: RustRiskRefused<DefaultOrderRequestOpen>| {
+            let RustRiskRefused { item, reason } = refused;
+            PyRiskRefused::from_parts(RequestVariant::Open(item), reason)
+        })
+        .collect();
+
+    (
+        approved_cancels,
+        approved_opens,
+        refused_cancels,
+        refused_opens,
+    )
+}
+
+fn open_request_to_dict<'py>(
+    py: Python<'py>,
+    request: &DefaultOrderRequestOpen,
+) -> PyResult<Bound<'py, PyDict>> {
+    let wrapper = PyOrderRequestOpen::from_inner(request.clone());
+    let dict = PyDict::new_bound(py);
+    dict.set_item("exchange", request.key.exchange.index())?;
+    dict.set_item("instrument", request.key.instrument.index())?;
+    dict.set_item("strategy", request.key.strategy.to_string())?;
+    dict.set_item("cid", request.key.cid.to_string())?;
+    dict.set_item("side", wrapper.side())?;
+    dict.set_item("price", decimal_to_py(py, request.state.price)?)?;
+    dict.set_item("quantity", decimal_to_py(py, request.state.quantity)?)?;
+    dict.set_item("kind", wrapper.kind())?;
+    dict.set_item("time_in_force", wrapper.time_in_force())?;
+    Ok(dict)
+}
+
+fn parse_callback_decision(decision: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    if let Ok(approved) = decision.downcast::<PyBool>() {
+        return Ok((!approved.is_true()).then(|| "refused by risk callback".to_string()));
+    }
+
+    if let Ok(reason) = decision.extract::<String>() {
+        return Ok(Some(reason));
+    }
+
+    Err(PyTypeError::new_err(
+        "risk callback must return a bool or a refusal reason str",
+    ))
+}
+
 fn collect_cancel_requests(
     iterable: &Bound<'_, PyAny>,
 ) -> PyResult<Vec<DefaultOrderRequestCancel>> {
@@ -259,6 +909,23 @@ fn collect_cancel_requests(
     Ok(results)
 }
 
+/// Split an iterable of open and cancel order requests, in any order.
+pub(crate) fn collect_requests(
+    iterable: &Bound<'_, PyAny>,
+) -> PyResult<(Vec<DefaultOrderRequestCancel>, Vec<DefaultOrderRequestOpen>)> {
+    let mut cancels = Vec::new();
+    let mut opens = Vec::new();
+
+    for item in iterable.iter()? {
+        match RequestVariant::extract(&item?)? {
+            RequestVariant::Cancel(request) => cancels.push(request),
+            RequestVariant::Open(request) => opens.push(request),
+        }
+    }
+
+    Ok((cancels, opens))
+}
+
 fn collect_open_requests(iterable: &Bound<'_, PyAny>) -> PyResult<Vec<DefaultOrderRequestOpen>> {
     let mut results = Vec::new();
 
@@ -330,6 +997,14 @@ pub fn calculate_delta(
     decimal_to_py(py, result)
 }
 
+fn positive_decimal_from_py(value: &Bound<'_, PyAny>, field: &str) -> PyResult<Decimal> {
+    let value = decimal_from_py(value, field)?;
+    if value <= Decimal::ZERO {
+        return Err(PyValueError::new_err(format!("{field} must be positive")));
+    }
+    Ok(value)
+}
+
 fn decimal_from_py(value: &Bound<'_, PyAny>, field: &str) -> PyResult<Decimal> {
     let mut text: String = value.str()?.extract()?;
     if text.contains(['e', 'E']) {
@@ -343,6 +1018,108 @@ fn decimal_from_py(value: &Bound<'_, PyAny>, field: &str) -> PyResult<Decimal> {
     })
 }
 
+fn positions_from_py(state: &Bound<'_, PyAny>) -> PyResult<HashMap<InstrumentIndex, Decimal>> {
+    if state.is_none() {
+        return Ok(HashMap::new());
+    }
+
+    let items = state.call_method0("items").map_err(|_| {
+        PyTypeError::new_err(
+            "state must be None or a mapping of instrument to net position quantity",
+        )
+    })?;
+
+    items
+        .iter()?
+        .map(|item| {
+            let (instrument, quantity): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item?.extract()?;
+            Ok((
+                parse_instrument_index(&instrument)?,
+                decimal_from_py(&quantity, "position quantity")?,
+            ))
+        })
+        .collect()
+}
+
+/// Parse a mapping of instrument index to maximum absolute net position quantity.
+pub(crate) fn parse_max_position_quantity(
+    limits: &Bound<'_, PyDict>,
+) -> PyResult<HashMap<InstrumentIndex, Decimal>> {
+    limits
+        .iter()
+        .map(|(instrument, value)| {
+            Ok((
+                parse_instrument_index(&instrument)?,
+                positive_decimal_from_py(&value, "max_position_quantity")?,
+            ))
+        })
+        .collect()
+}
+
+/// Return the reason an open order request would push the net position of its instrument
+/// beyond its `max_position_quantity`, if any.
+///
+/// Orders that reduce the absolute net position are always permitted.
+fn position_quantity_breach(
+    request: &DefaultOrderRequestOpen,
+    positions: &HashMap<InstrumentIndex, Decimal>,
+    max_position_quantity: &HashMap<InstrumentIndex, Decimal>,
+) -> Option<String> {
+    let instrument = request.key.instrument;
+    let max_quantity = *max_position_quantity.get(&instrument)?;
+
+    let current = positions.get(&instrument).copied().unwrap_or_default();
+    let projected = current + signed_quantity(request);
+
+    if projected.abs() <= max_quantity || projected.abs() <= current.abs() {
+        return None;
+    }
+
+    Some(format!(
+        "instrument {} net position {projected} exceeds max_position_quantity {max_quantity} by {}",
+        instrument.index(),
+        projected.abs() - max_quantity,
+    ))
+}
+
+/// Return the reason an open order request would open a new position while `max_open_positions`
+/// positions are already open, if any.
+///
+/// Requests for an instrument with an existing position only increase, reduce or flip it, so are
+/// always permitted.
+fn open_positions_breach(
+    request: &DefaultOrderRequestOpen,
+    positions: &HashMap<InstrumentIndex, Decimal>,
+    max_open_positions: usize,
+) -> Option<String> {
+    let instrument = request.key.instrument;
+    if positions
+        .get(&instrument)
+        .is_some_and(|quantity| !quantity.is_zero())
+    {
+        return None;
+    }
+
+    let open = positions
+        .values()
+        .filter(|quantity| !quantity.is_zero())
+        .count();
+
+    (open >= max_open_positions).then(|| {
+        format!(
+            "instrument {} would open a position but {open} positions are already open, max_open_positions {max_open_positions}",
+            instrument.index()
+        )
+    })
+}
+
+fn signed_quantity(request: &DefaultOrderRequestOpen) -> Decimal {
+    match request.state.side {
+        Side::Buy => request.state.quantity.abs(),
+        Side::Sell => -request.state.quantity.abs(),
+    }
+}
+
 fn side_from_py(value: &Bound<'_, PyAny>) -> PyResult<Side> {
     if let Ok(handle) = value.extract::<Py<PySide>>() {
         let borrowed = handle.borrow(value.py());
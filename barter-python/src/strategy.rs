@@ -1,14 +1,368 @@
 use crate::{
-    command::{DefaultOrderRequestOpen, PyOrderRequestOpen, parse_decimal},
+    command::{
+        DefaultOrderRequestCancel, DefaultOrderRequestOpen, PyOrderRequestOpen, parse_decimal,
+    },
     execution::{PyStrategyId, coerce_client_order_id},
+    risk::collect_requests,
+    summary::decimal_to_py,
+};
+use barter::{
+    engine::{
+        Engine,
+        action::{
+            cancel_orders::CancelOrders,
+            close_positions::ClosePositions,
+            send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
+        },
+        execution_tx::ExecutionTxMap,
+        state::{
+            EngineState,
+            connectivity::Health,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+            trading::TradingState,
+        },
+    },
+    strategy::{
+        DefaultStrategy, algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
 };
 use barter_execution::order::{
     OrderKey, OrderKind, TimeInForce,
     id::ClientOrderId,
-    request::{OrderRequestOpen, RequestOpen},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
 };
-use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
-use pyo3::{PyObject, PyResult, Python, exceptions::PyValueError, prelude::*};
+use pyo3::{
+    PyObject, PyResult, Python,
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Actions performed by a [`SystemStrategy`] when an exchange execution (account) link
+/// disconnects, so the engine does not keep trading while blind to its account state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct AccountDisconnectPolicy {
+    pub disable_trading: bool,
+    pub cancel_orders: bool,
+}
+
+/// Behaviour of a [`SystemStrategy`] when an exchange market data link disconnects, so the
+/// engine does not trade on stale prices.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum MarketDisconnectPolicy {
+    /// Keep generating orders as normal.
+    #[default]
+    Continue,
+    /// Generate no algorithmic orders until every market data link has reconnected, or received
+    /// its first market event.
+    Pause,
+    /// Close all open positions on the disconnected exchange.
+    Flatten,
+}
+
+impl MarketDisconnectPolicy {
+    pub(crate) fn parse(value: &str) -> PyResult<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "continue" => Ok(Self::Continue),
+            "pause" => Ok(Self::Pause),
+            "flatten" => Ok(Self::Flatten),
+            other => Err(PyValueError::new_err(format!(
+                "on_market_disconnect must be one of 'continue', 'pause' or 'flatten', got '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Work done by a [`SystemStrategy`] after an exchange disconnection, forwarded to the audit
+/// stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub(crate) struct DisconnectPolicyOutput {
+    pub trading_disabled: bool,
+    pub cancels: Option<SendRequestsOutput<RequestCancel>>,
+    pub closes: Option<SendCancelsAndOpensOutput>,
+}
+
+/// [`DefaultStrategy`] extended with an optional Python algorithmic strategy and the configurable
+/// disconnection policies exposed by `start_system`.
+///
+/// The algorithmic orders of the Python `algo` callable are subject to the per-instrument
+/// [`TradingState`] overrides and to the [`MarketDisconnectPolicy::Pause`] policy.
+#[derive(Debug, Clone)]
+pub(crate) struct SystemStrategy<State> {
+    default: DefaultStrategy<State>,
+    algo: Option<Arc<PyObject>>,
+    on_account_disconnect: AccountDisconnectPolicy,
+    on_market_disconnect: MarketDisconnectPolicy,
+}
+
+impl<State> SystemStrategy<State> {
+    pub(crate) fn new(
+        on_account_disconnect: AccountDisconnectPolicy,
+        on_market_disconnect: MarketDisconnectPolicy,
+    ) -> Self {
+        Self {
+            default: DefaultStrategy::default(),
+            algo: None,
+            on_account_disconnect,
+            on_market_disconnect,
+        }
+    }
+
+    /// Generate algorithmic orders with the provided Python callable, which must be callable.
+    pub(crate) fn with_algo_strategy(self, algo: Option<PyObject>) -> PyResult<Self> {
+        if let Some(algo) = &algo {
+            let callable = Python::with_gil(|py| algo.bind(py).is_callable());
+            if !callable {
+                return Err(PyTypeError::new_err("algo_strategy must be callable"));
+            }
+        }
+
+        Ok(Self {
+            algo: algo.map(Arc::new),
+            ..self
+        })
+    }
+}
+
+/// Call the Python `algo` strategy with a list of instrument dicts (`exchange`, `instrument`,
+/// `price` & `trading_enabled`), returning the open and cancel order requests it generates.
+///
+/// If the callable raises or returns anything other than an iterable of order requests (or
+/// `None`), no orders are generated and the error is logged.
+fn evaluate_algo_strategy<GlobalData, InstrumentData>(
+    py: Python<'_>,
+    algo: &PyObject,
+    state: &EngineState<GlobalData, InstrumentData>,
+) -> (Vec<DefaultOrderRequestCancel>, Vec<DefaultOrderRequestOpen>)
+where
+    InstrumentData: InstrumentDataState,
+{
+    let requests = instruments_to_py(py, state)
+        .and_then(|instruments| algo.call1(py, (instruments,)))
+        .and_then(|requests| {
+            let requests = requests.bind(py);
+            if requests.is_none() {
+                Ok((Vec::new(), Vec::new()))
+            } else {
+                collect_requests(requests)
+            }
+        });
+
+    requests.unwrap_or_else(|error| {
+        warn!(%error, "algo strategy callback failed, generating no orders");
+        (Vec::new(), Vec::new())
+    })
+}
+
+fn instruments_to_py<'py, GlobalData, InstrumentData>(
+    py: Python<'py>,
+    state: &EngineState<GlobalData, InstrumentData>,
+) -> PyResult<Bound<'py, PyList>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    let instruments = PyList::empty_bound(py);
+    for instrument in state.instruments.0.values() {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("exchange", instrument.instrument.exchange.index())?;
+        dict.set_item("instrument", instrument.key.index())?;
+        dict.set_item(
+            "price",
+            instrument
+                .data
+                .price()
+                .map(|price| decimal_to_py(py, price))
+                .transpose()?,
+        )?;
+        dict.set_item(
+            "trading_enabled",
+            instrument.trading == TradingState::Enabled,
+        )?;
+        instruments.append(dict)?;
+    }
+    Ok(instruments)
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for SystemStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let paused = self.on_market_disconnect == MarketDisconnectPolicy::Pause
+            && state
+                .connectivity
+                .exchange_states()
+                .any(|connectivity| connectivity.market_data != Health::Healthy);
+
+        let (cancels, opens) = match &self.algo {
+            Some(algo) if !paused => Python::with_gil(|py| evaluate_algo_strategy(py, algo, state)),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        (
+            cancels
+                .into_iter()
+                .filter(move |cancel| instrument_trading_enabled(state, &cancel.key.instrument)),
+            opens
+                .into_iter()
+                .filter(move |open| instrument_trading_enabled(state, &open.key.instrument)),
+        )
+    }
+}
+
+/// Returns `true` if the instrument is tracked and algorithmic trading has not been disabled for
+/// it via a `Command::SetInstrumentTradingState` override.
+pub(crate) fn instrument_trading_enabled<GlobalData, InstrumentData>(
+    state: &EngineState<GlobalData, InstrumentData>,
+    instrument: &InstrumentIndex,
+) -> bool {
+    state
+        .instruments
+        .0
+        .get_index(instrument.index())
+        .is_some_and(|(_, state)| state.trading == TradingState::Enabled)
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for SystemStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        self.default.close_positions_requests(state, filter)
+    }
+}
+
+impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Risk>
+    OnDisconnectStrategy<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+    for SystemStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+    ExecutionTxs: ExecutionTxMap,
+{
+    type OnDisconnect = DisconnectPolicyOutput;
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+        DisconnectPolicyOutput::default()
+    }
+
+    fn on_account_disconnect(
+        engine: &mut Engine<
+            Clock,
+            EngineState<GlobalData, InstrumentData>,
+            ExecutionTxs,
+            Self,
+            Risk,
+        >,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        let policy = engine.strategy.on_account_disconnect;
+
+        let cancels = policy
+            .cancel_orders
+            .then(|| {
+                engine
+                    .state
+                    .connectivity
+                    .exchanges
+                    .get_index_of(&exchange)
+                    .map(|index| {
+                        engine.cancel_orders(&InstrumentFilter::exchanges([ExchangeIndex(index)]))
+                    })
+            })
+            .flatten();
+
+        let trading_disabled = policy.disable_trading
+            && engine
+                .state
+                .trading
+                .update(TradingState::Disabled)
+                .transitioned_to_disabled();
+
+        DisconnectPolicyOutput {
+            trading_disabled,
+            cancels,
+            closes: None,
+        }
+    }
+
+    fn on_market_disconnect(
+        engine: &mut Engine<
+            Clock,
+            EngineState<GlobalData, InstrumentData>,
+            ExecutionTxs,
+            Self,
+            Risk,
+        >,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        if engine.strategy.on_market_disconnect != MarketDisconnectPolicy::Flatten {
+            return DisconnectPolicyOutput::default();
+        }
+
+        let closes = engine
+            .state
+            .connectivity
+            .exchanges
+            .get_index_of(&exchange)
+            .map(|index| {
+                engine.close_positions(&InstrumentFilter::exchanges([ExchangeIndex(index)]))
+            });
+
+        DisconnectPolicyOutput {
+            trading_disabled: false,
+            cancels: None,
+            closes,
+        }
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for SystemStrategy<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
 
 fn ensure_positive(value: &rust_decimal::Decimal, field: &str) -> PyResult<()> {
     if value <= &rust_decimal::Decimal::ZERO {
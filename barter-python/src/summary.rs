@@ -1,31 +1,45 @@
+use barter::Timed;
 use barter::backtest::summary::{BacktestSummary, MultiBacktestSummary};
+use barter::engine::state::position::PositionManager;
 use barter::statistic::{
     metric::{
         calmar::CalmarRatio,
-        drawdown::{Drawdown, mean::MeanDrawdown},
+        drawdown::{Drawdown, max::MaxDrawdown, mean::MeanDrawdown},
+        profit_factor::ProfitFactor,
         rate_of_return::RateOfReturn,
         sharpe::SharpeRatio,
         sortino::SortinoRatio,
+        win_rate::WinRate,
     },
     summary::{
-        TradingSummary, TradingSummaryGenerator, asset::TearSheetAsset, instrument::TearSheet,
+        TradingSummary, TradingSummaryGenerator,
+        asset::{TearSheetAsset, TearSheetAssetGenerator},
+        instrument::{TearSheet, TearSheetGenerator},
     },
     time::{Annual252, Annual365, Daily, TimeInterval},
 };
-use barter_execution::balance::Balance;
+use barter_execution::balance::{AssetBalance, Balance};
+use barter_instrument::{
+    asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
+    exchange::ExchangeId,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
+};
 use barter_integration::snapshot::Snapshot;
-use chrono::{DateTime, Utc};
-use pyo3::exceptions::PyTypeError;
+use chrono::{DateTime, TimeDelta, Utc};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::{
     PyClass,
     prelude::*,
     types::{IntoPyDict, PyDict, PyModule},
 };
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, prelude::FromPrimitive};
 use std::fmt::Write;
 
 use crate::{
-    common::{SummaryInterval, parse_summary_interval},
+    analytics::extract_trade,
+    command::parse_decimal,
+    common::{SummaryInterval, parse_summary_interval, timestamp_to_py},
+    config::{PySystemConfig, parse_instrument_key},
     execution::PyExecutionAssetBalance,
     system::PyPositionExit,
 };
@@ -56,12 +70,102 @@ impl PyTradingSummaryGenerator {
         &mut self,
         py: Python<'_>,
         interval: Option<&str>,
+        min_samples: u64,
+        metrics: Option<Vec<String>>,
     ) -> PyResult<Py<PyTradingSummary>> {
         let summary_interval = parse_summary_interval(interval)?;
-        match summary_interval {
-            SummaryInterval::Daily => summary_to_py(py, self.inner.generate(Daily)),
-            SummaryInterval::Annual252 => summary_to_py(py, self.inner.generate(Annual252)),
-            SummaryInterval::Annual365 => summary_to_py(py, self.inner.generate(Annual365)),
+        let summary = match metrics.map(MetricSelection::parse).transpose()? {
+            Some(selection) => match summary_interval {
+                SummaryInterval::Daily => self.generate_selected(py, Daily, &selection),
+                SummaryInterval::Annual252 => self.generate_selected(py, Annual252, &selection),
+                SummaryInterval::Annual365 => self.generate_selected(py, Annual365, &selection),
+            },
+            None => match summary_interval {
+                SummaryInterval::Daily => summary_to_py(py, self.inner.generate(Daily)),
+                SummaryInterval::Annual252 => summary_to_py(py, self.inner.generate(Annual252)),
+                SummaryInterval::Annual365 => summary_to_py(py, self.inner.generate(Annual365)),
+            },
+        }?;
+
+        self.apply_min_samples(py, &summary, min_samples);
+        Ok(summary)
+    }
+
+    /// Generate a [`PyTradingSummary`] containing only the selected metrics.
+    ///
+    /// Unlike [`TradingSummaryGenerator::generate`] this leaves the generator untouched and never
+    /// clones the drawdown series or builds asset tear sheets unless they are requested.
+    fn generate_selected<Interval>(
+        &self,
+        py: Python<'_>,
+        interval: Interval,
+        selection: &MetricSelection,
+    ) -> PyResult<Py<PyTradingSummary>>
+    where
+        Interval: TimeInterval,
+    {
+        let instruments = self
+            .inner
+            .instruments
+            .iter()
+            .map(|(instrument, generator)| {
+                let sheet = PyInstrumentTearSheet::from_generator(
+                    py,
+                    generator,
+                    self.inner.risk_free_return,
+                    interval,
+                    selection,
+                )?;
+                Ok((instrument.to_string(), sheet))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let assets = if selection.contains("assets") {
+            self.inner
+                .assets
+                .iter()
+                .map(|(exchange_asset, generator)| {
+                    let key = format!(
+                        "{}:{}",
+                        exchange_asset.exchange.as_str(),
+                        exchange_asset.asset.as_ref()
+                    );
+                    let sheet =
+                        PyAssetTearSheet::from_tear_sheet(py, generator.clone().generate())?;
+                    Ok((key, sheet))
+                })
+                .collect::<PyResult<Vec<_>>>()?
+        } else {
+            Vec::new()
+        };
+
+        Py::new(
+            py,
+            PyTradingSummary {
+                time_engine_start: self.inner.time_engine_start,
+                time_engine_end: self.inner.time_engine_now,
+                instruments,
+                assets,
+            },
+        )
+    }
+
+    /// Clear the ratio metrics of every instrument with fewer than `min_samples` PnL returns.
+    fn apply_min_samples(&self, py: Python<'_>, summary: &Py<PyTradingSummary>, min_samples: u64) {
+        let min_samples = Decimal::from(min_samples);
+        let summary = summary.borrow(py);
+
+        for (name, sheet) in &summary.instruments {
+            let insufficient = self
+                .inner
+                .instruments
+                .iter()
+                .find(|(instrument, _)| instrument.to_string() == *name)
+                .is_some_and(|(_, generator)| generator.pnl_returns.total.count < min_samples);
+
+            if insufficient {
+                sheet.borrow_mut(py).clear_ratios();
+            }
         }
     }
 }
@@ -75,6 +179,10 @@ impl PyTradingSummaryGenerator {
         ))
     }
 
+    /// Metric names accepted by the `metrics` filter of [`Self::generate`].
+    #[classattr]
+    const METRICS: [&'static str; 12] = SUMMARY_METRICS;
+
     #[getter]
     pub fn risk_free_return(&self, py: Python<'_>) -> PyResult<PyObject> {
         decimal_to_py(py, self.inner.risk_free_return)
@@ -95,13 +203,24 @@ impl PyTradingSummaryGenerator {
         self.duration_to_py(py)
     }
 
-    #[pyo3(signature = (interval = None))]
+    /// Generate a [`TradingSummary`] for the provided interval.
+    ///
+    /// Instruments with fewer than `min_samples` PnL returns report `None` for their Sharpe,
+    /// Sortino and Calmar ratios rather than a value computed from too little data.
+    ///
+    /// Passing `metrics` computes only the named tear sheet metrics (see
+    /// `TradingSummaryGenerator.METRICS`); everything else is reported as `None` or empty, and
+    /// asset tear sheets are only built when `"assets"` is requested. `pnl` and `pnl_return`
+    /// are always present.
+    #[pyo3(signature = (interval = None, min_samples = 2, metrics = None))]
     pub fn generate(
         &mut self,
         py: Python<'_>,
         interval: Option<&str>,
+        min_samples: u64,
+        metrics: Option<Vec<String>>,
     ) -> PyResult<Py<PyTradingSummary>> {
-        self.generate_internal(py, interval)
+        self.generate_internal(py, interval, min_samples, metrics)
     }
 
     pub fn update_from_balance(&mut self, balance: &PyExecutionAssetBalance) -> PyResult<()> {
@@ -120,6 +239,41 @@ impl PyTradingSummaryGenerator {
         self.inner.update_time_now(time);
     }
 
+    /// Time-ordered cumulative PnL series used to compute the PnL drawdowns.
+    ///
+    /// With `instrument` set, returns that instrument's curve. Otherwise returns the combined
+    /// curve across all instruments, with one point per position exit.
+    ///
+    /// The curve is only recorded by back-tests. Generators taken from a live system, which does
+    /// not retain the history, return an empty curve.
+    #[pyo3(signature = (instrument = None))]
+    pub fn equity_curve(
+        &self,
+        py: Python<'_>,
+        instrument: Option<&str>,
+    ) -> PyResult<Vec<(DateTime<Utc>, PyObject)>> {
+        let curve = match instrument {
+            Some(name) => self
+                .inner
+                .instruments
+                .iter()
+                .find(|(instrument, _)| instrument.to_string() == name)
+                .map(|(_, generator)| generator.pnl_equity_curve.clone())
+                .ok_or_else(|| PyValueError::new_err(format!("unknown instrument '{name}'")))?,
+            None => combined_equity_curve(
+                self.inner
+                    .instruments
+                    .values()
+                    .map(|generator| generator.pnl_equity_curve.as_slice()),
+            ),
+        };
+
+        curve
+            .into_iter()
+            .map(|point| Ok((point.time, decimal_to_py(py, point.value)?)))
+            .collect()
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!(
             "TradingSummaryGenerator(risk_free_return={}, time_now={})",
@@ -128,6 +282,156 @@ impl PyTradingSummaryGenerator {
     }
 }
 
+/// Merge per-instrument cumulative PnL curves into a single time-ordered portfolio curve.
+fn combined_equity_curve<'a>(
+    curves: impl Iterator<Item = &'a [Timed<Decimal>]>,
+) -> Vec<Timed<Decimal>> {
+    let mut changes = curves
+        .flat_map(|curve| {
+            curve.iter().scan(Decimal::ZERO, |previous, point| {
+                let change = point.value - *previous;
+                *previous = point.value;
+                Some(Timed::new(change, point.time))
+            })
+        })
+        .collect::<Vec<_>>();
+    changes.sort_by_key(|change| change.time);
+
+    changes
+        .into_iter()
+        .scan(Decimal::ZERO, |total, change| {
+            *total += change.value;
+            Some(Timed::new(*total, change.time))
+        })
+        .collect()
+}
+
+/// Generate a [`PyTradingSummary`] from a blotter of `trades`, such as one from an external
+/// source rather than an engine run.
+///
+/// Trades are replayed in `time_exchange` order through a position per instrument, feeding each
+/// position exit into a [`TradingSummaryGenerator`]. Positions still open after the last trade
+/// are not included. Instruments are named `instrument_{index}` by their `InstrumentIndex`, and
+/// a single `other:quote` asset tracks `initial_balance` plus the realised PnL of every exit.
+#[pyfunction]
+#[pyo3(signature = (trades, initial_balance, risk_free_return = 0.0, interval = None))]
+pub fn summarize_trades(
+    py: Python<'_>,
+    trades: &Bound<'_, PyAny>,
+    initial_balance: f64,
+    risk_free_return: f64,
+    interval: Option<&str>,
+) -> PyResult<Py<PyTradingSummary>> {
+    let risk_free_return = parse_decimal(risk_free_return, "risk_free_return")?;
+    let initial_balance = parse_decimal(initial_balance, "initial_balance")?;
+    if initial_balance <= Decimal::ZERO {
+        return Err(PyValueError::new_err("initial_balance must be positive"));
+    }
+
+    let mut trades = trades
+        .iter()
+        .map_err(|_| PyValueError::new_err("trades must be an iterable of Trade instances"))?
+        .enumerate()
+        .map(|(index, trade)| extract_trade(&trade?, index))
+        .collect::<PyResult<Vec<_>>>()?;
+    trades.sort_by_key(|trade| trade.time_exchange);
+
+    let (Some(first), Some(last)) = (trades.first(), trades.last()) else {
+        return Err(PyValueError::new_err("trades must not be empty"));
+    };
+    let (start, end) = (first.time_exchange, last.time_exchange);
+
+    let instrument_count = trades
+        .iter()
+        .map(|trade| trade.instrument.index() + 1)
+        .max()
+        .unwrap_or_default();
+    let instruments = (0..instrument_count)
+        .map(|index| {
+            (
+                InstrumentNameInternal::new(format!("instrument_{index}")),
+                TearSheetGenerator::init(start).with_history(true),
+            )
+        })
+        .collect();
+
+    let mut balance = initial_balance;
+    let assets = std::iter::once((
+        ExchangeAsset::new(ExchangeId::Other, AssetNameInternal::new("quote")),
+        TearSheetAssetGenerator::init(&Timed::new(Balance::new(balance, balance), start))
+            .with_history(true),
+    ))
+    .collect();
+
+    let mut generator =
+        TradingSummaryGenerator::new(risk_free_return, start, start, instruments, assets);
+    let mut positions = vec![PositionManager::<InstrumentIndex>::default(); instrument_count];
+
+    for trade in &trades {
+        let Some(exited) = positions[trade.instrument.index()].update_from_trade(trade) else {
+            continue;
+        };
+
+        generator.update_from_position(&exited);
+
+        balance += exited.pnl_realised;
+        let asset_balance = AssetBalance::new(
+            AssetIndex(0),
+            Balance::new(balance, balance),
+            exited.time_exit,
+        );
+        generator.update_from_balance(Snapshot::new(&asset_balance));
+    }
+    generator.update_time_now(end);
+
+    PyTradingSummaryGenerator { inner: generator }.generate_internal(py, interval, 2, None)
+}
+
+/// Tear sheet metrics that may be requested via `TradingSummaryGenerator.generate(metrics=...)`.
+const SUMMARY_METRICS: [&str; 12] = [
+    "pnl",
+    "pnl_return",
+    "sharpe_ratio",
+    "sortino_ratio",
+    "calmar_ratio",
+    "pnl_drawdown",
+    "pnl_drawdown_mean",
+    "pnl_drawdown_max",
+    "pnl_drawdown_series",
+    "win_rate",
+    "profit_factor",
+    "assets",
+];
+
+/// Validated subset of [`SUMMARY_METRICS`] to compute when generating a summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MetricSelection(Vec<&'static str>);
+
+impl MetricSelection {
+    fn parse(metrics: Vec<String>) -> PyResult<Self> {
+        metrics
+            .iter()
+            .map(|metric| {
+                SUMMARY_METRICS
+                    .iter()
+                    .find(|known| **known == metric.as_str())
+                    .copied()
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!(
+                            "unknown summary metric '{metric}', expected one of: {}",
+                            SUMMARY_METRICS.join(", ")
+                        ))
+                    })
+            })
+            .collect::<PyResult<Vec<_>>>()
+            .map(Self)
+    }
+
+    fn contains(&self, metric: &str) -> bool {
+        self.0.contains(&metric)
+    }
+}
+
 pub fn summary_to_py<Interval>(
     py: Python<'_>,
     summary: TradingSummary<Interval>,
@@ -225,6 +529,85 @@ impl PyTradingSummary {
         }
         Ok(dict.into())
     }
+
+    fn fields(&self, py: Python<'_>) -> SummaryFields {
+        let mut fields = SummaryFields::default();
+        fields.label("time_engine_start", self.time_engine_start);
+        fields.label("time_engine_end", self.time_engine_end);
+
+        for (name, sheet) in &self.instruments {
+            let sheet = sheet.borrow(py);
+            let prefix = format!("instruments.{name}");
+            fields.metric(format!("{prefix}.pnl"), Some(sheet.pnl));
+            fields.metric_with_interval(py, format!("{prefix}.pnl_return"), &sheet.pnl_return);
+            fields.optional_metric_with_interval(
+                py,
+                format!("{prefix}.sharpe_ratio"),
+                sheet.sharpe_ratio.as_ref(),
+            );
+            fields.optional_metric_with_interval(
+                py,
+                format!("{prefix}.sortino_ratio"),
+                sheet.sortino_ratio.as_ref(),
+            );
+            fields.optional_metric_with_interval(
+                py,
+                format!("{prefix}.calmar_ratio"),
+                sheet.calmar_ratio.as_ref(),
+            );
+            fields.drawdown(
+                py,
+                format!("{prefix}.pnl_drawdown"),
+                sheet.pnl_drawdown.as_ref(),
+            );
+            fields.mean_drawdown(
+                py,
+                format!("{prefix}.pnl_drawdown_mean"),
+                sheet.pnl_drawdown_mean.as_ref(),
+            );
+            fields.drawdown(
+                py,
+                format!("{prefix}.pnl_drawdown_max"),
+                sheet.pnl_drawdown_max.as_ref(),
+            );
+            fields.drawdown_series(
+                py,
+                format!("{prefix}.pnl_drawdown_series"),
+                &sheet.pnl_drawdown_series,
+            );
+            fields.metric(format!("{prefix}.win_rate"), sheet.win_rate);
+            fields.metric(format!("{prefix}.profit_factor"), sheet.profit_factor);
+        }
+
+        for (name, sheet) in &self.assets {
+            let sheet = sheet.borrow(py);
+            let prefix = format!("assets.{name}");
+            fields.balance(
+                py,
+                format!("{prefix}.balance_end"),
+                sheet.balance_end.as_ref(),
+            );
+            fields.drawdown(py, format!("{prefix}.drawdown"), sheet.drawdown.as_ref());
+            fields.mean_drawdown(
+                py,
+                format!("{prefix}.drawdown_mean"),
+                sheet.drawdown_mean.as_ref(),
+            );
+            fields.drawdown(
+                py,
+                format!("{prefix}.drawdown_max"),
+                sheet.drawdown_max.as_ref(),
+            );
+            fields.drawdown_series(
+                py,
+                format!("{prefix}.drawdown_series"),
+                &sheet.drawdown_series,
+            );
+        }
+
+        fields.sort();
+        fields
+    }
 }
 
 #[pymethods]
@@ -249,10 +632,25 @@ impl PyTradingSummary {
         Ok(self.assets_dict(py)?.into_py(py))
     }
 
-    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+    /// Return the summary as a dictionary.
+    ///
+    /// When `sort_keys` is set the `instruments` & `assets` entries are ordered by name,
+    /// rather than by the order in which they were generated.
+    #[pyo3(signature = (sort_keys = false))]
+    pub fn to_dict(&self, py: Python<'_>, sort_keys: bool) -> PyResult<PyObject> {
+        if sort_keys {
+            return self.sorted(py).to_dict(py, false);
+        }
+
         let dict = PyDict::new_bound(py);
-        dict.set_item("time_engine_start", self.time_engine_start)?;
-        dict.set_item("time_engine_end", self.time_engine_end)?;
+        dict.set_item(
+            "time_engine_start",
+            timestamp_to_py(py, self.time_engine_start)?,
+        )?;
+        dict.set_item(
+            "time_engine_end",
+            timestamp_to_py(py, self.time_engine_end)?,
+        )?;
 
         let instruments = PyDict::new_bound(py);
         for (name, sheet) in &self.instruments {
@@ -269,6 +667,98 @@ impl PyTradingSummary {
         Ok(dict.into_py(py))
     }
 
+    /// Return a copy of the summary with `instruments` & `assets` ordered by name.
+    ///
+    /// Iteration and serialisation of the returned summary are deterministic across runs.
+    pub fn sorted(&self, py: Python<'_>) -> Self {
+        let mut instruments = self
+            .instruments
+            .iter()
+            .map(|(name, sheet)| (name.clone(), sheet.clone_ref(py)))
+            .collect::<Vec<_>>();
+        instruments.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut assets = self
+            .assets
+            .iter()
+            .map(|(name, sheet)| (name.clone(), sheet.clone_ref(py)))
+            .collect::<Vec<_>>();
+        assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self {
+            time_engine_start: self.time_engine_start,
+            time_engine_end: self.time_engine_end,
+            instruments,
+            assets,
+        }
+    }
+
+    /// Consolidate instrument results across the instrument aliases configured in `config`.
+    ///
+    /// Returns a dictionary keyed by alias name, or by instrument name for instruments without
+    /// an alias, containing the member `instruments`, their summed `pnl` and the largest
+    /// `pnl_drawdown_max` value of any member.
+    pub fn consolidated(&self, py: Python<'_>, config: &PySystemConfig) -> PyResult<PyObject> {
+        let mut groups: Vec<(String, Vec<String>, Decimal, Option<Decimal>)> = Vec::new();
+
+        for (name, sheet) in &self.instruments {
+            let sheet = sheet.borrow(py);
+            let group = config
+                .inner
+                .instrument_alias(&parse_instrument_key(name))
+                .unwrap_or(name)
+                .to_string();
+            let drawdown_max = sheet
+                .pnl_drawdown_max
+                .as_ref()
+                .map(|drawdown| drawdown.borrow(py).value);
+
+            match groups.iter_mut().find(|(existing, ..)| *existing == group) {
+                Some((_, members, pnl, max)) => {
+                    members.push(name.clone());
+                    *pnl += sheet.pnl;
+                    *max = (*max).max(drawdown_max);
+                }
+                None => groups.push((group, vec![name.clone()], sheet.pnl, drawdown_max)),
+            }
+        }
+
+        let dict = PyDict::new_bound(py);
+        for (group, members, pnl, drawdown_max) in groups {
+            let entry = PyDict::new_bound(py);
+            entry.set_item("instruments", members)?;
+            entry.set_item("pnl", decimal_to_py(py, pnl)?)?;
+            entry.set_item("pnl_drawdown_max", optional_decimal(py, drawdown_max)?)?;
+            dict.set_item(group, entry)?;
+        }
+
+        Ok(dict.into_py(py))
+    }
+
+    /// Compare two summaries, allowing each metric to differ by at most `tolerance`.
+    ///
+    /// Timestamps, intervals and the set of instruments & assets must still match exactly.
+    pub fn approx_eq(
+        &self,
+        py: Python<'_>,
+        other: PyRef<'_, Self>,
+        tolerance: f64,
+    ) -> PyResult<bool> {
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(PyValueError::new_err(
+                "tolerance must be a non-negative finite number",
+            ));
+        }
+        let tolerance = Decimal::from_f64(tolerance)
+            .ok_or_else(|| PyValueError::new_err("tolerance is out of range"))?;
+
+        Ok(self.fields(py).approx_eq(&other.fields(py), tolerance))
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: PyRef<'_, Self>) -> bool {
+        self.fields(py) == other.fields(py)
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         let mut repr = String::new();
         write!(
@@ -288,12 +778,13 @@ impl PyTradingSummary {
 pub struct PyInstrumentTearSheet {
     pnl: Decimal,
     pnl_return: Py<PyMetricWithInterval>,
-    sharpe_ratio: Py<PyMetricWithInterval>,
-    sortino_ratio: Py<PyMetricWithInterval>,
-    calmar_ratio: Py<PyMetricWithInterval>,
+    sharpe_ratio: Option<Py<PyMetricWithInterval>>,
+    sortino_ratio: Option<Py<PyMetricWithInterval>>,
+    calmar_ratio: Option<Py<PyMetricWithInterval>>,
     pnl_drawdown: Option<Py<PyDrawdown>>,
     pnl_drawdown_mean: Option<Py<PyMeanDrawdown>>,
     pnl_drawdown_max: Option<Py<PyDrawdown>>,
+    pnl_drawdown_series: Vec<Py<PyDrawdown>>,
     win_rate: Option<Decimal>,
     profit_factor: Option<Decimal>,
 }
@@ -315,6 +806,7 @@ impl PyInstrumentTearSheet {
             pnl_drawdown,
             pnl_drawdown_mean,
             pnl_drawdown_max,
+            pnl_drawdown_series,
             win_rate,
             profit_factor,
         } = sheet;
@@ -366,20 +858,170 @@ impl PyInstrumentTearSheet {
         let pnl_drawdown_max = pnl_drawdown_max
             .map(|max| PyDrawdown::from_drawdown(py, max.0))
             .transpose()?;
+        let pnl_drawdown_series = drawdown_series_to_py(py, pnl_drawdown_series)?;
 
         Py::new(
             py,
             PyInstrumentTearSheet {
                 pnl,
                 pnl_return,
+                sharpe_ratio: Some(sharpe_ratio),
+                sortino_ratio: Some(sortino_ratio),
+                calmar_ratio: Some(calmar_ratio),
+                pnl_drawdown,
+                pnl_drawdown_mean,
+                pnl_drawdown_max,
+                pnl_drawdown_series,
+                win_rate: win_rate.map(|rate| rate.value),
+                profit_factor: profit_factor.map(|factor| factor.value),
+            },
+        )
+    }
+
+    /// Build a tear sheet directly from a [`TearSheetGenerator`], computing only the selected
+    /// metrics. Mirrors [`TearSheetGenerator::generate`] without mutating the generator.
+    fn from_generator<Interval>(
+        py: Python<'_>,
+        generator: &TearSheetGenerator,
+        risk_free_return: Decimal,
+        interval: Interval,
+        selection: &MetricSelection,
+    ) -> PyResult<Py<PyInstrumentTearSheet>>
+    where
+        Interval: TimeInterval,
+    {
+        let trading_period = generator
+            .time_engine_now
+            .signed_duration_since(generator.time_engine_start)
+            .max(TimeDelta::seconds(1));
+        let returns = &generator.pnl_returns;
+        let metric = |value: Decimal, interval: &Interval| {
+            PyMetricWithInterval::from_components(py, value, interval_name(interval))
+        };
+
+        let pnl_return =
+            RateOfReturn::calculate(returns.total.mean, trading_period).scale(interval);
+        let pnl_return = metric(pnl_return.value, &pnl_return.interval)?;
+
+        let sharpe_ratio = selection
+            .contains("sharpe_ratio")
+            .then(|| {
+                let sharpe = SharpeRatio::calculate(
+                    risk_free_return,
+                    returns.total.mean,
+                    returns.total.dispersion.std_dev,
+                    trading_period,
+                )
+                .scale(interval);
+                metric(sharpe.value, &sharpe.interval)
+            })
+            .transpose()?;
+
+        let sortino_ratio = selection
+            .contains("sortino_ratio")
+            .then(|| {
+                let sortino = SortinoRatio::calculate(
+                    risk_free_return,
+                    returns.total.mean,
+                    returns.losses.dispersion.std_dev,
+                    trading_period,
+                )
+                .scale(interval);
+                metric(sortino.value, &sortino.interval)
+            })
+            .transpose()?;
+
+        let needs_drawdown = [
+            "calmar_ratio",
+            "pnl_drawdown",
+            "pnl_drawdown_mean",
+            "pnl_drawdown_max",
+            "pnl_drawdown_series",
+        ]
+        .into_iter()
+        .any(|metric| selection.contains(metric));
+
+        let (current_drawdown, drawdown_mean, drawdown_max) = if needs_drawdown {
+            let current = generator.pnl_drawdown.clone().generate();
+            let mut mean = generator.pnl_drawdown_mean.clone();
+            let mut max = generator.pnl_drawdown_max.clone();
+            if let Some(current) = &current {
+                mean.update(current);
+                max.update(current);
+            }
+            (current, mean.generate(), max.generate())
+        } else {
+            (None, None, None)
+        };
+
+        let calmar_ratio = selection
+            .contains("calmar_ratio")
+            .then(|| {
+                let calmar = CalmarRatio::calculate(
+                    risk_free_return,
+                    returns.total.mean,
+                    drawdown_max
+                        .as_ref()
+                        .map_or(Decimal::ZERO, |MaxDrawdown(drawdown)| drawdown.value),
+                    trading_period,
+                )
+                .scale(interval);
+                metric(calmar.value, &calmar.interval)
+            })
+            .transpose()?;
+
+        let pnl_drawdown_series = if selection.contains("pnl_drawdown_series") {
+            drawdown_series_to_py(
+                py,
+                generator
+                    .pnl_drawdown_series
+                    .iter()
+                    .cloned()
+                    .chain(current_drawdown.clone())
+                    .collect(),
+            )?
+        } else {
+            Vec::new()
+        };
+
+        let pnl_drawdown = current_drawdown
+            .filter(|_| selection.contains("pnl_drawdown"))
+            .map(|drawdown| PyDrawdown::from_drawdown(py, drawdown))
+            .transpose()?;
+        let pnl_drawdown_mean = drawdown_mean
+            .filter(|_| selection.contains("pnl_drawdown_mean"))
+            .map(|mean| PyMeanDrawdown::from_mean(py, mean))
+            .transpose()?;
+        let pnl_drawdown_max = drawdown_max
+            .filter(|_| selection.contains("pnl_drawdown_max"))
+            .map(|max| PyDrawdown::from_drawdown(py, max.0))
+            .transpose()?;
+
+        let win_rate = selection
+            .contains("win_rate")
+            .then(|| WinRate::calculate(returns.losses.count, returns.total.count))
+            .flatten()
+            .map(|rate| rate.value);
+        let profit_factor = selection
+            .contains("profit_factor")
+            .then(|| ProfitFactor::calculate(returns.total.sum, returns.losses.sum))
+            .flatten()
+            .map(|factor| factor.value);
+
+        Py::new(
+            py,
+            PyInstrumentTearSheet {
+                pnl: returns.pnl_raw,
+                pnl_return,
                 sharpe_ratio,
                 sortino_ratio,
                 calmar_ratio,
                 pnl_drawdown,
                 pnl_drawdown_mean,
                 pnl_drawdown_max,
-                win_rate: win_rate.map(|rate| rate.value),
-                profit_factor: profit_factor.map(|factor| factor.value),
+                pnl_drawdown_series,
+                win_rate,
+                profit_factor,
             },
         )
     }
@@ -393,21 +1035,15 @@ impl PyInstrumentTearSheet {
         )?;
         dict.set_item(
             "sharpe_ratio",
-            self.sharpe_ratio
-                .clone_ref(py)
-                .call_method0(py, "to_dict")?,
+            optional_to_dict(py, self.sharpe_ratio.as_ref())?,
         )?;
         dict.set_item(
             "sortino_ratio",
-            self.sortino_ratio
-                .clone_ref(py)
-                .call_method0(py, "to_dict")?,
+            optional_to_dict(py, self.sortino_ratio.as_ref())?,
         )?;
         dict.set_item(
             "calmar_ratio",
-            self.calmar_ratio
-                .clone_ref(py)
-                .call_method0(py, "to_dict")?,
+            optional_to_dict(py, self.calmar_ratio.as_ref())?,
         )?;
         dict.set_item(
             "pnl_drawdown",
@@ -421,10 +1057,20 @@ impl PyInstrumentTearSheet {
             "pnl_drawdown_max",
             optional_to_object(py, self.pnl_drawdown_max.as_ref())?,
         )?;
+        dict.set_item(
+            "pnl_drawdown_series",
+            clone_drawdown_series(py, &self.pnl_drawdown_series),
+        )?;
         dict.set_item("win_rate", optional_decimal(py, self.win_rate)?)?;
         dict.set_item("profit_factor", optional_decimal(py, self.profit_factor)?)?;
         Ok(dict.into())
     }
+
+    fn clear_ratios(&mut self) {
+        self.sharpe_ratio = None;
+        self.sortino_ratio = None;
+        self.calmar_ratio = None;
+    }
 }
 
 #[pymethods]
@@ -440,18 +1086,18 @@ impl PyInstrumentTearSheet {
     }
 
     #[getter]
-    pub fn sharpe_ratio(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(self.sharpe_ratio.clone_ref(py).into_py(py))
+    pub fn sharpe_ratio(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        optional_to_object(py, self.sharpe_ratio.as_ref())
     }
 
     #[getter]
-    pub fn sortino_ratio(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(self.sortino_ratio.clone_ref(py).into_py(py))
+    pub fn sortino_ratio(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        optional_to_object(py, self.sortino_ratio.as_ref())
     }
 
     #[getter]
-    pub fn calmar_ratio(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(self.calmar_ratio.clone_ref(py).into_py(py))
+    pub fn calmar_ratio(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        optional_to_object(py, self.calmar_ratio.as_ref())
     }
 
     #[getter]
@@ -469,6 +1115,12 @@ impl PyInstrumentTearSheet {
         optional_to_object(py, self.pnl_drawdown_max.as_ref())
     }
 
+    /// Every PnL drawdown period used to compute the mean and max, ordered by time.
+    #[getter]
+    pub fn pnl_drawdown_series(&self, py: Python<'_>) -> Vec<Py<PyDrawdown>> {
+        clone_drawdown_series(py, &self.pnl_drawdown_series)
+    }
+
     #[getter]
     pub fn win_rate(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
         optional_decimal(py, self.win_rate)
@@ -499,6 +1151,7 @@ pub struct PyAssetTearSheet {
     drawdown: Option<Py<PyDrawdown>>,
     drawdown_mean: Option<Py<PyMeanDrawdown>>,
     drawdown_max: Option<Py<PyDrawdown>>,
+    drawdown_series: Vec<Py<PyDrawdown>>,
 }
 
 impl PyAssetTearSheet {
@@ -508,6 +1161,7 @@ impl PyAssetTearSheet {
             drawdown,
             drawdown_mean,
             drawdown_max,
+            drawdown_series,
         } = sheet;
 
         let balance_end = balance_end
@@ -522,6 +1176,7 @@ impl PyAssetTearSheet {
         let drawdown_max = drawdown_max
             .map(|max| PyDrawdown::from_drawdown(py, max.0))
             .transpose()?;
+        let drawdown_series = drawdown_series_to_py(py, drawdown_series)?;
 
         Py::new(
             py,
@@ -530,6 +1185,7 @@ impl PyAssetTearSheet {
                 drawdown,
                 drawdown_mean,
                 drawdown_max,
+                drawdown_series,
             },
         )
     }
@@ -549,6 +1205,10 @@ impl PyAssetTearSheet {
             "drawdown_max",
             optional_to_object(py, self.drawdown_max.as_ref())?,
         )?;
+        dict.set_item(
+            "drawdown_series",
+            clone_drawdown_series(py, &self.drawdown_series),
+        )?;
         Ok(dict.into())
     }
 }
@@ -575,6 +1235,12 @@ impl PyAssetTearSheet {
         optional_to_object(py, self.drawdown_max.as_ref())
     }
 
+    /// Every balance drawdown period used to compute the mean and max, ordered by time.
+    #[getter]
+    pub fn drawdown_series(&self, py: Python<'_>) -> Vec<Py<PyDrawdown>> {
+        clone_drawdown_series(py, &self.drawdown_series)
+    }
+
     pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
         Ok(self.dictionary(py)?.into_py(py))
     }
@@ -659,8 +1325,8 @@ impl PyDrawdown {
     fn dictionary(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
         let dict = PyDict::new_bound(py);
         dict.set_item("value", decimal_to_py(py, self.value)?)?;
-        dict.set_item("time_start", self.time_start)?;
-        dict.set_item("time_end", self.time_end)?;
+        dict.set_item("time_start", timestamp_to_py(py, self.time_start)?)?;
+        dict.set_item("time_end", timestamp_to_py(py, self.time_end)?)?;
         dict.set_item("duration", timedelta_from_millis(py, self.duration_ms())?)?;
         Ok(dict.into())
     }
@@ -979,6 +1645,258 @@ impl PyMultiBacktestSummary {
     }
 }
 
+/// Build a comparison table with one row per run from a list of `BacktestSummary` or
+/// `TradingSummary` objects.
+///
+/// Each row is a dictionary of headline metrics aggregated across the run's instruments, so the
+/// result can be passed straight to `pandas.DataFrame`. Labels default to the backtest id, or
+/// the run index for plain trading summaries.
+#[pyfunction]
+#[pyo3(signature = (summaries, labels = None))]
+pub fn build_comparison_table(
+    py: Python<'_>,
+    summaries: Vec<Bound<'_, PyAny>>,
+    labels: Option<Vec<String>>,
+) -> PyResult<Vec<PyObject>> {
+    if let Some(labels) = &labels
+        && labels.len() != summaries.len()
+    {
+        return Err(PyValueError::new_err(format!(
+            "labels length {} does not match summaries length {}",
+            labels.len(),
+            summaries.len()
+        )));
+    }
+
+    summaries
+        .iter()
+        .enumerate()
+        .map(|(index, summary)| {
+            let (label, risk_free_return, trading_summary) =
+                if let Ok(backtest) = summary.downcast::<PyBacktestSummary>() {
+                    let backtest = backtest.borrow();
+                    (
+                        backtest.id.clone(),
+                        Some(backtest.risk_free_return),
+                        backtest.trading_summary.clone_ref(py),
+                    )
+                } else if let Ok(trading) = summary.downcast::<PyTradingSummary>() {
+                    (index.to_string(), None, trading.clone().unbind())
+                } else {
+                    return Err(PyTypeError::new_err(
+                        "summaries must contain BacktestSummary or TradingSummary instances",
+                    ));
+                };
+
+            let label = labels
+                .as_ref()
+                .map_or(label, |labels| labels[index].clone());
+
+            comparison_row(py, label, risk_free_return, &trading_summary.borrow(py))
+        })
+        .collect()
+}
+
+fn comparison_row(
+    py: Python<'_>,
+    label: String,
+    risk_free_return: Option<Decimal>,
+    summary: &PyTradingSummary,
+) -> PyResult<PyObject> {
+    let sheets = summary
+        .instruments
+        .iter()
+        .map(|(_, sheet)| sheet.borrow(py))
+        .collect::<Vec<_>>();
+
+    let pnl = sheets
+        .iter()
+        .try_fold(Decimal::ZERO, |total, sheet| total.checked_add(sheet.pnl))
+        .ok_or_else(|| PyValueError::new_err("total pnl overflowed"))?;
+    let metric_mean = |metric: fn(&PyInstrumentTearSheet) -> Option<&Py<PyMetricWithInterval>>| {
+        decimal_mean(
+            sheets
+                .iter()
+                .filter_map(|sheet| metric(sheet).map(|metric| metric.borrow(py).value)),
+        )
+    };
+    let drawdown_max = sheets
+        .iter()
+        .filter_map(|sheet| {
+            sheet
+                .pnl_drawdown_max
+                .as_ref()
+                .map(|drawdown| drawdown.borrow(py).value)
+        })
+        .max();
+
+    let row = PyDict::new_bound(py);
+    row.set_item("label", label)?;
+    row.set_item(
+        "time_engine_start",
+        timestamp_to_py(py, summary.time_engine_start)?,
+    )?;
+    row.set_item(
+        "time_engine_end",
+        timestamp_to_py(py, summary.time_engine_end)?,
+    )?;
+    row.set_item("risk_free_return", optional_decimal(py, risk_free_return)?)?;
+    row.set_item("instruments", sheets.len())?;
+    row.set_item("pnl", decimal_to_py(py, pnl)?)?;
+    row.set_item(
+        "pnl_return",
+        optional_decimal(py, metric_mean(|sheet| Some(&sheet.pnl_return)))?,
+    )?;
+    row.set_item(
+        "sharpe_ratio",
+        optional_decimal(py, metric_mean(|sheet| sheet.sharpe_ratio.as_ref()))?,
+    )?;
+    row.set_item(
+        "sortino_ratio",
+        optional_decimal(py, metric_mean(|sheet| sheet.sortino_ratio.as_ref()))?,
+    )?;
+    row.set_item(
+        "calmar_ratio",
+        optional_decimal(py, metric_mean(|sheet| sheet.calmar_ratio.as_ref()))?,
+    )?;
+    row.set_item("pnl_drawdown_max", optional_decimal(py, drawdown_max)?)?;
+    row.set_item(
+        "win_rate",
+        optional_decimal(
+            py,
+            decimal_mean(sheets.iter().filter_map(|sheet| sheet.win_rate)),
+        )?,
+    )?;
+    row.set_item(
+        "profit_factor",
+        optional_decimal(
+            py,
+            decimal_mean(sheets.iter().filter_map(|sheet| sheet.profit_factor)),
+        )?,
+    )?;
+    Ok(row.into_py(py))
+}
+
+/// Arithmetic mean of the provided values, or `None` if there are none (or the sum overflows).
+fn decimal_mean(values: impl Iterator<Item = Decimal>) -> Option<Decimal> {
+    let (sum, count) = values.fold((Some(Decimal::ZERO), 0u32), |(sum, count), value| {
+        (sum.and_then(|sum| sum.checked_add(value)), count + 1)
+    });
+    if count == 0 {
+        return None;
+    }
+    sum?.checked_div(Decimal::from(count))
+}
+
+/// Flattened view of a [`PyTradingSummary`] used for (approximate) equality checks.
+///
+/// `labels` hold values that must always match exactly (timestamps, intervals, durations),
+/// whereas `metrics` hold the decimal values that [`SummaryFields::approx_eq`] compares with
+/// a tolerance.
+#[derive(Debug, Default, PartialEq)]
+struct SummaryFields {
+    labels: Vec<(String, String)>,
+    metrics: Vec<(String, Option<Decimal>)>,
+}
+
+impl SummaryFields {
+    fn label(&mut self, key: impl Into<String>, value: impl ToString) {
+        self.labels.push((key.into(), value.to_string()));
+    }
+
+    fn metric(&mut self, key: impl Into<String>, value: Option<Decimal>) {
+        self.metrics.push((key.into(), value));
+    }
+
+    fn metric_with_interval(
+        &mut self,
+        py: Python<'_>,
+        key: String,
+        metric: &Py<PyMetricWithInterval>,
+    ) {
+        let metric = metric.borrow(py);
+        self.label(format!("{key}.interval"), &metric.interval);
+        self.metric(key, Some(metric.value));
+    }
+
+    fn optional_metric_with_interval(
+        &mut self,
+        py: Python<'_>,
+        key: String,
+        metric: Option<&Py<PyMetricWithInterval>>,
+    ) {
+        match metric {
+            Some(metric) => self.metric_with_interval(py, key, metric),
+            None => self.metric(key, None),
+        }
+    }
+
+    fn drawdown(&mut self, py: Python<'_>, key: String, drawdown: Option<&Py<PyDrawdown>>) {
+        match drawdown {
+            Some(drawdown) => {
+                let drawdown = drawdown.borrow(py);
+                self.label(format!("{key}.time_start"), drawdown.time_start);
+                self.label(format!("{key}.time_end"), drawdown.time_end);
+                self.metric(key, Some(drawdown.value));
+            }
+            None => self.metric(key, None),
+        }
+    }
+
+    fn drawdown_series(&mut self, py: Python<'_>, key: String, drawdowns: &[Py<PyDrawdown>]) {
+        self.label(format!("{key}.len"), drawdowns.len());
+        for (index, drawdown) in drawdowns.iter().enumerate() {
+            self.drawdown(py, format!("{key}.{index}"), Some(drawdown));
+        }
+    }
+
+    fn mean_drawdown(&mut self, py: Python<'_>, key: String, mean: Option<&Py<PyMeanDrawdown>>) {
+        match mean {
+            Some(mean) => {
+                let mean = mean.borrow(py);
+                self.label(format!("{key}.mean_duration_ms"), mean.mean_drawdown_ms);
+                self.metric(key, Some(mean.mean_drawdown));
+            }
+            None => self.metric(key, None),
+        }
+    }
+
+    fn balance(&mut self, py: Python<'_>, key: String, balance: Option<&Py<PyBalance>>) {
+        match balance {
+            Some(balance) => {
+                let balance = balance.borrow(py);
+                self.metric(format!("{key}.total"), Some(balance.total));
+                self.metric(format!("{key}.free"), Some(balance.free));
+            }
+            None => self.metric(key, None),
+        }
+    }
+
+    fn sort(&mut self) {
+        self.labels.sort();
+        self.metrics.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+
+    fn approx_eq(&self, other: &Self, tolerance: Decimal) -> bool {
+        self.labels == other.labels
+            && self.metrics.len() == other.metrics.len()
+            && self
+                .metrics
+                .iter()
+                .zip(&other.metrics)
+                .all(|((key_a, a), (key_b, b))| {
+                    key_a == key_b
+                        && match (a, b) {
+                            (Some(a), Some(b)) => a
+                                .checked_sub(*b)
+                                .is_some_and(|delta| delta.abs() <= tolerance),
+                            (None, None) => true,
+                            _ => false,
+                        }
+                })
+    }
+}
+
 fn interval_name<Interval>(interval: &Interval) -> String
 where
     Interval: TimeInterval,
@@ -993,6 +1911,32 @@ where
     Ok(value.map(|item| item.clone_ref(py).into_py(py)))
 }
 
+fn optional_to_dict<T>(py: Python<'_>, value: Option<&Py<T>>) -> PyResult<Option<PyObject>>
+where
+    T: PyClass,
+{
+    value
+        .map(|item| item.clone_ref(py).call_method0(py, "to_dict"))
+        .transpose()
+}
+
+fn drawdown_series_to_py(
+    py: Python<'_>,
+    drawdowns: Vec<Drawdown>,
+) -> PyResult<Vec<Py<PyDrawdown>>> {
+    drawdowns
+        .into_iter()
+        .map(|drawdown| PyDrawdown::from_drawdown(py, drawdown))
+        .collect()
+}
+
+fn clone_drawdown_series(py: Python<'_>, drawdowns: &[Py<PyDrawdown>]) -> Vec<Py<PyDrawdown>> {
+    drawdowns
+        .iter()
+        .map(|drawdown| drawdown.clone_ref(py))
+        .collect()
+}
+
 fn optional_decimal(py: Python<'_>, value: Option<Decimal>) -> PyResult<Option<PyObject>> {
     value.map(|decimal| decimal_to_py(py, decimal)).transpose()
 }
@@ -1035,7 +1979,7 @@ mod tests {
         let mut instruments = FnvIndexMap::default();
         instruments.insert(
             InstrumentNameInternal::new("binance_spot-btc_usdt"),
-            TearSheetGenerator::init(start),
+            TearSheetGenerator::init(start).with_history(true),
         );
 
         let mut assets = FnvIndexMap::default();
@@ -1076,7 +2020,7 @@ mod tests {
 
             let generated = py_generator
                 .borrow_mut(py)
-                .generate(py, Some("annual_252"))
+                .generate(py, Some("annual_252"), 2, None)
                 .unwrap();
 
             let summary = generated.borrow(py);
@@ -1115,10 +2059,154 @@ mod tests {
                     .unwrap();
             }
 
-            let summary = py_generator.borrow_mut(py).generate(py, None).unwrap();
+            let summary = py_generator
+                .borrow_mut(py)
+                .generate(py, None, 2, None)
+                .unwrap();
 
             let summary_ref = summary.borrow(py);
             assert_eq!(summary_ref.time_engine_end(), exit_time);
         });
     }
+
+    #[test]
+    fn equity_curve_tracks_position_exits() {
+        Python::with_gil(|py| {
+            let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+            let mut generator = sample_generator(start);
+
+            for (minutes, pnl) in [(30, "50"), (60, "-20")] {
+                generator.update_from_position(&PositionExited {
+                    instrument: InstrumentIndex(0),
+                    side: Side::Buy,
+                    price_entry_average: Decimal::from_str("10000").unwrap(),
+                    quantity_abs_max: Decimal::from_str("1").unwrap(),
+                    pnl_realised: Decimal::from_str(pnl).unwrap(),
+                    fees_enter: AssetFees::quote_fees(Decimal::ZERO),
+                    fees_exit: AssetFees::quote_fees(Decimal::ZERO),
+                    time_enter: start,
+                    time_exit: start + TimeDelta::minutes(minutes),
+                    trades: vec![TradeId::new(format!("trade-{minutes}"))],
+                });
+            }
+
+            let py_generator = PyTradingSummaryGenerator::from_inner(py, generator).unwrap();
+            let py_generator = py_generator.borrow(py);
+
+            let curve = py_generator.equity_curve(py, None).unwrap();
+            let times = curve.iter().map(|(time, _)| *time).collect::<Vec<_>>();
+            assert_eq!(
+                times,
+                vec![
+                    start + TimeDelta::minutes(30),
+                    start + TimeDelta::minutes(60)
+                ]
+            );
+
+            let instrument = py_generator
+                .equity_curve(py, Some("binance_spot-btc_usdt"))
+                .unwrap();
+            assert_eq!(instrument.len(), 2);
+
+            let error = py_generator.equity_curve(py, Some("unknown")).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn summary_equality_compares_drawdown_series() {
+        Python::with_gil(|py| {
+            let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+            let summary = |value: &str| {
+                let drawdown = PyDrawdown::from_drawdown(
+                    py,
+                    Drawdown {
+                        value: Decimal::from_str(value).unwrap(),
+                        time_start: start,
+                        time_end: start + TimeDelta::hours(1),
+                    },
+                )
+                .unwrap();
+                let sheet = Py::new(
+                    py,
+                    PyAssetTearSheet {
+                        balance_end: None,
+                        drawdown: None,
+                        drawdown_mean: None,
+                        drawdown_max: None,
+                        drawdown_series: vec![drawdown],
+                    },
+                )
+                .unwrap();
+                Py::new(
+                    py,
+                    PyTradingSummary {
+                        time_engine_start: start,
+                        time_engine_end: start,
+                        instruments: Vec::new(),
+                        assets: vec![("binance_spot:usdt".to_string(), sheet)],
+                    },
+                )
+                .unwrap()
+            };
+
+            let a = summary("-0.10");
+            let b = summary("-0.25");
+            let (a, b) = (a.borrow(py), b.borrow(py));
+
+            assert!(a.fields(py) == summary("-0.10").borrow(py).fields(py));
+            assert!(a.fields(py) != b.fields(py));
+            assert!(
+                !a.fields(py)
+                    .approx_eq(&b.fields(py), Decimal::from_str("0.1").unwrap())
+            );
+            assert!(
+                a.fields(py)
+                    .approx_eq(&b.fields(py), Decimal::from_str("0.2").unwrap())
+            );
+        });
+    }
+
+    #[test]
+    fn metric_selection_rejects_unknown_metrics() {
+        let selection =
+            MetricSelection::parse(vec!["pnl".to_string(), "sharpe_ratio".to_string()]).unwrap();
+        assert!(selection.contains("sharpe_ratio"));
+        assert!(!selection.contains("pnl_drawdown_series"));
+
+        Python::with_gil(|py| {
+            let error = MetricSelection::parse(vec!["alpha".to_string()]).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn decimal_mean_averages_values() {
+        assert_eq!(decimal_mean(std::iter::empty()), None);
+        assert_eq!(
+            decimal_mean([Decimal::from(1), Decimal::from(2), Decimal::from(6)].into_iter()),
+            Some(Decimal::from(3))
+        );
+    }
+
+    #[test]
+    fn combined_equity_curve_merges_instrument_pnl_in_time_order() {
+        let time = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        let btc = [
+            Timed::new(Decimal::from(10), time(1)),
+            Timed::new(Decimal::from(4), time(3)),
+        ];
+        let eth = [Timed::new(Decimal::from(-2), time(2))];
+
+        let curve = combined_equity_curve([btc.as_slice(), eth.as_slice()].into_iter());
+
+        assert_eq!(
+            curve,
+            vec![
+                Timed::new(Decimal::from(10), time(1)),
+                Timed::new(Decimal::from(8), time(2)),
+                Timed::new(Decimal::from(2), time(3)),
+            ]
+        );
+    }
 }
@@ -3,33 +3,58 @@ use crate::{
     collection::{PyNoneOneOrMany, wrap_none_one_or_many},
     command::{
         DefaultOrderRequestCancel, DefaultOrderRequestOpen, PyInstrumentFilter,
-        PyOrderRequestCancel, PyOrderRequestOpen,
+        PyOrderRequestCancel, PyOrderRequestOpen, PyOrderSnapshot, parse_decimal,
+    },
+    common::{
+        SummaryInterval, duration_from_secs, parse_initial_balances, parse_summary_interval,
+        timestamp_str_to_py, timestamp_to_py,
     },
-    common::{SummaryInterval, parse_initial_balances, parse_summary_interval},
     config::PySystemConfig,
-    execution::PyTradeId,
-    instrument::{PyInstrumentIndex, PySide},
+    data::StaleEventFilter,
+    execution::{PyTrade, PyTradeId},
+    instrument::{PyInstrumentIndex, PySide, order_notional},
     integration::{PySnapUpdates, PySnapshot},
+    metric::{PyField, PyMetric, PyMetricSink, PyTag, PyValue},
+    risk::{
+        CallbackRiskManager, OrderRateLimiter, PyCallbackRiskManager, parse_max_position_quantity,
+        validate_max_open_positions,
+    },
+    strategy::{
+        AccountDisconnectPolicy, DisconnectPolicyOutput, MarketDisconnectPolicy, SystemStrategy,
+    },
     summary::{PyTradingSummary, PyTradingSummaryGenerator, decimal_to_py, summary_to_py},
 };
 use barter::engine::{
     EngineOutput,
-    action::{ActionOutput, send_requests::SendRequestsOutput},
+    action::{
+        ActionOutput, generate_algo_orders::GenerateAlgoOrdersOutput,
+        send_requests::SendRequestsOutput,
+    },
     error::EngineError,
-    state::position::PositionExited,
+    state::{
+        instrument::filter::InstrumentFilter,
+        order::in_flight_recorder::InFlightRequestRecorder,
+        position::{Position, PositionExited, PositionManager},
+    },
 };
 use barter::{
     EngineEvent, Sequence,
     engine::{
         Engine, Processor,
-        audit::{AuditTick, EngineAudit, context::EngineContext},
-        clock::{HistoricalClock, LiveClock},
+        audit::{
+            AuditTick, EngineAudit, context::EngineContext, state_replica::StateReplicaManager,
+        },
+        clock::{EngineClock, HistoricalClock, LiveClock},
         execution_tx::MultiExchangeTxMap,
         state::{
-            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            EngineState,
+            asset::AssetState,
+            global::DefaultGlobalData,
+            instrument::data::{DefaultInstrumentMarketData, InstrumentDataState},
             trading::TradingState,
         },
     },
+    error::BarterError,
     risk::DefaultRiskManager,
     statistic::{
         summary::TradingSummaryGenerator,
@@ -37,9 +62,9 @@ use barter::{
     },
     strategy::DefaultStrategy,
     system::{
-        System,
+        System, SystemAuxillaryHandles,
         builder::{AuditMode, EngineFeedMode, SystemArgs, SystemBuilder},
-        config::ExecutionConfig,
+        config::{ExecutionConfig, RiskConfiguration},
     },
 };
 use barter_data::{
@@ -50,51 +75,124 @@ use barter_data::{
     },
 };
 use barter_execution::{
-    order::OrderEvent,
+    balance::AssetBalance,
+    order::{Order, OrderEvent, request::OrderRequestOpen, state::OrderState},
     trade::{AssetFees, TradeId},
 };
 use barter_instrument::{
-    Side, asset::QuoteAsset, index::IndexedInstruments, instrument::InstrumentIndex,
+    Side,
+    asset::{
+        ExchangeAsset, QuoteAsset,
+        name::{AssetNameExchange, AssetNameInternal},
+    },
+    exchange::ExchangeId,
+    index::IndexedInstruments,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
 };
 use barter_integration::{
-    channel::{Tx, UnboundedRx},
+    channel::{Tx, UnboundedRx, UnboundedTx, mpsc_unbounded},
     collection::none_one_or_many::NoneOneOrMany,
     snapshot::{SnapUpdates, Snapshot},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHasher;
 use futures::{Stream, StreamExt, stream};
 use pyo3::{
-    exceptions::PyValueError,
+    exceptions::{PyTypeError, PyValueError},
     prelude::*,
     types::{PyDict, PyList},
 };
 use rust_decimal::Decimal;
-use rust_decimal::prelude::FromPrimitive;
-use serde::Serialize;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     fs::File,
-    io::Read,
-    path::Path,
-    sync::{Arc, Mutex, MutexGuard},
+    hash::Hasher,
+    io::{BufRead, BufReader, BufWriter, Lines, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex, MutexGuard, RwLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
     time::Duration,
 };
 use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
+use tokio::sync::Notify;
 use tokio::sync::mpsc::error::TryRecvError;
+use tokio::task::{AbortHandle, JoinHandle};
 use tracing::{info, warn};
 
 type DefaultEngineState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
 type TradingEngine = Engine<
-    LiveClock,
+    SystemClock,
     DefaultEngineState,
     MultiExchangeTxMap,
-    DefaultStrategy<DefaultEngineState>,
-    DefaultRiskManager<DefaultEngineState>,
+    SystemStrategy<DefaultEngineState>,
+    CallbackRiskManager<DefaultEngineState, SystemClock>,
 >;
 type RunningSystem = System<TradingEngine, EngineEvent>;
 type TradingSnapshotTick = AuditTick<DefaultEngineState, EngineContext>;
 type TradingEngineAudit = <TradingEngine as Processor<EngineEvent<DataKind>>>::Audit;
 type TradingAuditTick = AuditTick<TradingEngineAudit, EngineContext>;
 type TradingAuditSnapUpdates = SnapUpdates<TradingSnapshotTick, UnboundedRx<TradingAuditTick>>;
+type TradingStateReplica = StateReplicaManager<DefaultEngineState, ()>;
+
+/// [`EngineClock`] of a [`PySystemHandle`] engine, selected with `start_system(engine_clock=...)`.
+#[derive(Debug, Clone)]
+enum SystemClock {
+    /// Wall-clock time, for live trading.
+    Live(LiveClock),
+    /// `time_exchange` of the most recent market event, so paper trading observes the same time
+    /// as a backtest over the same events. Wall-clock time is used until a market event arrives.
+    Event(Arc<RwLock<Option<DateTime<Utc>>>>),
+}
+
+impl SystemClock {
+    fn parse(value: Option<&str>) -> PyResult<Self> {
+        match value.map(|raw| raw.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("live") => Ok(Self::Live(LiveClock)),
+            Some("event") => Ok(Self::Event(Arc::new(RwLock::new(None)))),
+            Some(_) => Err(PyValueError::new_err(format!(
+                "engine_clock must be 'live' or 'event', got {}",
+                value.unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+impl EngineClock for SystemClock {
+    fn time(&self) -> DateTime<Utc> {
+        match self {
+            Self::Live(clock) => clock.time(),
+            Self::Event(latest) => latest
+                .read()
+                .ok()
+                .and_then(|latest| *latest)
+                .unwrap_or_else(Utc::now),
+        }
+    }
+}
+
+impl<MarketEventKind> Processor<&EngineEvent<MarketEventKind>> for SystemClock {
+    type Audit = ();
+
+    fn process(&mut self, event: &EngineEvent<MarketEventKind>) -> Self::Audit {
+        let Self::Event(latest) = self else {
+            return;
+        };
+        let EngineEvent::Market(MarketStreamEvent::Item(market)) = event else {
+            return;
+        };
+
+        // Out of order market events never move the clock backwards
+        if let Ok(mut latest) = latest.write()
+            && latest.is_none_or(|time| market.time_exchange > time)
+        {
+            *latest = Some(market.time_exchange);
+        }
+    }
+}
 
 #[pyclass(module = "barter_python", name = "AuditUpdates", unsendable)]
 pub struct PyAuditUpdates {
@@ -126,9 +224,17 @@ impl PyAuditUpdates {
         func(receiver)
     }
 
-    fn recv_tick_inner(&self, timeout: Option<f64>) -> PyResult<Option<TradingAuditTick>> {
+    /// Blocks without holding the GIL, so Python callbacks run by the engine (eg/ an
+    /// `algo_strategy`) can progress meanwhile.
+    fn recv_tick_inner(
+        &self,
+        py: Python<'_>,
+        timeout: Option<f64>,
+    ) -> PyResult<Option<TradingAuditTick>> {
         let runtime = Arc::clone(&self.runtime);
-        self.with_receiver(|receiver| Self::blocking_recv(runtime, receiver, timeout))
+        self.with_receiver(|receiver| {
+            py.allow_threads(|| Self::blocking_recv(runtime, receiver, timeout))
+        })
     }
 
     fn try_recv_tick_inner(&self) -> PyResult<Option<TradingAuditTick>> {
@@ -152,7 +258,7 @@ impl PyAuditUpdates {
                 return Err(PyValueError::new_err("timeout must be finite"));
             }
 
-            let duration = Duration::from_secs_f64(secs);
+            let duration = duration_from_secs(secs, "timeout")?;
             runtime
                 .block_on(async { tokio::time::timeout(duration, receiver.rx.recv()).await })
                 .map_err(|_| PyValueError::new_err("timeout elapsed awaiting audit update"))
@@ -160,6 +266,53 @@ impl PyAuditUpdates {
             Ok(runtime.block_on(receiver.rx.recv()))
         }
     }
+
+    /// Receive ticks until the `predicate` accepts the summary dict of one, returning it.
+    ///
+    /// Returns `None` if the stream is exhausted or `timeout` (shared across every tick
+    /// received) elapses first.
+    fn recv_matching_inner(
+        &self,
+        py: Python<'_>,
+        predicate: &Bound<'_, PyAny>,
+        timeout: Option<f64>,
+    ) -> PyResult<Option<PyObject>> {
+        let deadline = timeout
+            .map(|secs| {
+                if secs.is_sign_negative() {
+                    return Err(PyValueError::new_err("timeout must be non-negative"));
+                }
+                if !secs.is_finite() {
+                    return Err(PyValueError::new_err("timeout must be finite"));
+                }
+                let timeout = duration_from_secs(secs, "timeout")?;
+                tokio::time::Instant::now()
+                    .checked_add(timeout)
+                    .ok_or_else(|| PyValueError::new_err("timeout is out of range"))
+            })
+            .transpose()?;
+
+        let runtime = Arc::clone(&self.runtime);
+        self.with_receiver(|receiver| {
+            loop {
+                let tick = py.allow_threads(|| match deadline {
+                    Some(deadline) => runtime
+                        .block_on(tokio::time::timeout_at(deadline, receiver.rx.recv()))
+                        .ok()
+                        .flatten(),
+                    None => runtime.block_on(receiver.rx.recv()),
+                });
+                let Some(tick) = tick else {
+                    return Ok(None);
+                };
+
+                let summary = audit_tick_summary_to_py(py, &tick)?;
+                if predicate.call1((summary.clone_ref(py),))?.is_truthy()? {
+                    return Ok(Some(summary));
+                }
+            }
+        })
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -230,6 +383,10 @@ impl PyPositionExit {
         Ok(list.into_py(py))
     }
 
+    pub(crate) fn realised_pnl(&self) -> Decimal {
+        self.pnl_realised
+    }
+
     pub(crate) fn to_position_exited(&self) -> PositionExited<QuoteAsset, InstrumentIndex> {
         PositionExited {
             instrument: self.instrument,
@@ -284,13 +441,13 @@ impl PyPositionExit {
     }
 
     #[getter]
-    pub fn time_enter(&self) -> String {
-        self.time_enter.to_rfc3339()
+    pub fn time_enter(&self, py: Python<'_>) -> PyResult<PyObject> {
+        timestamp_str_to_py(py, self.time_enter)
     }
 
     #[getter]
-    pub fn time_exit(&self) -> String {
-        self.time_exit.to_rfc3339()
+    pub fn time_exit(&self, py: Python<'_>) -> PyResult<PyObject> {
+        timestamp_str_to_py(py, self.time_exit)
     }
 
     #[getter]
@@ -298,6 +455,34 @@ impl PyPositionExit {
         self.trades_to_list(py)
     }
 
+    /// Duration the position was held, from entry to exit.
+    pub fn holding_duration(&self) -> TimeDelta {
+        self.time_exit.signed_duration_since(self.time_enter)
+    }
+
+    /// Realised PnL as a percentage of the entry notional.
+    ///
+    /// The entry notional defaults to `price_entry_average * quantity_abs_max`, and returns
+    /// `None` if it is zero.
+    #[pyo3(signature = (entry_notional = None))]
+    pub fn return_pct(
+        &self,
+        py: Python<'_>,
+        entry_notional: Option<f64>,
+    ) -> PyResult<Option<PyObject>> {
+        let entry_notional = match entry_notional {
+            Some(value) => parse_decimal(value, "entry_notional")?,
+            None => self.price_entry_average * self.quantity_abs_max,
+        };
+
+        if entry_notional.is_zero() {
+            return Ok(None);
+        }
+
+        let return_pct = self.pnl_realised / entry_notional.abs() * Decimal::ONE_HUNDRED;
+        decimal_to_py(py, return_pct.normalize()).map(Some)
+    }
+
     pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
         let dict = PyDict::new_bound(py);
         let instrument = self.instrument(py)?;
@@ -315,8 +500,9 @@ impl PyPositionExit {
         dict.set_item("pnl_realised", decimal_to_py(py, self.pnl_realised)?)?;
         dict.set_item("fees_enter", decimal_to_py(py, self.fees_enter)?)?;
         dict.set_item("fees_exit", decimal_to_py(py, self.fees_exit)?)?;
-        dict.set_item("time_enter", self.time_enter())?;
-        dict.set_item("time_exit", self.time_exit())?;
+        dict.set_item("time_enter", self.time_enter(py)?)?;
+        dict.set_item("time_exit", self.time_exit(py)?)?;
+        dict.set_item("holding_duration", self.holding_duration())?;
         dict.set_item("trades", self.trades_to_list(py)?)?;
         Ok(dict.into_py(py))
     }
@@ -329,6 +515,90 @@ impl PyPositionExit {
     }
 }
 
+/// Reconstruct the `PositionExit`s produced by replaying `trades` in order, without running an
+/// engine.
+///
+/// Each instrument's trades are applied to its own [`PositionManager`], using the same
+/// volume-weighted average entry price & cumulative fee accounting as the engine. An exit is
+/// emitted whenever a position fully closes or flips side; still open positions emit nothing.
+#[pyfunction]
+pub fn reconstruct_positions(trades: Vec<PyRef<'_, PyTrade>>) -> Vec<PyPositionExit> {
+    let mut managers = HashMap::<InstrumentIndex, PositionManager>::new();
+
+    trades
+        .iter()
+        .filter_map(|trade| {
+            managers
+                .entry(trade.inner.instrument)
+                .or_default()
+                .update_from_trade(&trade.inner)
+        })
+        .map(|exit| PyPositionExit::from_position(&exit))
+        .collect()
+}
+
+/// Summarise trade-level statistics across a list of `PositionExit`s.
+///
+/// Returns a dict with the exit `count`, `pnl_realised_total`, `win_rate`, `average_win`,
+/// `average_loss`, `largest_win`, `largest_loss` & `average_holding_duration`. Exits with zero
+/// PnL count towards the total but are neither wins nor losses, and losses keep their negative
+/// sign. Statistics without any contributing exits are `None`.
+#[pyfunction]
+pub fn position_exit_stats(
+    py: Python<'_>,
+    exits: Vec<PyRef<'_, PyPositionExit>>,
+) -> PyResult<PyObject> {
+    let pnls = exits
+        .iter()
+        .map(|exit| exit.pnl_realised)
+        .collect::<Vec<_>>();
+    let wins = pnls
+        .iter()
+        .copied()
+        .filter(|pnl| *pnl > Decimal::ZERO)
+        .collect::<Vec<_>>();
+    let losses = pnls
+        .iter()
+        .copied()
+        .filter(|pnl| *pnl < Decimal::ZERO)
+        .collect::<Vec<_>>();
+
+    let mean = |values: &[Decimal]| {
+        (!values.is_empty()).then(|| values.iter().sum::<Decimal>() / Decimal::from(values.len()))
+    };
+    let optional_decimal =
+        |value: Option<Decimal>| value.map(|value| decimal_to_py(py, value)).transpose();
+
+    let count = exits.len();
+    let win_rate = (count > 0).then(|| Decimal::from(wins.len()) / Decimal::from(count));
+
+    let holding_total = exits
+        .iter()
+        .map(|exit| exit.holding_duration())
+        .fold(TimeDelta::zero(), |total, duration| total + duration);
+    let average_holding_duration = i32::try_from(count)
+        .ok()
+        .filter(|count| *count > 0)
+        .map(|count| holding_total / count);
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("count", count)?;
+    dict.set_item(
+        "pnl_realised_total",
+        decimal_to_py(py, pnls.iter().sum::<Decimal>())?,
+    )?;
+    dict.set_item("win_rate", optional_decimal(win_rate)?)?;
+    dict.set_item("average_win", optional_decimal(mean(&wins))?)?;
+    dict.set_item("average_loss", optional_decimal(mean(&losses))?)?;
+    dict.set_item("largest_win", optional_decimal(wins.iter().max().copied())?)?;
+    dict.set_item(
+        "largest_loss",
+        optional_decimal(losses.iter().min().copied())?,
+    )?;
+    dict.set_item("average_holding_duration", average_holding_duration)?;
+    Ok(dict.into_py(py))
+}
+
 enum PyEngineOutputInner {
     Commanded { output: Py<PyActionOutput> },
     OnTradingDisabled { payload: PyObject },
@@ -789,6 +1059,17 @@ impl PyActionOutput {
                     payload: dict.into_py(py),
                 }
             }
+            ActionOutput::InstrumentTradingState(instrument, audit) => {
+                let payload = serialize_to_py_object(py, audit)?;
+                let dict = PyDict::new_bound(py);
+                dict.set_item("variant", "InstrumentTradingState")?;
+                dict.set_item("instrument", instrument.index())?;
+                dict.set_item("payload", payload)?;
+                PyActionOutputInner::Other {
+                    original_variant: "InstrumentTradingState",
+                    payload: dict.into_py(py),
+                }
+            }
         };
 
         Py::new(py, PyActionOutput { inner })
@@ -1030,12 +1311,27 @@ impl PyAuditTick {
 impl PyAuditUpdates {
     #[pyo3(signature = (timeout=None))]
     pub fn recv(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Option<PyObject>> {
-        match self.recv_tick_inner(timeout)? {
+        match self.recv_tick_inner(py, timeout)? {
             Some(tick) => audit_tick_summary_to_py(py, &tick).map(Some),
             None => Ok(None),
         }
     }
 
+    /// Return the summary dict of the first tick for which `predicate(summary)` is truthy,
+    /// discarding the ticks before it, or `None` on exhaustion or once `timeout` elapses.
+    #[pyo3(signature = (predicate, timeout=None))]
+    pub fn recv_matching(
+        &self,
+        py: Python<'_>,
+        predicate: &Bound<'_, PyAny>,
+        timeout: Option<f64>,
+    ) -> PyResult<Option<PyObject>> {
+        if !predicate.is_callable() {
+            return Err(PyTypeError::new_err("predicate must be callable"));
+        }
+        self.recv_matching_inner(py, predicate, timeout)
+    }
+
     pub fn try_recv(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
         match self.try_recv_tick_inner()? {
             Some(tick) => audit_tick_summary_to_py(py, &tick).map(Some),
@@ -1049,7 +1345,7 @@ impl PyAuditUpdates {
         py: Python<'_>,
         timeout: Option<f64>,
     ) -> PyResult<Option<Py<PyAuditTick>>> {
-        self.recv_tick_inner(timeout)?
+        self.recv_tick_inner(py, timeout)?
             .map(|tick| audit_tick_to_py(py, &tick))
             .transpose()
     }
@@ -1084,16 +1380,170 @@ impl PyAuditUpdates {
 pub struct PySystemHandle {
     runtime: Arc<Runtime>,
     system: Mutex<Option<RunningSystem>>,
+    metric_sinks: Mutex<Vec<PyMetricSink>>,
+    state: Arc<Mutex<TradingStateReplica>>,
+    audit: Mutex<Option<TradingAuditSnapUpdates>>,
+    heartbeat: Option<JoinHandle<()>>,
+    stale_filter: Option<StaleEventFilter>,
+    recorder: Arc<Mutex<Option<EventRecorder>>>,
 }
 
 impl PySystemHandle {
-    fn new(runtime: Arc<Runtime>, system: RunningSystem) -> Self {
-        Self {
+    /// Wrap a [`RunningSystem`] built in [`AuditMode::Enabled`].
+    ///
+    /// The engine audit stream is always consumed to maintain an [`EngineState`] replica, backing
+    /// the state queries of this handle, and is only forwarded for [`Self::take_audit`] if an
+    /// `audit` [`AuditFilter`] is requested.
+    ///
+    /// If a [`Heartbeat`] is provided it is driven from the replica until the system stops.
+    ///
+    /// If a [`StaleEventFilter`] is provided, stale market events fed via this handle are dropped
+    /// before reaching the engine.
+    fn new(
+        runtime: Arc<Runtime>,
+        mut system: RunningSystem,
+        audit: Option<AuditFilter>,
+        heartbeat: Option<Heartbeat>,
+        stale_filter: Option<StaleEventFilter>,
+    ) -> PyResult<Self> {
+        let SnapUpdates { snapshot, updates } = system
+            .take_audit()
+            .ok_or_else(|| PyValueError::new_err("system was built without an audit stream"))?;
+
+        let state = Arc::new(Mutex::new(StateReplicaManager::new(snapshot.clone(), ())));
+
+        let (forward, audit) = match audit {
+            Some(filter) => {
+                let (tx, rx) = mpsc_unbounded();
+                (Some((tx, filter)), Some(SnapUpdates::new(snapshot, rx)))
+            }
+            None => (None, None),
+        };
+
+        let recorder = Arc::new(Mutex::new(None));
+        runtime.spawn(replicate_engine_state(
+            Arc::clone(&state),
+            updates,
+            forward,
+            Arc::clone(&recorder),
+        ));
+
+        let heartbeat = heartbeat.map(|heartbeat| runtime.spawn(heartbeat.run(Arc::clone(&state))));
+
+        Ok(Self {
             runtime,
             system: Mutex::new(Some(system)),
+            metric_sinks: Mutex::new(Vec::new()),
+            state,
+            audit: Mutex::new(audit),
+            heartbeat,
+            stale_filter,
+            recorder,
+        })
+    }
+
+    /// Check open order requests conform to the spec of their instruments, returning an error
+    /// listing every violation.
+    fn validate_open_requests(
+        &self,
+        py: Python<'_>,
+        requests: &[Py<PyOrderRequestOpen>],
+    ) -> PyResult<()> {
+        let replica = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?;
+        let instruments = &replica.replica_engine_state().instruments;
+
+        let violations = requests
+            .iter()
+            .enumerate()
+            .flat_map(|(index, request)| {
+                let request = request.borrow(py);
+                let OrderRequestOpen { key, state } = &request.inner;
+
+                let Some((_, instrument)) = instruments.0.get_index(key.instrument.index()) else {
+                    return vec![format!(
+                        "request {index}: unknown instrument {}",
+                        key.instrument
+                    )];
+                };
+                let Some(spec) = &instrument.instrument.spec else {
+                    return Vec::new();
+                };
+
+                // A zero tick_size or increment places no constraint, rather than dividing by zero
+                let is_off_increment = |value: Decimal, increment: Decimal| {
+                    value
+                        .checked_rem(increment)
+                        .is_some_and(|remainder| !remainder.is_zero())
+                };
+
+                let mut violations = Vec::new();
+                if is_off_increment(state.price, spec.price.tick_size) {
+                    violations.push(format!(
+                        "request {index}: price {} is not a multiple of tick_size {}",
+                        state.price, spec.price.tick_size
+                    ));
+                }
+                if is_off_increment(state.quantity, spec.quantity.increment) {
+                    violations.push(format!(
+                        "request {index}: quantity {} is not a multiple of increment {}",
+                        state.quantity, spec.quantity.increment
+                    ));
+                }
+                let notional = order_notional(spec, state.price, state.quantity);
+                if notional < spec.notional.min {
+                    violations.push(format!(
+                        "request {index}: notional {notional} is below minimum {}",
+                        spec.notional.min
+                    ));
+                }
+                violations
+            })
+            .collect::<Vec<_>>();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err(format!(
+                "order requests violate instrument specs: {}",
+                violations.join("; ")
+            )))
         }
     }
 
+    fn lock_recorder(&self) -> PyResult<MutexGuard<'_, Option<EventRecorder>>> {
+        self.recorder
+            .lock()
+            .map_err(|_| PyValueError::new_err("event recorder poisoned"))
+    }
+
+    /// Emit an `engine_event` [`PyMetric`] tagged with the event `kind` to every attached sink.
+    fn emit_event_metric(&self, kind: &str) -> PyResult<()> {
+        let sinks = self
+            .metric_sinks
+            .lock()
+            .map_err(|_| PyValueError::new_err("system handle poisoned"))?;
+
+        if sinks.is_empty() {
+            return Ok(());
+        }
+
+        let metric = PyMetric::new(
+            "engine_event".to_string(),
+            Utc::now().timestamp_millis().unsigned_abs(),
+            vec![PyTag::new("kind".to_string(), kind.to_string())],
+            vec![PyField::new("count".to_string(), PyValue::uint(1))],
+        )?;
+
+        for sink in sinks.iter() {
+            sink.emit_metric(metric.clone());
+        }
+
+        Ok(())
+    }
+
     fn lock_system(&self) -> PyResult<MutexGuard<'_, Option<RunningSystem>>> {
         self.system
             .lock()
@@ -1102,7 +1552,13 @@ impl PySystemHandle {
 
     fn take_system(&self) -> PyResult<RunningSystem> {
         let mut guard = self.lock_system()?;
-        guard.take().ok_or_else(Self::system_not_running_err)
+        let system = guard.take().ok_or_else(Self::system_not_running_err)?;
+
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.abort();
+        }
+
+        Ok(system)
     }
 
     fn system_not_running_err() -> PyErr {
@@ -1110,6 +1566,14 @@ impl PySystemHandle {
     }
 }
 
+impl Drop for PySystemHandle {
+    fn drop(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+    }
+}
+
 #[pymethods]
 impl PySystemHandle {
     /// Return `True` if the underlying system is still running.
@@ -1118,14 +1582,37 @@ impl PySystemHandle {
     }
 
     /// Send an [`EngineEvent`] to the running system.
+    ///
+    /// Market events older than the `max_message_age_secs` the system was started with are
+    /// dropped.
     pub fn send_event(&self, event: &PyEngineEvent) -> PyResult<()> {
+        if let (Some(filter), EngineEvent::Market(Event::Item(market))) =
+            (&self.stale_filter, &event.inner)
+            && filter.is_stale(market)
+        {
+            return Ok(());
+        }
+
         let guard = self.lock_system()?;
         let system = guard.as_ref().ok_or_else(Self::system_not_running_err)?;
 
         system
             .feed_tx
             .send(event.inner.clone())
-            .map_err(|err| PyValueError::new_err(err.to_string()))
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        drop(guard);
+
+        self.emit_event_metric(engine_event_metric_kind(&event.inner))
+    }
+
+    /// Attach a [`PyMetricSink`] receiving an `engine_event` metric, tagged with the event `kind`,
+    /// for every event subsequently sent to the engine via this handle.
+    pub fn attach_metric_sink(&self, sink: PyMetricSink) -> PyResult<()> {
+        self.metric_sinks
+            .lock()
+            .map_err(|_| PyValueError::new_err("system handle poisoned"))?
+            .push(sink);
+        Ok(())
     }
 
     /// Send multiple [`EngineEvent`] values to the system in order.
@@ -1138,24 +1625,191 @@ impl PySystemHandle {
         Ok(())
     }
 
+    /// Send multiple [`EngineEvent`] values to the system in order, continuing past failures.
+    ///
+    /// Returns an `(index, error)` pair for every event that could not be sent, so an empty list
+    /// means every event was delivered.
+    #[pyo3(signature = (events))]
+    pub fn try_feed_events(
+        &self,
+        py: Python<'_>,
+        events: Vec<Py<PyEngineEvent>>,
+    ) -> Vec<(usize, PyObject)> {
+        events
+            .iter()
+            .enumerate()
+            .filter_map(|(index, event)| {
+                let event_ref = event.borrow(py);
+                self.send_event(&event_ref)
+                    .err()
+                    .map(|error| (index, error.into_value(py).into_py(py)))
+            })
+            .collect()
+    }
+
     /// Take ownership of the audit snapshot and update stream if audit mode is enabled.
     pub fn take_audit(&self, py: Python<'_>) -> PyResult<Option<Py<PySnapUpdates>>> {
-        let mut guard = self.lock_system()?;
-        let system = guard.as_mut().ok_or_else(Self::system_not_running_err)?;
+        if !self.is_running()? {
+            return Err(Self::system_not_running_err());
+        }
+
+        let updates = self
+            .audit
+            .lock()
+            .map_err(|_| PyValueError::new_err("system handle poisoned"))?
+            .take();
 
-        match system.take_audit() {
+        match updates {
             Some(updates) => build_py_snapupdates(py, Arc::clone(&self.runtime), updates).map(Some),
             None => Ok(None),
         }
     }
 
+    /// Active orders tracked by the engine, optionally narrowed by an [`PyInstrumentFilter`].
+    ///
+    /// Includes orders still in flight to the exchange. Like [`Self::positions`], orders are read
+    /// from the engine state replica and are ordered by instrument, then client order id.
+    #[pyo3(signature = (filter = None))]
+    pub fn open_orders(
+        &self,
+        py: Python<'_>,
+        filter: Option<&PyInstrumentFilter>,
+    ) -> PyResult<Vec<Py<PyOrderSnapshot>>> {
+        let filter = filter.map_or(InstrumentFilter::None, PyInstrumentFilter::clone_inner);
+        let replica = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?;
+
+        replica
+            .replica_engine_state()
+            .instruments
+            .instruments(&filter)
+            .flat_map(|state| {
+                let mut orders = state.orders.0.values().collect::<Vec<_>>();
+                orders.sort_by(|a, b| a.key.cid.cmp(&b.key.cid));
+                orders
+            })
+            .map(|order| {
+                let snapshot = Order {
+                    key: order.key.clone(),
+                    side: order.side,
+                    price: order.price,
+                    quantity: order.quantity,
+                    kind: order.kind,
+                    time_in_force: order.time_in_force,
+                    state: OrderState::Active(order.state.clone()),
+                };
+                Py::new(py, PyOrderSnapshot::from_inner(snapshot))
+            })
+            .collect()
+    }
+
+    /// Deterministic hash of the engine state balances, positions and open orders.
+    ///
+    /// Equal states hash equally across runs and processes, so comparing hashes (eg/ against the
+    /// `state_hash` recorded in a backtest checkpoint) detects divergence between two runs. Like
+    /// [`Self::positions`], the hash is computed from the engine state replica.
+    pub fn state_hash(&self) -> PyResult<u64> {
+        let replica = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?;
+
+        Ok(engine_state_hash(replica.replica_engine_state()))
+    }
+
+    /// Current open positions, one dictionary per instrument with a non-flat position.
+    ///
+    /// Positions are read from an engine state replica maintained from the audit stream, so an
+    /// event the engine has only just processed may take a moment to be reflected. Returns an
+    /// empty list when flat.
+    pub fn positions(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let replica = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?;
+
+        replica
+            .replica_engine_state()
+            .instruments
+            .0
+            .values()
+            .filter_map(|state| {
+                state
+                    .position
+                    .current
+                    .as_ref()
+                    .map(|position| position_to_py(py, &state.instrument.name_internal, position))
+            })
+            .collect()
+    }
+
+    /// Gross & net exposure of the current open positions, per instrument and in aggregate.
+    ///
+    /// Each position's notional is its quantity, contract size and the instrument's last known
+    /// market price, falling back to the average entry price if no market data has been seen.
+    /// Gross exposure sums absolute notionals, while net exposure counts short positions
+    /// negatively. Like [`Self::positions`], this is read from the engine state replica.
+    pub fn exposure(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let replica = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?;
+
+        let mut gross_total = Decimal::ZERO;
+        let mut net_total = Decimal::ZERO;
+        let instruments = PyList::empty_bound(py);
+
+        for state in replica.replica_engine_state().instruments.0.values() {
+            let Some(position) = state.position.current.as_ref() else {
+                continue;
+            };
+
+            let price = state.data.price().unwrap_or(position.price_entry_average);
+            let gross = position.quantity_abs * state.instrument.kind.contract_size() * price;
+            let net = match position.side {
+                Side::Buy => gross,
+                Side::Sell => -gross,
+            };
+            gross_total += gross;
+            net_total += net;
+
+            let entry = PyDict::new_bound(py);
+            entry.set_item(
+                "instrument",
+                Py::new(py, PyInstrumentIndex::from_inner(position.instrument))?,
+            )?;
+            entry.set_item("instrument_name", state.instrument.name_internal.as_ref())?;
+            entry.set_item("price", decimal_to_py(py, price)?)?;
+            entry.set_item("gross", decimal_to_py(py, gross)?)?;
+            entry.set_item("net", decimal_to_py(py, net)?)?;
+            instruments.append(entry)?;
+        }
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("instruments", instruments)?;
+        dict.set_item("gross", decimal_to_py(py, gross_total)?)?;
+        dict.set_item("net", decimal_to_py(py, net_total)?)?;
+        Ok(dict.into_py(py))
+    }
+
     /// Send open order requests to the engine.
-    #[pyo3(signature = (requests))]
+    ///
+    /// If `validate` is `True`, every request is first checked against the spec of its
+    /// instrument (price tick size, quantity increment and minimum notional), and nothing is
+    /// sent if any request violates it.
+    #[pyo3(signature = (requests, validate = false))]
     pub fn send_open_requests(
         &self,
         py: Python<'_>,
         requests: Vec<Py<PyOrderRequestOpen>>,
+        validate: bool,
     ) -> PyResult<()> {
+        if validate {
+            self.validate_open_requests(py, &requests)?;
+        }
+
         let event = PyEngineEvent::send_open_requests(py, requests)?;
         self.send_event(&event)
     }
@@ -1196,9 +1850,130 @@ impl PySystemHandle {
             TradingState::Disabled
         };
         system.trading_state(state);
+        drop(guard);
+
+        self.emit_event_metric("trading_state")
+    }
+
+    /// Toggle algorithmic trading on or off for a single instrument, leaving every other
+    /// instrument (and the system wide trading state) untouched.
+    ///
+    /// While disabled, algorithmic orders generated for the instrument by the `algo_strategy` of
+    /// `start_system` are dropped. Requests sent directly via this handle are unaffected.
+    pub fn set_instrument_trading_enabled(&self, instrument: usize, enabled: bool) -> PyResult<()> {
+        let instrument_count = self
+            .state
+            .lock()
+            .map_err(|_| PyValueError::new_err("system state replica poisoned"))?
+            .replica_engine_state()
+            .instruments
+            .0
+            .len();
+        if instrument >= instrument_count {
+            return Err(PyValueError::new_err(format!(
+                "instrument {instrument} is out of range for {instrument_count} instruments"
+            )));
+        }
+
+        let guard = self.lock_system()?;
+        let system = guard.as_ref().ok_or_else(Self::system_not_running_err)?;
+
+        let state = if enabled {
+            TradingState::Enabled
+        } else {
+            TradingState::Disabled
+        };
+        system.instrument_trading_state(InstrumentIndex(instrument), state);
+        drop(guard);
+
+        self.emit_event_metric("command")
+    }
+
+    /// Record every event subsequently processed by the engine to `path`, so the exact event
+    /// sequence of a session can be replayed later.
+    ///
+    /// `format` is either `"binary"` (a versioned header followed by length prefixed
+    /// MessagePack frames) or `"json"` (one event per line). Any recording already in progress is
+    /// stopped first.
+    #[pyo3(signature = (path, format = "binary"))]
+    pub fn record_events(&self, path: PathBuf, format: &str) -> PyResult<()> {
+        let format = EventLogFormat::parse(format)?;
+        if self.lock_system()?.is_none() {
+            return Err(Self::system_not_running_err());
+        }
+
+        let recorder = EventRecorder::create(&path, format)
+            .map_err(|err| PyValueError::new_err(format!("invalid record_events path: {err}")))?;
+
+        let previous = self.lock_recorder()?.replace(recorder);
+        if let Some(previous) = previous {
+            previous
+                .finish()
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        }
+
         Ok(())
     }
 
+    /// Stop recording engine events, flushing the event log to disk.
+    ///
+    /// Returns the number of events recorded, or `None` if no recording was in progress.
+    pub fn stop_recording(&self) -> PyResult<Option<u64>> {
+        let Some(recorder) = self.lock_recorder()?.take() else {
+            return Ok(None);
+        };
+
+        recorder
+            .finish()
+            .map(Some)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Replay a recorded market data file into the running system, paced by the gaps between
+    /// event `time_exchange` values divided by `speed` (as fast as possible if `None`).
+    ///
+    /// Returns a [`PyPaperReplay`] controlling the replay, which may begin paused so events can
+    /// be released one at a time with [`PyPaperReplay::step`].
+    #[pyo3(signature = (market_data_path, speed=None, start_paused=false))]
+    pub fn replay_market_data(
+        &self,
+        market_data_path: &str,
+        speed: Option<f64>,
+        start_paused: bool,
+    ) -> PyResult<PyPaperReplay> {
+        let speed = parse_replay_speed(speed)?;
+        let (_, events) = load_historic_market_events(Path::new(market_data_path))?;
+
+        let guard = self.lock_system()?;
+        let system = guard.as_ref().ok_or_else(Self::system_not_running_err)?;
+        let feed_tx = system.feed_tx.clone();
+        drop(guard);
+
+        let control = Arc::new(PaperReplayControl::new(start_paused));
+        let task = self.runtime.spawn(run_paper_replay(
+            Arc::clone(&control),
+            feed_tx,
+            events,
+            speed,
+            self.stale_filter.clone(),
+        ));
+
+        Ok(PyPaperReplay {
+            runtime: Arc::clone(&self.runtime),
+            control,
+            task: Mutex::new(Some(task)),
+        })
+    }
+
+    /// Number of stale market events dropped because their `time_exchange` was more than
+    /// `max_message_age_secs` older than their `time_received`.
+    #[getter]
+    pub fn stale_events_dropped(&self) -> u64 {
+        self.stale_filter
+            .as_ref()
+            .map_or(0, StaleEventFilter::dropped)
+    }
+
     /// Gracefully shut down the system.
     pub fn shutdown(&self, py: Python<'_>) -> PyResult<()> {
         let system = self.take_system()?;
@@ -1210,6 +1985,57 @@ impl PySystemHandle {
         }
     }
 
+    /// Shut down the system gracefully, aborting it if that does not complete within `seconds`.
+    ///
+    /// Returns `True` if the graceful shutdown completed in time, or `False` if the engine and
+    /// auxiliary tasks were aborted instead. Either way the system is no longer running.
+    #[pyo3(signature = (seconds))]
+    pub fn shutdown_timeout(&self, py: Python<'_>, seconds: f64) -> PyResult<bool> {
+        if seconds.is_sign_negative() || !seconds.is_finite() {
+            return Err(PyValueError::new_err(
+                "seconds must be a non-negative finite number",
+            ));
+        }
+        let timeout = duration_from_secs(seconds, "seconds")?;
+
+        let system = self.take_system()?;
+        let runtime = Arc::clone(&self.runtime);
+
+        // Captured up front, since a timed out shutdown future drops (detaching) its handles.
+        let SystemAuxillaryHandles {
+            execution,
+            market_to_engine,
+            account_to_engine,
+        } = &system.handles;
+        let abort_handles = std::iter::once(system.engine.abort_handle())
+            .chain([
+                market_to_engine.abort_handle(),
+                account_to_engine.abort_handle(),
+            ])
+            .chain(
+                execution
+                    .mock_exchanges
+                    .iter()
+                    .chain(&execution.managers)
+                    .chain(&execution.account_to_engines)
+                    .map(JoinHandle::abort_handle),
+            )
+            .collect::<Vec<_>>();
+
+        let shutdown =
+            py.allow_threads(|| runtime.block_on(tokio::time::timeout(timeout, system.shutdown())));
+
+        match shutdown {
+            Ok(Ok((_engine, _audit))) => Ok(true),
+            Ok(Err(err)) => Err(PyValueError::new_err(err.to_string())),
+            Err(_elapsed) => {
+                warn!(?timeout, "graceful system shutdown timed out, aborting");
+                abort_handles.iter().for_each(AbortHandle::abort);
+                Ok(false)
+            }
+        }
+    }
+
     /// Abort the system without waiting for a graceful shutdown.
     pub fn abort(&self, py: Python<'_>) -> PyResult<()> {
         let system = self.take_system()?;
@@ -1270,6 +2096,58 @@ impl PySystemHandle {
 }
 
 /// Start a live or paper trading system using the provided configuration.
+///
+/// If an `algo_strategy` callable is provided, the engine calls it after each processed event
+/// while trading is enabled with a list of instrument dicts (`exchange`, `instrument`, `price` and
+/// `trading_enabled`), and sends the `OrderRequestOpen` & `OrderRequestCancel` requests it returns
+/// as algorithmic orders. Without one, the engine generates no algorithmic orders.
+///
+/// If a `risk_manager` is provided it reviews the algorithmic orders generated by the engine,
+/// otherwise all orders are approved. If `max_open_positions` is provided, orders that would open
+/// a position while that many positions are already open are refused, while orders for
+/// instruments with an open position are still permitted. If `max_position_quantity` (a mapping of
+/// instrument index to maximum absolute net position quantity) is provided, orders that would take
+/// the net position of an instrument beyond its limit are refused, while orders reducing the
+/// absolute net position are still permitted.
+///
+/// Orders for an instrument within the `exit_cooldown_secs` risk limit of its last position exit
+/// are refused, with the per-instrument limit taking precedence over the global limit.
+///
+/// If `max_orders_per_second` is provided, orders beyond that many per exchange within any one
+/// second are refused with an `"order rate limit exceeded"` reason, rather than being sent on to
+/// the exchange.
+///
+/// With `audit=True` the engine audit stream is available via `SystemHandle.take_audit`. Setting
+/// `audit_skip_empty` drops ticks whose processed event produced no outputs and no errors, such
+/// as quiet market events, from that stream.
+///
+/// The engine always runs with auditing enabled, regardless of `audit`, since
+/// `SystemHandle.positions`, `open_orders`, `exposure`, `state_hash` and the heartbeat read from an
+/// engine state replica maintained from the audit stream. This costs a clone of each processed
+/// event and its outputs, plus replaying them onto the replica off the engine loop. With
+/// `audit=False` the ticks are only applied to that replica and `take_audit` returns `None`.
+///
+/// If a `heartbeat_callback` is provided it is called with a `heartbeat` [`PyMetric`] every
+/// `heartbeat_interval_secs` (default 1s), carrying the system uptime, the number of events
+/// processed by the engine and whether trading is enabled. Callbacks run off the engine loop.
+///
+/// When an execution (account) link disconnects, the engine cancels that exchange's open orders
+/// if `cancel_orders_on_disconnect` is set, and disables trading if
+/// `disable_trading_on_disconnect` is set, so it does not keep trading while blind to its account.
+///
+/// When a market data link disconnects, the `on_market_disconnect` policy decides whether the
+/// engine carries on (`"continue"`, the default), generates no algorithmic orders until every
+/// feed reconnects (`"pause"`), or closes open positions on that exchange (`"flatten"`). While
+/// paused the `algo_strategy` is not called, and feeds count as reconnecting until their first
+/// market event.
+///
+/// If `max_message_age_secs` is provided, market events whose `time_exchange` is older than
+/// their `time_received` by more than that age are dropped rather than acted upon, such as data
+/// replayed after a reconnect. The count is exposed as `SystemHandle.stale_events_dropped`.
+///
+/// `engine_clock` selects how the engine tells the time: `"live"` (the default) uses wall-clock
+/// time, while `"event"` advances to the `time_exchange` of each market event processed, as in
+/// a backtest, for paper trading that behaves identically to its backtest.
 #[pyfunction]
 #[pyo3(
     signature = (
@@ -1278,17 +2156,80 @@ impl PySystemHandle {
         trading_enabled = true,
         initial_balances = None,
         audit = false,
-        engine_feed_mode = None
+        audit_skip_empty = false,
+        engine_feed_mode = None,
+        algo_strategy = None,
+        risk_manager = None,
+        max_open_positions = None,
+        max_position_quantity = None,
+        max_orders_per_second = None,
+        heartbeat_callback = None,
+        heartbeat_interval_secs = None,
+        disable_trading_on_disconnect = false,
+        cancel_orders_on_disconnect = false,
+        on_market_disconnect = None,
+        max_message_age_secs = None,
+        engine_clock = None
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn start_system(
     py: Python<'_>,
     config: &PySystemConfig,
     trading_enabled: bool,
     initial_balances: Option<PyObject>,
     audit: bool,
+    audit_skip_empty: bool,
     engine_feed_mode: Option<&str>,
+    algo_strategy: Option<PyObject>,
+    risk_manager: Option<PyRef<'_, PyCallbackRiskManager>>,
+    max_open_positions: Option<usize>,
+    max_position_quantity: Option<&Bound<'_, PyDict>>,
+    max_orders_per_second: Option<usize>,
+    heartbeat_callback: Option<PyObject>,
+    heartbeat_interval_secs: Option<f64>,
+    disable_trading_on_disconnect: bool,
+    cancel_orders_on_disconnect: bool,
+    on_market_disconnect: Option<&str>,
+    max_message_age_secs: Option<f64>,
+    engine_clock: Option<&str>,
 ) -> PyResult<PySystemHandle> {
+    let max_open_positions = max_open_positions
+        .map(validate_max_open_positions)
+        .transpose()?;
+    let max_position_quantity = max_position_quantity
+        .map(parse_max_position_quantity)
+        .transpose()?
+        .unwrap_or_default();
+    if audit_skip_empty && !audit {
+        return Err(PyValueError::new_err(
+            "audit_skip_empty requires audit=True",
+        ));
+    }
+    let audit = audit.then_some(if audit_skip_empty {
+        AuditFilter::SkipEmpty
+    } else {
+        AuditFilter::All
+    });
+    let order_rate_limiter = max_orders_per_second
+        .map(OrderRateLimiter::new)
+        .transpose()?;
+    let heartbeat = Heartbeat::new(heartbeat_callback, heartbeat_interval_secs)?;
+    let on_account_disconnect = AccountDisconnectPolicy {
+        disable_trading: disable_trading_on_disconnect,
+        cancel_orders: cancel_orders_on_disconnect,
+    };
+    let on_market_disconnect = on_market_disconnect
+        .map(MarketDisconnectPolicy::parse)
+        .transpose()?
+        .unwrap_or_default();
+    let strategy = SystemStrategy::new(on_account_disconnect, on_market_disconnect)
+        .with_algo_strategy(algo_strategy)?;
+    let stale_filter = max_message_age_secs
+        .map(|secs| StaleEventFilter::new(secs, "max_message_age_secs"))
+        .transpose()?;
+    let clock = SystemClock::parse(engine_clock)?;
+
     let runtime = Arc::new(
         RuntimeBuilder::new_multi_thread()
             .enable_all()
@@ -1299,12 +2240,6 @@ pub fn start_system(
     let seeded_balances = parse_initial_balances(py, initial_balances)?;
     let feed_mode = parse_engine_feed_mode(engine_feed_mode)?;
 
-    let audit_mode = if audit {
-        AuditMode::Enabled
-    } else {
-        AuditMode::Disabled
-    };
-
     let mut config_inner = config.clone_inner();
 
     // Clear initial balances from executions to allow seeded balances to take precedence
@@ -1315,15 +2250,24 @@ pub fn start_system(
         }
     }
 
+    let exit_cooldowns = exit_cooldowns(&config_inner.risk, config_inner.instruments.len())?;
     let instruments = IndexedInstruments::new(config_inner.instruments.drain(..));
     let market_stream = stream::pending::<MarketStreamEvent<InstrumentIndex, DataKind>>();
+    let risk_manager = risk_manager
+        .map(|risk_manager| risk_manager.risk_manager(py))
+        .unwrap_or_default()
+        .with_max_open_positions(max_open_positions)
+        .with_max_position_quantity(max_position_quantity)
+        .with_exit_cooldowns(exit_cooldowns)
+        .with_order_rate_limiter(order_rate_limiter)
+        .with_clock(clock.clone());
 
     let args = SystemArgs::new(
         &instruments,
         config_inner.executions,
-        LiveClock,
-        DefaultStrategy::default(),
-        DefaultRiskManager::default(),
+        clock,
+        strategy,
+        risk_manager,
         market_stream,
         DefaultGlobalData,
         |_| DefaultInstrumentMarketData::default(),
@@ -1337,7 +2281,7 @@ pub fn start_system(
 
     let system_build = SystemBuilder::new(args)
         .engine_feed_mode(feed_mode)
-        .audit_mode(audit_mode)
+        .audit_mode(AuditMode::Enabled)
         .trading_state(trading_state)
         .balances(seeded_balances)
         .build::<EngineEvent, _>()
@@ -1347,37 +2291,352 @@ pub fn start_system(
         .block_on(system_build.init_with_runtime(runtime.handle().clone()))
         .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
-    Ok(PySystemHandle::new(runtime, system))
+    PySystemHandle::new(runtime, system, audit, heartbeat, stale_filter)
 }
 
-fn run_historic_backtest_inner(
-    py: Python<'_>,
-    config: &PySystemConfig,
-    market_data_path: &str,
-    risk_free_return: f64,
-    interval: Option<&str>,
-    initial_balances: Option<PyObject>,
-    engine_feed_mode: Option<&str>,
-) -> PyResult<(Py<PyTradingSummary>, TradingSummaryGenerator)> {
-    let (clock, market_stream) =
-        load_historic_clock_and_market_stream(Path::new(market_data_path))?;
+/// Periodic liveness [`PyMetric`] delivered to a Python callback while a system is running.
+#[derive(Debug)]
+struct Heartbeat {
+    callback: Arc<PyObject>,
+    interval: Duration,
+}
 
-    let seeded_balances = parse_initial_balances(py, initial_balances)?;
-    let feed_mode = parse_engine_feed_mode(engine_feed_mode)?;
+impl Heartbeat {
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
 
-    let mut config_inner = config.clone_inner();
+    fn new(callback: Option<PyObject>, interval_secs: Option<f64>) -> PyResult<Option<Self>> {
+        let interval = match interval_secs {
+            Some(secs) if secs.is_finite() && secs > 0.0 => {
+                duration_from_secs(secs, "heartbeat_interval_secs")?
+            }
+            Some(_) => {
+                return Err(PyValueError::new_err(
+                    "heartbeat_interval_secs must be a positive number",
+                ));
+            }
+            None => Self::DEFAULT_INTERVAL,
+        };
 
-    // Clear initial balances from executions to allow seeded balances to take precedence
-    if !seeded_balances.is_empty() {
-        for execution in &mut config_inner.executions {
-            let ExecutionConfig::Mock(mock) = execution;
-            mock.initial_state.balances.clear();
+        match (callback, interval_secs) {
+            (Some(callback), _) => Ok(Some(Self {
+                callback: Arc::new(callback),
+                interval,
+            })),
+            (None, Some(_)) => Err(PyValueError::new_err(
+                "heartbeat_interval_secs requires a heartbeat_callback",
+            )),
+            (None, None) => Ok(None),
         }
     }
-    let instruments = IndexedInstruments::new(config_inner.instruments.drain(..));
 
-    let args = SystemArgs::new(
-        &instruments,
+    /// Emit a heartbeat every interval, reading engine progress from the state `replica`.
+    ///
+    /// The callback is invoked on a blocking thread so acquiring the GIL never stalls the
+    /// runtime driving the engine.
+    async fn run(self, replica: Arc<Mutex<TradingStateReplica>>) {
+        let started = tokio::time::Instant::now();
+        let mut ticks = tokio::time::interval_at(started + self.interval, self.interval);
+        ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticks.tick().await;
+
+            let Ok((events_processed, trading_enabled)) = replica.lock().map(|replica| {
+                (
+                    replica.state_replica.context.sequence.value(),
+                    replica.replica_engine_state().trading == TradingState::Enabled,
+                )
+            }) else {
+                warn!("system state replica poisoned, stopping heartbeat");
+                return;
+            };
+
+            let metric = PyMetric::new(
+                "heartbeat".to_string(),
+                Utc::now().timestamp_millis().unsigned_abs(),
+                Vec::new(),
+                vec![
+                    PyField::new(
+                        "uptime_secs".to_string(),
+                        PyValue::float(started.elapsed().as_secs_f64()),
+                    ),
+                    PyField::new(
+                        "events_processed".to_string(),
+                        PyValue::uint(events_processed),
+                    ),
+                    PyField::new(
+                        "trading_enabled".to_string(),
+                        PyValue::bool(trading_enabled),
+                    ),
+                ],
+            );
+
+            let callback = Arc::clone(&self.callback);
+            let delivered = tokio::task::spawn_blocking(move || {
+                Python::with_gil(|py| metric.and_then(|metric| callback.call1(py, (metric,))))
+            })
+            .await;
+
+            match delivered {
+                Ok(Ok(_)) => {}
+                Ok(Err(error)) => warn!(%error, "heartbeat_callback raised, continuing"),
+                Err(error) => warn!(%error, "heartbeat_callback task failed"),
+            }
+        }
+    }
+}
+
+/// Engine audit ticks forwarded to the [`PySystemHandle::take_audit`] stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuditFilter {
+    /// Forward every tick.
+    All,
+    /// Skip ticks whose processed event produced no outputs and no errors.
+    SkipEmpty,
+}
+
+impl AuditFilter {
+    fn forwards(&self, tick: &TradingAuditTick) -> bool {
+        match (self, &tick.event) {
+            (Self::SkipEmpty, EngineAudit::Process(audit)) => {
+                !audit.outputs.is_empty() || !audit.errors.is_empty()
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Maintain the `replica` [`EngineState`] from the engine audit `updates`, forwarding the ticks
+/// accepted by the [`AuditFilter`] to `forward` if audit updates were requested.
+///
+/// The replica is updated from every tick, regardless of the filter.
+async fn replicate_engine_state(
+    replica: Arc<Mutex<TradingStateReplica>>,
+    mut updates: UnboundedRx<TradingAuditTick>,
+    forward: Option<(UnboundedTx<TradingAuditTick>, AuditFilter)>,
+    recorder: Arc<Mutex<Option<EventRecorder>>>,
+) {
+    while let Some(tick) = updates.rx.recv().await {
+        if let EngineAudit::Process(audit) = &tick.event
+            && let Ok(mut state) = replica.lock()
+            && state.state_replica.context.sequence < tick.context.sequence
+        {
+            state.state_replica.context = tick.context;
+            state.update_from_event(audit.event.clone());
+            for output in &audit.outputs {
+                record_sent_requests(&mut state.state_replica.event, output);
+            }
+        }
+
+        if let EngineAudit::Process(audit) = &tick.event
+            && let Ok(mut recording) = recorder.lock()
+            && let Some(active) = recording.as_mut()
+            && let Err(error) = active.record(&audit.event)
+        {
+            warn!(%error, "failed to record engine event, stopping recording");
+            *recording = None;
+        }
+
+        if let Some((forward, filter)) = &forward
+            && filter.forwards(&tick)
+            && forward.send(tick).is_err()
+        {
+            warn!("audit updates receiver dropped");
+        }
+    }
+}
+
+/// Record the order requests the engine sent for execution as in-flight, mirroring the tracking
+/// the engine performs when actioning them (the replica only sees the triggering event).
+///
+/// Likewise applies any [`DisconnectPolicyOutput`] trading state change to the replica.
+fn record_sent_requests<OnTradingDisabled>(
+    state: &mut DefaultEngineState,
+    output: &EngineOutput<OnTradingDisabled, DisconnectPolicyOutput>,
+) {
+    let (cancels, opens) = match output {
+        EngineOutput::Commanded(ActionOutput::CancelOrders(cancels)) => (Some(cancels), None),
+        EngineOutput::Commanded(ActionOutput::OpenOrders(opens)) => (None, Some(opens)),
+        EngineOutput::Commanded(ActionOutput::ClosePositions(output))
+        | EngineOutput::Commanded(ActionOutput::GenerateAlgoOrders(GenerateAlgoOrdersOutput {
+            cancels_and_opens: output,
+            ..
+        }))
+        | EngineOutput::AlgoOrders(GenerateAlgoOrdersOutput {
+            cancels_and_opens: output,
+            ..
+        }) => (Some(&output.cancels), Some(&output.opens)),
+        EngineOutput::AccountDisconnect(output) | EngineOutput::MarketDisconnect(output) => {
+            if output.trading_disabled {
+                state.trading.update(TradingState::Disabled);
+            }
+            if let Some(closes) = &output.closes {
+                state.record_in_flight_cancels(&closes.cancels.sent);
+            }
+            (
+                output.cancels.as_ref(),
+                output.closes.as_ref().map(|closes| &closes.opens),
+            )
+        }
+        EngineOutput::Commanded(ActionOutput::InstrumentTradingState(..))
+        | EngineOutput::OnTradingDisabled(_)
+        | EngineOutput::PositionExit(_) => (None, None),
+    };
+
+    if let Some(cancels) = cancels {
+        state.record_in_flight_cancels(&cancels.sent);
+    }
+    if let Some(opens) = opens {
+        state.record_in_flight_opens(&opens.sent);
+    }
+}
+
+fn position_to_py(
+    py: Python<'_>,
+    name: &InstrumentNameInternal,
+    position: &Position<QuoteAsset, InstrumentIndex>,
+) -> PyResult<PyObject> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item(
+        "instrument",
+        Py::new(py, PyInstrumentIndex::from_inner(position.instrument))?,
+    )?;
+    dict.set_item("instrument_name", name.as_ref())?;
+    dict.set_item("side", Py::new(py, PySide::from_side(position.side))?)?;
+    dict.set_item("quantity", decimal_to_py(py, position.quantity_abs)?)?;
+    dict.set_item(
+        "price_entry_average",
+        decimal_to_py(py, position.price_entry_average)?,
+    )?;
+    dict.set_item(
+        "pnl_unrealised",
+        decimal_to_py(py, position.pnl_unrealised)?,
+    )?;
+    dict.set_item("pnl_realised", decimal_to_py(py, position.pnl_realised)?)?;
+    dict.set_item("time_enter", timestamp_str_to_py(py, position.time_enter)?)?;
+    dict.set_item(
+        "time_exchange_update",
+        timestamp_str_to_py(py, position.time_exchange_update)?,
+    )?;
+    Ok(dict.into_py(py))
+}
+
+/// Kind of an [`EngineEvent`], as tagged on `engine_event` metrics.
+fn engine_event_metric_kind(event: &EngineEvent) -> &'static str {
+    match event {
+        EngineEvent::Shutdown(_) => "shutdown",
+        EngineEvent::Command(_) => "command",
+        EngineEvent::TradingStateUpdate(_) => "trading_state",
+        EngineEvent::Account(_) => "account",
+        EngineEvent::Market(_) => "market",
+    }
+}
+
+/// Resolve the `exit_cooldown_secs` risk limit of each configured instrument, falling back to the
+/// global limit.
+fn exit_cooldowns(
+    risk: &RiskConfiguration,
+    instruments: usize,
+) -> PyResult<HashMap<InstrumentIndex, TimeDelta>> {
+    let global = risk.global().and_then(|limits| limits.exit_cooldown_secs);
+
+    (0..instruments)
+        .filter_map(|index| {
+            let secs = risk
+                .instrument_limits(index)
+                .and_then(|limits| limits.exit_cooldown_secs)
+                .or(global)?;
+
+            let cooldown = secs
+                .checked_mul(Decimal::ONE_THOUSAND)
+                .and_then(|millis| millis.round().to_i64())
+                .and_then(TimeDelta::try_milliseconds)
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!("exit_cooldown_secs {secs} is out of range"))
+                });
+
+            Some(cooldown.map(|cooldown| (InstrumentIndex(index), cooldown)))
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_historic_backtest_inner(
+    py: Python<'_>,
+    config: &PySystemConfig,
+    market_data_path: &str,
+    risk_free_return: f64,
+    interval: Option<&str>,
+    initial_balances: Option<PyObject>,
+    engine_feed_mode: Option<&str>,
+    speed: Option<f64>,
+    progress: Option<ReplayProgress>,
+    checkpointer: Option<BacktestCheckpointer>,
+    resume: Option<BacktestCheckpoint>,
+) -> PyResult<(Py<PyTradingSummary>, TradingSummaryGenerator)> {
+    let speed = parse_replay_speed(speed)?;
+    let (clock, market_stream) = load_historic_clock_and_market_stream(
+        Path::new(market_data_path),
+        speed,
+        progress,
+        resume.as_ref().map(|checkpoint| checkpoint.time_exchange),
+    )?;
+
+    run_backtest_with_market_stream(
+        py,
+        config,
+        clock,
+        market_stream,
+        risk_free_return,
+        interval,
+        initial_balances,
+        engine_feed_mode,
+        checkpointer,
+        resume,
+    )
+}
+
+/// Run a backtest [`System`] to completion over the provided historical `market_stream`.
+#[allow(clippy::too_many_arguments)]
+fn run_backtest_with_market_stream<MarketStream>(
+    py: Python<'_>,
+    config: &PySystemConfig,
+    clock: HistoricalClock,
+    market_stream: MarketStream,
+    risk_free_return: f64,
+    interval: Option<&str>,
+    initial_balances: Option<PyObject>,
+    engine_feed_mode: Option<&str>,
+    checkpointer: Option<BacktestCheckpointer>,
+    resume: Option<BacktestCheckpoint>,
+) -> PyResult<(Py<PyTradingSummary>, TradingSummaryGenerator)>
+where
+    MarketStream: Stream<Item = MarketStreamEvent<InstrumentIndex, DataKind>> + Send + 'static,
+{
+    let seeded_balances = parse_initial_balances(py, initial_balances)?;
+    let feed_mode = parse_engine_feed_mode(engine_feed_mode)?;
+
+    let mut config_inner = config.clone_inner();
+
+    // Clear initial balances from executions to allow seeded balances to take precedence
+    if !seeded_balances.is_empty() {
+        for execution in &mut config_inner.executions {
+            let ExecutionConfig::Mock(mock) = execution;
+            mock.initial_state.balances.clear();
+        }
+    }
+
+    // Restore mocked exchange balances to match the checkpointed EngineState
+    if let Some(resume) = &resume {
+        for execution in &mut config_inner.executions {
+            let ExecutionConfig::Mock(mock) = execution;
+            mock.initial_state.balances = resume.balances(mock.mocked_exchange);
+        }
+    }
+    let instruments = IndexedInstruments::new(config_inner.instruments.drain(..));
+
+    let args = SystemArgs::new(
+        &instruments,
         config_inner.executions,
         clock,
         DefaultStrategy::default(),
@@ -1392,20 +2651,49 @@ fn run_historic_backtest_inner(
         .build()
         .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
-    let system_build = SystemBuilder::new(args)
+    // Checkpoints are built from a replica EngineState maintained from the audit stream
+    let audit_mode = if checkpointer.is_some() {
+        AuditMode::Enabled
+    } else {
+        AuditMode::Disabled
+    };
+
+    let mut system_build = SystemBuilder::new(args)
         .engine_feed_mode(feed_mode)
-        .audit_mode(AuditMode::Disabled)
+        .audit_mode(audit_mode)
         .trading_state(TradingState::Enabled)
+        .tear_sheet_history(true)
         .balances(seeded_balances)
         .build::<EngineEvent, _>()
         .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
-    let system = runtime
-        .block_on(system_build.init_with_runtime(runtime.handle().clone()))
-        .map_err(|err| PyValueError::new_err(err.to_string()))?;
-
-    let (engine, _audit) = runtime
-        .block_on(system.shutdown_after_backtest())
+    if let Some(resume) = resume {
+        system_build.engine.state = resume.into_state();
+    }
+
+    // Release the GIL while the backtest runs so a progress_callback can acquire it
+    let (engine, _audit) = py
+        .allow_threads(|| {
+            runtime.block_on(async {
+                let mut system = system_build
+                    .init_with_runtime(runtime.handle().clone())
+                    .await?;
+
+                let recorder =
+                    system
+                        .take_audit()
+                        .zip(checkpointer)
+                        .map(|(audit, checkpointer)| {
+                            tokio::spawn(checkpointer.run(audit.snapshot, audit.updates))
+                        });
+
+                let shutdown = system.shutdown_after_backtest().await?;
+                if let Some(recorder) = recorder {
+                    recorder.await?;
+                }
+                Ok::<_, BarterError>(shutdown)
+            })
+        })
         .map_err(|err| PyValueError::new_err(err.to_string()))?;
 
     let decimal_rfr = parse_risk_free_return(risk_free_return)?;
@@ -1422,6 +2710,25 @@ fn run_historic_backtest_inner(
 }
 
 /// Run a historic backtest using a [`SystemConfig`] and market data events encoded as JSON.
+///
+/// `market_data_path` may also be an event log written by [`PySystemHandle::record_events`]
+/// (either format), in which case only its recorded market events are replayed. Recorded
+/// commands, trading state updates and account events are not replayed, and the backtest runs
+/// the default strategy & risk manager rather than those of the recorded session, so the
+/// result reflects the recorded market data rather than reproducing the session itself.
+///
+/// `speed` paces market event replay relative to the gaps between consecutive
+/// `time_exchange` values (eg/ `2.0` replays at twice real time). `None` replays as fast
+/// as possible.
+///
+/// `progress_callback` is invoked as `callback(events_processed, time_exchange)` every
+/// `progress_interval` market events. Exceptions raised by the callback are logged and the
+/// backtest continues.
+///
+/// If `checkpoint_path` is provided, the engine state is written to it as JSON every
+/// `checkpoint_interval` market events. Passing that file as `resume_from` restores the engine
+/// state and mocked exchange balances, and skips market events at or before the checkpoint
+/// `time_exchange`.
 #[pyfunction]
 #[pyo3(
     signature = (
@@ -1430,9 +2737,16 @@ fn run_historic_backtest_inner(
         risk_free_return = 0.05,
         interval = None,
         initial_balances = None,
-        engine_feed_mode = None
+        engine_feed_mode = None,
+        speed = None,
+        progress_callback = None,
+        progress_interval = 1000,
+        checkpoint_path = None,
+        checkpoint_interval = None,
+        resume_from = None
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn run_historic_backtest(
     py: Python<'_>,
     config: &PySystemConfig,
@@ -1441,7 +2755,28 @@ pub fn run_historic_backtest(
     interval: Option<&str>,
     initial_balances: Option<PyObject>,
     engine_feed_mode: Option<&str>,
+    speed: Option<f64>,
+    progress_callback: Option<PyObject>,
+    progress_interval: usize,
+    checkpoint_path: Option<PathBuf>,
+    checkpoint_interval: Option<u64>,
+    resume_from: Option<PathBuf>,
 ) -> PyResult<Py<PyTradingSummary>> {
+    let progress = progress_callback
+        .map(|callback| ReplayProgress::new(callback, progress_interval))
+        .transpose()?;
+    let resume = resume_from
+        .as_deref()
+        .map(BacktestCheckpoint::read)
+        .transpose()?;
+    let checkpointer = BacktestCheckpointer::new(
+        checkpoint_path,
+        checkpoint_interval,
+        resume
+            .as_ref()
+            .map_or(0, |checkpoint| checkpoint.events_processed),
+    )?;
+
     let (summary, _) = run_historic_backtest_inner(
         py,
         config,
@@ -1450,6 +2785,10 @@ pub fn run_historic_backtest(
         interval,
         initial_balances,
         engine_feed_mode,
+        speed,
+        progress,
+        checkpointer,
+        resume,
     )?;
 
     Ok(summary)
@@ -1484,12 +2823,66 @@ pub fn run_historic_backtest_with_generator(
         interval,
         initial_balances,
         engine_feed_mode,
+        None,
+        None,
+        None,
+        None,
     )?;
 
     let generator = PyTradingSummaryGenerator::from_inner(py, generator)?;
     Ok((summary, generator))
 }
 
+/// Run a backtest over market events constructed in Python (eg/ via
+/// `EngineEvent.market_trade`), entirely in memory.
+///
+/// The historical clock starts at the `time_exchange` of the first market item. Events other
+/// than market events are ignored.
+#[pyfunction]
+#[pyo3(signature = (config, events, risk_free_return = 0.05, interval = None))]
+pub fn run_backtest_from_events(
+    py: Python<'_>,
+    config: &PySystemConfig,
+    events: Vec<PyRef<'_, PyEngineEvent>>,
+    risk_free_return: f64,
+    interval: Option<&str>,
+) -> PyResult<Py<PyTradingSummary>> {
+    if events.is_empty() {
+        return Err(PyValueError::new_err("events must not be empty"));
+    }
+
+    let market_events = events
+        .iter()
+        .filter_map(|event| match &event.inner {
+            EngineEvent::Market(market) => Some(market.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let time_exchange_first = market_events
+        .iter()
+        .find_map(|event| match event {
+            Event::Item(item) => Some(item.time_exchange),
+            Event::Reconnecting(_) => None,
+        })
+        .ok_or_else(|| PyValueError::new_err("events contain no market items"))?;
+
+    let (summary, _) = run_backtest_with_market_stream(
+        py,
+        config,
+        HistoricalClock::new(time_exchange_first),
+        futures::stream::iter(market_events),
+        risk_free_return,
+        interval,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    Ok(summary)
+}
+
 fn build_py_snapupdates(
     py: Python<'_>,
     runtime: Arc<Runtime>,
@@ -1554,7 +2947,7 @@ fn context_to_py(py: Python<'_>, context: &EngineContext) -> PyResult<Py<PyDict>
     let sequence = PySequence::from_inner(context.sequence);
     let sequence = Py::new(py, sequence)?;
     dict.set_item("sequence", sequence)?;
-    dict.set_item("time", context.time.to_rfc3339())?;
+    dict.set_item("time", timestamp_str_to_py(py, context.time)?)?;
     Ok(dict.into())
 }
 
@@ -1760,53 +3153,638 @@ fn order_request_cancel_to_py(
     Py::new(py, wrapper).map(|value| value.into_py(py))
 }
 
-fn parse_risk_free_return(value: f64) -> PyResult<Decimal> {
-    Decimal::from_f64(value).ok_or_else(|| PyValueError::new_err("risk_free_return must be finite"))
+fn parse_risk_free_return(value: f64) -> PyResult<Decimal> {
+    Decimal::from_f64(value).ok_or_else(|| PyValueError::new_err("risk_free_return must be finite"))
+}
+
+fn parse_engine_feed_mode(value: Option<&str>) -> PyResult<EngineFeedMode> {
+    match value {
+        None => Ok(EngineFeedMode::Stream),
+        Some(raw) => {
+            let normalized = raw.trim().to_ascii_lowercase();
+            match normalized.as_str() {
+                "stream" => Ok(EngineFeedMode::Stream),
+                "iterator" => Ok(EngineFeedMode::Iterator),
+                _ => Err(PyValueError::new_err(format!(
+                    "engine_feed_mode must be 'stream' or 'iterator', got {raw}",
+                ))),
+            }
+        }
+    }
+}
+
+/// Shared pause/step state between a [`PyPaperReplay`] and its replay task.
+#[derive(Debug)]
+struct PaperReplayControl {
+    paused: AtomicBool,
+    steps: AtomicUsize,
+    sent: AtomicUsize,
+    finished: AtomicBool,
+    notify: Notify,
+}
+
+impl PaperReplayControl {
+    fn new(paused: bool) -> Self {
+        Self {
+            paused: AtomicBool::new(paused),
+            steps: AtomicUsize::new(0),
+            sent: AtomicUsize::new(0),
+            finished: AtomicBool::new(false),
+            notify: Notify::new(),
+        }
+    }
+
+    /// Wait until the next event may be sent, returning `true` if it was released by a step.
+    async fn acquire(&self) -> bool {
+        loop {
+            if !self.paused.load(Ordering::SeqCst) {
+                return false;
+            }
+            if self
+                .steps
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |steps| {
+                    steps.checked_sub(1)
+                })
+                .is_ok()
+            {
+                return true;
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
+/// Send recorded market events to a running system's feed, honouring the replay controls.
+async fn run_paper_replay(
+    control: Arc<PaperReplayControl>,
+    feed_tx: UnboundedTx<EngineEvent>,
+    events: HistoricMarketEvents,
+    speed: Option<f64>,
+    stale_filter: Option<StaleEventFilter>,
+) {
+    let mut time_exchange_previous: Option<DateTime<Utc>> = None;
+
+    for result in events {
+        let event = match result {
+            Event::Item(Ok(event)) => MarketStreamEvent::Item(event),
+            Event::Item(Err(error)) => {
+                warn!(?error, "skipping market data error during paper replay");
+                continue;
+            }
+            Event::Reconnecting(exchange) => MarketStreamEvent::Reconnecting(exchange),
+        };
+
+        if let (Some(filter), Event::Item(item)) = (&stale_filter, &event)
+            && filter.is_stale(item)
+        {
+            continue;
+        }
+
+        let delay = match (&event, speed) {
+            (Event::Item(item), Some(speed)) => {
+                let delay = time_exchange_previous
+                    .and_then(|previous| replay_delay(previous, item.time_exchange, speed));
+                time_exchange_previous = Some(
+                    time_exchange_previous.map_or(item.time_exchange, |previous| {
+                        previous.max(item.time_exchange)
+                    }),
+                );
+                delay
+            }
+            _ => None,
+        };
+
+        let stepped = control.acquire().await;
+        if let (false, Some(delay)) = (stepped, delay) {
+            tokio::time::sleep(delay).await;
+        }
+
+        if feed_tx.send(EngineEvent::Market(event)).is_err() {
+            warn!("system feed closed, stopping paper replay");
+            break;
+        }
+        control.sent.fetch_add(1, Ordering::SeqCst);
+    }
+
+    control.finished.store(true, Ordering::SeqCst);
+}
+
+/// Handle controlling a market data replay started with
+/// [`PySystemHandle::replay_market_data`].
+#[pyclass(module = "barter_python", name = "PaperReplay", unsendable)]
+pub struct PyPaperReplay {
+    runtime: Arc<Runtime>,
+    control: Arc<PaperReplayControl>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PyPaperReplay {
+    fn lock_task(&self) -> PyResult<MutexGuard<'_, Option<JoinHandle<()>>>> {
+        self.task
+            .lock()
+            .map_err(|_| PyValueError::new_err("paper replay poisoned"))
+    }
+}
+
+#[pymethods]
+impl PyPaperReplay {
+    /// Stop sending events until [`Self::resume`] is called.
+    pub fn pause(&self) {
+        self.control.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Continue sending events at the configured speed, discarding any pending steps.
+    pub fn resume(&self) {
+        self.control.steps.store(0, Ordering::SeqCst);
+        self.control.paused.store(false, Ordering::SeqCst);
+        self.control.notify.notify_one();
+    }
+
+    /// Release the next `count` events immediately while paused.
+    #[pyo3(signature = (count=1))]
+    pub fn step(&self, count: usize) -> PyResult<()> {
+        if !self.control.paused.load(Ordering::SeqCst) {
+            return Err(PyValueError::new_err("paper replay must be paused to step"));
+        }
+        if count == 0 {
+            return Err(PyValueError::new_err("count must be a positive integer"));
+        }
+
+        self.control.steps.fetch_add(count, Ordering::SeqCst);
+        self.control.notify.notify_one();
+        Ok(())
+    }
+
+    /// Abort the replay, leaving the system running.
+    pub fn stop(&self) -> PyResult<()> {
+        if let Some(task) = self.lock_task()?.as_ref() {
+            task.abort();
+        }
+        Ok(())
+    }
+
+    /// Block until the replay has sent every event, returning `False` if `timeout_secs`
+    /// elapsed first.
+    #[pyo3(signature = (timeout_secs=None))]
+    pub fn wait(&self, py: Python<'_>, timeout_secs: Option<f64>) -> PyResult<bool> {
+        let timeout = timeout_secs
+            .map(|secs| {
+                if secs.is_sign_negative() || secs.is_nan() {
+                    return Err(PyValueError::new_err(
+                        "timeout_secs must be a non-negative number",
+                    ));
+                }
+                duration_from_secs(secs, "timeout_secs")
+            })
+            .transpose()?;
+
+        let mut guard = self.lock_task()?;
+        let Some(task) = guard.as_mut() else {
+            return Ok(self.control.finished.load(Ordering::SeqCst));
+        };
+
+        let runtime = Arc::clone(&self.runtime);
+        let joined = py.allow_threads(|| {
+            runtime.block_on(async {
+                match timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, task).await.ok(),
+                    None => Some(task.await),
+                }
+            })
+        });
+
+        match joined {
+            Some(_) => {
+                guard.take();
+                Ok(self.control.finished.load(Ordering::SeqCst))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Return `True` if the replay is paused.
+    #[getter]
+    pub fn is_paused(&self) -> bool {
+        self.control.paused.load(Ordering::SeqCst)
+    }
+
+    /// Return `True` once every event in the market data file has been sent.
+    #[getter]
+    pub fn is_finished(&self) -> bool {
+        self.control.finished.load(Ordering::SeqCst)
+    }
+
+    /// Number of market events sent to the system so far.
+    #[getter]
+    pub fn events_sent(&self) -> usize {
+        self.control.sent.load(Ordering::SeqCst)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PaperReplay(events_sent={}, paused={}, finished={})",
+            self.events_sent(),
+            self.is_paused(),
+            self.is_finished()
+        )
+    }
+}
+
+fn parse_replay_speed(speed: Option<f64>) -> PyResult<Option<f64>> {
+    match speed {
+        Some(value) if !value.is_finite() || value <= 0.0 => Err(PyValueError::new_err(format!(
+            "speed must be a positive finite multiplier, got {value}",
+        ))),
+        speed => Ok(speed),
+    }
+}
+
+/// Wall-clock delay to wait before emitting an event with `time_exchange` `current`, given the
+/// previously emitted event's `time_exchange` and the replay `speed` multiplier.
+fn replay_delay(previous: DateTime<Utc>, current: DateTime<Utc>, speed: f64) -> Option<Duration> {
+    let gap = current.signed_duration_since(previous).to_std().ok()?;
+    (!gap.is_zero()).then(|| gap.div_f64(speed))
+}
+
+/// Python progress callback invoked every `interval` historic market events.
+#[derive(Debug)]
+struct ReplayProgress {
+    callback: PyObject,
+    interval: usize,
+    processed: usize,
+}
+
+impl ReplayProgress {
+    fn new(callback: PyObject, interval: usize) -> PyResult<Self> {
+        if interval == 0 {
+            return Err(PyValueError::new_err(
+                "progress_interval must be greater than zero",
+            ));
+        }
+
+        Ok(Self {
+            callback,
+            interval,
+            processed: 0,
+        })
+    }
+
+    fn record(&mut self, time_exchange: DateTime<Utc>) {
+        self.processed += 1;
+        if self.processed % self.interval != 0 {
+            return;
+        }
+
+        let processed = self.processed;
+        Python::with_gil(|py| {
+            let result = timestamp_to_py(py, time_exchange)
+                .and_then(|time| self.callback.call1(py, (processed, time)));
+
+            if let Err(error) = result {
+                warn!(%error, processed, "progress_callback raised, continuing backtest");
+            }
+        });
+    }
+}
+
+/// Deterministic hash of the balances, positions and open orders of an [`EngineState`].
+///
+/// Each component is rendered as a record with normalised decimals (eg/ `1.0` and `1.00` hash
+/// equally), and the records are sorted before being hashed with FNV-1a, so the result is
+/// independent of map iteration order and stable across processes.
+fn engine_state_hash(state: &DefaultEngineState) -> u64 {
+    let mut records = Vec::new();
+
+    for (key, asset) in &state.assets.0 {
+        if let Some(balance) = &asset.balance {
+            records.push(format!(
+                "balance|{}|{}|{}|{}",
+                key.exchange,
+                key.asset,
+                balance.value.total.normalize(),
+                balance.value.free.normalize()
+            ));
+        }
+    }
+
+    for instrument in state.instruments.0.values() {
+        let name = &instrument.instrument.name_internal;
+
+        if let Some(position) = &instrument.position.current {
+            records.push(format!(
+                "position|{name}|{}|{}|{}|{}",
+                position.side,
+                position.quantity_abs.normalize(),
+                position.price_entry_average.normalize(),
+                position.pnl_realised.normalize()
+            ));
+        }
+
+        for order in instrument.orders.0.values() {
+            records.push(format!(
+                "order|{name}|{}|{}|{}|{}|{:?}|{:?}|{:?}",
+                order.key.cid,
+                order.side,
+                order.price.normalize(),
+                order.quantity.normalize(),
+                order.kind,
+                order.time_in_force,
+                order.state
+            ));
+        }
+    }
+
+    records.sort_unstable();
+
+    let mut hasher = FnvHasher::default();
+    for record in records {
+        hasher.write(record.as_bytes());
+        hasher.write(b"\n");
+    }
+    hasher.finish()
+}
+
+/// Historic backtest progress persisted by [`run_historic_backtest`], used to resume an
+/// interrupted backtest.
+///
+/// [`EngineState`] assets are keyed by [`ExchangeAsset`], which cannot be a JSON object key, so
+/// they are persisted as `(key, state)` pairs alongside the rest of the state.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct BacktestCheckpoint {
+    events_processed: u64,
+    time_exchange: DateTime<Utc>,
+    /// [`engine_state_hash`] of the checkpointed state, for comparison with other runs.
+    state_hash: u64,
+    assets: Vec<(ExchangeAsset<AssetNameInternal>, AssetState)>,
+    state: DefaultEngineState,
+}
+
+impl BacktestCheckpoint {
+    fn new(
+        events_processed: u64,
+        time_exchange: DateTime<Utc>,
+        mut state: DefaultEngineState,
+    ) -> Self {
+        let state_hash = engine_state_hash(&state);
+        let assets = std::mem::take(&mut state.assets.0).into_iter().collect();
+        Self {
+            events_processed,
+            time_exchange,
+            state_hash,
+            assets,
+            state,
+        }
+    }
+
+    fn into_state(self) -> DefaultEngineState {
+        let mut state = self.state;
+        state.assets.0 = self.assets.into_iter().collect();
+        state
+    }
+
+    fn read(path: &Path) -> PyResult<Self> {
+        let reader = File::open(path)
+            .map(BufReader::new)
+            .map_err(|err| PyValueError::new_err(format!("invalid resume_from: {err}")))?;
+
+        serde_json::from_reader(reader)
+            .map_err(|err| PyValueError::new_err(format!("invalid resume_from checkpoint: {err}")))
+    }
+
+    /// Atomically write the checkpoint to `path`, via a temporary file so an interruption never
+    /// leaves a partially written checkpoint behind.
+    fn write(&self, path: &Path) -> std::io::Result<()> {
+        let path_tmp = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&path_tmp)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer
+            .into_inner()
+            .map_err(|err| err.into_error())?
+            .sync_all()?;
+        std::fs::rename(path_tmp, path)
+    }
+
+    /// Checkpointed asset balances on the provided `exchange`, used to seed its mocked execution.
+    fn balances(&self, exchange: ExchangeId) -> Vec<AssetBalance<AssetNameExchange>> {
+        self.assets
+            .iter()
+            .filter(|(key, _)| key.exchange == exchange)
+            .filter_map(|(_, state)| {
+                state.balance.map(|balance| {
+                    AssetBalance::new(
+                        state.asset.name_exchange.clone(),
+                        balance.value,
+                        balance.time,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Writes a [`BacktestCheckpoint`] every `interval` market events processed by the engine,
+/// maintaining a replica of the [`EngineState`] from the engine audit stream.
+struct BacktestCheckpointer {
+    path: PathBuf,
+    interval: u64,
+    events_processed: u64,
+}
+
+impl BacktestCheckpointer {
+    fn new(
+        path: Option<PathBuf>,
+        interval: Option<u64>,
+        events_processed: u64,
+    ) -> PyResult<Option<Self>> {
+        match (path, interval) {
+            (Some(_), Some(0)) => Err(PyValueError::new_err(
+                "checkpoint_interval must be a positive integer",
+            )),
+            (Some(path), interval) => Ok(Some(Self {
+                path,
+                interval: interval.unwrap_or(10_000),
+                events_processed,
+            })),
+            (None, Some(_)) => Err(PyValueError::new_err(
+                "checkpoint_interval requires a checkpoint_path",
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    async fn run<OnDisable, OnDisconnect>(
+        mut self,
+        snapshot: AuditTick<DefaultEngineState, EngineContext>,
+        mut updates: UnboundedRx<
+            AuditTick<
+                EngineAudit<EngineEvent, EngineOutput<OnDisable, OnDisconnect>>,
+                EngineContext,
+            >,
+        >,
+    ) {
+        let mut replica = StateReplicaManager::new(snapshot, ());
+
+        while let Some(tick) = updates.rx.recv().await {
+            let EngineAudit::Process(audit) = tick.event else {
+                continue;
+            };
+            replica.state_replica.context = tick.context;
+
+            let time_exchange = match &audit.event {
+                EngineEvent::Market(MarketStreamEvent::Item(event)) => Some(event.time_exchange),
+                _ => None,
+            };
+            replica.update_from_event(audit.event);
+
+            let Some(time_exchange) = time_exchange else {
+                continue;
+            };
+            self.events_processed += 1;
+            if self.events_processed % self.interval != 0 {
+                continue;
+            }
+
+            let checkpoint = BacktestCheckpoint::new(
+                self.events_processed,
+                time_exchange,
+                replica.replica_engine_state().clone(),
+            );
+            if let Err(error) = checkpoint.write(&self.path) {
+                warn!(%error, path = %self.path.display(), "failed to write backtest checkpoint");
+            }
+        }
+    }
+}
+
+/// Leading bytes of a binary event log written by [`EventRecorder`].
+const EVENT_LOG_MAGIC: &[u8; 8] = b"BARTEREV";
+
+/// Version of the binary event log framing, written after [`EVENT_LOG_MAGIC`] and bumped on any
+/// incompatible change.
+const EVENT_LOG_VERSION: u16 = 1;
+
+/// On disk encoding of an event log written by [`EventRecorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventLogFormat {
+    /// [`EVENT_LOG_MAGIC`] and [`EVENT_LOG_VERSION`] header, followed by one MessagePack encoded
+    /// [`EngineEvent`] per frame, each prefixed by its little endian `u32` length.
+    Binary,
+    /// One JSON encoded [`EngineEvent`] per line.
+    Json,
+}
+
+impl EventLogFormat {
+    fn parse(value: &str) -> PyResult<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "binary" => Ok(Self::Binary),
+            "json" => Ok(Self::Json),
+            other => Err(PyValueError::new_err(format!(
+                "format must be one of 'binary' or 'json', got '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Writes every [`EngineEvent`] processed by a running engine to an event log.
+#[derive(Debug)]
+struct EventRecorder {
+    format: EventLogFormat,
+    writer: BufWriter<File>,
+    events_recorded: u64,
 }
 
-fn parse_engine_feed_mode(value: Option<&str>) -> PyResult<EngineFeedMode> {
-    match value {
-        None => Ok(EngineFeedMode::Stream),
-        Some(raw) => {
-            let normalized = raw.trim().to_ascii_lowercase();
-            match normalized.as_str() {
-                "stream" => Ok(EngineFeedMode::Stream),
-                "iterator" => Ok(EngineFeedMode::Iterator),
-                _ => Err(PyValueError::new_err(format!(
-                    "engine_feed_mode must be 'stream' or 'iterator', got {raw}",
-                ))),
+impl EventRecorder {
+    fn create(path: &Path, format: EventLogFormat) -> std::io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        if format == EventLogFormat::Binary {
+            writer.write_all(EVENT_LOG_MAGIC)?;
+            writer.write_all(&EVENT_LOG_VERSION.to_le_bytes())?;
+        }
+
+        Ok(Self {
+            format,
+            writer,
+            events_recorded: 0,
+        })
+    }
+
+    fn record(&mut self, event: &EngineEvent<DataKind>) -> std::io::Result<()> {
+        match self.format {
+            EventLogFormat::Binary => {
+                let frame = rmp_serde::to_vec_named(event).map_err(std::io::Error::other)?;
+                let length = u32::try_from(frame.len()).map_err(std::io::Error::other)?;
+                self.writer.write_all(&length.to_le_bytes())?;
+                self.writer.write_all(&frame)?;
+            }
+            EventLogFormat::Json => {
+                serde_json::to_writer(&mut self.writer, event)?;
+                self.writer.write_all(b"\n")?;
             }
         }
+
+        self.events_recorded += 1;
+        Ok(())
+    }
+
+    /// Flush the event log to disk, returning the number of events recorded.
+    fn finish(self) -> std::io::Result<u64> {
+        self.writer
+            .into_inner()
+            .map_err(|err| err.into_error())?
+            .sync_all()?;
+        Ok(self.events_recorded)
     }
 }
 
 fn load_historic_clock_and_market_stream(
     path: &Path,
+    speed: Option<f64>,
+    mut progress: Option<ReplayProgress>,
+    resume_after: Option<DateTime<Utc>>,
 ) -> PyResult<(
     HistoricalClock,
     impl Stream<Item = MarketStreamEvent<InstrumentIndex, DataKind>> + Send + 'static,
 )> {
-    let mut file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|err| PyValueError::new_err(err.to_string()))?;
-
-    let events =
-        serde_json::from_str::<Vec<MarketStreamResult<InstrumentIndex, DataKind>>>(&contents)
-            .map_err(|err| PyValueError::new_err(err.to_string()))?;
-
-    let time_exchange_first = events
-        .iter()
-        .find_map(|result| match result {
-            Event::Item(Ok(event)) => Some(event.time_exchange),
-            _ => None,
-        })
-        .ok_or_else(|| PyValueError::new_err("market data contains no events"))?;
+    let (time_exchange_first, events) = load_historic_market_events(path)?;
+
+    // Skip market events already processed before the checkpoint being resumed from
+    let (time_exchange_first, events): (_, HistoricMarketEvents) = match resume_after {
+        Some(resume_after) => (
+            time_exchange_first.max(resume_after),
+            Box::new(events.filter(move |event| {
+                market_event_time_exchange(event).is_none_or(|time| time > resume_after)
+            })),
+        ),
+        None => (time_exchange_first, events),
+    };
 
     let clock = HistoricalClock::new(time_exchange_first);
 
+    let mut time_exchange_previous = time_exchange_first;
     let stream = futures::stream::iter(events)
         .with_error_handler(|error| warn!(?error, "MarketStream generated error"))
+        .then(move |event| {
+            let delay = match (&event, speed) {
+                (Event::Item(item), Some(speed)) => {
+                    let delay = replay_delay(time_exchange_previous, item.time_exchange, speed);
+                    time_exchange_previous = time_exchange_previous.max(item.time_exchange);
+                    delay
+                }
+                _ => None,
+            };
+
+            async move {
+                if let Some(delay) = delay {
+                    tokio::time::sleep(delay).await;
+                }
+                event
+            }
+        })
+        .inspect(move |event| {
+            if let (Some(progress), Event::Item(item)) = (progress.as_mut(), event) {
+                progress.record(item.time_exchange);
+            }
+        })
         .inspect(|event| match event {
             Event::Reconnecting(exchange) => {
                 info!(%exchange, "sending historical disconnection to Engine")
@@ -1824,6 +3802,301 @@ fn load_historic_clock_and_market_stream(
     Ok((clock, stream))
 }
 
+type HistoricMarketEvents =
+    Box<dyn Iterator<Item = MarketStreamResult<InstrumentIndex, DataKind>> + Send>;
+
+/// Load a market data file, returning the first event `time_exchange` alongside the events.
+///
+/// Files starting with [`EVENT_LOG_MAGIC`] are binary event logs written by
+/// [`PySystemHandle::record_events`], otherwise files are parsed as JSON lines if they have a
+/// `.jsonl` extension or do not start with a JSON array, and as a JSON array otherwise.
+fn load_historic_market_events(path: &Path) -> PyResult<(DateTime<Utc>, HistoricMarketEvents)> {
+    if is_binary_event_log(path)? {
+        return load_historic_binary_event_log(path);
+    }
+
+    let is_json_lines = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("jsonl"))
+        || !is_json_array(path)?;
+
+    if is_json_lines {
+        load_historic_json_lines(path)
+    } else {
+        load_historic_json_array(path)
+    }
+}
+
+fn is_binary_event_log(path: &Path) -> PyResult<bool> {
+    let mut file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let mut magic = [0; EVENT_LOG_MAGIC.len()];
+    Ok(file.read_exact(&mut magic).is_ok() && &magic == EVENT_LOG_MAGIC)
+}
+
+/// Returns true if the first non-whitespace byte of the file opens a JSON array.
+fn is_json_array(path: &Path) -> PyResult<bool> {
+    let file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    for byte in BufReader::new(file).bytes() {
+        let byte = byte.map_err(|err| PyValueError::new_err(err.to_string()))?;
+        if !byte.is_ascii_whitespace() {
+            return Ok(byte == b'[');
+        }
+    }
+    Ok(false)
+}
+
+/// Load the market events of a binary event log, parsing one frame at a time lazily as the
+/// Engine consumes the stream. Recorded commands, trading state updates and account events are
+/// skipped, since the backtest Engine regenerates its own account stream.
+///
+/// Frames up to and including the first market event are parsed eagerly to seed the
+/// [`HistoricalClock`], so an incompatible or malformed log is reported as an error.
+fn load_historic_binary_event_log(path: &Path) -> PyResult<(DateTime<Utc>, HistoricMarketEvents)> {
+    let mut frames = BinaryEventLogMarketEvents::open(path)?;
+
+    let mut buffered = Vec::new();
+    let time_exchange_first = loop {
+        let Some(event) = frames.next_event()? else {
+            return Err(PyValueError::new_err("event log contains no market events"));
+        };
+
+        let time_exchange = market_event_time_exchange(&event);
+        buffered.push(event);
+
+        if let Some(time_exchange) = time_exchange {
+            break time_exchange;
+        }
+    };
+
+    Ok((
+        time_exchange_first,
+        Box::new(buffered.into_iter().chain(frames)),
+    ))
+}
+
+/// Load a market data file containing a single JSON array of [`MarketStreamResult`]s.
+fn load_historic_json_array(path: &Path) -> PyResult<(DateTime<Utc>, HistoricMarketEvents)> {
+    let mut file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    let events =
+        serde_json::from_str::<Vec<MarketStreamResult<InstrumentIndex, DataKind>>>(&contents)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    let time_exchange_first = events
+        .iter()
+        .find_map(market_event_time_exchange)
+        .ok_or_else(|| PyValueError::new_err("market data contains no events"))?;
+
+    Ok((time_exchange_first, Box::new(events.into_iter())))
+}
+
+/// Load a newline-delimited JSON market data file, parsing one [`MarketStreamResult`] per line
+/// lazily as the Engine consumes the stream.
+///
+/// Lines may also be [`EngineEvent`]s from a JSON event log written by
+/// [`PySystemHandle::record_events`], in which case non-market events are skipped.
+///
+/// Lines up to and including the first market event are parsed eagerly to seed the
+/// [`HistoricalClock`], so malformed data at the start of the file is reported as an error.
+fn load_historic_json_lines(path: &Path) -> PyResult<(DateTime<Utc>, HistoricMarketEvents)> {
+    let mut lines = JsonLinesMarketEvents::open(path)?;
+
+    let mut buffered = Vec::new();
+    let time_exchange_first = loop {
+        let Some(event) = lines.next_event()? else {
+            return Err(PyValueError::new_err("market data contains no events"));
+        };
+
+        let time_exchange = market_event_time_exchange(&event);
+        buffered.push(event);
+
+        if let Some(time_exchange) = time_exchange {
+            break time_exchange;
+        }
+    };
+
+    Ok((
+        time_exchange_first,
+        Box::new(buffered.into_iter().chain(lines)),
+    ))
+}
+
+fn market_event_time_exchange(
+    result: &MarketStreamResult<InstrumentIndex, DataKind>,
+) -> Option<DateTime<Utc>> {
+    match result {
+        Event::Item(Ok(event)) => Some(event.time_exchange),
+        _ => None,
+    }
+}
+
+/// Lazy [`Iterator`] over the [`MarketStreamResult`]s of a JSON Lines market data file.
+struct JsonLinesMarketEvents {
+    lines: Lines<BufReader<File>>,
+    line_number: usize,
+}
+
+impl JsonLinesMarketEvents {
+    fn open(path: &Path) -> PyResult<Self> {
+        let file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(Self {
+            lines: BufReader::new(file).lines(),
+            line_number: 0,
+        })
+    }
+
+    /// Parse the next non-empty line, returning `Ok(None)` once the file is exhausted.
+    fn next_event(&mut self) -> PyResult<Option<MarketStreamResult<InstrumentIndex, DataKind>>> {
+        for line in self.lines.by_ref() {
+            self.line_number += 1;
+            let line = line.map_err(|err| {
+                PyValueError::new_err(format!("line {}: {err}", self.line_number))
+            })?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line = serde_json::from_str::<JsonLinesEvent>(&line).map_err(|err| {
+                PyValueError::new_err(format!("line {}: {err}", self.line_number))
+            })?;
+
+            match line {
+                JsonLinesEvent::Market(event) => return Ok(Some(event)),
+                JsonLinesEvent::Engine(event) => {
+                    if let Some(event) = engine_event_to_market_stream_result(event) {
+                        return Ok(Some(event));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Line of a JSON Lines market data file, either a plain [`MarketStreamResult`] or an
+/// [`EngineEvent`] recorded by [`PySystemHandle::record_events`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonLinesEvent {
+    Market(MarketStreamResult<InstrumentIndex, DataKind>),
+    Engine(EngineEvent<DataKind>),
+}
+
+/// Extract the market event of a recorded [`EngineEvent`], if any.
+fn engine_event_to_market_stream_result(
+    event: EngineEvent<DataKind>,
+) -> Option<MarketStreamResult<InstrumentIndex, DataKind>> {
+    match event {
+        EngineEvent::Market(Event::Item(event)) => Some(Event::Item(Ok(event))),
+        EngineEvent::Market(Event::Reconnecting(exchange)) => Some(Event::Reconnecting(exchange)),
+        _ => None,
+    }
+}
+
+impl Iterator for JsonLinesMarketEvents {
+    type Item = MarketStreamResult<InstrumentIndex, DataKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.next_event() {
+                Ok(event) => return event,
+                Err(error) => warn!(%error, "skipping invalid JSON Lines market data"),
+            }
+        }
+    }
+}
+
+/// Lazy [`Iterator`] over the market events of a binary event log written by an
+/// [`EventRecorder`].
+struct BinaryEventLogMarketEvents {
+    reader: BufReader<File>,
+    frame: usize,
+}
+
+impl BinaryEventLogMarketEvents {
+    /// Open an event log, validating its header is compatible with [`EVENT_LOG_VERSION`].
+    fn open(path: &Path) -> PyResult<Self> {
+        let file = File::open(path).map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0; EVENT_LOG_MAGIC.len()];
+        let mut version = [0; 2];
+        reader
+            .read_exact(&mut magic)
+            .and_then(|_| reader.read_exact(&mut version))
+            .map_err(|err| PyValueError::new_err(format!("invalid event log header: {err}")))?;
+
+        if &magic != EVENT_LOG_MAGIC {
+            return Err(PyValueError::new_err("invalid event log header"));
+        }
+
+        let version = u16::from_le_bytes(version);
+        if version != EVENT_LOG_VERSION {
+            return Err(PyValueError::new_err(format!(
+                "unsupported event log version {version}, expected {EVENT_LOG_VERSION}"
+            )));
+        }
+
+        Ok(Self { reader, frame: 0 })
+    }
+
+    /// Decode frames until the next market event, returning `Ok(None)` once the log is
+    /// exhausted.
+    fn next_event(&mut self) -> PyResult<Option<MarketStreamResult<InstrumentIndex, DataKind>>> {
+        loop {
+            let exhausted = self
+                .reader
+                .fill_buf()
+                .map_err(|err| PyValueError::new_err(err.to_string()))?
+                .is_empty();
+            if exhausted {
+                return Ok(None);
+            }
+
+            self.frame += 1;
+            let frame_err = |err: &dyn std::fmt::Display| {
+                PyValueError::new_err(format!("frame {}: {err}", self.frame))
+            };
+
+            let mut length = [0; 4];
+            self.reader
+                .read_exact(&mut length)
+                .map_err(|err| frame_err(&err))?;
+            let mut frame = vec![0; u32::from_le_bytes(length) as usize];
+            self.reader
+                .read_exact(&mut frame)
+                .map_err(|err| frame_err(&err))?;
+
+            let event = rmp_serde::from_slice::<EngineEvent<DataKind>>(&frame)
+                .map_err(|err| frame_err(&err))?;
+
+            if let Some(event) = engine_event_to_market_stream_result(event) {
+                return Ok(Some(event));
+            }
+        }
+    }
+}
+
+impl Iterator for BinaryEventLogMarketEvents {
+    type Item = MarketStreamResult<InstrumentIndex, DataKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_event() {
+            Ok(event) => event,
+            Err(error) => {
+                // Frames are length prefixed, so a corrupt frame leaves the remainder unreadable
+                warn!(%error, "stopping replay of invalid binary event log");
+                None
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1844,6 +4117,151 @@ mod tests {
     use rust_decimal::Decimal;
     use rust_decimal::prelude::FromPrimitive;
 
+    #[test]
+    fn engine_state_hash_is_stable_and_ignores_decimal_scale() {
+        use barter_execution::balance::Balance;
+        use barter_instrument::{Underlying, instrument::Instrument};
+
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let state = |usdt: Decimal| -> DefaultEngineState {
+            EngineState::builder(&instruments, DefaultGlobalData, |_| {
+                DefaultInstrumentMarketData::default()
+            })
+            .balances([(ExchangeId::BinanceSpot, "usdt", Balance::new(usdt, usdt))])
+            .build()
+        };
+
+        let hash = engine_state_hash(&state(Decimal::new(1_000, 0)));
+        assert_eq!(hash, engine_state_hash(&state(Decimal::new(1_000, 0))));
+        assert_eq!(hash, engine_state_hash(&state(Decimal::new(100_000, 2))));
+        assert_ne!(hash, engine_state_hash(&state(Decimal::new(999, 0))));
+    }
+
+    #[test]
+    fn audit_updates_recv_matching_skips_non_matching_ticks() {
+        use barter::engine::audit::ProcessAudit;
+
+        let runtime = Arc::new(
+            RuntimeBuilder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap(),
+        );
+        let (tx, rx) = mpsc_unbounded();
+
+        let tick = |sequence: u64, event: TradingEngineAudit| TradingAuditTick {
+            event,
+            context: EngineContext {
+                sequence: Sequence(sequence),
+                time: Utc
+                    .timestamp_opt(1_700_000_000 + sequence as i64, 0)
+                    .unwrap(),
+            },
+        };
+        let trading_state = |state: TradingState| {
+            EngineAudit::Process(ProcessAudit::with_event(
+                EngineEvent::<DataKind>::TradingStateUpdate(state),
+            ))
+        };
+
+        tx.send(tick(0, EngineAudit::FeedEnded)).unwrap();
+        tx.send(tick(1, trading_state(TradingState::Enabled)))
+            .unwrap();
+        tx.send(tick(2, trading_state(TradingState::Disabled)))
+            .unwrap();
+        tx.send(tick(3, EngineAudit::FeedEnded)).unwrap();
+
+        let updates = PyAuditUpdates::new(runtime, rx);
+
+        Python::with_gil(|py| {
+            let predicate = py
+                .eval_bound(
+                    "lambda tick: tick['event'].get('event_type') == 'TradingStateUpdate'",
+                    None,
+                    None,
+                )
+                .unwrap();
+
+            let matched = updates
+                .recv_matching(py, &predicate, Some(1.0))
+                .unwrap()
+                .expect("a TradingStateUpdate tick was enqueued");
+            let sequence = matched
+                .bind(py)
+                .get_item("context")
+                .and_then(|context| context.get_item("sequence"))
+                .and_then(|sequence| sequence.getattr("value"))
+                .and_then(|value| value.extract::<u64>())
+                .unwrap();
+            assert_eq!(sequence, 1);
+
+            // Sequence 2 matches next, after which only a FeedEnded tick remains before the
+            // timeout elapses.
+            assert!(
+                updates
+                    .recv_matching(py, &predicate, Some(1.0))
+                    .unwrap()
+                    .is_some()
+            );
+            assert!(
+                updates
+                    .recv_matching(py, &predicate, Some(0.05))
+                    .unwrap()
+                    .is_none()
+            );
+            assert!(
+                updates
+                    .recv_matching(py, &py.None().into_bound(py), None)
+                    .is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn audit_filter_skip_empty_drops_quiet_process_ticks() {
+        use barter::engine::audit::ProcessAudit;
+
+        let tick = |event: TradingEngineAudit| TradingAuditTick {
+            event,
+            context: EngineContext {
+                sequence: Sequence(0),
+                time: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            },
+        };
+        let quiet = tick(EngineAudit::Process(ProcessAudit::with_event(
+            EngineEvent::<DataKind>::TradingStateUpdate(TradingState::Enabled),
+        )));
+        let feed_ended = tick(EngineAudit::FeedEnded);
+
+        assert!(AuditFilter::All.forwards(&quiet));
+        assert!(!AuditFilter::SkipEmpty.forwards(&quiet));
+        assert!(AuditFilter::SkipEmpty.forwards(&feed_ended));
+    }
+
+    #[test]
+    fn replay_delay_scales_time_exchange_gaps_by_speed() {
+        let previous = Utc.timestamp_millis_opt(1_000).unwrap();
+        let current = Utc.timestamp_millis_opt(1_200).unwrap();
+
+        assert_eq!(
+            replay_delay(previous, current, 2.0),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(replay_delay(previous, previous, 2.0), None);
+        assert_eq!(replay_delay(current, previous, 2.0), None);
+        assert!(parse_replay_speed(Some(0.0)).is_err());
+        assert!(parse_replay_speed(Some(f64::NAN)).is_err());
+    }
+
     #[test]
     fn action_output_open_orders_exposes_structured_wrapper() {
         Python::with_gil(|py| {
@@ -2208,4 +4626,343 @@ mod tests {
         assert!(message.contains("engine_feed_mode"));
         assert!(message.contains("warp"));
     }
+
+    #[test]
+    fn parse_system_clock_rejects_unknown_value() {
+        assert!(matches!(
+            SystemClock::parse(None).unwrap(),
+            SystemClock::Live(_)
+        ));
+        assert!(matches!(
+            SystemClock::parse(Some(" Event ")).unwrap(),
+            SystemClock::Event(_)
+        ));
+
+        let message = SystemClock::parse(Some("wall")).unwrap_err().to_string();
+        assert!(message.contains("engine_clock"));
+        assert!(message.contains("wall"));
+    }
+
+    #[test]
+    fn event_clock_tracks_latest_market_event_time() {
+        use barter_data::{event::MarketEvent, subscription::trade::PublicTrade};
+
+        let trade = |time_exchange: DateTime<Utc>| -> EngineEvent {
+            EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
+                time_exchange,
+                time_received: time_exchange,
+                exchange: ExchangeId::BinanceSpot,
+                instrument: InstrumentIndex(0),
+                kind: DataKind::Trade(PublicTrade {
+                    id: "trade".to_string(),
+                    price: 100.0,
+                    amount: 1.0,
+                    side: Side::Buy,
+                }),
+            }))
+        };
+        let later = Utc.with_ymd_and_hms(2035, 1, 1, 0, 0, 0).unwrap();
+        let earlier = Utc.with_ymd_and_hms(2034, 1, 1, 0, 0, 0).unwrap();
+
+        let mut clock = SystemClock::parse(Some("event")).unwrap();
+        clock.process(&trade(later));
+        assert_eq!(clock.time(), later);
+
+        clock.process(&trade(earlier));
+        assert_eq!(clock.time(), later);
+    }
+
+    #[test]
+    fn exit_cooldowns_prefer_instrument_limits_over_global() {
+        use barter::system::config::RiskLimits;
+
+        let mut risk = RiskConfiguration::default();
+        risk.set_global(Some(RiskLimits {
+            exit_cooldown_secs: Some(Decimal::from(60)),
+            ..RiskLimits::default()
+        }))
+        .unwrap();
+        risk.set_instrument_limits(
+            1,
+            Some(RiskLimits {
+                exit_cooldown_secs: Some(Decimal::new(15, 1)),
+                ..RiskLimits::default()
+            }),
+        )
+        .unwrap();
+
+        let cooldowns = exit_cooldowns(&risk, 2).unwrap();
+
+        assert_eq!(cooldowns[&InstrumentIndex(0)], TimeDelta::seconds(60));
+        assert_eq!(
+            cooldowns[&InstrumentIndex(1)],
+            TimeDelta::milliseconds(1500)
+        );
+        assert!(
+            exit_cooldowns(&RiskConfiguration::default(), 2)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn position_exit_holding_duration_and_return_pct() {
+        Python::with_gil(|py| {
+            let time_enter = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+            let position = PositionExited {
+                instrument: InstrumentIndex(0),
+                side: Side::Buy,
+                price_entry_average: Decimal::from(200),
+                quantity_abs_max: Decimal::from(2),
+                pnl_realised: Decimal::from(10),
+                fees_enter: AssetFees::quote_fees(Decimal::ZERO),
+                fees_exit: AssetFees::quote_fees(Decimal::ZERO),
+                time_enter,
+                time_exit: time_enter + TimeDelta::minutes(90),
+                trades: vec![TradeId::new("trade-1")],
+            };
+            let exit = PyPositionExit::from_position(&position);
+
+            assert_eq!(exit.holding_duration(), TimeDelta::minutes(90));
+
+            let return_pct = exit.return_pct(py, None).unwrap().unwrap();
+            assert_eq!(return_pct.bind(py).str().unwrap().to_string(), "2.5");
+
+            let return_pct = exit.return_pct(py, Some(100.0)).unwrap().unwrap();
+            assert_eq!(return_pct.bind(py).str().unwrap().to_string(), "10");
+
+            assert!(exit.return_pct(py, Some(0.0)).unwrap().is_none());
+
+            let dict = exit.to_dict(py).unwrap();
+            let holding: TimeDelta = dict
+                .bind(py)
+                .get_item("holding_duration")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(holding, TimeDelta::minutes(90));
+        });
+    }
+
+    #[test]
+    fn position_exit_stats_summarises_exits() {
+        Python::with_gil(|py| {
+            let time_enter = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+            let exit = |pnl: i64, minutes: i64| {
+                let position = PositionExited {
+                    instrument: InstrumentIndex(0),
+                    side: Side::Buy,
+                    price_entry_average: Decimal::from(100),
+                    quantity_abs_max: Decimal::ONE,
+                    pnl_realised: Decimal::from(pnl),
+                    fees_enter: AssetFees::quote_fees(Decimal::ZERO),
+                    fees_exit: AssetFees::quote_fees(Decimal::ZERO),
+                    time_enter,
+                    time_exit: time_enter + TimeDelta::minutes(minutes),
+                    trades: vec![TradeId::new("trade-1")],
+                };
+                Py::new(py, PyPositionExit::from_position(&position)).unwrap()
+            };
+
+            let exits = [exit(30, 10), exit(10, 20), exit(-20, 30), exit(0, 60)];
+            let refs = exits.iter().map(|exit| exit.borrow(py)).collect();
+            let stats = position_exit_stats(py, refs).unwrap();
+            let stats = stats.bind(py);
+
+            let text = |key: &str| stats.get_item(key).unwrap().str().unwrap().to_string();
+            assert_eq!(text("count"), "4");
+            assert_eq!(text("pnl_realised_total"), "20");
+            assert_eq!(text("win_rate"), "0.5");
+            assert_eq!(text("average_win"), "20");
+            assert_eq!(text("average_loss"), "-20");
+            assert_eq!(text("largest_win"), "30");
+            assert_eq!(text("largest_loss"), "-20");
+
+            let holding: TimeDelta = stats
+                .get_item("average_holding_duration")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(holding, TimeDelta::minutes(30));
+
+            let empty = position_exit_stats(py, Vec::new()).unwrap();
+            assert!(empty.bind(py).get_item("win_rate").unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn callback_risk_manager_refuses_orders_beyond_max_position_quantity() {
+        use barter::risk::RiskManager;
+        use barter_execution::{
+            order::id::OrderId,
+            trade::{Trade, TradeId},
+        };
+        use barter_instrument::{Underlying, instrument::Instrument};
+
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let mut state: DefaultEngineState =
+            EngineState::builder(&instruments, DefaultGlobalData, |_| {
+                DefaultInstrumentMarketData::default()
+            })
+            .build();
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position
+            .update_from_trade(&Trade {
+                id: TradeId::new("trade-1"),
+                order_id: OrderId::new("order-1"),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+                side: Side::Buy,
+                price: Decimal::from(100),
+                quantity: Decimal::ONE,
+                fees: AssetFees {
+                    asset: QuoteAsset,
+                    fees: Decimal::ZERO,
+                },
+            });
+
+        let open = |cid: &str, side: Side, quantity: i64| OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen::new(
+                side,
+                Decimal::from(100),
+                Decimal::from(quantity),
+                OrderKind::Market,
+                TimeInForce::ImmediateOrCancel,
+            ),
+        };
+
+        let risk = CallbackRiskManager::<DefaultEngineState>::new(None)
+            .with_max_position_quantity(HashMap::from([(InstrumentIndex(0), Decimal::from(2))]));
+        let (_, approved, _, refused) = risk.check(
+            &state,
+            Vec::<DefaultOrderRequestCancel>::new(),
+            [
+                open("cid-within", Side::Buy, 1),
+                open("cid-beyond", Side::Buy, 1),
+                open("cid-reduce", Side::Sell, 3),
+            ],
+        );
+
+        let approved = approved
+            .into_iter()
+            .map(|approved| approved.into_item().key.cid)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            approved,
+            vec![
+                ClientOrderId::new("cid-within"),
+                ClientOrderId::new("cid-reduce")
+            ]
+        );
+
+        let refused = refused.into_iter().collect::<Vec<_>>();
+        assert_eq!(refused.len(), 1);
+        assert_eq!(refused[0].item.key.cid, ClientOrderId::new("cid-beyond"));
+        assert!(refused[0].reason.contains("max_position_quantity 2"));
+    }
+
+    #[test]
+    fn instrument_trading_state_override_suppresses_only_that_instrument() {
+        use crate::strategy::instrument_trading_enabled;
+        use barter::engine::command::Command;
+        use barter_data::{event::MarketEvent, subscription::trade::PublicTrade};
+        use barter_instrument::{Underlying, instrument::Instrument};
+
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_eth_usdt",
+                "ETHUSDT",
+                Underlying::new("eth", "usdt"),
+                None,
+            ))
+            .build();
+
+        let time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let state: DefaultEngineState =
+            EngineState::builder(&instruments, DefaultGlobalData, |_| {
+                DefaultInstrumentMarketData::default()
+            })
+            .build();
+        let mut replica: TradingStateReplica = StateReplicaManager::new(
+            AuditTick {
+                event: state,
+                context: EngineContext {
+                    sequence: Sequence(0),
+                    time,
+                },
+            },
+            (),
+        );
+
+        let set_trading = |instrument: usize, state: TradingState| {
+            EngineEvent::<DataKind>::Command(Command::SetInstrumentTradingState(
+                InstrumentIndex(instrument),
+                state,
+            ))
+        };
+        let allowed = |replica: &TradingStateReplica| {
+            [InstrumentIndex(0), InstrumentIndex(1)]
+                .into_iter()
+                .filter(|instrument| {
+                    instrument_trading_enabled(replica.replica_engine_state(), instrument)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        replica.update_from_event(set_trading(0, TradingState::Disabled));
+        for instrument in [0, 1] {
+            replica.update_from_event(EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
+                time_exchange: time,
+                time_received: time,
+                exchange: ExchangeId::BinanceSpot,
+                instrument: InstrumentIndex(instrument),
+                kind: DataKind::Trade(PublicTrade {
+                    id: format!("trade-{instrument}"),
+                    price: 100.0,
+                    amount: 1.0,
+                    side: Side::Buy,
+                }),
+            })));
+        }
+        assert_eq!(allowed(&replica), vec![InstrumentIndex(1)]);
+
+        replica.update_from_event(set_trading(0, TradingState::Enabled));
+        assert_eq!(
+            allowed(&replica),
+            vec![InstrumentIndex(0), InstrumentIndex(1)]
+        );
+
+        // Untracked instruments are ignored rather than panicking
+        replica.update_from_event(set_trading(5, TradingState::Disabled));
+        assert_eq!(
+            allowed(&replica),
+            vec![InstrumentIndex(0), InstrumentIndex(1)]
+        );
+    }
 }
@@ -3,12 +3,13 @@
 from __future__ import annotations
 
 import asyncio
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
 from decimal import Decimal
 
 import pytest
 
 from barter_python import SystemConfig, backtest
+from barter_python.barter_python import MarketDataInMemory as _RustMarketDataInMemory
 from barter_python.data import DataKind, MarketEvent, PublicTrade
 from barter_python.instrument import Side
 
@@ -75,6 +76,54 @@ class TestMarketDataInMemory:
         assert asyncio.run(fetch_time()) == first_time
 
 
+    def test_filter_kinds(self):
+        """Filtering a mixed dataset retains only the requested kinds."""
+        start = datetime(2025, 1, 1, tzinfo=timezone.utc)
+
+        def market_event(index: int, kind: DataKind) -> MarketEvent:
+            time = start + timedelta(seconds=index)
+            return MarketEvent(
+                time_exchange=time,
+                time_received=time,
+                exchange="binance_spot",
+                instrument=0,
+                kind=kind,
+            )
+
+        events = [
+            market_event(
+                index,
+                DataKind.trade(
+                    PublicTrade(id=str(index), price=50000.0, amount=1.0, side=Side.BUY)
+                ),
+            )
+            if index % 2 == 0
+            else market_event(index, DataKind.order_book("snapshot"))
+            for index in range(7)
+        ]
+        inner = _RustMarketDataInMemory(events)
+        market_data = backtest.MarketDataInMemory(
+            _time_first_event=inner.time_first_event,
+            events=inner.events(),
+            _inner=inner,
+        )
+
+        trades = market_data.filter_kinds(["trade"])
+        assert len(trades) == 4
+        assert all(event.kind.kind == "trade" for event in trades.events)
+
+        books = market_data.filter_kinds(["l2"])
+        assert len(books) == 3
+
+        assert len(market_data.filter_kinds(["public_trade", "order_book"])) == 7
+
+        with pytest.raises(ValueError, match="unknown DataKind"):
+            market_data.filter_kinds(["quotes"])
+
+        with pytest.raises(ValueError, match="at least one item"):
+            market_data.filter_kinds(["candle"])
+
+
 class TestBacktestArgs:
     """Exercise constant and dynamic backtest argument wrappers."""
 
@@ -184,3 +233,55 @@ class TestBacktestExecution:
         assert len(summaries) == 2
         assert {summary.id for summary in summaries} == {"baseline", "alt"}
 
+    def test_build_comparison_table_rows_per_run(self, example_paths):
+        args_constant = self._build_args(example_paths)
+        dynamics = [
+            backtest.BacktestArgsDynamic(id="baseline", risk_free_return=Decimal("0.01")),
+            backtest.BacktestArgsDynamic(id="alt", risk_free_return=Decimal("0.02")),
+        ]
+        summaries = backtest.run_backtests(args_constant, dynamics).summaries
+
+        rows = backtest.build_comparison_table(summaries)
+
+        assert [row["label"] for row in rows] == [summary.id for summary in summaries]
+        for row, summary in zip(rows, summaries):
+            instruments = summary.trading_summary.instruments
+            assert row["instruments"] == len(instruments)
+            assert row["pnl"] == sum(
+                (sheet.pnl for sheet in instruments.values()), Decimal(0)
+            )
+            assert row["risk_free_return"] == summary.risk_free_return
+            assert {
+                "time_engine_start",
+                "time_engine_end",
+                "pnl_return",
+                "sharpe_ratio",
+                "sortino_ratio",
+                "calmar_ratio",
+                "pnl_drawdown_max",
+                "win_rate",
+                "profit_factor",
+            } <= row.keys()
+
+    def test_build_comparison_table_labels(self, example_paths):
+        args_constant = self._build_args(example_paths)
+        summary = backtest.backtest(
+            args_constant,
+            backtest.BacktestArgsDynamic(id="baseline", risk_free_return=Decimal("0")),
+        )
+
+        rows = backtest.build_comparison_table(
+            [summary, summary.trading_summary], labels=["run-a", "run-b"]
+        )
+        assert [row["label"] for row in rows] == ["run-a", "run-b"]
+        assert rows[1]["risk_free_return"] is None
+        assert rows[0]["pnl"] == rows[1]["pnl"]
+
+        default_rows = backtest.build_comparison_table([summary.trading_summary])
+        assert default_rows[0]["label"] == "0"
+
+        with pytest.raises(ValueError, match="labels length"):
+            backtest.build_comparison_table([summary], labels=["a", "b"])
+
+        with pytest.raises(TypeError, match="BacktestSummary or TradingSummary"):
+            backtest.build_comparison_table([object()])
@@ -1,6 +1,9 @@
 from __future__ import annotations
 
+import copy
 import datetime as dt
+import json
+import time
 from decimal import Decimal
 from pathlib import Path
 
@@ -32,6 +35,26 @@ def test_subscription_id() -> None:
     assert sid != sid3
 
 
+def test_subscription_id_for_market_matches_subscription() -> None:
+    sub = bp.Subscription(
+        bp.ExchangeId.BINANCE_SPOT, "btc", "usdt", bp.SubKind.PUBLIC_TRADES
+    )
+    sid = bp.SubscriptionId.for_market(
+        bp.ExchangeId.BINANCE_SPOT, "btc", "usdt", bp.SubKind.PUBLIC_TRADES
+    )
+
+    assert sub.id == sid
+    assert sid.value == "@trade|BTCUSDT"
+    assert sid != bp.SubscriptionId.for_market(
+        bp.ExchangeId.BINANCE_SPOT, "btc", "usdt", bp.SubKind.ORDER_BOOKS_L1
+    )
+
+    with pytest.raises(ValueError, match="does not support"):
+        bp.SubscriptionId.for_market(
+            bp.ExchangeId.COINBASE, "btc", "usd", bp.SubKind.ORDER_BOOKS_L3
+        )
+
+
 def test_index_wrappers_roundtrip() -> None:
     exchange_idx = bp.ExchangeIndex(5)
     instrument_idx = bp.InstrumentIndex(11)
@@ -109,6 +132,29 @@ def test_engine_event_roundtrip() -> None:
     assert not replayed.is_terminal()
 
 
+def test_engine_event_bytes_roundtrip() -> None:
+    timestamp = dt.datetime(2024, 3, 4, 5, 6, 7, tzinfo=dt.timezone.utc)
+    events = [
+        bp.EngineEvent.shutdown(),
+        bp.EngineEvent.trading_state(False),
+        bp.EngineEvent.market_trade(
+            "binance_spot", 1, "py-trade-1", 101.25, 0.75, "buy", timestamp
+        ),
+    ]
+
+    for event in events:
+        encoded = event.to_bytes()
+        assert isinstance(encoded, bytes)
+        assert len(encoded) < len(event.to_json())
+
+        decoded = bp.EngineEvent.from_bytes(encoded)
+        assert decoded.to_json() == event.to_json()
+        assert decoded.is_terminal() == event.is_terminal()
+
+    with pytest.raises(ValueError, match="invalid EngineEvent bytes"):
+        bp.EngineEvent.from_bytes(b"not-msgpack")
+
+
 def test_engine_event_balance_snapshot_builder() -> None:
     timestamp = dt.datetime(2024, 1, 2, tzinfo=dt.timezone.utc)
 
@@ -197,13 +243,12 @@ def test_engine_event_market_order_book_l1_builder() -> None:
 
 def test_engine_event_market_candle_builder() -> None:
     time_exchange = dt.datetime(2025, 2, 3, 4, 5, 6, tzinfo=dt.timezone.utc)
-    close_time = time_exchange + dt.timedelta(minutes=1)
+    close_time = time_exchange
 
     event = bp.EngineEvent.market_candle(
         "kraken",
         4,
         time_exchange=time_exchange,
-        close_time=close_time,
         open=100.0,
         high=110.0,
         low=95.0,
@@ -225,6 +270,104 @@ def test_engine_event_market_candle_builder() -> None:
     assert candle["trade_count"] == 42
 
 
+
+def test_candle_construction_and_to_dict() -> None:
+    close_time = dt.datetime(2025, 2, 3, 4, 6, 6, tzinfo=dt.timezone.utc)
+
+    candle = bp.Candle(close_time, 100.0, 110.0, 95.0, 105.0, 250.5, trade_count=42)
+
+    assert candle.close_time == close_time
+    assert candle.open == pytest.approx(100.0)
+    assert candle.high == pytest.approx(110.0)
+    assert candle.low == pytest.approx(95.0)
+    assert candle.close == pytest.approx(105.0)
+    assert candle.volume == pytest.approx(250.5)
+    assert candle.trade_count == 42
+
+    assert candle.to_dict() == {
+        "close_time": close_time,
+        "open": 100.0,
+        "high": 110.0,
+        "low": 95.0,
+        "close": 105.0,
+        "volume": 250.5,
+        "trade_count": 42,
+    }
+    assert candle == bp.Candle(close_time, 100.0, 110.0, 95.0, 105.0, 250.5, 42)
+    assert bp.Candle(close_time, 1.0, 1.0, 1.0, 1.0, 0.0).trade_count == 0
+
+
+def test_timestamp_format_epoch_ns() -> None:
+    close_time = dt.datetime(2025, 2, 3, 4, 6, 6, 123456, tzinfo=dt.timezone.utc)
+    candle = bp.Candle(close_time, 100.0, 110.0, 95.0, 105.0, 250.5)
+
+    assert bp.timestamp_format() == "native"
+    try:
+        bp.set_timestamp_format("epoch_ns")
+        assert bp.timestamp_format() == "epoch_ns"
+        assert candle.to_dict()["close_time"] == 1_738_555_566_123_456_000
+        assert candle.close_time == close_time
+    finally:
+        bp.set_timestamp_format("native")
+
+    assert candle.to_dict()["close_time"] == close_time
+    with pytest.raises(ValueError, match="invalid timestamp format"):
+        bp.set_timestamp_format("seconds")
+
+
+def test_decimals_batch_conversion() -> None:
+    values = [Decimal("1.50"), "-0.25", 3, Decimal("1E-3")]
+
+    assert bp.decimals_to_floats(values) == [1.5, -0.25, 3.0, 0.001]
+    assert bp.decimals_to_strings(values) == ["1.50", "-0.25", "3", "0.001"]
+    assert bp.decimals_to_floats([]) == []
+
+    with pytest.raises(ValueError, match=r"values\[1\] is not a valid decimal"):
+        bp.decimals_to_strings([Decimal("1"), "abc"])
+
+
+@pytest.mark.parametrize(
+    ("high", "low", "volume"),
+    [
+        (90.0, 95.0, 1.0),
+        (110.0, 95.0, -1.0),
+        (float("nan"), 95.0, 1.0),
+        (110.0, 95.0, float("inf")),
+    ],
+)
+def test_candle_validation_failures(high: float, low: float, volume: float) -> None:
+    close_time = dt.datetime(2025, 2, 3, 4, 6, 6, tzinfo=dt.timezone.utc)
+
+    with pytest.raises(ValueError):
+        bp.Candle(close_time, 100.0, high, low, 100.0, volume)
+
+
+def test_engine_event_market_candle_from_builder() -> None:
+    close_time = dt.datetime(2025, 2, 3, 4, 6, 6, tzinfo=dt.timezone.utc)
+    candle = bp.Candle(close_time, 100.0, 110.0, 95.0, 105.0, 250.5, trade_count=7)
+
+    event = bp.EngineEvent.market_candle_from("kraken", 4, candle)
+
+    market = event.to_dict()["Market"]["Item"]
+    assert market["exchange"] == "kraken"
+    assert market["instrument"] == 4
+    assert market["time_exchange"] == close_time.isoformat().replace("+00:00", "Z")
+
+    payload = market["kind"]["Candle"]
+    assert payload["close_time"] == close_time.isoformat().replace("+00:00", "Z")
+    assert payload["high"] == pytest.approx(110.0)
+    assert payload["trade_count"] == 7
+
+    time_exchange = close_time + dt.timedelta(seconds=1)
+    event = bp.EngineEvent.market_candle_from(
+        "kraken", 4, candle, time_exchange=time_exchange
+    )
+    market = event.to_dict()["Market"]["Item"]
+    assert market["time_exchange"] == time_exchange.isoformat().replace("+00:00", "Z")
+
+    with pytest.raises(ValueError):
+        bp.EngineEvent.market_candle_from("not-an-exchange", 4, candle)
+
 def test_engine_event_market_liquidation_builder() -> None:
     timestamp = dt.datetime(2025, 3, 4, 5, 6, 7, tzinfo=dt.timezone.utc)
 
@@ -320,6 +463,75 @@ def test_system_config_dict_roundtrip(example_paths: dict[str, Path]) -> None:
     assert restored.to_dict() == config_dict
 
 
+def test_system_config_from_dict_reports_offending_field(
+    example_paths: dict[str, Path],
+) -> None:
+    config_dict = bp.SystemConfig.from_json(str(example_paths["system_config"])).to_dict()
+    assert bp.SystemConfig.from_dict(config_dict).validate() == []
+
+    bad_exchange = copy.deepcopy(config_dict)
+    bad_exchange["instruments"][0]["exchange"] = "not_an_exchange"
+    with pytest.raises(ValueError, match=r"instruments\[0\]"):
+        bp.SystemConfig.from_dict(bad_exchange)
+
+    bad_execution = copy.deepcopy(config_dict)
+    bad_execution["executions"].append({"mocked_exchange": "binance_spot"})
+    index = len(bad_execution["executions"]) - 1
+    with pytest.raises(ValueError, match=rf"executions\[{index}\]"):
+        bp.SystemConfig.from_dict(bad_execution)
+
+    with pytest.raises(ValueError, match="instruments must be a list"):
+        bp.SystemConfig.from_dict({**config_dict, "instruments": {}})
+
+    with pytest.raises(ValueError, match="must be a dictionary"):
+        bp.SystemConfig.from_dict([])
+
+
+def test_system_config_validate_reports_problems(example_paths: dict[str, Path]) -> None:
+    config_dict = bp.SystemConfig.from_json(str(example_paths["system_config"])).to_dict()
+    instrument_count = len(config_dict["instruments"])
+    config_dict["executions"] = []
+    config_dict["risk"]["instruments"] = [{"index": instrument_count + 5, "limits": {}}]
+
+    problems = bp.SystemConfig.from_dict(config_dict).validate()
+    assert any("no execution configured" in problem for problem in problems)
+    assert any("risk.instruments" in problem for problem in problems)
+
+
+def test_diff_universes_reports_instrument_changes(example_paths: dict[str, Path]) -> None:
+    old_config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    config_dict = old_config.to_dict()
+    removed, modified, *unchanged = config_dict["instruments"]
+
+    changed = dict(modified, underlying={"base": "weth", "quote": "usdt"})
+    added = dict(removed, name_exchange="BNBUSDT", underlying={"base": "bnb", "quote": "usdt"})
+    config_dict["instruments"] = [changed, *unchanged, added]
+    new_config = bp.SystemConfig.from_dict(config_dict)
+
+    diff = bp.diff_universes(old_config, new_config)
+
+    assert diff["added"] == [added]
+    assert diff["removed"] == [removed]
+    assert diff["modified"] == [{"old": modified, "new": changed}]
+
+    assert bp.diff_universes(old_config, old_config) == {
+        "added": [],
+        "removed": [],
+        "modified": [],
+    }
+
+
+def test_system_config_json_roundtrip(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    rebuilt = bp.SystemConfig.from_json_str(config.to_json())
+
+    assert rebuilt == config
+    assert len(rebuilt.to_dict()["instruments"]) == len(config.to_dict()["instruments"])
+    assert len(rebuilt.executions()) == len(config.executions())
+    assert bp.SystemConfig.from_dict(json.loads(config.to_json())) == config
+
+
 def test_system_config_from_json_str(example_paths: dict[str, Path]) -> None:
     contents = example_paths["system_config"].read_text()
     config = bp.SystemConfig.from_json_str(contents)
@@ -368,6 +580,230 @@ def test_run_historic_backtest_summary(example_paths: dict[str, Path]) -> None:
     assert isinstance(asset_summary, bp.AssetTearSheet)
 
 
+def test_run_historic_backtest_paced_replay(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    market_data = str(example_paths["market_data"])
+
+    unpaced = bp.run_historic_backtest(config, market_data)
+    paced = bp.run_historic_backtest(config, market_data, speed=1000.0)
+
+    assert paced.time_engine_start == unpaced.time_engine_start
+    assert paced.time_engine_end == unpaced.time_engine_end
+    assert set(paced.instruments) == set(unpaced.instruments)
+
+    with pytest.raises(ValueError, match="speed must be a positive"):
+        bp.run_historic_backtest(config, market_data, speed=0.0)
+
+
+def _write_binary_event_log(path: Path, events: list[bp.EngineEvent], version: int = 1) -> None:
+    frames = [b"BARTEREV", version.to_bytes(2, "little")]
+    for event in events:
+        encoded = event.to_bytes()
+        frames.append(len(encoded).to_bytes(4, "little"))
+        frames.append(encoded)
+    path.write_bytes(b"".join(frames))
+
+
+def test_run_historic_backtest_from_binary_event_log(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    market_data = json.loads(example_paths["market_data"].read_text())
+
+    events = [bp.EngineEvent.trading_state(True)]
+    for entry in market_data:
+        if "Item" in entry:
+            market = {"Item": entry["Item"]["Ok"]}
+        else:
+            market = entry
+        events.append(bp.EngineEvent.from_json(json.dumps({"Market": market})))
+
+    log_path = tmp_path / "session.bin"
+    _write_binary_event_log(log_path, events)
+
+    expected = bp.run_historic_backtest(config, str(example_paths["market_data"]))
+    replayed = bp.run_historic_backtest(config, str(log_path))
+
+    assert replayed.time_engine_start == expected.time_engine_start
+    assert replayed.time_engine_end == expected.time_engine_end
+    assert set(replayed.instruments) == set(expected.instruments)
+
+    unsupported = tmp_path / "unsupported.bin"
+    _write_binary_event_log(unsupported, events, version=2)
+    with pytest.raises(ValueError, match="unsupported event log version 2"):
+        bp.run_historic_backtest(config, str(unsupported))
+
+    no_market = tmp_path / "no_market.bin"
+    _write_binary_event_log(no_market, events[:1])
+    with pytest.raises(ValueError, match="event log contains no market events"):
+        bp.run_historic_backtest(config, str(no_market))
+
+
+def test_run_backtest_from_events(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    start = dt.datetime(2024, 12, 20, 19, 5, 18, tzinfo=dt.timezone.utc)
+
+    events = [
+        bp.EngineEvent.market_trade(
+            "binance_spot",
+            0,
+            f"trade-{index}",
+            96_000.0 + index,
+            0.01,
+            "buy" if index % 2 == 0 else "sell",
+            start + dt.timedelta(seconds=index),
+        )
+        for index in range(5)
+    ]
+
+    summary = bp.run_backtest_from_events(config, events, interval="daily")
+
+    assert isinstance(summary, bp.TradingSummary)
+    # The historical clock is seeded from the first market item
+    assert start <= summary.time_engine_start < start + dt.timedelta(minutes=1)
+    assert summary.time_engine_start <= summary.time_engine_end
+    assert summary.instruments
+
+    with pytest.raises(ValueError, match="events must not be empty"):
+        bp.run_backtest_from_events(config, [])
+
+    with pytest.raises(ValueError, match="events contain no market items"):
+        bp.run_backtest_from_events(config, [bp.EngineEvent.trading_state(True)])
+
+
+def test_run_historic_backtest_from_json_event_log(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    market_data = json.loads(example_paths["market_data"].read_text())
+
+    events = [bp.EngineEvent.trading_state(True), bp.EngineEvent.close_positions(None)]
+    for entry in market_data:
+        if "Item" in entry:
+            market = {"Item": entry["Item"]["Ok"]}
+        else:
+            market = entry
+        events.append(bp.EngineEvent.from_json(json.dumps({"Market": market})))
+
+    # record_events(format="json") writes one EngineEvent per line, whatever the extension
+    log_path = tmp_path / "session.json"
+    log_path.write_text("\n".join(event.to_json() for event in events) + "\n")
+
+    expected = bp.run_historic_backtest(config, str(example_paths["market_data"]))
+    replayed = bp.run_historic_backtest(config, str(log_path))
+
+    assert replayed.time_engine_start == expected.time_engine_start
+    assert replayed.time_engine_end == expected.time_engine_end
+    assert set(replayed.instruments) == set(expected.instruments)
+
+    no_market = tmp_path / "no_market.jsonl"
+    no_market.write_text(events[0].to_json() + "\n")
+    with pytest.raises(ValueError, match="market data contains no events"):
+        bp.run_historic_backtest(config, str(no_market))
+
+
+def test_run_historic_backtest_progress_callback(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    calls: list[tuple[int, dt.datetime]] = []
+
+    def on_progress(processed: int, time_exchange: dt.datetime) -> None:
+        calls.append((processed, time_exchange))
+        raise RuntimeError("progress callbacks must not abort the backtest")
+
+    summary = bp.run_historic_backtest(
+        config,
+        str(example_paths["market_data"]),
+        progress_callback=on_progress,
+        progress_interval=2,
+    )
+
+    assert isinstance(summary, bp.TradingSummary)
+    assert calls
+    assert [processed for processed, _ in calls] == list(range(2, 2 * len(calls) + 1, 2))
+    assert all(isinstance(time, dt.datetime) for _, time in calls)
+
+    with pytest.raises(ValueError, match="progress_interval"):
+        bp.run_historic_backtest(
+            config,
+            str(example_paths["market_data"]),
+            progress_callback=on_progress,
+            progress_interval=0,
+        )
+
+
+def test_run_historic_backtest_checkpoint_and_resume(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    market_data = str(example_paths["market_data"])
+    checkpoint_path = tmp_path / "backtest_checkpoint.json"
+
+    uninterrupted = bp.run_historic_backtest(
+        config, market_data, checkpoint_path=checkpoint_path, checkpoint_interval=2
+    )
+
+    checkpoint = json.loads(checkpoint_path.read_text())
+    assert checkpoint["events_processed"] > 0
+    assert checkpoint["events_processed"] % 2 == 0
+    assert "state" in checkpoint
+    assert isinstance(checkpoint["state_hash"], int)
+    assert not checkpoint_path.with_suffix(".tmp").exists()
+
+    resumed = bp.run_historic_backtest(config, market_data, resume_from=checkpoint_path)
+
+    assert resumed.time_engine_end == uninterrupted.time_engine_end
+    assert set(resumed.instruments) == set(uninterrupted.instruments)
+
+    with pytest.raises(ValueError, match="checkpoint_interval must be a positive"):
+        bp.run_historic_backtest(
+            config, market_data, checkpoint_path=checkpoint_path, checkpoint_interval=0
+        )
+    with pytest.raises(ValueError, match="requires a checkpoint_path"):
+        bp.run_historic_backtest(config, market_data, checkpoint_interval=2)
+    with pytest.raises(ValueError, match="resume_from"):
+        bp.run_historic_backtest(
+            config, market_data, resume_from=tmp_path / "missing_checkpoint.json"
+        )
+
+
+def test_run_historic_backtest_json_lines_matches_array(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    array_path = example_paths["market_data"]
+
+    events = json.loads(array_path.read_text())
+    assert len(events) > 1
+
+    jsonl_path = tmp_path / "market_data.jsonl"
+    jsonl_path.write_text(
+        "\n".join(json.dumps(event) for event in events) + "\n\n"
+    )
+
+    from_array = bp.run_historic_backtest(config, str(array_path))
+    from_lines = bp.run_historic_backtest(config, str(jsonl_path))
+
+    array_dict = from_array.to_dict()
+    lines_dict = from_lines.to_dict()
+    assert lines_dict["instruments"].keys() == array_dict["instruments"].keys()
+    assert lines_dict["assets"].keys() == array_dict["assets"].keys()
+
+    for name, sheet in array_dict["instruments"].items():
+        other = lines_dict["instruments"][name]
+        for metric in ("pnl", "pnl_return", "sharpe_ratio", "sortino_ratio", "calmar_ratio"):
+            assert other[metric] == sheet[metric]
+
+
+def test_run_historic_backtest_json_lines_without_events_raises(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    jsonl_path = tmp_path / "empty.jsonl"
+    jsonl_path.write_text("\n")
+
+    with pytest.raises(ValueError):
+        bp.run_historic_backtest(config, str(jsonl_path))
+
 def test_system_handle_lifecycle(example_paths: dict[str, Path]) -> None:
     config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
     handle = bp.start_system(config, trading_enabled=False)
@@ -387,6 +823,768 @@ def test_system_handle_lifecycle(example_paths: dict[str, Path]) -> None:
     assert not handle.is_running()
 
 
+def test_system_handle_replay_market_data_controls(
+    example_paths: dict[str, Path],
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        with pytest.raises(ValueError):
+            handle.replay_market_data(str(example_paths["market_data"]), speed=0.0)
+
+        replay = handle.replay_market_data(
+            str(example_paths["market_data"]), speed=1000.0, start_paused=True
+        )
+        assert replay.is_paused
+        assert not replay.wait(timeout_secs=0.1)
+        assert replay.events_sent == 0
+
+        replay.step()
+        deadline = time.monotonic() + 5.0
+        while replay.events_sent < 1 and time.monotonic() < deadline:
+            time.sleep(0.01)
+        assert replay.events_sent == 1
+
+        replay.resume()
+        with pytest.raises(ValueError):
+            replay.step()
+
+        assert replay.wait(timeout_secs=5.0)
+        assert replay.is_finished
+        assert replay.events_sent > 1
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_with_max_open_positions(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="max_open_positions must be positive"):
+        bp.start_system(config, trading_enabled=False, max_open_positions=0)
+
+    handle = bp.start_system(config, trading_enabled=False, max_open_positions=1)
+    try:
+        assert handle.is_running()
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_with_max_position_quantity(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="max_position_quantity"):
+        bp.start_system(config, trading_enabled=False, max_position_quantity={0: 0})
+
+    handle = bp.start_system(
+        config, trading_enabled=False, max_position_quantity={0: Decimal("0.5")}
+    )
+    try:
+        assert handle.is_running()
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_with_max_orders_per_second(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="max_orders_per_second must be positive"):
+        bp.start_system(config, trading_enabled=False, max_orders_per_second=0)
+
+    handle = bp.start_system(config, trading_enabled=False, max_orders_per_second=5)
+    try:
+        assert handle.is_running()
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_audit_skip_empty(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="audit_skip_empty requires audit=True"):
+        bp.start_system(config, trading_enabled=False, audit_skip_empty=True)
+
+    handle = bp.start_system(config, trading_enabled=False, audit=True, audit_skip_empty=True)
+    try:
+        updates = handle.take_audit().updates
+        # Trading state updates with no resulting outputs are dropped from the audit stream.
+        handle.set_trading_enabled(False)
+        tick = updates.recv_matching(
+            lambda tick: tick["event"].get("event_type") == "TradingStateUpdate", timeout=0.5
+        )
+        assert tick is None
+    finally:
+        handle.shutdown()
+
+
+def _buy_once_algo_strategy(sent: set[int]):
+    """Algo strategy buying 1.0 of each priced instrument once, unless it is in `sent`."""
+
+    def strategy(instruments: list[dict]) -> list[bp.OrderRequestOpen]:
+        orders = []
+        for item in instruments:
+            instrument = item["instrument"]
+            if item["price"] is None or instrument in sent:
+                continue
+            sent.add(instrument)
+            orders.append(
+                bp.OrderRequestOpen(
+                    bp.OrderKey(item["exchange"], instrument, "algo", f"cid-algo-{instrument}"),
+                    "buy",
+                    float(item["price"]),
+                    1.0,
+                    kind="market",
+                    time_in_force="immediate_or_cancel",
+                )
+            )
+        return orders
+
+    return strategy
+
+
+def _wait_for_position(handle, instrument: int, timeout: float = 5.0) -> bool:
+    deadline = time.monotonic() + timeout
+    while time.monotonic() < deadline:
+        if any(int(position["instrument"]) == instrument for position in handle.positions()):
+            return True
+        time.sleep(0.05)
+    return False
+
+
+def _market_trade(instrument: int, trade_id: str, price: float = 100.0) -> bp.EngineEvent:
+    return bp.EngineEvent.market_trade(
+        "binance_spot",
+        instrument,
+        trade_id,
+        price,
+        1.0,
+        "buy",
+        dt.datetime.now(dt.timezone.utc),
+    )
+
+
+def test_system_handle_set_instrument_trading_enabled(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(TypeError, match="algo_strategy must be callable"):
+        bp.start_system(config, algo_strategy=42)
+
+    sent: set[int] = set()
+    handle = bp.start_system(
+        config, trading_enabled=True, algo_strategy=_buy_once_algo_strategy(sent)
+    )
+
+    try:
+        with pytest.raises(ValueError, match="out of range"):
+            handle.set_instrument_trading_enabled(99, False)
+
+        handle.set_instrument_trading_enabled(0, False)
+        handle.send_event(_market_trade(0, "trade-0"))
+        handle.send_event(_market_trade(1, "trade-1"))
+
+        # The strategy orders both instruments, but only the order for instrument 1 is sent.
+        assert _wait_for_position(handle, 1)
+        assert sent == {0, 1}
+        assert not any(int(position["instrument"]) == 0 for position in handle.positions())
+
+        # Re-enabled, the next order generated for instrument 0 reaches the exchange.
+        handle.set_instrument_trading_enabled(0, True)
+        sent.discard(0)
+        handle.send_event(_market_trade(0, "trade-0-enabled"))
+        assert _wait_for_position(handle, 0)
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_event_clock_tracks_market_event_time(
+    example_paths: dict[str, Path],
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="engine_clock"):
+        bp.start_system(config, trading_enabled=False, engine_clock="sundial")
+
+    handle = bp.start_system(config, trading_enabled=False, audit=True, engine_clock="event")
+
+    try:
+        updates = handle.take_audit().updates
+        start = dt.datetime(2035, 6, 1, 12, tzinfo=dt.timezone.utc)
+
+        for offset in range(3):
+            time_exchange = start + dt.timedelta(minutes=offset)
+            handle.send_event(
+                bp.EngineEvent.market_trade(
+                    "binance_spot", 0, f"future-{offset}", 100.0, 1.0, "buy", time_exchange
+                )
+            )
+            tick = updates.recv_matching(
+                lambda tick: tick["event"].get("event_type") == "Market", timeout=1.0
+            )
+            assert tick is not None
+            assert dt.datetime.fromisoformat(tick["context"]["time"]) == time_exchange
+    finally:
+        handle.shutdown()
+
+
+def test_system_handle_record_events(tmp_path: Path, example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    handle = bp.start_system(config, trading_enabled=False, audit=True)
+    log_path = tmp_path / "events.bin"
+
+    timestamp = dt.datetime.now(dt.timezone.utc)
+    events = [
+        bp.EngineEvent.market_trade(
+            "binance_spot", 0, f"trade-{index}", 100.0 + index, 1.0, "buy", timestamp
+        )
+        for index in range(3)
+    ]
+
+    try:
+        updates = handle.take_audit().updates
+
+        with pytest.raises(ValueError, match="format must be one of"):
+            handle.record_events(str(log_path), format="xml")
+        assert handle.stop_recording() is None
+
+        handle.record_events(str(log_path))
+        handle.feed_events(events)
+        for _ in events:
+            tick = updates.recv_matching(
+                lambda tick: tick["event"].get("event_type") == "Market", timeout=1.0
+            )
+            assert tick is not None
+
+        assert handle.stop_recording() == len(events)
+    finally:
+        handle.shutdown()
+
+    data = log_path.read_bytes()
+    assert data[:8] == b"BARTEREV"
+    assert int.from_bytes(data[8:10], "little") == 1
+
+    recorded = []
+    offset = 10
+    while offset < len(data):
+        length = int.from_bytes(data[offset : offset + 4], "little")
+        offset += 4
+        recorded.append(bp.EngineEvent.from_bytes(data[offset : offset + length]))
+        offset += length
+
+    assert [event.to_json() for event in recorded] == [event.to_json() for event in events]
+
+
+def test_system_handle_shutdown_timeout(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    handle = bp.start_system(config, trading_enabled=False)
+    with pytest.raises(ValueError, match="non-negative"):
+        handle.shutdown_timeout(-1.0)
+    assert handle.shutdown_timeout(10.0) is True
+    assert not handle.is_running()
+
+    # A zero timeout cannot wait for the engine, so the system is aborted promptly instead.
+    handle = bp.start_system(config, trading_enabled=False)
+    started = time.monotonic()
+    graceful = handle.shutdown_timeout(0.0)
+    assert time.monotonic() - started < 5.0
+    assert isinstance(graceful, bool)
+    assert not handle.is_running()
+    with pytest.raises(ValueError, match="system is not running"):
+        handle.shutdown_timeout(1.0)
+
+
+def test_start_system_heartbeat_callback(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="heartbeat_interval_secs"):
+        bp.start_system(config, heartbeat_callback=print, heartbeat_interval_secs=0.0)
+    with pytest.raises(ValueError, match="heartbeat_interval_secs is out of range"):
+        bp.start_system(config, heartbeat_callback=print, heartbeat_interval_secs=1e300)
+    with pytest.raises(ValueError, match="requires a heartbeat_callback"):
+        bp.start_system(config, heartbeat_interval_secs=1.0)
+
+    heartbeats: list[bp.Metric] = []
+    handle = bp.start_system(
+        config,
+        trading_enabled=False,
+        heartbeat_callback=heartbeats.append,
+        heartbeat_interval_secs=0.05,
+    )
+    try:
+        deadline = time.monotonic() + 5.0
+        while len(heartbeats) < 2 and time.monotonic() < deadline:
+            time.sleep(0.01)
+    finally:
+        handle.shutdown()
+
+    assert len(heartbeats) >= 2
+    heartbeat = heartbeats[-1]
+    assert heartbeat.name == "heartbeat"
+    fields = {field.key: field.value for field in heartbeat.fields}
+    assert fields["uptime_secs"].as_float() > 0.0
+    assert fields["events_processed"].is_uint()
+    assert fields["trading_enabled"].as_bool() is False
+
+    received = len(heartbeats)
+    time.sleep(0.15)
+    assert len(heartbeats) == received
+
+
+def test_start_system_disables_trading_on_execution_disconnect(
+    example_paths: dict[str, Path],
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    def trading_enabled(heartbeat: bp.Metric) -> bool:
+        fields = {field.key: field.value for field in heartbeat.fields}
+        return fields["trading_enabled"].as_bool()
+
+    heartbeats: list[bp.Metric] = []
+    handle = bp.start_system(
+        config,
+        trading_enabled=True,
+        disable_trading_on_disconnect=True,
+        cancel_orders_on_disconnect=True,
+        heartbeat_callback=heartbeats.append,
+        heartbeat_interval_secs=0.02,
+    )
+    try:
+        handle.send_event(bp.EngineEvent.account_reconnecting("binance_spot"))
+
+        deadline = time.monotonic() + 5.0
+        while time.monotonic() < deadline and (
+            not heartbeats or trading_enabled(heartbeats[-1])
+        ):
+            time.sleep(0.01)
+    finally:
+        handle.shutdown()
+
+    assert heartbeats
+    assert not trading_enabled(heartbeats[-1])
+
+
+def test_start_system_market_disconnect_policy(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="on_market_disconnect"):
+        bp.start_system(config, on_market_disconnect="panic")
+
+    handle = bp.start_system(
+        config, trading_enabled=False, audit=True, on_market_disconnect="flatten"
+    )
+    try:
+        updates = handle.take_audit().updates
+        handle.send_event(bp.EngineEvent.market_reconnecting("binance_spot"))
+
+        payload = None
+        deadline = time.monotonic() + 5.0
+        while payload is None and time.monotonic() < deadline:
+            tick = updates.recv(timeout=1.0)
+            if tick is None or tick["event"]["kind"] != "Process":
+                continue
+            for output in tick["event"]["outputs"].to_list():
+                if output.variant == "MarketDisconnect":
+                    payload = output.market_disconnect
+    finally:
+        handle.shutdown()
+
+    assert payload is not None
+    assert payload["trading_disabled"] is False
+    assert payload["closes"] is not None
+
+
+def test_start_system_market_disconnect_pause_holds_algo_orders(
+    example_paths: dict[str, Path],
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    wanted: set[int] = set()
+    calls: list[list[dict]] = []
+
+    def strategy(instruments: list[dict]) -> list[bp.OrderRequestOpen]:
+        calls.append(instruments)
+        orders = [
+            bp.OrderRequestOpen(
+                bp.OrderKey(item["exchange"], item["instrument"], "algo", "cid-pause"),
+                "buy",
+                float(item["price"]),
+                1.0,
+                kind="market",
+                time_in_force="immediate_or_cancel",
+            )
+            for item in instruments
+            if item["instrument"] in wanted and item["price"] is not None
+        ]
+        wanted.difference_update(order.key.instrument for order in orders)
+        return orders
+
+    handle = bp.start_system(
+        config,
+        trading_enabled=True,
+        audit=True,
+        algo_strategy=strategy,
+        on_market_disconnect="pause",
+    )
+    try:
+        updates = handle.take_audit().updates
+
+        def is_command(tick: dict) -> bool:
+            return tick["event"].get("event_type") == "Command"
+
+        handle.send_event(_market_trade(0, "trade-healthy"))
+        handle.set_instrument_trading_enabled(0, True)
+        assert updates.recv_matching(is_command, timeout=5.0) is not None
+        assert calls
+        healthy_calls = len(calls)
+
+        # While the feed is reconnecting the strategy is not consulted and no orders are sent.
+        handle.send_event(bp.EngineEvent.market_reconnecting("binance_spot"))
+        wanted.add(0)
+        handle.set_instrument_trading_enabled(0, True)
+        assert updates.recv_matching(is_command, timeout=5.0) is not None
+        assert len(calls) == healthy_calls
+        assert handle.positions() == []
+
+        # The next market event marks the feed healthy, and the held order is generated.
+        handle.send_event(_market_trade(0, "trade-reconnected"))
+        assert _wait_for_position(handle, 0)
+        assert wanted == set()
+    finally:
+        handle.shutdown()
+
+
+def test_start_system_drops_stale_market_events(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+
+    with pytest.raises(ValueError, match="max_message_age_secs"):
+        bp.start_system(config, max_message_age_secs=0.0)
+
+    received = dt.datetime(2025, 1, 1, 12, tzinfo=dt.timezone.utc)
+
+    def trade(trade_id: str, age: dt.timedelta) -> bp.EngineEvent:
+        return bp.EngineEvent.market_trade(
+            "binance_spot",
+            0,
+            trade_id,
+            100.0,
+            1.0,
+            "buy",
+            time_exchange=received - age,
+            time_received=received,
+        )
+
+    handle = bp.start_system(config, trading_enabled=False, max_message_age_secs=5.0)
+    try:
+        assert handle.stale_events_dropped == 0
+        handle.send_event(trade("fresh", dt.timedelta(seconds=1)))
+        handle.send_event(trade("stale", dt.timedelta(minutes=1)))
+        assert handle.stale_events_dropped == 1
+    finally:
+        handle.shutdown()
+
+
+def test_system_handle_positions_reflect_fills(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        assert handle.positions() == []
+
+        key = bp.OrderKey(0, 0, "positions", "cid-positions-0")
+        handle.send_open_requests(
+            [
+                bp.OrderRequestOpen(
+                    key,
+                    "buy",
+                    100.0,
+                    1.0,
+                    kind="market",
+                    time_in_force="immediate_or_cancel",
+                )
+            ]
+        )
+
+        positions = []
+        deadline = time.monotonic() + 5.0
+        while not positions and time.monotonic() < deadline:
+            time.sleep(0.05)
+            positions = handle.positions()
+
+        assert len(positions) == 1
+        position = positions[0]
+        assert int(position["instrument"]) == 0
+        assert position["instrument_name"].startswith("binance_spot-")
+        assert position["side"] == bp.Side.BUY
+        assert position["quantity"] == Decimal("1")
+        assert position["price_entry_average"] == Decimal("100")
+        assert "pnl_unrealised" in position
+    finally:
+        handle.shutdown()
+
+
+def test_system_resting_limit_order_fills_at_maker_fee(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    raw = json.loads(example_paths["system_config"].read_text())
+    for execution in raw["executions"]:
+        execution["maker_fee"] = "0.002"
+        execution["taker_fee"] = "0.01"
+    config_path = tmp_path / "maker_fee_system_config.json"
+    config_path.write_text(json.dumps(raw))
+
+    config = bp.SystemConfig.from_json(str(config_path))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        handle.send_open_requests(
+            [
+                bp.OrderRequestOpen(
+                    bp.OrderKey(0, 0, "maker", "cid-maker-0"),
+                    "buy",
+                    90.0,
+                    0.5,
+                    kind="limit",
+                    time_in_force="good_until_cancelled",
+                )
+            ]
+        )
+
+        orders = []
+        deadline = time.monotonic() + 5.0
+        while [order.state for order in orders] != ["open"] and time.monotonic() < deadline:
+            time.sleep(0.05)
+            orders = handle.open_orders()
+        assert [order.state for order in orders] == ["open"]
+
+        # Market prices above the limit leave the order resting on the mock exchange.
+        handle.send_event(_market_trade(0, "trade-above-limit", 100.0))
+        time.sleep(0.2)
+        assert handle.positions() == []
+        assert len(handle.open_orders()) == 1
+
+        # A crossing market price fills the order at its limit price, charging the maker fee.
+        handle.send_event(_market_trade(0, "trade-crosses-limit", 85.0))
+        assert _wait_for_position(handle, 0)
+
+        position = handle.positions()[0]
+        assert position["quantity"] == Decimal("0.5")
+        assert position["price_entry_average"] == Decimal("90")
+        assert position["pnl_realised"] == -(Decimal("90") * Decimal("0.5") * Decimal("0.002"))
+        assert handle.open_orders() == []
+    finally:
+        handle.shutdown()
+
+
+def test_system_handle_exposure_long_and_short(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        empty = handle.exposure()
+        assert empty["instruments"] == []
+        assert empty["gross"] == Decimal("0")
+        assert empty["net"] == Decimal("0")
+
+        handle.send_open_requests(
+            [
+                bp.OrderRequestOpen(
+                    bp.OrderKey(0, 0, "exposure", "cid-exposure-long"),
+                    "buy",
+                    100.0,
+                    1.0,
+                    kind="market",
+                    time_in_force="immediate_or_cancel",
+                ),
+                bp.OrderRequestOpen(
+                    bp.OrderKey(0, 1, "exposure", "cid-exposure-short"),
+                    "sell",
+                    200.0,
+                    0.25,
+                    kind="market",
+                    time_in_force="immediate_or_cancel",
+                ),
+            ]
+        )
+
+        deadline = time.monotonic() + 5.0
+        while len(handle.positions()) < 2 and time.monotonic() < deadline:
+            time.sleep(0.05)
+
+        exposure = handle.exposure()
+        by_instrument = {int(entry["instrument"]): entry for entry in exposure["instruments"]}
+        assert by_instrument[0]["net"] == Decimal("100")
+        assert by_instrument[1]["net"] == Decimal("-50")
+        assert exposure["gross"] == Decimal("150")
+        assert exposure["net"] == Decimal("50")
+        assert exposure["gross"] > exposure["net"]
+    finally:
+        handle.shutdown()
+
+
+def test_system_handle_state_hash_is_deterministic(example_paths: dict[str, Path]) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    first = bp.start_system(config, trading_enabled=False)
+    second = bp.start_system(config, trading_enabled=False)
+
+    try:
+        initial = first.state_hash()
+        assert isinstance(initial, int)
+        assert initial == first.state_hash()
+        assert initial == second.state_hash()
+
+        key = bp.OrderKey(0, 0, "state-hash", "cid-state-hash-0")
+        first.send_open_requests(
+            [
+                bp.OrderRequestOpen(
+                    key, "buy", 100.0, 1.0, kind="market", time_in_force="immediate_or_cancel"
+                )
+            ]
+        )
+
+        deadline = time.monotonic() + 5.0
+        while not first.positions() and time.monotonic() < deadline:
+            time.sleep(0.05)
+
+        assert first.state_hash() != initial
+        assert second.state_hash() == initial
+    finally:
+        first.shutdown()
+        second.shutdown()
+
+
+def test_system_handle_open_orders_track_in_flight_requests(
+    example_paths: dict[str, Path], tmp_path: Path
+) -> None:
+    # Slow the mock exchange down so the request stays in flight while inspected.
+    raw = json.loads(example_paths["system_config"].read_text())
+    for execution in raw["executions"]:
+        execution["latency_ms"] = 10_000
+    config_path = tmp_path / "slow_system_config.json"
+    config_path.write_text(json.dumps(raw))
+
+    config = bp.SystemConfig.from_json(str(config_path))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        assert handle.open_orders() == []
+
+        key = bp.OrderKey(0, 0, "open-orders", "cid-open-orders-0")
+        handle.send_open_requests(
+            [
+                bp.OrderRequestOpen(
+                    key,
+                    "buy",
+                    100.0,
+                    0.5,
+                    kind="market",
+                    time_in_force="immediate_or_cancel",
+                )
+            ]
+        )
+
+        orders = []
+        deadline = time.monotonic() + 5.0
+        while not orders and time.monotonic() < deadline:
+            time.sleep(0.05)
+            orders = handle.open_orders()
+
+        assert len(orders) == 1
+        order = orders[0]
+        assert isinstance(order, bp.OrderSnapshot)
+        assert order.key.client_order_id == "cid-open-orders-0"
+        assert order.key.instrument == 0
+        assert order.side == "buy"
+        assert Decimal(order.quantity) == Decimal("0.5")
+        assert order.state == "open_in_flight"
+
+        assert len(handle.open_orders(bp.InstrumentFilter.instruments([0]))) == 1
+        assert handle.open_orders(bp.InstrumentFilter.instruments([1])) == []
+    finally:
+        handle.abort()
+
+
+def test_system_handle_send_open_requests_validates_instrument_specs(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    raw = json.loads(example_paths["system_config"].read_text())
+    raw["instruments"][0]["spec"] = {
+        "price": {"min": "0.01", "tick_size": "0.01"},
+        "quantity": {"unit": "Contract", "min": "0.0001", "increment": "0.0001"},
+        "notional": {"min": "10"},
+    }
+    config_path = tmp_path / "spec_system_config.json"
+    config_path.write_text(json.dumps(raw))
+
+    config = bp.SystemConfig.from_json(str(config_path))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    def request(cid: str, price: float, quantity: float) -> bp.OrderRequestOpen:
+        return bp.OrderRequestOpen(
+            bp.OrderKey(0, 0, "validate", cid),
+            "buy",
+            price,
+            quantity,
+            kind="market",
+            time_in_force="immediate_or_cancel",
+        )
+
+    try:
+        with pytest.raises(ValueError) as excinfo:
+            handle.send_open_requests(
+                [request("cid-tick", 100.005, 0.5), request("cid-notional", 100.0, 0.05)],
+                validate=True,
+            )
+
+        message = str(excinfo.value)
+        assert "request 0: price 100.005 is not a multiple of tick_size 0.01" in message
+        assert "request 1: notional" in message
+        assert "below minimum 10" in message
+
+        # Nothing reached the engine
+        time.sleep(0.2)
+        assert handle.open_orders() == []
+    finally:
+        handle.shutdown()
+
+
+def test_system_handle_validate_ignores_zero_spec_increments(
+    tmp_path: Path, example_paths: dict[str, Path]
+) -> None:
+    raw = json.loads(example_paths["system_config"].read_text())
+    raw["instruments"][0]["spec"] = {
+        "price": {"min": "0", "tick_size": "0"},
+        "quantity": {"unit": "Contract", "min": "0", "increment": "0"},
+        "notional": {"min": "10"},
+    }
+    config_path = tmp_path / "zero_spec_system_config.json"
+    config_path.write_text(json.dumps(raw))
+
+    config = bp.SystemConfig.from_json(str(config_path))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    try:
+        with pytest.raises(ValueError) as excinfo:
+            handle.send_open_requests(
+                [
+                    bp.OrderRequestOpen(
+                        bp.OrderKey(0, 0, "validate", "cid-zero-spec"),
+                        "buy",
+                        100.005,
+                        0.05,
+                        kind="market",
+                        time_in_force="immediate_or_cancel",
+                    )
+                ],
+                validate=True,
+            )
+
+        message = str(excinfo.value)
+        assert "below minimum 10" in message
+        assert "tick_size" not in message
+        assert "increment" not in message
+    finally:
+        handle.shutdown()
+
+
 def test_system_handle_feed_events(example_paths: dict[str, Path]) -> None:
     config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
     handle = bp.start_system(config, trading_enabled=False)
@@ -406,6 +1604,27 @@ def test_system_handle_feed_events(example_paths: dict[str, Path]) -> None:
     assert not handle.is_running()
 
 
+def test_system_handle_try_feed_events_reports_failures(
+    example_paths: dict[str, Path],
+) -> None:
+    config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+    handle = bp.start_system(config, trading_enabled=False)
+
+    events = [
+        bp.EngineEvent.trading_state(True),
+        bp.EngineEvent.cancel_orders(bp.InstrumentFilter.none()),
+    ]
+
+    try:
+        assert handle.try_feed_events(events) == []
+    finally:
+        handle.shutdown()
+
+    failures = handle.try_feed_events(events)
+    assert [index for index, _ in failures] == [0, 1]
+    assert all(isinstance(error, ValueError) for _, error in failures)
+
+
 def test_system_handle_abort(example_paths: dict[str, Path]) -> None:
     config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
     handle = bp.start_system(config, trading_enabled=False)
@@ -468,6 +1687,36 @@ def test_order_request_helpers() -> None:
     assert not cancel_event.is_terminal()
 
 
+def test_order_approx_eq_tolerates_rounding_differences() -> None:
+    key = bp.OrderKey(1, 2, "strategy-alpha", "cid-1")
+    request = bp.OrderRequestOpen(key, "buy", 105.25, 0.75, kind="limit")
+    rounded = bp.OrderRequestOpen(key, "buy", 105.2, 0.7501, kind="limit")
+
+    assert not request.approx_eq(rounded)
+    assert request.approx_eq(rounded, price_tol=0.05, qty_tol=0.0001)
+    assert not request.approx_eq(
+        bp.OrderRequestOpen(key, "sell", 105.25, 0.75, kind="limit"),
+        price_tol=1.0,
+        qty_tol=1.0,
+    )
+    with pytest.raises(ValueError, match="qty_tol"):
+        request.approx_eq(rounded, qty_tol=-1.0)
+
+    timestamp = dt.datetime(2025, 9, 10, 11, 12, 13, tzinfo=dt.timezone.utc)
+    snapshot = bp.OrderSnapshot.from_open_request(
+        request, order_id="order-1", time_exchange=timestamp, filled_quantity=0.25
+    )
+    rounded_snapshot = bp.OrderSnapshot.from_open_request(
+        rounded, order_id="order-1", time_exchange=timestamp, filled_quantity=0.2501
+    )
+
+    assert not snapshot.approx_eq(rounded_snapshot)
+    assert snapshot.approx_eq(rounded_snapshot, price_tol=0.05, qty_tol=0.0001)
+    assert not snapshot.approx_eq(
+        bp.OrderSnapshot.from_open_request(request), price_tol=1.0, qty_tol=1.0
+    )
+
+
 def test_order_snapshot_open_helper() -> None:
     timestamp = dt.datetime(2025, 9, 10, 11, 12, 13, tzinfo=dt.timezone.utc)
     key = bp.OrderKey(1, 2, "strategy-alpha", "cid-1")
@@ -698,6 +1947,18 @@ def test_calculate_win_rate() -> None:
     assert no_trades is None
 
 
+def test_exchange_id_trading_hours() -> None:
+    at = dt.datetime(2025, 1, 1, 19, 5, tzinfo=dt.timezone.utc)
+
+    assert bp.ExchangeId.BINANCE_SPOT.trading_hours() is None
+    assert bp.ExchangeId.BINANCE_SPOT.is_open(at)
+
+    assert bp.ExchangeId.BITFLYER.trading_hours() == (dt.time(19, 10), dt.time(19, 0))
+    assert not bp.ExchangeId.BITFLYER.is_open(at)
+    assert bp.ExchangeId.BITFLYER.is_open(at.replace(hour=19, minute=10))
+    assert bp.ExchangeId.BITFLYER.is_open(at.replace(hour=3))
+
+
 def test_exchange_id_constants() -> None:
     assert str(bp.ExchangeId.OTHER) == "Other"
     assert str(bp.ExchangeId.SIMULATED) == "Simulated"
@@ -898,3 +2159,115 @@ class TestWelfordOnlineAlgorithms:
 
         result = bp.welford_calculate_population_variance(99999.9999, 23232)
         assert result == Decimal("4.3044077091942148760330578512")
+
+    def test_welford_accumulator_matches_batch_functions(self) -> None:
+        dataset = [0.1, -0.2, -0.05, 0.2, 0.15, -0.17]
+        accumulator = bp.Welford()
+        mean = Decimal("0")
+        m = Decimal("0")
+
+        for count, value in enumerate(dataset, start=1):
+            accumulator.update(value)
+            next_mean = bp.welford_calculate_mean(mean, value, count)
+            m = bp.welford_calculate_recurrence_relation_m(m, mean, value, next_mean)
+            mean = next_mean
+
+            assert accumulator.count() == count
+            assert accumulator.mean() == mean
+            assert accumulator.sample_variance() == bp.welford_calculate_sample_variance(
+                m, count
+            )
+            assert accumulator.population_variance() == (
+                bp.welford_calculate_population_variance(m, count)
+            )
+
+        assert accumulator.mean() == Decimal("0.005")
+
+    def test_welford_merge_equals_combined_series(self) -> None:
+        left, right = bp.Welford(), bp.Welford()
+        combined = bp.Welford()
+        for value in range(1, 5):
+            left.update(value)
+            combined.update(value)
+        for value in range(5, 11):
+            right.update(value)
+            combined.update(value)
+
+        left.merge(right)
+
+        assert left.count() == combined.count() == 10
+        assert left.mean() == combined.mean() == Decimal("5.5")
+        assert left.population_variance() == combined.population_variance() == Decimal("8.25")
+        assert left.sample_variance() == combined.sample_variance()
+        # Merging into or from an empty accumulator is a no-op on the statistics.
+        empty = bp.Welford()
+        empty.merge(left)
+        left.merge(bp.Welford())
+        assert empty.mean() == left.mean() == Decimal("5.5")
+        assert empty.count() == left.count() == 10
+
+
+def test_data_kind_from_engine_events() -> None:
+    timestamp = dt.datetime(2025, 5, 6, 7, 8, 9, tzinfo=dt.timezone.utc)
+    candle = bp.Candle(timestamp, 100.0, 110.0, 95.0, 105.0, 250.5)
+
+    cases = [
+        (
+            bp.EngineEvent.market_trade(
+                "binance_spot", 1, "trade-1", 101.25, 0.75, "buy", timestamp
+            ),
+            bp.DataKind.TRADE,
+        ),
+        (bp.EngineEvent.market_candle_from("kraken", 4, candle), bp.DataKind.CANDLE),
+        (
+            bp.EngineEvent.market_order_book_l1(
+                "binance_spot", 7, 100.5, 2.0, 101.0, 1.5, timestamp
+            ),
+            bp.DataKind.ORDER_BOOK_L1,
+        ),
+        (
+            bp.EngineEvent.market_order_book_snapshot(
+                "binance_spot", 3, 1, timestamp, [(100.5, 2.0)], [(101.0, 1.0)]
+            ),
+            bp.DataKind.ORDER_BOOK,
+        ),
+        (
+            bp.EngineEvent.market_liquidation("mock", 2, 20550.25, 0.35, "sell", timestamp),
+            bp.DataKind.LIQUIDATION,
+        ),
+    ]
+
+    for event, expected in cases:
+        kind = bp.DataKind.from_market_event(event)
+        assert kind is expected
+        assert kind == expected
+
+    assert len({kind for _, kind in cases}) == len(cases)
+    assert repr(bp.DataKind.ORDER_BOOK_L1) == "DataKind.ORDER_BOOK_L1"
+    assert bp.DataKind.CANDLE.name == "candle"
+
+
+def test_data_kind_from_python_market_event() -> None:
+    from barter_python import data
+
+    timestamp = dt.datetime(2025, 5, 6, 7, 8, 9, tzinfo=dt.timezone.utc)
+    liquidation = data.Liquidation(
+        bp.instrument.Side.SELL, Decimal("20550.25"), Decimal("0.35"), timestamp
+    )
+    event = data.MarketEvent(
+        timestamp, timestamp, "mock", 2, data.DataKind.liquidation(liquidation)
+    )
+
+    assert bp.DataKind.from_market_event(event) is bp.DataKind.LIQUIDATION
+    assert (
+        bp.DataKind.from_market_event(data.MarketStreamItem(event))
+        is bp.DataKind.LIQUIDATION
+    )
+
+
+def test_data_kind_from_non_market_event_raises() -> None:
+    with pytest.raises(ValueError):
+        bp.DataKind.from_market_event(bp.EngineEvent.trading_state(True))
+
+    with pytest.raises(ValueError):
+        bp.DataKind.from_market_event(object())
@@ -1,5 +1,8 @@
 """Tests for OrderBook bindings."""
 
+import math
+from decimal import Decimal
+
 import pytest
 
 import barter_python as bp
@@ -65,6 +68,13 @@ class TestOrderBook:
         assert asks_result[0][0] == "100.5"
         assert asks_result[1][0] == "101"
 
+    def test_desynchronised(self):
+        book = bp.OrderBook(123, [(100.0, 1.0)], [(100.5, 1.0)])
+        assert book.desynchronised is False
+
+        book.mark_desynchronised()
+        assert book.desynchronised is True
+
     def test_new_invalid_bid_price(self):
         """Test creating OrderBook with invalid bid price."""
         with pytest.raises(ValueError, match="bid price must be positive and finite"):
@@ -91,6 +101,24 @@ class TestOrderBook:
         mid = book.mid_price()
         assert mid is None
 
+    def test_spread(self):
+        """Test spread and spread in basis points of the mid-price."""
+        book = bp.OrderBook(123, [(100.0, 1.0)], [(102.0, 1.0)])
+
+        assert book.spread == Decimal("2")
+        assert book.spread_bps == Decimal("2") / Decimal("101") * 10_000
+
+    def test_spread_one_sided_or_empty(self):
+        """Test spread is undefined on a one-sided or empty book."""
+        for book in (
+            bp.OrderBook(123, [(100.0, 1.0)], []),
+            bp.OrderBook(123, [], [(102.0, 1.0)]),
+            bp.OrderBook(123, [], []),
+        ):
+            assert book.mid_price() is None
+            assert book.spread is None
+            assert book.spread_bps is None
+
     def test_volume_weighted_mid_price(self):
         """Test volume weighted mid-price calculation."""
         bids = [(100.0, 2.0)]
@@ -109,6 +137,156 @@ class TestOrderBook:
 
         assert repr(book) == "OrderBook(sequence=123, bids=1, asks=1)"
 
+    def test_price_scale_normalises_and_merges_levels(self):
+        """Test prices are rounded to price_scale and collapsed levels merged."""
+        bids = [(100.1 + 1e-9, 1.0), (100.1, 3.0), (99.95, 2.0)]
+        asks = [(100.2, 1.0), (100.20000000001, 4.0)]
+        book = bp.OrderBook(123, bids, asks, price_scale=2)
+
+        assert book.price_scale == 2
+        assert book.bids() == [("100.1", "3"), ("99.95", "2")]
+        assert book.asks() == [("100.2", "4")]
+
+    def test_to_records(self):
+        """Test conversion to tabular records, bids first in depth order."""
+        book = bp.OrderBook(123, [(100.0, 1.0), (99.5, 2.0)], [(100.5, 1.5)])
+
+        assert book.to_records() == [
+            {"side": "bid", "level": 0, "price": Decimal("100"), "amount": Decimal("1")},
+            {"side": "bid", "level": 1, "price": Decimal("99.5"), "amount": Decimal("2")},
+            {"side": "ask", "level": 0, "price": Decimal("100.5"), "amount": Decimal("1.5")},
+        ]
+        assert bp.OrderBook(1, [], []).to_records() == []
+
+    def test_to_arrays(self):
+        """Test conversion to equal length float columns, padding the shorter side with NaN."""
+        book = bp.OrderBook(
+            123, [(100.0, 1.0), (99.5, 2.0), (99.0, 3.0)], [(100.5, 1.5)]
+        )
+
+        arrays = book.to_arrays()
+        assert list(arrays) == ["bid_price", "bid_qty", "ask_price", "ask_qty"]
+        assert arrays["bid_price"] == [100.0, 99.5, 99.0]
+        assert arrays["bid_qty"] == [1.0, 2.0, 3.0]
+        assert arrays["ask_price"][0] == 100.5
+        assert arrays["ask_qty"][0] == 1.5
+        assert all(math.isnan(value) for value in arrays["ask_price"][1:])
+        assert all(math.isnan(value) for value in arrays["ask_qty"][1:])
+
+        truncated = book.to_arrays(depth=2)
+        assert truncated["bid_price"] == [100.0, 99.5]
+        assert truncated["ask_price"][0] == 100.5
+        assert math.isnan(truncated["ask_price"][1])
+        assert book.to_arrays(depth=1) == {
+            "bid_price": [100.0],
+            "bid_qty": [1.0],
+            "ask_price": [100.5],
+            "ask_qty": [1.5],
+        }
+
+    def test_truncate(self):
+        """Test truncation keeps only the top levels on each side."""
+        bids = [(100.0, 1.0), (99.5, 2.0), (99.0, 3.0)]
+        asks = [(100.5, 1.5), (101.0, 1.0)]
+        book = bp.OrderBook(123, bids, asks)
+
+        book.truncate(1)
+
+        assert book.bids() == [("100", "1")]
+        assert book.asks() == [("100.5", "1.5")]
+
+        book.truncate(0)
+        assert book.bids() == []
+        assert book.asks() == []
+
+    def test_validate_healthy_book(self):
+        """Test a well-formed book reports no violations."""
+        book = bp.OrderBook(123, [(100.0, 1.0), (99.5, 2.0)], [(100.5, 1.5), (101.0, 1.0)])
+
+        assert book.validate() == []
+
+    def test_validate_reports_crossed_and_duplicate_levels(self):
+        """Test crossed books and duplicate price levels are reported."""
+        book = bp.OrderBook(123, [(101.0, 1.0), (101.0, 2.0)], [(100.5, 1.0)])
+
+        violations = book.validate()
+
+        assert len(violations) == 2
+        assert any("bids not strictly descending" in violation for violation in violations)
+        assert any("crossed book" in violation for violation in violations)
+
+    def test_price_scale_defaults_to_none(self):
+        """Test prices are kept as provided without a price_scale."""
+        book = bp.OrderBook(123, [(100.1 + 1e-9, 1.0), (100.1, 3.0)], [])
+
+        assert book.price_scale is None
+        assert len(book.bids()) == 2
+
+
+class TestSnapshotUpdateConversion:
+    """Test conversion between order book snapshots and updates."""
+
+    def test_snapshot_to_updates_round_trip(self):
+        book = bp.OrderBook(7, [(100.0, 1.0), (99.5, 2.0)], [(100.5, 1.5)])
+
+        updates = bp.snapshot_to_updates(book)
+
+        assert len(updates) == 3
+        assert [update.sequence for update in updates] == [7, 7, 7]
+        assert updates[0].bids() == [("100", "1")]
+        assert updates[0].asks() == []
+        assert updates[2].asks() == [("100.5", "1.5")]
+
+        snapshot = bp.updates_to_snapshot(updates)
+        assert snapshot.sequence == 7
+        assert snapshot.bids() == book.bids()
+        assert snapshot.asks() == book.asks()
+
+    def test_apply_update_removes_and_modifies_levels(self):
+        book = bp.OrderBook(1, [(100.0, 1.0), (99.0, 1.0)], [(101.0, 1.0), (102.0, 2.0)])
+        update = bp.OrderBook(5, [(99.0, 0.0), (99.5, 3.0)], [(101.0, 4.0)])
+
+        merged = book.apply_update(update)
+
+        assert merged.sequence == 5
+        assert merged.bids() == [("100", "1"), ("99.5", "3")]
+        assert merged.asks() == [("101", "4"), ("102", "2")]
+        assert book.sequence == 1
+        assert book.bids() == [("100", "1"), ("99", "1")]
+
+    def test_apply_update_normalises_mismatched_price_scales(self):
+        book = bp.OrderBook(1, [(100.1, 1.0)], [(101.2, 1.0)], price_scale=2)
+        update = bp.OrderBook(2, [(100.104, 3.0)], [(101.2, 0.0)], price_scale=3)
+
+        merged = book.apply_update(update)
+
+        assert merged.bids() == [("100.1", "3")]
+        assert merged.asks() == []
+
+        snapshot = bp.updates_to_snapshot([update], base=book)
+        assert snapshot.bids() == merged.bids()
+        assert snapshot.asks() == merged.asks()
+
+    def test_updates_to_snapshot_applies_to_base(self):
+        base = bp.OrderBook(1, [(100.0, 1.0), (99.0, 1.0)], [(101.0, 1.0)])
+        updates = [
+            bp.OrderBook(2, [(99.0, 0.0)], []),
+            bp.OrderBook(3, [], [(101.0, 4.0), (102.0, 1.0)]),
+        ]
+
+        snapshot = bp.updates_to_snapshot(updates, base=base)
+
+        assert snapshot.sequence == 3
+        assert snapshot.bids() == [("100", "1")]
+        assert snapshot.asks() == [("101", "4"), ("102", "1")]
+        assert base.sequence == 1
+
+    def test_updates_to_snapshot_empty(self):
+        snapshot = bp.updates_to_snapshot([])
+        assert snapshot.sequence == 0
+        assert snapshot.bids() == []
+        assert snapshot.asks() == []
+
 
 class TestCalculateMidPrice:
     """Test calculate_mid_price function."""
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import asyncio
 import datetime as dt
 
 import pytest
@@ -119,3 +120,256 @@ def test_market_stream_reconnecting_equality_and_repr():
     assert hash(reconnect_a) == hash(reconnect_b)
     assert reconnect_a != reconnect_c
     assert "MarketStreamReconnecting" in repr(reconnect_a)
+
+
+def test_market_stream_supports_iteration():
+    events = [
+        build_trade_event(trade_id="trade-1"),
+        build_reconnect_event(),
+        build_trade_event(trade_id="trade-2", price=102.0),
+    ]
+    streams = bp._testing_dynamic_trades(events)
+    stream = streams.select_trades(bp.ExchangeId.BINANCE_SPOT)
+    assert stream is not None
+    assert iter(stream) is stream
+
+    collected = [event for event in stream]
+
+    assert [event.kind for event in collected] == ["item", "reconnecting", "item"]
+    assert [item.event.kind.data.id for item in collected if item.kind == "item"] == [
+        "trade-1",
+        "trade-2",
+    ]
+    assert stream.is_closed()
+    with pytest.raises(StopIteration):
+        next(stream)
+
+
+def test_market_stream_counts_consecutive_reconnects():
+    events = [
+        build_reconnect_event(),
+        build_reconnect_event(),
+        build_trade_event(),
+        build_reconnect_event(),
+    ]
+    streams = bp._testing_dynamic_trades(events)
+    stream = streams.select_trades(bp.ExchangeId.BINANCE_SPOT)
+    assert stream is not None
+
+    reconnects = [event for event in stream if event.kind == "reconnecting"]
+
+    assert [event.attempt for event in reconnects] == [1, 2, 1]
+    assert all(event.policy is None for event in reconnects)
+
+
+def test_reconnect_policy_defaults_and_repr():
+    policy = bp.ReconnectPolicy()
+
+    assert policy.initial_delay_ms == 125
+    assert policy.max_delay_ms == 60_000
+    assert policy.multiplier == 2
+    assert policy.max_attempts is None
+    assert "ReconnectPolicy(" in repr(policy)
+
+    custom = bp.ReconnectPolicy(
+        initial_delay_ms=0, max_delay_ms=500, multiplier=3, max_attempts=5
+    )
+    assert custom.initial_delay_ms == 0
+    assert custom.max_attempts == 5
+    assert custom == bp.ReconnectPolicy(
+        initial_delay_ms=0, max_delay_ms=500, multiplier=3, max_attempts=5
+    )
+
+
+@pytest.mark.parametrize(
+    ("kwargs", "message"),
+    [
+        ({"initial_delay_ms": -1}, "initial_delay_ms must be non-negative"),
+        ({"max_delay_ms": -1}, "max_delay_ms must be non-negative"),
+        ({"initial_delay_ms": 1_000, "max_delay_ms": 10}, "max_delay_ms"),
+        ({"multiplier": 0}, "multiplier"),
+        ({"max_attempts": 0}, "max_attempts"),
+    ],
+)
+def test_reconnect_policy_rejects_invalid_values(kwargs, message):
+    with pytest.raises(ValueError, match=message):
+        bp.ReconnectPolicy(**kwargs)
+
+
+def test_init_dynamic_streams_rejects_non_policy_argument():
+    with pytest.raises(TypeError):
+        bp.init_dynamic_streams([], reconnect_policy={"initial_delay_ms": 10})
+
+
+@pytest.mark.skipif(
+    not hasattr(bp, "_testing_dynamic_trades"),
+    reason="requires the python-tests feature",
+)
+def test_async_market_stream_supports_async_iteration():
+    events = [
+        build_trade_event(trade_id="trade-1"),
+        build_trade_event(trade_id="trade-2", price=102.0),
+        build_trade_event(trade_id="trade-3", price=103.0),
+    ]
+    streams = bp._testing_dynamic_trades(events)
+    stream = streams.select_all_trades_async()
+
+    async def consume() -> list:
+        first = await stream.recv(timeout=5.0)
+        remaining = [event async for event in stream]
+        closed = await stream.recv(timeout=5.0)
+        return [first, *remaining, closed]
+
+    collected = asyncio.run(consume())
+
+    assert [item.event.kind.data.id for item in collected[:-1]] == [
+        "trade-1",
+        "trade-2",
+        "trade-3",
+    ]
+    assert collected[-1] is None
+    assert "closed=True" in repr(stream)
+
+
+def test_async_market_stream_recv_rejects_negative_timeout():
+    streams = bp._testing_dynamic_trades([build_trade_event()])
+    stream = streams.select_all_trades_async()
+
+    with pytest.raises(ValueError):
+        stream.recv(timeout=-1.0)
+
+
+def build_candle_event(
+    *,
+    exchange: str = "binance_spot",
+    instrument: int = 3,
+    close: float = 101.0,
+    close_time: dt.datetime | None = None,
+) -> dict:
+    close_time = close_time or dt.datetime(2025, 10, 4, 12, 1, tzinfo=UTC)
+
+    return {
+        "type": "item",
+        "exchange": exchange,
+        "instrument": instrument,
+        "time_exchange": close_time,
+        "time_received": close_time,
+        "candle": bp.Candle(close_time, 100.0, 102.0, 99.5, close, 12.5, 40),
+    }
+
+
+@pytest.mark.skipif(
+    not hasattr(bp, "_testing_dynamic_candles"),
+    reason="requires the python-tests feature",
+)
+def test_dynamic_candle_streams_select_by_exchange_and_all():
+    events = [
+        build_candle_event(close=101.0),
+        build_candle_event(exchange="kraken", instrument=4, close=55.0),
+        build_candle_event(close=101.5),
+    ]
+
+    streams = bp._testing_dynamic_candles(events)
+    assert streams.select_trades(bp.ExchangeId.BINANCE_SPOT) is None
+
+    binance = streams.select_candles(bp.ExchangeId.BINANCE_SPOT)
+    assert binance is not None
+    assert streams.select_candles(bp.ExchangeId.BINANCE_SPOT) is None
+
+    collected = list(binance)
+    assert [item.event.kind.kind for item in collected] == ["candle", "candle"]
+    assert [item.event.kind.data.close for item in collected] == [101.0, 101.5]
+    assert all(item.event.instrument == 3 for item in collected)
+
+    remaining = list(streams.select_all_candles())
+    assert [item.event.exchange for item in remaining] == ["kraken"]
+    assert remaining[0].event.kind.data.close == 55.0
+
+
+def test_market_stream_filter_instrument_forwards_matching_events():
+    events = [
+        build_trade_event(instrument=7, trade_id="keep-1"),
+        build_trade_event(instrument=8, trade_id="drop-1"),
+        build_reconnect_event(),
+        build_trade_event(instrument=8, trade_id="drop-2"),
+        build_trade_event(instrument=7, trade_id="keep-2"),
+    ]
+    streams = bp._testing_dynamic_trades(events)
+    stream = streams.select_trades(bp.ExchangeId.BINANCE_SPOT)
+    assert stream is not None
+
+    filtered = stream.filter_instrument(7)
+    assert stream.is_closed()
+
+    collected = list(filtered)
+
+    assert [event.kind for event in collected] == ["item", "reconnecting", "item"]
+    items = [event.event for event in collected if event.kind == "item"]
+    assert [event.instrument for event in items] == [7, 7]
+    assert [event.kind.data.id for event in items] == ["keep-1", "keep-2"]
+
+
+def test_market_stream_filter_stale_drops_lagging_events():
+    received = dt.datetime(2025, 10, 4, 12, 0, 10, tzinfo=UTC)
+    events = [
+        build_trade_event(
+            trade_id="fresh",
+            time_exchange=received - dt.timedelta(seconds=1),
+            time_received=received,
+        ),
+        build_trade_event(
+            trade_id="stale",
+            time_exchange=received - dt.timedelta(seconds=30),
+            time_received=received,
+        ),
+        build_reconnect_event(),
+    ]
+    streams = bp._testing_dynamic_trades(events)
+    stream = streams.select_trades(bp.ExchangeId.BINANCE_SPOT)
+    assert stream is not None
+    assert stream.stale_dropped == 0
+
+    with pytest.raises(ValueError, match="max_age_secs"):
+        stream.filter_stale(-1.0)
+
+    filtered = stream.filter_stale(5.0)
+    assert stream.is_closed()
+
+    collected = list(filtered)
+
+    assert [event.kind for event in collected] == ["item", "reconnecting"]
+    assert collected[0].event.kind.data.id == "fresh"
+    assert filtered.stale_dropped == 1
+
+
+def test_merge_market_streams_yields_events_from_every_input():
+    binance = bp._testing_dynamic_trades(
+        [
+            build_trade_event(trade_id="binance-1"),
+            build_trade_event(trade_id="binance-2"),
+        ]
+    ).select_trades(bp.ExchangeId.BINANCE_SPOT)
+    kraken = bp._testing_dynamic_trades(
+        [
+            build_trade_event(exchange="kraken", trade_id="kraken-1"),
+            build_reconnect_event(exchange="kraken"),
+        ]
+    ).select_trades(bp.ExchangeId.KRAKEN)
+    assert binance is not None and kraken is not None
+
+    merged = bp.merge_market_streams([binance, kraken])
+    assert binance.is_closed()
+    assert kraken.is_closed()
+
+    collected = list(merged)
+
+    assert len(collected) == 4
+    ids = sorted(event.event.kind.data.id for event in collected if event.kind == "item")
+    assert ids == ["binance-1", "binance-2", "kraken-1"]
+    assert [event.exchange for event in collected if event.kind == "reconnecting"] == ["kraken"]
+    assert merged.is_closed()
+
+
+def test_merge_market_streams_requires_streams():
+    with pytest.raises(ValueError):
+        bp.merge_market_streams([])
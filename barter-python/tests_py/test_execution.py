@@ -1,6 +1,7 @@
 """Unit tests for pure Python execution data structures."""
 
-from datetime import datetime, timezone
+import time
+from datetime import datetime, time as dt_time, timedelta, timezone
 from decimal import Decimal
 
 import pytest
@@ -143,6 +144,32 @@ class TestClientOrderId:
         assert str(cid) == "test-123"
         assert repr(cid) == "ClientOrderId('test-123')"
 
+    def test_sequential(self):
+        assert ClientOrderId.sequential("strat", 7).value == "strat-0007"
+        assert ClientOrderId.sequential("strat", 12345).value == "strat-12345"
+
+        with pytest.raises(ValueError):
+            ClientOrderId.sequential("", 0)
+
+
+class TestClientOrderIdGenerator:
+    def test_generators_with_same_prefix_are_identical(self):
+        first = bp.ClientOrderIdGenerator("snap")
+        second = bp.ClientOrderIdGenerator("snap")
+
+        first_ids = [first.next_id() for _ in range(3)]
+        second_ids = [next(second) for _ in range(3)]
+
+        assert first_ids == second_ids
+        assert [cid.value for cid in first_ids] == ["snap-0000", "snap-0001", "snap-0002"]
+        assert first.count == 3
+
+    def test_reset_and_start(self):
+        generator = bp.ClientOrderIdGenerator("ord", start=10)
+        assert generator.next_id().value == "ord-0010"
+        generator.reset()
+        assert generator.next_id() == ClientOrderId.sequential("ord", 10)
+
 
 class TestOrderId:
     def test_creation(self):
@@ -255,6 +282,17 @@ class TestBalance:
         assert balance.total == Decimal("100.5")
         assert balance.free == Decimal("90.5")
 
+    def test_delta(self):
+        before = Balance(Decimal("100"), Decimal("90"))
+        after = Balance(Decimal("100"), Decimal("60"))
+
+        assert after.delta(before) == {
+            "total_delta": Decimal("0"),
+            "free_delta": Decimal("-30"),
+            "used_delta": Decimal("30"),
+        }
+        assert before.delta(after)["used_delta"] == Decimal("-30")
+
     def test_hashable(self):
         balance = Balance(Decimal("5"), Decimal("3"))
         assert hash(balance) == hash(Balance(Decimal("5"), Decimal("3")))
@@ -467,6 +505,53 @@ class TestTrade:
         assert "Trade(" in repr(trade)
 
 
+class TestReconstructPositions:
+    @staticmethod
+    def _trade(trade_id, side, price, quantity, fee, minute):
+        return Trade(
+            TradeId.new(trade_id),
+            OrderId.new(f"order-{trade_id}"),
+            0,
+            StrategyId.new("strategy-alpha"),
+            datetime(2024, 1, 1, 12, minute, 0, tzinfo=timezone.utc),
+            side,
+            Decimal(price),
+            Decimal(quantity),
+            AssetFees(QuoteAsset(), Decimal(fee)),
+        )
+
+    def test_buy_then_sell_produces_single_exit(self):
+        trades = [
+            self._trade("t1", Side.BUY, "100", "2", "0.2", 0),
+            self._trade("t2", Side.SELL, "110", "2", "0.2", 5),
+        ]
+
+        exits = bp.reconstruct_positions(trades)
+
+        assert len(exits) == 1
+        exit = exits[0]
+        assert exit.price_entry_average == Decimal("100")
+        assert exit.quantity_abs_max == Decimal("2")
+        assert exit.fees_enter == Decimal("0.2")
+        assert exit.fees_exit == Decimal("0.2")
+        assert exit.pnl_realised == Decimal("19.6")
+
+    def test_weighted_average_entry_and_open_positions(self):
+        trades = [
+            self._trade("t1", Side.BUY, "100", "1", "0.1", 0),
+            self._trade("t2", Side.BUY, "120", "1", "0.1", 1),
+            self._trade("t3", Side.SELL, "130", "2", "0.2", 2),
+            self._trade("t4", Side.BUY, "130", "1", "0.1", 3),
+        ]
+
+        exits = bp.reconstruct_positions(trades)
+
+        assert len(exits) == 1
+        assert exits[0].price_entry_average == Decimal("110")
+        assert exits[0].fees_enter == Decimal("0.2")
+        assert exits[0].pnl_realised == Decimal("39.6")
+
+
 class TestOpenInFlight:
     def test_creation(self):
         oif = OpenInFlight()
@@ -1083,6 +1168,44 @@ class TestAccountEvent:
         assert event.exchange_index.index == BINANCE_INDEX
         assert event.kind.variant == "snapshot"
 
+    def test_typed_payload_accessors(self):
+        event = self._build_event()
+        assert event.variant == "snapshot"
+        assert isinstance(event.snapshot, AccountSnapshot)
+        assert event.trade is None
+        assert event.balance is None
+        assert event.order is None
+        assert event.cancelled is None
+
+        trade = Trade(
+            TradeId.new("trade-123"),
+            OrderId.new("order-456"),
+            42,
+            StrategyId.new("strategy-alpha"),
+            datetime(2024, 1, 1, 12, 0, 0, tzinfo=timezone.utc),
+            Side.BUY,
+            Decimal("50000.0"),
+            Decimal("0.1"),
+            AssetFees(QuoteAsset(), Decimal("0.005")),
+        )
+        trade_event = AccountEvent.new(BINANCE_INDEX, AccountEventKind.trade(trade))
+        assert trade_event.variant == "trade"
+        assert trade_event.trade == trade
+        assert trade_event.snapshot is None
+
+        balance_event = AccountEvent.new(
+            BINANCE_INDEX,
+            AccountEventKind.balance_snapshot(
+                AssetBalance(
+                    BTC_ASSET_INDEX,
+                    Balance(Decimal("1.0"), Decimal("0.9")),
+                    datetime(2024, 1, 1, 12, 0, 0, tzinfo=timezone.utc),
+                )
+            ),
+        )
+        assert balance_event.balance.asset == BTC_ASSET_INDEX
+        assert balance_event.trade is None
+
     def test_equality_and_hash(self):
         event_a = self._build_event()
         event_b = self._build_event()
@@ -1164,6 +1287,59 @@ class TestMockExecutionConfigBindings:
         system_config.clear_executions()
         assert system_config.executions() == []
 
+    def test_latency_range(self):
+        config = MockExecutionConfig(latency_ms=10, latency_max_ms=40)
+
+        assert config.latency_ms == 10
+        assert config.latency_max_ms == 40
+        assert config.to_dict()["latency_max_ms"] == 40
+
+        with pytest.raises(ValueError, match="latency_max_ms"):
+            MockExecutionConfig(latency_ms=50, latency_max_ms=10)
+        with pytest.raises(ValueError, match="latency_max_ms"):
+            config.latency_ms = 50
+
+        config.latency_max_ms = None
+        assert config.latency_max_ms is None
+        assert MockExecutionConfig().latency_max_ms is None
+
+    def test_maker_taker_fees(self):
+        config = MockExecutionConfig(maker_fee=Decimal("0.0002"), taker_fee="0.001")
+
+        assert config.maker_fee == Decimal("0.0002")
+        assert config.taker_fee == Decimal("0.001")
+        assert config.to_dict()["taker_fee"] == "0.001"
+
+        with pytest.raises(ValueError, match="taker_fee"):
+            MockExecutionConfig(taker_fee=Decimal("-0.001"))
+        with pytest.raises(ValueError, match="maker_fee"):
+            config.maker_fee = "1.5"
+
+        config.maker_fee = None
+        assert config.maker_fee is None
+        assert MockExecutionConfig().taker_fee is None
+
+    def test_max_fill_quantity(self):
+        config = MockExecutionConfig(max_fill_quantity={"BTCUSDT": "0.5"})
+
+        assert config.max_fill_quantity == {"BTCUSDT": Decimal("0.5")}
+        assert config.to_dict()["max_fill_quantity"] == {"BTCUSDT": "0.5"}
+        assert MockExecutionConfig().max_fill_quantity == {}
+
+        with pytest.raises(ValueError, match="max_fill_quantity"):
+            MockExecutionConfig(max_fill_quantity={"BTCUSDT": 0})
+
+
+    def test_day_boundary(self):
+        config = MockExecutionConfig(day_boundary=dt_time(17, 0))
+
+        assert config.day_boundary == dt_time(17, 0)
+        assert config.to_dict()["day_boundary"] == "17:00:00"
+        assert MockExecutionConfig().day_boundary == dt_time(0, 0)
+
+        config.day_boundary = dt_time(22, 30)
+        assert config.day_boundary == dt_time(22, 30)
+
 
 class TestMockExecutionClientBindings:
     def _instrument_map(self):
@@ -1234,6 +1410,152 @@ class TestMockExecutionClientBindings:
                 assert observed.exchange == instrument_map.exchange_index.index
                 assert observed.kind.variant in {"order_snapshot", "trade", "snapshot"}
 
+    def test_market_order_events_delayed_by_latency(self):
+        config = self._config()
+        config.latency_ms = 50
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            assert client.poll_event(timeout=0) is None
+
+            started = time.monotonic()
+            response = client.open_market_order(
+                "BTCUSDT", "buy", Decimal("0.1"), price=Decimal("100")
+            )
+            observed = client.poll_event(timeout=2.0)
+            elapsed = time.monotonic() - started
+
+        assert response is not None
+        assert observed is not None
+        assert elapsed >= 0.05
+
+    def test_poll_event_returns_typed_account_event(self):
+        config = self._config()
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            client.open_market_order("BTCUSDT", "buy", Decimal("0.1"), price=Decimal("100"))
+
+            events = []
+            while len(events) < 2:
+                event = client.poll_event(timeout=1.0)
+                if event is None:
+                    break
+                events.append(event)
+
+        assert [event.variant for event in events] == ["balance_snapshot", "trade"]
+        balance, trade = events
+        assert balance.balance is not None
+        assert balance.trade is None
+        assert trade.trade is not None
+        assert trade.trade.quantity == Decimal("0.1")
+        assert trade.trade.price == Decimal("100")
+        assert "Trade" in trade.to_dict()["kind"]
+
+    def test_poll_event_raw_returns_dict(self):
+        config = self._config()
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            client.open_market_order("BTCUSDT", "buy", Decimal("0.1"), price=Decimal("100"))
+            event = client.poll_event(timeout=1.0, raw=True)
+
+        assert isinstance(event, dict)
+        assert event["exchange"] == "mock"
+        assert "BalanceSnapshot" in event["kind"]
+
+    def test_market_order_charges_taker_fee(self):
+        config = self._config()
+        config.maker_fee = Decimal("0.0002")
+        config.taker_fee = Decimal("0.001")
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            assert client.poll_event(timeout=0) is None
+
+            client.open_market_order("BTCUSDT", "buy", Decimal("0.1"), price=Decimal("100"))
+
+            trade = None
+            for _ in range(5):
+                event = client.poll_event(timeout=1.0)
+                if event is not None and event.trade is not None:
+                    trade = event.trade
+                    break
+
+        assert trade is not None
+        assert trade.fees.fees == Decimal("100") * Decimal("0.1") * Decimal("0.001")
+
+    def test_crossed_limit_order_charges_maker_fee(self):
+        config = self._config()
+        config.maker_fee = Decimal("0.0002")
+        config.taker_fee = Decimal("0.001")
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            client.open_limit_order(
+                "BTCUSDT",
+                "buy",
+                Decimal("100"),
+                Decimal("0.1"),
+                time_in_force="good_until_end_of_day",
+            )
+
+            with pytest.raises(ValueError, match="price"):
+                client.update_market_price("BTCUSDT", Decimal("0"))
+
+            client.update_market_price("BTCUSDT", Decimal("101"))
+            assert client.poll_event(timeout=0.2) is None
+            assert len(client.fetch_open_orders()) == 1
+
+            client.update_market_price("BTCUSDT", Decimal("99"))
+
+            trade = None
+            for _ in range(5):
+                event = client.poll_event(timeout=1.0)
+                if event is not None and event.trade is not None:
+                    trade = event.trade
+                    break
+
+            assert client.fetch_open_orders() == []
+
+        assert trade is not None
+        assert trade.price == Decimal("100")
+        assert trade.fees.fees == Decimal("100") * Decimal("0.1") * Decimal("0.0002")
+
+    def test_market_order_partially_filled_above_max_fill_quantity(self):
+        config = self._config()
+        config.max_fill_quantity = {"BTCUSDT": Decimal("0.04")}
+        assert config.max_fill_quantity == {"BTCUSDT": Decimal("0.04")}
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            assert client.poll_event(timeout=0) is None
+
+            response = client.open_market_order(
+                "BTCUSDT", "buy", Decimal("0.1"), price=Decimal("100")
+            )
+
+            trades = []
+            order_states = []
+            while "fully_filled" not in order_states:
+                event = client.poll_event(timeout=1.0)
+                if event is None:
+                    break
+                if event.trade is not None:
+                    trades.append(event.trade)
+                if event.order is not None:
+                    order_states.append(event.order.state)
+
+        assert response is not None
+        assert Decimal(str(response["state"]["Ok"]["filled_quantity"])) == Decimal("0")
+        assert [trade.quantity for trade in trades] == [
+            Decimal("0.04"),
+            Decimal("0.04"),
+            Decimal("0.02"),
+        ]
+        assert sum((trade.quantity for trade in trades), Decimal("0")) == Decimal("0.1")
+        assert order_states == ["open", "open", "fully_filled"]
+
     def test_open_limit_order_with_post_only(self):
         config = self._config()
         instrument_map = self._instrument_map()
@@ -1263,6 +1585,95 @@ class TestMockExecutionClientBindings:
                 assert isinstance(observed, AccountEvent)
                 assert observed.exchange == instrument_map.exchange_index.index
 
+    def _poll_order_update(self, client, attempts=5):
+        for _ in range(attempts):
+            event = client.poll_event(timeout=1.0)
+            if event is not None and event.order is not None:
+                return event.order
+        return None
+
+    def test_unfilled_immediate_or_cancel_limit_order_expires(self):
+        config = self._config()
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            assert client.poll_event(timeout=0) is None
+
+            response = client.open_limit_order(
+                "BTCUSDT",
+                "buy",
+                Decimal("100"),
+                Decimal("0.1"),
+                time_in_force="immediate_or_cancel",
+                client_order_id="ioc-001",
+            )
+            order = self._poll_order_update(client)
+            open_orders = client.fetch_open_orders()
+
+        assert response is not None
+        assert "Ok" in response["state"]
+        assert order is not None
+        assert order.state == "expired"
+        assert open_orders == []
+
+    def test_good_until_end_of_day_limit_order_expires_at_day_boundary(self):
+        config = self._config()
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            assert client.poll_event(timeout=0) is None
+
+            client.open_limit_order(
+                "BTCUSDT",
+                "sell",
+                Decimal("45000"),
+                Decimal("0.05"),
+                time_in_force="good_until_end_of_day",
+                client_order_id="day-001",
+            )
+            assert client.poll_event(timeout=0.2) is None
+            assert len(client.fetch_open_orders()) == 1
+
+            with pytest.raises(ValueError, match="non-negative"):
+                client.advance_time(timedelta(seconds=-1))
+
+            time_exchange = client.advance_time(timedelta(days=1))
+            order = self._poll_order_update(client)
+            open_orders = client.fetch_open_orders()
+
+        assert time_exchange > datetime.now(timezone.utc) + timedelta(hours=23)
+        assert order is not None
+        assert order.state == "expired"
+        assert open_orders == []
+
+    def test_cancel_resting_limit_order(self):
+        config = self._config()
+        instrument_map = self._instrument_map()
+
+        with execution.MockExecutionClient(config, instrument_map) as client:
+            client.open_limit_order(
+                "BTCUSDT",
+                "sell",
+                Decimal("45000"),
+                Decimal("0.05"),
+                time_in_force="good_until_end_of_day",
+                client_order_id="day-002",
+            )
+            assert len(client.fetch_open_orders()) == 1
+
+            cancelled = client.cancel_order("BTCUSDT", "day-002")
+            assert cancelled is not None
+            assert "Ok" in cancelled["state"]
+            assert client.fetch_open_orders() == []
+
+            again = client.cancel_order("BTCUSDT", "day-002")
+            assert again is not None
+            assert "Err" in again["state"]
+
+            # Cancelled orders are never expired
+            client.advance_time(timedelta(days=1))
+            assert client.poll_event(timeout=0.2) is None
+
     def test_open_limit_order_rejects_invalid_price(self):
         config = self._config()
         instrument_map = self._instrument_map()
@@ -1322,6 +1733,30 @@ class TestExecutionInstrumentMap:
         with pytest.raises(ValueError):
             instrument_map.instrument_index("DOGEUSDT")
 
+    def test_external_index_offset(self):
+        instrument_map = bp.ExecutionInstrumentMap.from_definitions(
+            bp.ExchangeId.BINANCE_SPOT,
+            self._definitions(),
+            index_offset=1,
+        )
+        assert instrument_map.index_offset == 1
+
+        ethusdt_index = instrument_map.instrument_index("ETHUSDT")
+        external = instrument_map.to_external_index(ethusdt_index)
+        assert external == ethusdt_index.index + 1
+        assert instrument_map.from_external_index(external) == ethusdt_index
+
+        with pytest.raises(ValueError):
+            instrument_map.from_external_index(0)
+
+        zero_based = instrument_map.with_index_offset(0)
+        assert zero_based.to_external_index(ethusdt_index) == ethusdt_index.index
+
+        event = bp.EngineEvent.market_trade(
+            "binance_spot", external, "trade-1", 100.0, 1.0, "buy", index_offset=1
+        )
+        assert event.to_dict()["Market"]["Item"]["instrument"] == ethusdt_index.index
+
     def test_from_system_config(self, example_paths):
         config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
 
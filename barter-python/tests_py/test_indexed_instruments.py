@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 import re
+from decimal import Decimal
 from pathlib import Path
 from typing import Any
 
@@ -59,6 +60,27 @@ class TestIndexedInstrumentsBindings:
         with pytest.raises(ValueError):
             indexed.asset_index(bp.ExchangeId.BINANCE_SPOT, "doge")
 
+    def test_find_index_and_name_for_index_round_trip(self) -> None:
+        definitions = [
+            _spot_definition(bp.ExchangeId.BINANCE_SPOT, "BTC/USDT"),
+            _spot_definition(bp.ExchangeId.BINANCE_SPOT, "ETH/USDT"),
+        ]
+        indexed = bp.IndexedInstruments.from_definitions(definitions)
+
+        for position in range(len(indexed)):
+            name = indexed.name_for_index(bp.InstrumentIndex(position))
+            index = indexed.find_index(name)
+            assert index.index == position
+            assert indexed.name_for_index(index) == name
+
+        eth_index = indexed.find_index("binance_spot-ETHUSDT")
+        assert indexed.instrument(eth_index)["name_exchange"] == "ETHUSDT"
+
+        with pytest.raises(ValueError):
+            indexed.find_index("binance_spot-DOGEUSDT")
+        with pytest.raises(ValueError):
+            indexed.name_for_index(bp.InstrumentIndex(len(indexed)))
+
     def test_from_system_config(self, example_paths: dict[str, Path]) -> None:
         config_path = example_paths["system_config"]
         config = bp.SystemConfig.from_json(str(config_path))
@@ -78,3 +100,68 @@ class TestIndexedInstrumentsBindings:
 
         with pytest.raises(ValueError):
             indexed.exchange_index(bp.ExchangeId.BITFINEX)
+
+    def test_repeated_definitions_reuse_cache(self) -> None:
+        bp.clear_instrument_cache()
+
+        btc = _spot_definition(bp.ExchangeId.BINANCE_SPOT, "BTC/USDT")
+        eth = _spot_definition(bp.ExchangeId.BINANCE_SPOT, "ETH/USDT")
+
+        first = bp.IndexedInstruments.from_definitions([btc, eth])
+        second = bp.IndexedInstruments.from_definitions([btc, eth])
+        assert len(first) == len(second) == 2
+
+        # Different content must not be served from the cache
+        reordered = bp.IndexedInstruments.from_definitions([eth, btc])
+        index = reordered.instrument_index_from_exchange_name(
+            bp.ExchangeId.BINANCE_SPOT, "ETHUSDT"
+        )
+        assert index.index == 0
+
+        single = bp.IndexedInstruments.from_definitions([btc])
+        assert len(single) == 1
+
+        bp.clear_instrument_cache()
+        rebuilt = bp.IndexedInstruments.from_definitions([btc, eth])
+        assert len(rebuilt) == 2
+
+    def test_from_csv_builds_instruments_with_specs(self, tmp_path: Path) -> None:
+        csv_path = tmp_path / "instruments.csv"
+        csv_path.write_text(
+            "base,quote,kind,price_tick,quantity_step,min_notional\n"
+            "btc,usdt,spot,0.01,0.00001,10\n"
+            "\n"
+            "eth,usdt,spot,0.01,0.0001,5\n"
+        )
+
+        indexed = bp.IndexedInstruments.from_csv(str(csv_path), bp.ExchangeId.BINANCE_SPOT)
+        assert len(indexed) == 2
+
+        eth_index = indexed.instrument_index_from_exchange_name(
+            bp.ExchangeId.BINANCE_SPOT, "ETHUSDT"
+        )
+        spec = indexed.instrument(eth_index)["spec"]
+        assert Decimal(str(spec["price"]["tick_size"])) == Decimal("0.01")
+        assert Decimal(str(spec["quantity"]["increment"])) == Decimal("0.0001")
+        assert Decimal(str(spec["notional"]["min"])) == Decimal("5")
+
+    def test_from_csv_reports_malformed_line(self, tmp_path: Path) -> None:
+        csv_path = tmp_path / "instruments.csv"
+        csv_path.write_text(
+            "base,quote,kind,price_tick,quantity_step,min_notional\n"
+            "btc,usdt,spot,0.01,0.00001,10\n"
+            "eth,usdt,spot,abc,0.0001,5\n"
+        )
+
+        with pytest.raises(ValueError, match="line 3"):
+            bp.IndexedInstruments.from_csv(str(csv_path), bp.ExchangeId.BINANCE_SPOT)
+
+    def test_from_csv_rejects_zero_increments(self, tmp_path: Path) -> None:
+        csv_path = tmp_path / "instruments.csv"
+        csv_path.write_text(
+            "base,quote,kind,price_tick,quantity_step,min_notional\n"
+            "btc,usdt,spot,0.01,0,10\n"
+        )
+
+        with pytest.raises(ValueError, match="line 2: quantity_step must be a positive decimal"):
+            bp.IndexedInstruments.from_csv(str(csv_path), bp.ExchangeId.BINANCE_SPOT)
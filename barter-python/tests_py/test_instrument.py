@@ -3,6 +3,8 @@
 from datetime import datetime, timezone
 from decimal import Decimal
 
+import pytest
+
 import barter_python as bp
 from barter_python import instrument as instrument_module
 from barter_python.instrument import (
@@ -520,6 +522,45 @@ class TestPySide:
         assert repr(bp.Side.BUY) == "Side.Buy"
         assert repr(bp.Side.SELL) == "Side.Sell"
 
+    @pytest.mark.parametrize(
+        ("exchange", "buy", "sell"),
+        [
+            (bp.ExchangeId.BINANCE_SPOT, "BUY", "SELL"),
+            (bp.ExchangeId.BYBIT_SPOT, "Buy", "Sell"),
+            (bp.ExchangeId.BITMEX, "Buy", "Sell"),
+            (bp.ExchangeId.COINBASE, "buy", "sell"),
+            (bp.ExchangeId.OKX, "buy", "sell"),
+            (bp.ExchangeId.KRAKEN, "b", "s"),
+            (bp.ExchangeId.BITFINEX, 1, -1),
+            (bp.ExchangeId.GATEIO_PERPETUALS_USD, 1, -1),
+        ],
+    )
+    def test_side_exchange_round_trip(self, exchange, buy, sell):
+        assert bp.Side.BUY.to_exchange(exchange) == buy
+        assert bp.Side.SELL.to_exchange(exchange) == sell
+        assert bp.Side.from_exchange(exchange, buy) == bp.Side.BUY
+        assert bp.Side.from_exchange(exchange, sell) == bp.Side.SELL
+
+    def test_side_exchange_accepts_string_ids(self):
+        assert bp.Side.BUY.to_exchange("binance_spot") == "BUY"
+        assert bp.Side.from_exchange("kraken", "s") == bp.Side.SELL
+
+    def test_side_from_signed_amount(self):
+        assert bp.Side.from_exchange(bp.ExchangeId.BITFINEX, -0.25) == bp.Side.SELL
+        assert bp.Side.from_exchange(bp.ExchangeId.BITFINEX, Decimal("3")) == bp.Side.BUY
+        with pytest.raises(ValueError):
+            bp.Side.from_exchange(bp.ExchangeId.BITFINEX, 0)
+
+    def test_side_from_exchange_rejects_foreign_convention(self):
+        with pytest.raises(ValueError, match="expected 'BUY' or 'SELL'"):
+            bp.Side.from_exchange(bp.ExchangeId.BINANCE_SPOT, "buy")
+
+    def test_side_exchange_without_convention(self):
+        with pytest.raises(NotImplementedError):
+            bp.Side.BUY.to_exchange(bp.ExchangeId.MOCK)
+        with pytest.raises(NotImplementedError):
+            bp.Side.from_exchange(bp.ExchangeId.SIMULATED, "buy")
+
 
 class TestPyAssetIndex:
     def test_creation(self):
@@ -571,3 +612,35 @@ class TestInstrumentSpecBindings:
         assert spec.quantity.min == Decimal("0.1")
         assert spec.quantity.increment == Decimal("0.1")
         assert spec.notional.min == Decimal("5")
+
+    def test_instrument_spec_rounding_helpers(self):
+        spec = bp.InstrumentSpec(
+            price=bp.InstrumentSpecPrice(min=Decimal("0.01"), tick_size=Decimal("0.05")),
+            quantity=bp.InstrumentSpecQuantity(
+                unit=bp.OrderQuantityUnits.contract(),
+                min=Decimal("0.1"),
+                increment=Decimal("0.1"),
+            ),
+            notional=bp.InstrumentSpecNotional(min=Decimal("10")),
+        )
+
+        # Values already on a tick boundary are unchanged in every mode
+        for mode in ("toward_zero", "nearest", "up", "down"):
+            assert spec.round_price(Decimal("100.05"), mode=mode) == Decimal("100.05")
+
+        assert spec.round_price(Decimal("100.07")) == Decimal("100.05")
+        assert spec.round_price(Decimal("100.07"), mode="nearest") == Decimal("100.05")
+        assert spec.round_price(Decimal("100.08"), mode="nearest") == Decimal("100.10")
+        assert spec.round_price(Decimal("100.01"), mode="up") == Decimal("100.05")
+        assert spec.round_price(Decimal("-100.07")) == Decimal("-100.05")
+        assert spec.round_price(Decimal("-100.07"), mode="down") == Decimal("-100.10")
+
+        assert spec.round_quantity(Decimal("1.29")) == Decimal("1.2")
+        assert spec.round_quantity(Decimal("1.25"), mode="nearest") == Decimal("1.3")
+        assert spec.round_quantity(Decimal("1.21"), mode="up") == Decimal("1.3")
+
+        with pytest.raises(ValueError, match="mode must be one of"):
+            spec.round_price(Decimal("1"), mode="banker")
+
+        assert spec.min_notional_ok(Decimal("100"), Decimal("0.1"))
+        assert not spec.min_notional_ok(Decimal("99.95"), Decimal("0.1"))
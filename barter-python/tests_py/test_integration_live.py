@@ -79,9 +79,18 @@ def test_take_audit_disabled_returns_none(example_paths: dict[str, Path]) -> Non
 
     try:
         assert handle.take_audit() is None
+        assert handle.take_audit() is None
+
+        # State queries are served by the engine state replica regardless of audit
+        assert handle.positions() == []
+        assert handle.open_orders() == []
+        assert isinstance(handle.state_hash(), int)
     finally:
         handle.shutdown()
 
+    with pytest.raises(ValueError, match="system is not running"):
+        handle.take_audit()
+
 
 @pytest.mark.integration
 def test_audit_updates_typed_helpers(example_paths: dict[str, Path]) -> None:
@@ -2,7 +2,8 @@
 
 import pytest
 
-from barter_python import Field, Metric, Tag, Value
+import barter_python as bp
+from barter_python import Field, Metric, MetricSink, Tag, Value
 
 
 class TestTag:
@@ -145,3 +146,80 @@ class TestMetric:
         assert metric.time == 0
         assert len(metric.tags) == 0
         assert len(metric.fields) == 0
+
+
+class TestMetricSink:
+    """Test MetricSink binding."""
+
+    def test_flush_returns_and_clears_emitted_metrics(self):
+        """Test in-memory MetricSink collects metrics until flushed."""
+        sink = MetricSink()
+        sink.emit(Metric("first", 1, [Tag("env", "test")], []))
+        sink.emit(Metric("second", 2, [], [Field("count", Value.uint(1))]))
+
+        flushed = sink.flush()
+
+        assert [metric.name for metric in flushed] == ["first", "second"]
+        assert flushed[0].tags == [Tag("env", "test")]
+        assert sink.flush() == []
+
+    def test_system_handle_forwards_engine_event_metrics(self, example_paths):
+        """Test an attached sink receives metrics for events sent to a running system."""
+        config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+        handle = bp.start_system(config, trading_enabled=False)
+        sink = MetricSink()
+
+        try:
+            handle.attach_metric_sink(sink)
+            handle.set_trading_enabled(True)
+            handle.feed_events([bp.EngineEvent.cancel_orders(bp.InstrumentFilter.none())])
+        finally:
+            handle.shutdown()
+
+        metrics = sink.flush()
+
+        assert all(metric.name == "engine_event" for metric in metrics)
+        assert [metric.tags for metric in metrics] == [
+            [Tag("kind", "trading_state")],
+            [Tag("kind", "command")],
+        ]
+        assert metrics[0].fields[0].value.as_uint() == 1
+
+
+class TestLineProtocol:
+    """Test Metric line protocol rendering."""
+
+    @pytest.mark.parametrize(
+        ("value", "expected"),
+        [
+            (Value.float(85.5), "85.5"),
+            (Value.int(-42), "-42i"),
+            (Value.uint(1024), "1024u"),
+            (Value.bool(True), "true"),
+            (Value.bool(False), "false"),
+            (Value.string('say "hi"'), '"say \\"hi\\""'),
+        ],
+    )
+    def test_value_variants(self, value, expected):
+        """Test each Value variant renders as a line protocol field value."""
+        metric = Metric("cpu", 1700000000000000000, [], [Field("value", value)])
+
+        assert metric.to_line_protocol() == f"cpu value={expected} 1700000000000000000"
+
+    def test_escapes_tags(self):
+        """Test spaces, commas and equals signs in tag keys & values are escaped."""
+        metric = Metric(
+            "http latency",
+            42,
+            [Tag("host name", "us west,1"), Tag("path", "a=b")],
+            [Field("duration", Value.uint(5)), Field("ok", Value.bool(True))],
+        )
+
+        assert metric.to_line_protocol() == (
+            "http\\ latency,host\\ name=us\\ west\\,1,path=a\\=b duration=5u,ok=true 42"
+        )
+
+    def test_requires_a_field(self):
+        """Test rendering a metric without fields raises ValueError."""
+        with pytest.raises(ValueError, match="at least one field"):
+            Metric("empty", 0, [Tag("env", "test")], []).to_line_protocol()
@@ -1,6 +1,8 @@
 from __future__ import annotations
 
 import datetime as dt
+import math
+import statistics
 from decimal import Decimal
 
 import pytest
@@ -76,6 +78,78 @@ def test_calculate_sharpe_ratio_rejects_non_finite_input() -> None:
         )
 
 
+def _period_returns(equity: list[float]) -> list[float]:
+    return [(curr - prev) / prev for prev, curr in zip(equity, equity[1:])]
+
+
+EQUITY_CURVE = [100.0, 102.0, 99.5, 101.0, 104.0, 103.0]
+
+
+def test_calculate_sharpe_from_equity_matches_pre_differenced_returns() -> None:
+    returns = _period_returns(EQUITY_CURVE)
+
+    expected = bp.calculate_sharpe_ratio(
+        risk_free_return=0.0001,
+        mean_return=statistics.fmean(returns),
+        std_dev_returns=statistics.pstdev(returns),
+        interval="Daily",
+    )
+    metric = bp.calculate_sharpe_from_equity(EQUITY_CURVE, 0.0001, "Daily")
+
+    assert metric.interval == expected.interval
+    assert float(metric.value) == pytest.approx(float(expected.value), rel=1e-12)
+
+
+def test_calculate_sortino_from_equity_matches_pre_differenced_returns() -> None:
+    returns = _period_returns(EQUITY_CURVE)
+    losses = [value for value in returns if value < 0]
+
+    expected = bp.calculate_sortino_ratio(
+        risk_free_return=0.0001,
+        mean_return=statistics.fmean(returns),
+        std_dev_loss_returns=statistics.pstdev(losses),
+        interval="Annual(252)",
+    )
+    metric = bp.calculate_sortino_from_equity(EQUITY_CURVE, 0.0001, "Annual(252)")
+
+    assert metric.interval == expected.interval
+    assert float(metric.value) == pytest.approx(float(expected.value), rel=1e-12)
+
+
+def test_ratio_from_equity_uses_log_period_returns() -> None:
+    returns = [math.log(1.0 + value) for value in _period_returns(EQUITY_CURVE)]
+    losses = [value for value in returns if value < 0]
+    risk_free = math.log(1.0001)
+
+    sharpe = bp.calculate_sharpe_from_equity(
+        EQUITY_CURVE, 0.0001, "Daily", compounding="log"
+    )
+    assert float(sharpe.value) == pytest.approx(
+        (statistics.fmean(returns) - risk_free) / statistics.pstdev(returns), rel=1e-9
+    )
+
+    sortino = bp.calculate_sortino_from_equity(
+        EQUITY_CURVE, 0.0001, "Daily", compounding="log"
+    )
+    assert float(sortino.value) == pytest.approx(
+        (statistics.fmean(returns) - risk_free) / statistics.pstdev(losses), rel=1e-9
+    )
+
+    simple = bp.calculate_sharpe_from_equity(EQUITY_CURVE, 0.0001, "Daily")
+    assert float(sharpe.value) != pytest.approx(float(simple.value), rel=1e-6)
+
+
+@pytest.mark.parametrize(
+    "curve",
+    [[], [100.0], [100.0, 0.0, 50.0], [-10.0, 5.0]],
+)
+def test_ratio_from_equity_rejects_invalid_curves(curve: list[float]) -> None:
+    with pytest.raises(ValueError):
+        bp.calculate_sharpe_from_equity(curve, 0.0, "Daily")
+    with pytest.raises(ValueError):
+        bp.calculate_sortino_from_equity(curve, 0.0, "Daily")
+
+
 def test_calculate_calmar_ratio_daily_interval() -> None:
     metric = bp.calculate_calmar_ratio(
         risk_free_return=0.0015,
@@ -331,3 +405,271 @@ def test_calculate_rate_of_return_invalid_interval_raises() -> None:
             mean_return=0.01,
             interval="weekly",
         )
+
+
+def test_calculate_sharpe_ratio_zero_std_dev_on_undefined_none() -> None:
+    metric = bp.calculate_sharpe_ratio(
+        risk_free_return=0.001,
+        mean_return=0.002,
+        std_dev_returns=0.0,
+        interval="Daily",
+        on_undefined="none",
+    )
+
+    assert metric is None
+
+
+def test_calculate_ratios_on_undefined_raise() -> None:
+    with pytest.raises(bp.UndefinedMetricError):
+        bp.calculate_sharpe_ratio(0.001, 0.002, 0.0, "Daily", on_undefined="raise")
+
+    with pytest.raises(bp.UndefinedMetricError):
+        bp.calculate_sortino_ratio(0.001, 0.002, 0.0, "Daily", on_undefined="raise")
+
+    with pytest.raises(bp.UndefinedMetricError):
+        bp.calculate_calmar_ratio(0.001, 0.002, 0.0, "Daily", on_undefined="raise")
+
+    with pytest.raises(bp.UndefinedMetricError):
+        bp.calculate_profit_factor(10.0, 0.0, on_undefined="raise")
+
+    # UndefinedMetricError is a ValueError subclass.
+    with pytest.raises(ValueError):
+        bp.calculate_sharpe_ratio(0.001, 0.002, 0.0, "Daily", on_undefined="raise")
+
+
+def test_calculate_ratios_on_undefined_ignored_for_defined_results() -> None:
+    metric = bp.calculate_sortino_ratio(
+        risk_free_return=0.0015,
+        mean_return=0.0025,
+        std_dev_loss_returns=0.02,
+        interval="Daily",
+        on_undefined="raise",
+    )
+    assert metric is not None
+    assert metric.value == Decimal("0.05")
+
+    assert bp.calculate_profit_factor(10.0, 0.0, on_undefined="none") is None
+    assert bp.calculate_calmar_ratio(0.001, 0.002, 0.0, "Daily", on_undefined="none") is None
+
+
+def test_calculate_ratios_invalid_on_undefined_raises() -> None:
+    with pytest.raises(ValueError):
+        bp.calculate_sharpe_ratio(0.001, 0.002, 0.01, "Daily", on_undefined="nan")
+
+
+def test_annualization_factor_for_builtin_intervals() -> None:
+    assert bp.annualization_factor("daily") == Decimal("1")
+    assert bp.annualization_factor("annual_252") == Decimal("252")
+    assert bp.annualization_factor("annual_365") == Decimal("365")
+
+
+def test_annualization_factor_with_custom_base_interval() -> None:
+    assert bp.annualization_factor("annual_365", dt.timedelta(hours=1)) == Decimal("8760")
+    assert bp.annualization_factor(dt.timedelta(hours=12)) == Decimal("0.5")
+
+
+def test_annualization_factor_matches_rate_of_return_scaling() -> None:
+    annual = bp.calculate_rate_of_return(0.01, "daily", target_interval="annual_252")
+
+    factor = bp.annualization_factor("annual_252")
+    assert annual.value == Decimal("0.01") * factor
+
+
+def test_annualization_factor_rejects_zero_base() -> None:
+    with pytest.raises(ValueError):
+        bp.annualization_factor("daily", dt.timedelta(0))
+
+
+def test_ratio_functions_return_none_below_min_samples() -> None:
+    assert bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily", sample_count=1) is None
+    assert bp.calculate_sortino_ratio(0.0, 0.01, 0.02, "daily", sample_count=0) is None
+    assert (
+        bp.calculate_calmar_ratio(0.0, 0.01, 0.02, "daily", sample_count=4, min_samples=5)
+        is None
+    )
+
+
+def test_ratio_functions_compute_with_sufficient_samples() -> None:
+    metric = bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily", sample_count=2)
+    assert metric is not None
+    assert metric.value == Decimal("0.5")
+
+    # Without a sample count the guard does not apply.
+    assert bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily") is not None
+    assert (
+        bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily", sample_count=1, min_samples=1)
+        is not None
+    )
+
+
+def test_calculate_rate_of_return_compounding_modes() -> None:
+    simple = bp.calculate_rate_of_return(0.01, "daily", target_interval="annual_252")
+    explicit = bp.calculate_rate_of_return(
+        0.01, "daily", target_interval="annual_252", compounding="simple"
+    )
+    log = bp.calculate_rate_of_return(
+        0.01, "daily", target_interval="annual_252", compounding="log"
+    )
+    compound = bp.calculate_rate_of_return(
+        0.01, "daily", target_interval="annual_252", compounding="compound"
+    )
+
+    assert explicit.value == simple.value == Decimal("2.52")
+    assert float(log.value) == pytest.approx(252 * math.log(1.01), rel=1e-9)
+    assert float(compound.value) == pytest.approx(1.01**252 - 1, rel=1e-9)
+    assert log.interval == compound.interval == "Annual(252)"
+
+
+def test_calculate_rate_of_return_compounding_without_target() -> None:
+    log = bp.calculate_rate_of_return(0.05, "daily", compounding="log")
+    compound = bp.calculate_rate_of_return(0.05, "daily", compounding="compound")
+
+    assert float(log.value) == pytest.approx(math.log(1.05), rel=1e-9)
+    assert float(compound.value) == pytest.approx(0.05, rel=1e-9)
+    assert log.interval == "Daily"
+
+
+def test_compounding_rejects_invalid_inputs() -> None:
+    with pytest.raises(ValueError):
+        bp.calculate_rate_of_return(0.01, "daily", compounding="continuous")
+    with pytest.raises(ValueError):
+        bp.calculate_rate_of_return(-1.5, "daily", compounding="log")
+    with pytest.raises(ValueError):
+        bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily", compounding="weekly")
+
+
+def test_ratio_functions_apply_compounding_to_returns() -> None:
+    log_sharpe = bp.calculate_sharpe_ratio(0.0, 0.01, 0.02, "daily", compounding="log")
+    assert log_sharpe is not None
+    assert float(log_sharpe.value) == pytest.approx(math.log(1.01) / 0.02, rel=1e-9)
+
+    log_calmar = bp.calculate_calmar_ratio(0.0, 0.01, 0.2, "daily", compounding="log")
+    assert log_calmar is not None
+    assert float(log_calmar.value) == pytest.approx(
+        math.log(1.01) / -math.log(0.8), rel=1e-9
+    )
+
+
+def test_drawdown_helpers_support_log_compounding() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    points = [
+        (base, 100.0),
+        (base + dt.timedelta(days=1), 80.0),
+        (base + dt.timedelta(days=2), 120.0),
+        (base + dt.timedelta(days=3), 90.0),
+    ]
+
+    simple = bp.generate_drawdown_series(points)
+    log = bp.generate_drawdown_series(points, compounding="log")
+
+    assert [item.value for item in simple] == [Decimal("0.2"), Decimal("0.25")]
+    assert [float(item.value) for item in log] == pytest.approx(
+        [-math.log(0.8), -math.log(0.75)], rel=1e-9
+    )
+    assert [item.time_start for item in log] == [item.time_start for item in simple]
+
+    max_log = bp.calculate_max_drawdown(points, compounding="log")
+    assert max_log is not None
+    assert float(max_log.value) == pytest.approx(-math.log(0.75), rel=1e-9)
+
+    mean_log = bp.calculate_mean_drawdown(points, compounding="log")
+    assert mean_log is not None
+    assert float(mean_log.mean_drawdown) == pytest.approx(
+        (-math.log(0.8) - math.log(0.75)) / 2, rel=1e-9
+    )
+
+
+def test_historical_var_and_conditional_var_uniform_returns() -> None:
+    # 100 evenly spaced returns from -0.50 to 0.49: the 5 worst are -0.50..-0.46.
+    returns = [Decimal(i) / 100 for i in range(49, -51, -1)]
+
+    assert bp.calculate_historical_var(returns, 0.95) == Decimal("0.46")
+    assert bp.calculate_conditional_var(returns, 0.95) == Decimal("0.48")
+    assert bp.calculate_historical_var(returns, 0.99) == Decimal("0.50")
+    assert bp.calculate_conditional_var(returns, 0.99) == Decimal("0.50")
+
+
+def test_historical_var_reports_negative_loss_for_gains() -> None:
+    assert bp.calculate_historical_var([0.01, 0.02, 0.03], 0.5) == Decimal("-0.02")
+
+
+def test_historical_var_rejects_invalid_input() -> None:
+    with pytest.raises(ValueError, match="returns must not be empty"):
+        bp.calculate_historical_var([], 0.95)
+    with pytest.raises(ValueError, match="confidence must be between 0 and 1"):
+        bp.calculate_conditional_var([0.01], 1.0)
+    with pytest.raises(ValueError, match="confidence must be between 0 and 1"):
+        bp.calculate_historical_var([0.01], 0.0)
+
+
+def test_calculate_ulcer_index_zero_for_rising_curve() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    points = [(base + dt.timedelta(days=day), 100.0 + day) for day in range(5)]
+
+    assert bp.calculate_ulcer_index(points) == Decimal("0")
+    assert bp.calculate_ulcer_index([]) is None
+
+
+def test_calculate_ulcer_index_known_drawdown() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    points = [
+        (base, 100.0),
+        (base + dt.timedelta(days=1), 80.0),
+        (base + dt.timedelta(days=2), 100.0),
+        (base + dt.timedelta(days=3), 110.0),
+    ]
+
+    # Drawdowns of 0%, 20%, 0% and 0% give sqrt(20^2 / 4) = 10.
+    assert bp.calculate_ulcer_index(points).quantize(Decimal("1E-20")) == Decimal("10")
+
+
+def test_calculate_pain_ratio_known_drawdown() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    points = [
+        (base, 100.0),
+        (base + dt.timedelta(days=100), 80.0),
+        (base + dt.timedelta(days=200), 100.0),
+        (base + dt.timedelta(days=365), 110.0),
+    ]
+
+    metric = bp.calculate_pain_ratio(points, 0.0)
+
+    # 10% annual return over a single 20% drawdown period.
+    assert metric.value == Decimal("0.5")
+    assert metric.interval == "Annual(365)"
+    assert bp.calculate_pain_ratio(points, 0.05).value == Decimal("0.25")
+
+
+def test_calculate_sterling_ratio_averages_largest_drawdowns() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    # Distinct drawdowns of 10%, 20%, 5% & 30%, ending on a 10% annual return.
+    values = [100.0, 90.0, 100.0, 80.0, 100.0, 95.0, 100.0, 70.0, 100.0]
+    points = [(base + dt.timedelta(days=30 * index), value) for index, value in enumerate(values)]
+    points.append((base + dt.timedelta(days=365), 110.0))
+
+    # Default averages the 3 deepest drawdowns: (30% + 20% + 10%) / 3 = 20%.
+    metric = bp.calculate_sterling_ratio(points, 0.0)
+    assert metric.value == Decimal("0.5")
+    assert metric.interval == "Annual(365)"
+
+    assert bp.calculate_sterling_ratio(points, 0.0, largest_drawdowns=1).value == Decimal(
+        "0.1"
+    ) / Decimal("0.3")
+    assert bp.calculate_sterling_ratio(points, 0.0, largest_drawdowns=2).value == Decimal("0.4")
+    # Requesting more drawdowns than exist averages all four periods.
+    assert bp.calculate_sterling_ratio(points, 0.0, largest_drawdowns=10).value == Decimal(
+        "0.1"
+    ) / Decimal("0.1625")
+
+    with pytest.raises(ValueError, match="largest_drawdowns must be positive"):
+        bp.calculate_sterling_ratio(points, 0.0, largest_drawdowns=0)
+    assert bp.calculate_sterling_ratio(points[:1], 0.0) is None
+
+
+def test_calculate_pain_ratio_rising_curve_is_undefined() -> None:
+    base = dt.datetime(2025, 1, 1, tzinfo=dt.timezone.utc)
+    points = [(base + dt.timedelta(days=day), 100.0 + day) for day in range(5)]
+
+    assert bp.calculate_pain_ratio(points, 0.0).value == Decimal("79228162514264337593543950335")
+    assert bp.calculate_pain_ratio(points, 0.0, on_undefined="none") is None
+    assert bp.calculate_pain_ratio(points[:1], 0.0) is None
@@ -1,6 +1,7 @@
 """Unit tests for the risk management module."""
 
 from decimal import Decimal
+from types import SimpleNamespace
 
 import pytest
 
@@ -84,6 +85,194 @@ class TestDefaultRiskManager:
         assert isinstance(approved_cancels[0], core.RiskApproved)
         assert isinstance(approved_opens[0], core.RiskApproved)
 
+    def test_order_limits_default_to_none(self):
+        manager = risk.DefaultRiskManager()
+
+        assert manager.max_order_notional is None
+        assert manager.max_order_quantity is None
+
+    def test_max_order_notional_refuses_large_orders(self):
+        manager = risk.DefaultRiskManager(max_order_notional=Decimal("200"))
+        small = make_open_request()
+        large = bp.OrderRequestOpen(make_order_key(), "sell", 100.0, 5.0)
+
+        _, approved_opens, _, refused_opens = manager.check(None, [], [small, large])
+
+        assert manager.max_order_notional == Decimal("200")
+        assert [approved.item.quantity for approved in approved_opens] == ["1.5"]
+        assert len(refused_opens) == 1
+        assert refused_opens[0].item.quantity == "5"
+        assert refused_opens[0].reason == "order notional 500 exceeds max_order_notional 200"
+
+    def test_max_order_quantity_refuses_large_orders(self):
+        manager = risk.DefaultRiskManager(max_order_quantity=2)
+        large = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 3.0)
+
+        approved_cancels, approved_opens, _, refused_opens = manager.check(
+            None, [make_cancel_request()], [make_open_request(), large]
+        )
+
+        assert len(approved_cancels) == 1
+        assert len(approved_opens) == 1
+        assert refused_opens[0].reason == "order quantity 3 exceeds max_order_quantity 2"
+
+    def test_order_limits_must_be_positive(self):
+        with pytest.raises(ValueError, match="max_order_notional must be positive"):
+            risk.DefaultRiskManager(max_order_notional=0)
+        with pytest.raises(ValueError, match="max_order_quantity must be positive"):
+            risk.DefaultRiskManager(max_order_quantity=Decimal("-1"))
+
+    def test_max_position_quantity_refuses_orders_beyond_cap(self):
+        manager = risk.DefaultRiskManager(max_position_quantity={99: 3})
+        buy = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 1.0)
+        positions = {}
+
+        for _ in range(3):
+            _, approved_opens, _, refused_opens = manager.check(positions, [], [buy])
+            assert len(approved_opens) == 1
+            assert refused_opens == []
+            positions[99] = positions.get(99, Decimal("0")) + Decimal("1")
+
+        _, approved_opens, _, refused_opens = manager.check(positions, [], [buy])
+
+        assert manager.max_position_quantity == {99: Decimal("3")}
+        assert approved_opens == []
+        assert refused_opens[0].reason == (
+            "instrument 99 net position 4 exceeds max_position_quantity 3 by 1"
+        )
+
+    def test_max_position_quantity_accumulates_within_check(self):
+        manager = risk.DefaultRiskManager(max_position_quantity={99: 2})
+        buy = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 1.5)
+        sell = bp.OrderRequestOpen(make_order_key(), "sell", 100.0, 1.5)
+
+        _, approved_opens, _, refused_opens = manager.check(None, [], [buy, buy, sell])
+
+        assert [approved.item.side for approved in approved_opens] == ["buy", "sell"]
+        assert refused_opens[0].reason == (
+            "instrument 99 net position 3.0 exceeds max_position_quantity 2 by 1.0"
+        )
+
+    def test_max_position_quantity_permits_reducing_orders(self):
+        manager = risk.DefaultRiskManager(max_position_quantity={99: 1})
+        sell = bp.OrderRequestOpen(make_order_key(), "sell", 100.0, 1.0)
+
+        _, approved_opens, _, refused_opens = manager.check({99: 5}, [], [sell])
+
+        assert len(approved_opens) == 1
+        assert refused_opens == []
+
+    def test_max_open_positions_refuses_new_positions_at_limit(self):
+        manager = risk.DefaultRiskManager(max_open_positions=2)
+        new_instrument = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 1.0)
+
+        _, approved_opens, _, refused_opens = manager.check(
+            {1: Decimal("2"), 2: Decimal("-1"), 3: Decimal("0")}, [], [new_instrument]
+        )
+
+        assert manager.max_open_positions == 2
+        assert approved_opens == []
+        assert refused_opens[0].reason == (
+            "instrument 99 would open a position but 2 positions are already open, "
+            "max_open_positions 2"
+        )
+
+    def test_max_open_positions_permits_orders_for_open_instruments(self):
+        manager = risk.DefaultRiskManager(max_open_positions=1)
+        increase = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 1.0)
+        reduce = bp.OrderRequestOpen(make_order_key(), "sell", 100.0, 3.0)
+
+        _, approved_opens, _, refused_opens = manager.check(
+            {99: Decimal("2")}, [], [increase, reduce]
+        )
+
+        assert len(approved_opens) == 2
+        assert refused_opens == []
+
+    def test_max_open_positions_counts_approved_opens_within_check(self):
+        manager = risk.DefaultRiskManager(max_open_positions=1)
+        first = bp.OrderRequestOpen(make_order_key(), "buy", 100.0, 1.0)
+        other_key = bp.OrderKey(
+            1, 7, bp.StrategyId.new("strategy-alpha"), bp.ClientOrderId.new("cid-7")
+        )
+        second = bp.OrderRequestOpen(other_key, "buy", 100.0, 1.0)
+
+        _, approved_opens, _, refused_opens = manager.check(None, [], [first, second])
+
+        assert len(approved_opens) == 1
+        assert "instrument 7 would open a position" in refused_opens[0].reason
+
+    def test_max_open_positions_must_be_positive(self):
+        with pytest.raises(ValueError, match="max_open_positions must be positive"):
+            risk.DefaultRiskManager(max_open_positions=0)
+
+
+class TestCallbackRiskManager:
+    """Test CallbackRiskManager delegating to a Python callable."""
+
+    def test_refuses_orders_above_notional_threshold(self):
+        seen = []
+
+        def limit_notional(request: dict) -> object:
+            seen.append(request)
+            notional = request["price"] * request["quantity"]
+            if notional > Decimal("200"):
+                return f"notional {notional} exceeds 200"
+            return True
+
+        manager = risk.CallbackRiskManager(limit_notional)
+        small = make_open_request()
+        large = bp.OrderRequestOpen(make_order_key(), "sell", 100.0, 5.0)
+
+        approved_cancels, approved_opens, refused_cancels, refused_opens = manager.check(
+            None, [make_cancel_request()], [small, large]
+        )
+
+        assert len(approved_cancels) == 1
+        assert [approved.item.quantity for approved in approved_opens] == ["1.5"]
+        assert refused_cancels == []
+        assert len(refused_opens) == 1
+        assert refused_opens[0].item.quantity == "5"
+        assert refused_opens[0].reason == "notional 500 exceeds 200"
+
+        assert [request["side"] for request in seen] == ["buy", "sell"]
+        assert seen[0]["instrument"] == 99
+        assert seen[0]["strategy"] == "strategy-alpha"
+        assert seen[0]["cid"] == "cid-100"
+
+    def test_false_refuses_with_default_reason(self):
+        manager = risk.CallbackRiskManager(lambda request: False)
+
+        _, approved_opens, _, refused_opens = manager.check(None, [], [make_open_request()])
+
+        assert approved_opens == []
+        assert refused_opens[0].reason == "refused by risk callback"
+
+    def test_callback_errors_fall_back_to_approval(self):
+        def broken(request: dict) -> bool:
+            raise RuntimeError("boom")
+
+        manager = risk.CallbackRiskManager(broken)
+
+        _, approved_opens, _, refused_opens = manager.check(None, [], [make_open_request()])
+
+        assert len(approved_opens) == 1
+        assert refused_opens == []
+
+    def test_requires_callable(self):
+        with pytest.raises(TypeError):
+            risk.CallbackRiskManager(42)
+
+    def test_start_system_accepts_callback_risk_manager(self, example_paths):
+        config = bp.SystemConfig.from_json(str(example_paths["system_config"]))
+        manager = risk.CallbackRiskManager(lambda request: True)
+
+        handle = bp.start_system(config, trading_enabled=False, risk_manager=manager)
+        try:
+            assert handle.is_running()
+        finally:
+            handle.shutdown()
+
 
 class TestRiskUtilities:
     """Test bindings for risk utility helpers."""
@@ -154,6 +343,50 @@ class TestRiskUtilities:
         assert result == expected
 
 
+class TestConsecutiveLossTracker:
+    """Test ConsecutiveLossTracker streak tracking."""
+
+    def test_tracks_current_and_max_streak(self):
+        tracker = risk.ConsecutiveLossTracker(threshold=3)
+
+        streaks = [tracker.observe(make_exit(pnl)) for pnl in ("-1", "-2", "5", "-1", "-1")]
+
+        assert streaks == [1, 2, 0, 1, 2]
+        assert tracker.current_streak == 2
+        assert tracker.max_streak == 2
+        assert not tracker.should_halt()
+
+        tracker.observe(make_exit("-0.5"))
+
+        assert tracker.should_halt()
+        assert tracker.should_halt(4) is False
+        assert tracker.max_streak == 3
+
+        tracker.reset()
+        assert tracker.current_streak == 0
+        assert tracker.max_streak == 3
+
+    def test_breakeven_exit_resets_streak(self):
+        tracker = risk.ConsecutiveLossTracker()
+        tracker.observe(make_exit("-1"))
+        tracker.observe(make_exit("0"))
+
+        assert tracker.current_streak == 0
+        assert tracker.should_halt(1) is False
+
+    def test_threshold_validation(self):
+        with pytest.raises(ValueError, match="threshold must be positive"):
+            risk.ConsecutiveLossTracker(threshold=0)
+        with pytest.raises(ValueError, match="threshold must be provided"):
+            risk.ConsecutiveLossTracker().should_halt()
+
+
+def make_exit(pnl: str) -> SimpleNamespace:
+    """Stand-in for a PositionExit exposing only the realised PnL."""
+
+    return SimpleNamespace(pnl_realised=Decimal(pnl))
+
+
 def make_order_key() -> bp.OrderKey:
     """Helper to build a deterministic order key."""
 
@@ -1,5 +1,7 @@
 import decimal
 
+import pytest
+
 import barter_python as bp
 
 
@@ -45,3 +47,19 @@ def test_risk_limits_round_trip(example_paths, tmp_path):
 
     config.set_global_risk_limits(None)
     assert config.risk_limits()["global"] is None
+
+
+def test_exit_cooldown_risk_limit(example_paths):
+    config = _load_config(example_paths)
+
+    config.set_global_risk_limits({"exit_cooldown_secs": 30})
+    assert config.risk_limits()["global"]["exit_cooldown_secs"] == decimal.Decimal("30")
+
+    with pytest.raises(ValueError, match="exit_cooldown_secs"):
+        config.set_instrument_risk_limits(0, {"exit_cooldown_secs": 0})
+
+    handle = bp.start_system(config, trading_enabled=False)
+    try:
+        assert handle.is_running()
+    finally:
+        handle.shutdown()
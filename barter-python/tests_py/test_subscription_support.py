@@ -2,6 +2,8 @@ from __future__ import annotations
 
 import datetime as dt
 
+import pytest
+
 import barter_python as bp
 
 UTC = dt.timezone.utc
@@ -41,3 +43,55 @@ def test_subscription_is_supported_matches_helper():
     )
 
     assert not future_subscription.is_supported()
+
+
+def test_subscriptions_for_builds_cross_product():
+    symbols = [("btc", "usdt"), ("eth", "usdt"), ("sol", "usdt")]
+    kinds = [bp.SubKind.PUBLIC_TRADES, bp.SubKind.ORDER_BOOKS_L1]
+
+    subscriptions = bp.subscriptions_for(bp.ExchangeId.BINANCE_SPOT, symbols, kinds)
+
+    assert len(subscriptions) == 6
+    assert all(subscription.is_supported() for subscription in subscriptions)
+    assert [subscription.kind for subscription in subscriptions[:2]] == kinds
+    assert {subscription.instrument for subscription in subscriptions} == {
+        bp.Subscription(bp.ExchangeId.BINANCE_SPOT, base, quote, kinds[0]).instrument
+        for base, quote in symbols
+    }
+
+
+def test_subscriptions_for_skips_or_rejects_unsupported_kinds():
+    symbols = [("btc", "usdt")]
+    kinds = [bp.SubKind.PUBLIC_TRADES]
+
+    assert (
+        bp.subscriptions_for(
+            bp.ExchangeId.BINANCE_SPOT, symbols, kinds, instrument_kind=_future_kind()
+        )
+        == []
+    )
+
+    with pytest.raises(ValueError, match="does not support"):
+        bp.subscriptions_for(
+            bp.ExchangeId.BINANCE_SPOT,
+            symbols,
+            kinds,
+            instrument_kind=_future_kind(),
+            strict=True,
+        )
+
+
+def test_init_dynamic_streams_rejects_unsupported_subscriptions_in_strict_mode():
+    unsupported = bp.Subscription(
+        bp.ExchangeId.BINANCE_SPOT,
+        "btc",
+        "usdt",
+        bp.SubKind.PUBLIC_TRADES,
+        instrument_kind=_future_kind(),
+    )
+
+    with pytest.raises(ValueError, match="binance_spot does not support future") as error:
+        bp.init_dynamic_streams([[unsupported]])
+
+    assert "btc_usdt" in str(error.value)
+    assert "PublicTrades" in str(error.value)
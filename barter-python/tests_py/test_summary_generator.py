@@ -4,7 +4,11 @@ from datetime import datetime, timedelta, timezone
 from decimal import Decimal
 from pathlib import Path
 
+import pytest
+
 import barter_python as bp
+from barter_python.instrument import Side
+from barter_python.trade_bindings import AssetFees, Trade, TradeId
 
 
 def _load_config(example_paths: dict[str, Path]) -> bp.SystemConfig:
@@ -113,3 +117,266 @@ def test_generator_supports_time_scaling(example_paths: dict[str, Path]) -> None
 
     assert daily.time_engine_end <= datetime.now(timezone.utc)
     assert annual_365.to_dict()["instruments"]
+
+
+def test_trading_summary_equality(example_paths: dict[str, Path]) -> None:
+    """Summaries generated from identical state compare equal."""
+
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+
+    _, generator = bp.run_historic_backtest_with_generator(
+        config,
+        market_path,
+        risk_free_return=0.01,
+    )
+
+    first = generator.generate()
+    second = generator.generate()
+
+    assert first == second
+    assert not (first != second)
+    assert first.approx_eq(second, 0.0)
+    assert first.approx_eq(second, 1e-9)
+
+    annual = generator.generate("annual_252")
+    assert first != annual
+    assert not first.approx_eq(annual, 1e6)
+
+    assert first != "not a summary"
+
+    with pytest.raises(ValueError):
+        first.approx_eq(second, -1.0)
+
+
+def test_generator_min_samples_guard_clears_ratios(example_paths: dict[str, Path]) -> None:
+    """Instruments without enough PnL returns report no ratio metrics."""
+
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+
+    _, generator = bp.run_historic_backtest_with_generator(
+        config,
+        market_path,
+        risk_free_return=0.0,
+    )
+
+    guarded = generator.generate()
+    for sheet in guarded.instruments.values():
+        assert sheet.sharpe_ratio is None
+        assert sheet.sortino_ratio is None
+        assert sheet.calmar_ratio is None
+        assert sheet.pnl_return is not None
+
+    unguarded = generator.generate(min_samples=0)
+    for sheet in unguarded.instruments.values():
+        assert sheet.sharpe_ratio is not None
+        assert sheet.sortino_ratio is not None
+        assert sheet.calmar_ratio is not None
+
+
+def test_generator_metrics_filter_computes_requested_subset(
+    example_paths: dict[str, Path],
+) -> None:
+    """Selecting metrics skips everything else, including asset tear sheets."""
+
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+
+    _, generator = bp.run_historic_backtest_with_generator(
+        config,
+        market_path,
+        risk_free_return=0.0,
+    )
+
+    light = generator.generate(min_samples=0, metrics=["pnl", "sharpe_ratio"])
+    full = generator.generate(min_samples=0)
+
+    assert light.assets == {}
+    assert light.instruments.keys() == full.instruments.keys()
+    for name, sheet in light.instruments.items():
+        reference = full.instruments[name]
+        assert sheet.pnl == reference.pnl
+        assert sheet.sharpe_ratio.value == reference.sharpe_ratio.value
+        assert sheet.sortino_ratio is None
+        assert sheet.calmar_ratio is None
+        assert sheet.pnl_drawdown_max is None
+        assert sheet.pnl_drawdown_series == []
+        assert sheet.win_rate is None
+
+    with_assets = generator.generate(metrics=["assets", "pnl_drawdown_max"])
+    assert with_assets.assets.keys() == full.assets.keys()
+    for name, sheet in with_assets.instruments.items():
+        reference = full.instruments[name].pnl_drawdown_max
+        if reference is None:
+            assert sheet.pnl_drawdown_max is None
+        else:
+            assert sheet.pnl_drawdown_max.value == reference.value
+
+    assert "sharpe_ratio" in bp.TradingSummaryGenerator.METRICS
+    with pytest.raises(ValueError, match="unknown summary metric"):
+        generator.generate(metrics=["alpha"])
+
+
+def test_tear_sheets_expose_drawdown_series(example_paths: dict[str, Path]) -> None:
+    """Drawdown series should cover the periods behind the reported max drawdown."""
+
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+
+    summary = bp.run_historic_backtest(config, market_path, risk_free_return=0.0)
+
+    for sheet in list(summary.instruments.values()) + list(summary.assets.values()):
+        if isinstance(sheet, bp.InstrumentTearSheet):
+            series = sheet.pnl_drawdown_series
+            current, maximum = sheet.pnl_drawdown, sheet.pnl_drawdown_max
+            dict_key = "pnl_drawdown_series"
+        else:
+            series = sheet.drawdown_series
+            current, maximum = sheet.drawdown, sheet.drawdown_max
+            dict_key = "drawdown_series"
+
+        assert isinstance(series, list)
+        assert len(sheet.to_dict()[dict_key]) == len(series)
+
+        times = [drawdown.time_start for drawdown in series]
+        assert times == sorted(times)
+
+        if maximum is None:
+            assert not series
+            continue
+
+        assert series, "expected drawdown periods when a max drawdown is reported"
+        assert max(drawdown.value for drawdown in series) == maximum.value
+        if current is not None:
+            assert series[-1].value == current.value
+
+
+def test_system_config_instrument_aliases(example_paths: dict[str, Path]) -> None:
+    config = _load_config(example_paths)
+
+    assert config.instrument_aliases() == {}
+
+    config.set_instrument_alias("majors", ["binance_spot:eth_usdt", "binance_spot-btc_usdt"])
+    assert config.instrument_aliases() == {
+        "majors": ["binance_spot-btc_usdt", "binance_spot-eth_usdt"]
+    }
+    assert config.alias_for("binance_spot:btc_usdt") == "majors"
+    assert config.alias_for("binance_spot:sol_usdt") is None
+
+    round_trip = bp.SystemConfig.from_dict(config.to_dict())
+    assert round_trip.instrument_aliases() == config.instrument_aliases()
+
+    with pytest.raises(ValueError):
+        config.set_instrument_alias("unknown", ["coinbase:btc_usd"])
+    with pytest.raises(ValueError):
+        config.set_instrument_alias("duplicate", ["binance_spot:btc_usdt"])
+    with pytest.raises(ValueError):
+        config.set_instrument_alias("", ["binance_spot:sol_usdt"])
+
+    assert config.remove_instrument_alias("majors")
+    assert not config.remove_instrument_alias("majors")
+    assert config.instrument_aliases() == {}
+
+
+def test_trading_summary_consolidates_aliased_instruments(
+    example_paths: dict[str, Path],
+) -> None:
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+    summary = bp.run_historic_backtest(config, market_path, risk_free_return=0.0)
+
+    config.set_instrument_alias("majors", ["binance_spot:btc_usdt", "binance_spot:eth_usdt"])
+    consolidated = summary.consolidated(config)
+
+    members = ["binance_spot-btc_usdt", "binance_spot-eth_usdt"]
+    majors = consolidated["majors"]
+    assert majors["instruments"] == [name for name in summary.instruments if name in members]
+    assert majors["pnl"] == sum(summary.instruments[name].pnl for name in members)
+
+    for name, sheet in summary.instruments.items():
+        if name not in members:
+            assert consolidated[name]["instruments"] == [name]
+            assert consolidated[name]["pnl"] == sheet.pnl
+
+
+def test_trading_summary_sorted_orders_entries_by_name(example_paths: dict[str, Path]) -> None:
+    config = _load_config(example_paths)
+    market_path = str(example_paths["market_data"])
+    summary = bp.run_historic_backtest(config, market_path, risk_free_return=0.0)
+
+    ordered = summary.sorted()
+
+    assert list(ordered.instruments) == sorted(summary.instruments)
+    assert list(ordered.assets) == sorted(summary.assets)
+    assert ordered == summary
+
+    as_dict = summary.to_dict(sort_keys=True)
+    assert list(as_dict["instruments"]) == sorted(summary.instruments)
+    assert list(as_dict["assets"]) == sorted(summary.assets)
+    assert as_dict == ordered.to_dict()
+
+
+def test_generator_equity_curve_is_time_ordered(example_paths: dict[str, Path]) -> None:
+    config = _load_config(example_paths)
+    _, generator = bp.run_historic_backtest_with_generator(
+        config, str(example_paths["market_data"])
+    )
+
+    curve = generator.equity_curve()
+    times = [time for time, _ in curve]
+    assert times == sorted(times)
+    assert all(isinstance(value, Decimal) for _, value in curve)
+
+    with pytest.raises(ValueError, match="unknown instrument"):
+        generator.equity_curve("not-an-instrument")
+
+
+def _blotter_trade(
+    trade_id: str, order: str, instrument: int, minutes: int, side: Side, price: str
+) -> Trade:
+    return Trade(
+        TradeId.new(trade_id),
+        bp.OrderId.new(order),
+        instrument,
+        bp.StrategyId.new("external"),
+        datetime(2024, 1, 1, tzinfo=timezone.utc) + timedelta(minutes=minutes),
+        side,
+        Decimal(price),
+        Decimal("1"),
+        AssetFees.quote_fees(Decimal("0")),
+    )
+
+
+def test_summarize_trades_builds_summary_from_blotter() -> None:
+    trades = [
+        # Supplied out of order; trades are replayed by time_exchange.
+        _blotter_trade("t-3", "o-3", 0, 30, Side.BUY, "110"),
+        _blotter_trade("t-1", "o-1", 0, 0, Side.BUY, "100"),
+        _blotter_trade("t-2", "o-2", 0, 10, Side.SELL, "110"),
+        _blotter_trade("t-4", "o-4", 0, 40, Side.SELL, "105"),
+        # Instrument 1 position is left open, so contributes no realised PnL.
+        _blotter_trade("t-5", "o-5", 1, 50, Side.BUY, "10"),
+    ]
+
+    summary = bp.summarize_trades(trades, 1000.0, risk_free_return=0.0, interval="annual_365")
+
+    assert list(summary.instruments) == ["instrument_0", "instrument_1"]
+    assert summary.instruments["instrument_0"].pnl == Decimal("5")
+    assert summary.instruments["instrument_1"].pnl == Decimal("0")
+    assert summary.time_engine_start == datetime(2024, 1, 1, tzinfo=timezone.utc)
+    assert summary.time_engine_end == datetime(2024, 1, 1, 0, 50, tzinfo=timezone.utc)
+
+    quote = summary.assets["other:quote"]
+    assert quote.balance_end.total == Decimal("1005")
+
+
+def test_summarize_trades_validates_inputs() -> None:
+    trade = _blotter_trade("t-1", "o-1", 0, 0, Side.BUY, "100")
+
+    with pytest.raises(ValueError, match="trades must not be empty"):
+        bp.summarize_trades([], 1000.0)
+    with pytest.raises(ValueError, match="initial_balance must be positive"):
+        bp.summarize_trades([trade], 0.0)
+    with pytest.raises(ValueError):
+        bp.summarize_trades(["not-a-trade"], 1000.0)
@@ -1,6 +1,8 @@
 from datetime import datetime, timezone
 from decimal import Decimal
 
+import pytest
+
 import barter_python as bp
 from barter_python.instrument import Side
 from barter_python.trade_bindings import AssetFees, Trade, TradeId
@@ -82,3 +84,201 @@ def test_trade_wrapper_properties_and_value_quote():
 
     assert trade == trade_same
     assert hash(trade) == hash(trade_same)
+
+
+def _slippage_trade(trade_id: str, side: Side, price: str, quantity: str = "2") -> Trade:
+    return Trade(
+        TradeId.new(trade_id),
+        bp.OrderId.new(f"order-{trade_id}"),
+        7,
+        bp.StrategyId.new("strategy-alpha"),
+        datetime(2024, 1, 1, 12, 0, 0, tzinfo=timezone.utc),
+        side,
+        Decimal(price),
+        Decimal(quantity),
+        AssetFees.quote_fees(Decimal("0")),
+    )
+
+
+def test_trade_approx_eq_tolerates_rounding_differences():
+    engine = _slippage_trade("t-1", Side.BUY, "100.004", "2.0001")
+    exchange = _slippage_trade("t-1", Side.BUY, "100.00", "2")
+
+    assert engine != exchange
+    assert not engine.approx_eq(exchange)
+    assert engine.approx_eq(exchange, price_tol=0.01, qty_tol=0.001)
+    assert not engine.approx_eq(exchange, price_tol=0.001, qty_tol=0.001)
+    assert engine.approx_eq(exchange._inner, price_tol=0.01, qty_tol=0.001)
+
+    other_side = _slippage_trade("t-1", Side.SELL, "100.00", "2")
+    assert not engine.approx_eq(other_side, price_tol=1, qty_tol=1)
+
+    with pytest.raises(ValueError, match="price_tol"):
+        engine.approx_eq(exchange, price_tol=-0.01)
+
+
+def test_aggregate_trades_computes_vwap_and_net_quantity():
+    trades = [
+        _slippage_trade("buy-1", Side.BUY, "100", "2"),
+        _slippage_trade("buy-2", Side.BUY, "110", "1"),
+        _slippage_trade("sell-1", Side.SELL, "120", "1"),
+    ]
+
+    aggregate = bp.aggregate_trades(trades)
+
+    assert aggregate["instrument"] == 7
+    assert aggregate["trade_count"] == 3
+    assert aggregate["total_quantity"] == Decimal("4")
+    assert aggregate["net_quantity"] == Decimal("2")
+    assert aggregate["total_fees"] == Decimal("0")
+    assert aggregate["average_price"] == Decimal("107.5")
+    assert bp.execution.aggregate_trades(trades) == aggregate
+
+
+def test_aggregate_trades_rejects_mixed_instruments():
+    other = Trade(
+        TradeId.new("other"),
+        bp.OrderId.new("order-other"),
+        8,
+        bp.StrategyId.new("strategy-alpha"),
+        datetime(2024, 1, 1, 12, 0, 0, tzinfo=timezone.utc),
+        Side.BUY,
+        Decimal("100"),
+        Decimal("1"),
+        AssetFees.quote_fees(Decimal("0")),
+    )
+
+    with pytest.raises(ValueError, match="instrument"):
+        bp.aggregate_trades([_slippage_trade("t-1", Side.BUY, "100"), other])
+    with pytest.raises(ValueError, match="must not be empty"):
+        bp.aggregate_trades([])
+
+
+def test_slippage_report_signs_cost_by_side():
+    trades = [
+        _slippage_trade("buy-above", Side.BUY, "50010"),
+        _slippage_trade("sell-below", Side.SELL, "49990"),
+        _slippage_trade("sell-above", Side.SELL, "50005"),
+    ]
+
+    report = bp.slippage_report(trades, [50000.0, Decimal("50000"), 50000])
+
+    assert [entry["trade_id"] for entry in report] == ["buy-above", "sell-below", "sell-above"]
+    assert [entry["slippage_bps"] for entry in report] == [
+        Decimal("2"),
+        Decimal("2"),
+        Decimal("-1"),
+    ]
+    assert [entry["slippage_quote"] for entry in report] == [
+        Decimal("20"),
+        Decimal("20"),
+        Decimal("-10"),
+    ]
+    assert report[0]["arrival_mid"] == Decimal("50000")
+    assert report[0]["instrument"] == 7
+    assert report[0]["side"] == bp.Side.BUY
+
+
+def test_slippage_report_accepts_native_trades():
+    trade = _slippage_trade("native", Side.BUY, "101")._inner
+
+    (entry,) = bp.slippage_report([trade], [100])
+
+    assert entry["slippage_bps"] == Decimal("100")
+
+
+def test_slippage_report_validates_inputs():
+    trades = [_slippage_trade("t-1", Side.BUY, "100")]
+
+    with pytest.raises(ValueError):
+        bp.slippage_report(trades, [])
+    with pytest.raises(ValueError):
+        bp.slippage_report(trades, [0])
+    with pytest.raises(ValueError):
+        bp.slippage_report(["not-a-trade"], [100])
+
+
+def test_fill_stats_by_kind_aggregates_per_order_kind():
+    market_buy = _slippage_trade("m-1", Side.BUY, "50010")
+    market_sell = _slippage_trade("m-2", Side.SELL, "49970")
+    limit_fill = _slippage_trade("l-1", Side.BUY, "49990", quantity="1")
+    limit_partial = Trade(
+        TradeId.new("l-2"),
+        bp.OrderId.new("order-l-1"),
+        7,
+        bp.StrategyId.new("strategy-alpha"),
+        datetime(2024, 1, 1, 12, 0, 1, tzinfo=timezone.utc),
+        Side.BUY,
+        Decimal("49995"),
+        Decimal("1"),
+        AssetFees.quote_fees(Decimal("0")),
+    )
+
+    stats = bp.fill_stats_by_kind(
+        [
+            (market_buy, "market", 50000, 2),
+            (market_sell, bp.OrderKind.MARKET, 50000, 2),
+            (limit_fill, "limit", 50000, 4),
+            (limit_partial, "limit", 50000, 4),
+        ]
+    )
+
+    assert list(stats) == ["market", "limit"]
+    assert stats["market"] == {
+        "count": 2,
+        "average_slippage_bps": Decimal("4"),
+        "fill_rate": Decimal("1"),
+    }
+    # Both limit fills belong to one order for 4 units, of which 2 were filled.
+    assert stats["limit"]["count"] == 2
+    assert stats["limit"]["average_slippage_bps"] == Decimal("-1.5")
+    assert stats["limit"]["fill_rate"] == Decimal("0.5")
+
+    assert bp.fill_stats_by_kind([]) == {}
+    with pytest.raises(ValueError):
+        bp.fill_stats_by_kind([(market_buy, "market", 50000)])
+    with pytest.raises(ValueError):
+        bp.fill_stats_by_kind([(market_buy, "stop", 50000, 2)])
+    with pytest.raises(ValueError):
+        bp.fill_stats_by_kind([(market_buy, "market", 50000, 0)])
+
+
+def test_merge_timeline_orders_events_across_sources():
+    class Tick:
+        def __init__(self, time):
+            self.context = {"time": time}
+
+    trade = _slippage_trade("fill", Side.BUY, "100")
+    market = [
+        {"kind": "trade", "time_exchange": datetime(2024, 1, 1, 11, 59, tzinfo=timezone.utc)},
+        {"kind": "trade", "time_exchange": "2024-01-01T12:00:30Z"},
+    ]
+    audit = [Tick(datetime(2024, 1, 1, 11, 59, 30, tzinfo=timezone.utc))]
+
+    timeline = bp.merge_timeline(
+        market, audit, [trade], sources=["market", "audit", "trades"]
+    )
+
+    assert [entry["source"] for entry in timeline] == ["market", "audit", "trades", "market"]
+    assert [entry["kind"] for entry in timeline] == ["dict", "Tick", "Trade", "dict"]
+    assert timeline[2]["event"] is trade
+    assert timeline[2]["time"] == datetime(2024, 1, 1, 12, 0, tzinfo=timezone.utc)
+
+
+def test_merge_timeline_defaults_sources_to_list_position():
+    first = [{"time": 2_000}, {"time": 3_000}]
+    second = [{"time": 1_000}, {"time": 2_000}]
+
+    timeline = bp.merge_timeline(first, second)
+
+    assert [(entry["source"], entry["event"]["time"]) for entry in timeline] == [
+        (1, 1_000),
+        (0, 2_000),
+        (1, 2_000),
+        (0, 3_000),
+    ]
+
+    with pytest.raises(ValueError, match="has no time_exchange or time"):
+        bp.merge_timeline([{"price": 1}])
+    with pytest.raises(ValueError, match="one name per event list"):
+        bp.merge_timeline(first, sources=["a", "b"])
@@ -4,10 +4,11 @@ use crate::engine::{
         send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
     },
     error::UnrecoverableEngineError,
+    state::trading::TradingStateUpdateAudit,
 };
 use barter_execution::order::request::{RequestCancel, RequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
-use barter_integration::collection::one_or_many::OneOrMany;
+use barter_integration::collection::{none_one_or_many::NoneOneOrMany, one_or_many::OneOrMany};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -32,6 +33,9 @@ pub enum ActionOutput<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentInd
     CancelOrders(SendRequestsOutput<RequestCancel, ExchangeKey, InstrumentKey>),
     OpenOrders(SendRequestsOutput<RequestOpen, ExchangeKey, InstrumentKey>),
     ClosePositions(SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>),
+    /// Instrument level `TradingState` update, `None` if the instrument is not tracked by the
+    /// `EngineState`.
+    InstrumentTradingState(InstrumentKey, Option<TradingStateUpdateAudit>),
 }
 
 impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
@@ -42,6 +46,7 @@ impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
             ActionOutput::CancelOrders(cancels) => cancels.unrecoverable_errors(),
             ActionOutput::OpenOrders(opens) => opens.unrecoverable_errors(),
             ActionOutput::ClosePositions(requests) => requests.unrecoverable_errors(),
+            ActionOutput::InstrumentTradingState(..) => NoneOneOrMany::None,
         }
         .into_option()
     }
@@ -3,6 +3,7 @@ use crate::{
     engine::{
         EngineMeta, EngineOutput, Processor,
         audit::{AuditTick, EngineAudit, context::EngineContext},
+        command::Command,
         state::{EngineState, instrument::data::InstrumentDataState},
     },
     execution::AccountStreamEvent,
@@ -13,7 +14,7 @@ use barter_instrument::instrument::InstrumentIndex;
 use barter_integration::Terminal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use tracing::{info, info_span};
+use tracing::{info, info_span, warn};
 
 pub const AUDIT_REPLICA_STATE_UPDATE_SPAN_NAME: &str = "audit_replica_state_update_span";
 
@@ -117,6 +118,22 @@ where
     /// Updates the internal `EngineState` using the provided `EngineEvent`.
     pub fn update_from_event(&mut self, event: EngineEvent<InstrumentData::MarketEventKind>) {
         match event {
+            EngineEvent::Command(Command::SetInstrumentTradingState(instrument, trading_state)) => {
+                match self
+                    .replica_engine_state_mut()
+                    .instruments
+                    .0
+                    .get_index_mut(instrument.index())
+                {
+                    Some((_key, state)) => {
+                        let _audit = state.trading.update(trading_state);
+                    }
+                    None => warn!(
+                        %instrument,
+                        "AuditManager ignoring Command::SetInstrumentTradingState for untracked instrument"
+                    ),
+                }
+            }
             EngineEvent::Shutdown(_) | EngineEvent::Command(_) => {
                 // No action required
             }
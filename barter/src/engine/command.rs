@@ -1,4 +1,4 @@
-use crate::engine::state::instrument::filter::InstrumentFilter;
+use crate::engine::state::{instrument::filter::InstrumentFilter, trading::TradingState};
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::collection::one_or_many::OneOrMany;
@@ -16,4 +16,11 @@ pub enum Command<
     SendOpenRequests(OneOrMany<OrderRequestOpen<ExchangeKey, InstrumentKey>>),
     ClosePositions(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
     CancelOrders(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
+    /// Override the [`TradingState`] of a single instrument, leaving the `Engine` wide
+    /// `TradingState` untouched.
+    ///
+    /// The `Engine` only records the override - it is honoured by strategies that consult
+    /// `InstrumentState::trading` before generating algorithmic orders. Unknown instruments
+    /// are logged and ignored.
+    SetInstrumentTradingState(InstrumentKey, TradingState),
 }
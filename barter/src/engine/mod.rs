@@ -35,7 +35,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use tracing::info;
+use tracing::{info, warn};
 
 /// Defines how the [`Engine`] actions a [`Command`], and the associated outputs.
 pub mod action;
@@ -234,6 +234,28 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
                 info!(?filter, "Engine actioning user Command::CancelOrders");
                 ActionOutput::CancelOrders(self.cancel_orders(filter))
             }
+            Command::SetInstrumentTradingState(instrument, trading_state) => {
+                info!(
+                    %instrument,
+                    ?trading_state,
+                    "Engine actioning user Command::SetInstrumentTradingState"
+                );
+                let audit = self
+                    .state
+                    .instruments
+                    .0
+                    .get_index_mut(instrument.index())
+                    .map(|(_key, state)| state.trading.update(*trading_state));
+
+                if audit.is_none() {
+                    warn!(
+                        %instrument,
+                        "Engine ignoring Command::SetInstrumentTradingState for untracked instrument"
+                    );
+                }
+
+                ActionOutput::InstrumentTradingState(*instrument, audit)
+            }
         }
     }
 
@@ -275,7 +297,9 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
                     .connectivity
                     .update_from_account_reconnecting(exchange);
 
-                UpdateFromAccountOutput::OnDisconnect(Strategy::on_disconnect(self, *exchange))
+                UpdateFromAccountOutput::OnDisconnect(Strategy::on_account_disconnect(
+                    self, *exchange,
+                ))
             }
             AccountStreamEvent::Item(event) => self
                 .state
@@ -289,6 +313,9 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
     ///
     /// If the input `MarketStreamEvent` indicates the exchange market data link has disconnected,
     /// the `Engine` will call the configured [`OnDisconnectStrategy`] strategy logic.
+    ///
+    /// The updated price of the instrument of a market event is forwarded to the
+    /// `ExecutionManager` of its exchange (see [`ExecutionRequest::MarketPrice`]).
     pub fn update_from_market_stream(
         &mut self,
         event: &MarketStreamEvent<InstrumentIndex, InstrumentData::MarketEventKind>,
@@ -297,6 +324,7 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
         InstrumentData: InstrumentDataState,
         GlobalData:
             for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>,
+        ExecutionTxs: ExecutionTxMap,
         Strategy: OnDisconnectStrategy<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>,
     {
         match event {
@@ -305,15 +333,42 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
                     .connectivity
                     .update_from_market_reconnecting(exchange);
 
-                UpdateFromMarketOutput::OnDisconnect(Strategy::on_disconnect(self, *exchange))
+                UpdateFromMarketOutput::OnDisconnect(Strategy::on_market_disconnect(
+                    self, *exchange,
+                ))
             }
             MarketStreamEvent::Item(event) => {
                 self.state.update_from_market(event);
+                self.send_market_price(event.instrument);
                 UpdateFromMarketOutput::None
             }
         }
     }
 
+    /// Forward the latest price of an instrument to the `ExecutionManager` of its exchange.
+    ///
+    /// Instruments without a price, or traded on an exchange without an `ExecutionManager`, are
+    /// skipped. Send failures are only logged, since they surface on the next order request.
+    fn send_market_price(&self, instrument: InstrumentIndex)
+    where
+        InstrumentData: InstrumentDataState,
+        ExecutionTxs: ExecutionTxMap,
+    {
+        let Some((_, state)) = self.state.instruments.0.get_index(instrument.index()) else {
+            return;
+        };
+        let Some(price) = state.data.price() else {
+            return;
+        };
+        let Ok(execution_tx) = self.execution_txs.find(&state.instrument.exchange) else {
+            return;
+        };
+
+        if let Err(error) = execution_tx.send(ExecutionRequest::MarketPrice { instrument, price }) {
+            warn!(%instrument, ?error, "Engine failed to forward market price to ExecutionManager");
+        }
+    }
+
     /// Returns a [`TradingSummaryGenerator`] for the current trading session.
     pub fn trading_summary_generator(&self, risk_free_return: Decimal) -> TradingSummaryGenerator
     where
@@ -23,6 +23,7 @@ pub struct EngineStateBuilder<'a, GlobalData, FnInstrumentData> {
     instruments: &'a IndexedInstruments,
     trading_state: Option<TradingState>,
     time_engine_start: Option<DateTime<Utc>>,
+    tear_sheet_history: bool,
     global: GlobalData,
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
     instrument_data_init: FnInstrumentData,
@@ -44,6 +45,7 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         Self {
             instruments,
             time_engine_start: None,
+            tear_sheet_history: false,
             trading_state: None,
             global,
             balances: FnvHashMap::default(),
@@ -74,6 +76,20 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         }
     }
 
+    /// Optionally record the drawdown & equity curve history of every instrument and asset
+    /// tear sheet generator.
+    ///
+    /// Useful for back-test scenarios, where the history is bounded by the length of the
+    /// back-test. Live trading `EngineState`s should leave it disabled to keep memory bounded.
+    ///
+    /// Defaults to `false`.
+    pub fn tear_sheet_history(self, value: bool) -> Self {
+        Self {
+            tear_sheet_history: value,
+            ..self
+        }
+    }
+
     /// Optionally provide initial exchange asset `Balance`s.
     ///
     /// Useful for back-test scenarios where seeding EngineState with initial `Balance`s is
@@ -106,6 +122,7 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         let Self {
             instruments,
             time_engine_start,
+            tear_sheet_history,
             trading_state,
             global,
             balances,
@@ -124,6 +141,9 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
 
         // Update empty AssetStates from provided exchange asset Balances
         let mut assets = generate_empty_indexed_asset_states(instruments);
+        for state in assets.0.values_mut() {
+            state.statistics.record_history = tear_sheet_history;
+        }
         for (key, balance) in balances {
             assets
                 .asset_mut(&key)
@@ -135,13 +155,16 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         }
 
         // Generate empty InstrumentStates using provided FnInstrumentData etc.
-        let instruments = generate_indexed_instrument_states(
+        let mut instruments = generate_indexed_instrument_states(
             instruments,
             time_engine_start,
             PositionManager::default,
             Orders::default,
             instrument_data_init,
         );
+        for state in instruments.0.values_mut() {
+            state.tear_sheet.record_history = tear_sheet_history;
+        }
 
         EngineState {
             trading,
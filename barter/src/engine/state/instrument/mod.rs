@@ -3,6 +3,7 @@ use crate::{
         instrument::{data::InstrumentDataState, filter::InstrumentFilter},
         order::{Orders, manager::OrderManager},
         position::{PositionExited, PositionManager},
+        trading::TradingState,
     },
     statistic::summary::instrument::TearSheetGenerator,
 };
@@ -265,6 +266,12 @@ pub struct InstrumentState<
     /// Active orders and associated order management.
     pub orders: Orders<ExchangeKey, InstrumentKey>,
 
+    /// Instrument level `TradingState` override. Independent of the `Engine` wide `TradingState`.
+    ///
+    /// Not enforced by the `Engine` itself - only strategies that consult this field before
+    /// generating algorithmic orders for this instrument honour it.
+    pub trading: TradingState,
+
     /// User provided instrument level data state. This can include market data, strategy data,
     /// risk data, option pricing data, or any other instrument-specific information.
     pub data: InstrumentData,
@@ -376,6 +383,7 @@ where
         tear_sheet: _,
         position: _,
         orders,
+        trading: _,
         data: _,
     } = state;
 
@@ -446,6 +454,7 @@ where
                         TearSheetGenerator::init(time_engine_start),
                         position_manager_init(),
                         orders_init(),
+                        TradingState::Enabled,
                         instrument_data_init(instrument),
                     ),
                 )
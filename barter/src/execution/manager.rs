@@ -271,6 +271,29 @@ where
                             request,
                         ))
                     }
+                    Some(ExecutionRequest::MarketPrice { instrument, price }) => {
+                        let result = self
+                            .indexer
+                            .map
+                            .find_instrument_name_exchange(instrument)
+                            .map(|name| self.client.update_market_price(name, price));
+
+                        match result {
+                            Ok(Ok(())) => {}
+                            Ok(Err(error)) => warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                %instrument,
+                                ?error,
+                                "ExecutionManager failed to forward market price"
+                            ),
+                            Err(error) => warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                %instrument,
+                                ?error,
+                                "ExecutionManager received market price for non-configured instrument"
+                            ),
+                        }
+                    }
                 },
 
                 // Process next ExecutionRequest::Cancel response
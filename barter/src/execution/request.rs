@@ -1,6 +1,7 @@
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 use derive_more::From;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::{
     future::Future,
@@ -19,6 +20,14 @@ pub enum ExecutionRequest<ExchangeKey = ExchangeIndex, InstrumentKey = Instrumen
 
     /// Request to open an new `Order`.
     Open(OrderRequestOpen<ExchangeKey, InstrumentKey>),
+
+    /// Latest market `price` of an instrument, forwarded to the `ExecutionClient` so simulated
+    /// exchanges can fill the resting `Limit` orders it crosses.
+    #[from(skip)]
+    MarketPrice {
+        instrument: InstrumentKey,
+        price: Decimal,
+    },
 }
 
 #[derive(Debug)]
@@ -17,6 +17,12 @@ pub struct TearSheetAsset {
     pub drawdown: Option<Drawdown>,
     pub drawdown_mean: Option<MeanDrawdown>,
     pub drawdown_max: Option<MaxDrawdown>,
+    /// Every balance [`Drawdown`] period used to compute the mean & max, including the current one.
+    ///
+    /// Only the current period is included if the [`TearSheetAssetGenerator`] does not record
+    /// history.
+    #[serde(default)]
+    pub drawdown_series: Vec<Drawdown>,
 }
 
 /// Generator for an [`TearSheetAsset`].
@@ -26,6 +32,17 @@ pub struct TearSheetAssetGenerator {
     pub drawdown: DrawdownGenerator,
     pub drawdown_mean: MeanDrawdownGenerator,
     pub drawdown_max: MaxDrawdownGenerator,
+    /// Completed balance [`Drawdown`] periods, in the order they were recorded.
+    ///
+    /// Only recorded if `record_history` is enabled.
+    #[serde(default)]
+    pub drawdown_series: Vec<Drawdown>,
+    /// Whether to record the `drawdown_series` history.
+    ///
+    /// Disabled by default, since the history grows with every balance drawdown and would
+    /// otherwise bloat a long-running `EngineState`. Enable it for back-tests.
+    #[serde(default)]
+    pub record_history: bool,
 }
 
 impl TearSheetAssetGenerator {
@@ -36,6 +53,16 @@ impl TearSheetAssetGenerator {
             drawdown: DrawdownGenerator::init(Timed::new(initial.value.total, initial.time)),
             drawdown_mean: MeanDrawdownGenerator::default(),
             drawdown_max: MaxDrawdownGenerator::default(),
+            drawdown_series: Vec::new(),
+            record_history: false,
+        }
+    }
+
+    /// Configure whether the `drawdown_series` history is recorded.
+    pub fn with_history(self, record_history: bool) -> Self {
+        Self {
+            record_history,
+            ..self
         }
     }
 
@@ -49,6 +76,9 @@ impl TearSheetAssetGenerator {
         )) {
             self.drawdown_mean.update(&next_drawdown);
             self.drawdown_max.update(&next_drawdown);
+            if self.record_history {
+                self.drawdown_series.push(next_drawdown);
+            }
         }
     }
 
@@ -60,17 +90,25 @@ impl TearSheetAssetGenerator {
             self.drawdown_max.update(drawdown);
         }
 
+        let drawdown_series = self
+            .drawdown_series
+            .iter()
+            .cloned()
+            .chain(current_drawdown.clone())
+            .collect();
+
         TearSheetAsset {
             balance_end: self.balance_now,
             drawdown: current_drawdown,
             drawdown_mean: self.drawdown_mean.generate(),
             drawdown_max: self.drawdown_max.generate(),
+            drawdown_series,
         }
     }
 
     /// Reset the internal state, using a new starting `Timed<Balance>` as seed.
     pub fn reset(&mut self, balance_start: &Timed<Balance>) {
-        *self = Self::init(balance_start);
+        *self = Self::init(balance_start).with_history(self.record_history);
     }
 }
 
@@ -106,7 +144,8 @@ mod tests {
         let mut generator = TearSheetAssetGenerator::init(&Timed::new(
             Balance::new(dec!(1.0), dec!(1.0)),
             base_time,
-        ));
+        ))
+        .with_history(true);
 
         let cases = vec![
             // TC0: Balance increased from 1.0 peak, so no expected drawdowns
@@ -123,6 +162,8 @@ mod tests {
                     )),
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    drawdown_series: vec![],
+                    record_history: true,
                 },
             },
             // TC1: Balance decreased, so expect a current drawdown only
@@ -141,6 +182,8 @@ mod tests {
                     },
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    drawdown_series: vec![],
+                    record_history: true,
                 },
             },
             // TC2: Further decrease - larger drawdown
@@ -159,6 +202,8 @@ mod tests {
                     },
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    drawdown_series: vec![],
+                    record_history: true,
                 },
             },
             // TC3: Recovery above previous peak - should complete drawdown period
@@ -190,6 +235,12 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    drawdown_series: vec![Drawdown {
+                        value: dec!(0.5),
+                        time_start: time_plus_days(base_time, 1),
+                        time_end: time_plus_days(base_time, 4),
+                    }],
+                    record_history: true,
                 },
             },
             // TC4: Small drawdown after new peak (2.5 -> 2.4)
@@ -223,6 +274,12 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    drawdown_series: vec![Drawdown {
+                        value: dec!(0.5),
+                        time_start: time_plus_days(base_time, 1),
+                        time_end: time_plus_days(base_time, 4),
+                    }],
+                    record_history: true,
                 },
             },
             // TC5: Equal to previous value - drawdown continues
@@ -256,6 +313,12 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    drawdown_series: vec![Drawdown {
+                        value: dec!(0.5),
+                        time_start: time_plus_days(base_time, 1),
+                        time_end: time_plus_days(base_time, 4),
+                    }],
+                    record_history: true,
                 },
             },
             // TC6: Tiny change, but still in drawdown - retain max drawdown from current period
@@ -289,6 +352,12 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    drawdown_series: vec![Drawdown {
+                        value: dec!(0.5),
+                        time_start: time_plus_days(base_time, 1),
+                        time_end: time_plus_days(base_time, 4),
+                    }],
+                    record_history: true,
                 },
             },
             // TC7: recovery above previous peak - should complete drawdown period
@@ -323,6 +392,19 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    drawdown_series: vec![
+                        Drawdown {
+                            value: dec!(0.5),
+                            time_start: time_plus_days(base_time, 1),
+                            time_end: time_plus_days(base_time, 4),
+                        },
+                        Drawdown {
+                            value: dec!(0.04),
+                            time_start: time_plus_days(base_time, 4),
+                            time_end: time_plus_days(base_time, 8),
+                        },
+                    ],
+                    record_history: true,
                 },
             },
         ];
@@ -332,4 +414,37 @@ mod tests {
             assert_eq!(generator, test.expected, "TC{index} failed");
         }
     }
+
+    #[test]
+    fn test_tear_sheet_asset_generator_without_history() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        let mut generator = TearSheetAssetGenerator::init(&Timed::new(
+            Balance::new(dec!(1.0), dec!(1.0)),
+            base_time,
+        ));
+
+        for (days, total) in [
+            (1, dec!(0.5)),
+            (2, dec!(2.0)),
+            (3, dec!(1.0)),
+            (4, dec!(3.0)),
+        ] {
+            generator.update_from_balance(Snapshot(&balance(
+                Balance::new(total, total),
+                time_plus_days(base_time, days),
+            )));
+        }
+
+        // Completed drawdowns still feed the mean & max, but are not retained
+        assert!(generator.drawdown_series.is_empty());
+        assert_eq!(
+            generator.drawdown_max.generate(),
+            Some(MaxDrawdown(Drawdown {
+                value: dec!(0.5),
+                time_start: base_time,
+                time_end: time_plus_days(base_time, 2),
+            }))
+        );
+    }
 }
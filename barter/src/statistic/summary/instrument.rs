@@ -34,6 +34,11 @@ pub struct TearSheet<Interval> {
     pub pnl_drawdown: Option<Drawdown>,
     pub pnl_drawdown_mean: Option<MeanDrawdown>,
     pub pnl_drawdown_max: Option<MaxDrawdown>,
+    /// Every PnL [`Drawdown`] period used to compute the mean & max, including the current one.
+    ///
+    /// Only the current period is included if the [`TearSheetGenerator`] does not record history.
+    #[serde(default)]
+    pub pnl_drawdown_series: Vec<Drawdown>,
     pub win_rate: Option<WinRate>,
     pub profit_factor: Option<ProfitFactor>,
 }
@@ -51,6 +56,25 @@ pub struct TearSheetGenerator {
     pub pnl_drawdown: DrawdownGenerator,
     pub pnl_drawdown_mean: MeanDrawdownGenerator,
     pub pnl_drawdown_max: MaxDrawdownGenerator,
+
+    /// Completed PnL [`Drawdown`] periods, in the order they were recorded.
+    ///
+    /// Only recorded if `record_history` is enabled.
+    #[serde(default)]
+    pub pnl_drawdown_series: Vec<Drawdown>,
+
+    /// Cumulative PnL after each position exit, in the order they were recorded.
+    ///
+    /// Only recorded if `record_history` is enabled.
+    #[serde(default)]
+    pub pnl_equity_curve: Vec<Timed<Decimal>>,
+
+    /// Whether to record the `pnl_drawdown_series` and `pnl_equity_curve` history.
+    ///
+    /// Disabled by default, since the history grows with every position exit and would
+    /// otherwise bloat a long-running `EngineState`. Enable it for back-tests.
+    #[serde(default)]
+    pub record_history: bool,
 }
 
 impl TearSheetGenerator {
@@ -63,6 +87,17 @@ impl TearSheetGenerator {
             pnl_drawdown: DrawdownGenerator::default(),
             pnl_drawdown_mean: MeanDrawdownGenerator::default(),
             pnl_drawdown_max: MaxDrawdownGenerator::default(),
+            pnl_drawdown_series: Vec::new(),
+            pnl_equity_curve: Vec::new(),
+            record_history: false,
+        }
+    }
+
+    /// Configure whether the `pnl_drawdown_series` and `pnl_equity_curve` history is recorded.
+    pub fn with_history(self, record_history: bool) -> Self {
+        Self {
+            record_history,
+            ..self
         }
     }
 
@@ -74,12 +109,17 @@ impl TearSheetGenerator {
         self.time_engine_now = position.time_exit;
         self.pnl_returns.update(position);
 
-        if let Some(next_drawdown) = self
-            .pnl_drawdown
-            .update(Timed::new(self.pnl_returns.pnl_raw, self.time_engine_now))
-        {
+        let point = Timed::new(self.pnl_returns.pnl_raw, self.time_engine_now);
+        if self.record_history {
+            self.pnl_equity_curve.push(point);
+        }
+
+        if let Some(next_drawdown) = self.pnl_drawdown.update(point) {
             self.pnl_drawdown_mean.update(&next_drawdown);
             self.pnl_drawdown_max.update(&next_drawdown);
+            if self.record_history {
+                self.pnl_drawdown_series.push(next_drawdown);
+            }
         }
     }
 
@@ -123,6 +163,12 @@ impl TearSheetGenerator {
         }
         let pnl_drawdown_mean = self.pnl_drawdown_mean.generate();
         let pnl_drawdown_max = self.pnl_drawdown_max.generate();
+        let pnl_drawdown_series = self
+            .pnl_drawdown_series
+            .iter()
+            .cloned()
+            .chain(current_pnl_drawdown.clone())
+            .collect();
 
         let calmar_ratio = CalmarRatio::calculate(
             risk_free_return,
@@ -155,6 +201,7 @@ impl TearSheetGenerator {
             pnl_drawdown: current_pnl_drawdown,
             pnl_drawdown_mean,
             pnl_drawdown_max,
+            pnl_drawdown_series,
             win_rate,
             profit_factor,
         }
@@ -162,6 +209,6 @@ impl TearSheetGenerator {
 
     /// Reset the internal state, using a new starting `DateTime<Utc>` as seed.
     pub fn reset(&mut self, time_engine_start: DateTime<Utc>) {
-        *self = Self::init(time_engine_start);
+        *self = Self::init(time_engine_start).with_history(self.record_history);
     }
 }
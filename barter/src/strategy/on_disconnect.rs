@@ -20,4 +20,24 @@ where
         engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
         exchange: ExchangeId,
     ) -> Self::OnDisconnect;
+
+    /// Perform [`Engine`] actions after the [`ExchangeId`] execution (account) link disconnects.
+    ///
+    /// Defaults to [`Self::on_disconnect`].
+    fn on_account_disconnect(
+        engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        Self::on_disconnect(engine, exchange)
+    }
+
+    /// Perform [`Engine`] actions after the [`ExchangeId`] market data link disconnects.
+    ///
+    /// Defaults to [`Self::on_disconnect`].
+    fn on_market_disconnect(
+        engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        Self::on_disconnect(engine, exchange)
+    }
 }
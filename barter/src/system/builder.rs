@@ -103,6 +103,7 @@ pub struct SystemBuilder<'a, Clock, Strategy, Risk, MarketStream, GlobalData, Fn
     engine_feed_mode: Option<EngineFeedMode>,
     audit_mode: Option<AuditMode>,
     trading_state: Option<TradingState>,
+    tear_sheet_history: bool,
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
 }
 
@@ -120,6 +121,7 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             engine_feed_mode: None,
             audit_mode: None,
             trading_state: None,
+            tear_sheet_history: false,
             balances: FnvHashMap::default(),
         }
     }
@@ -154,6 +156,16 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         }
     }
 
+    /// Optionally record the drawdown & equity curve history of the `EngineState` tear sheets.
+    ///
+    /// Useful for back-tests; see [`EngineStateBuilder::tear_sheet_history`].
+    pub fn tear_sheet_history(self, value: bool) -> Self {
+        Self {
+            tear_sheet_history: value,
+            ..self
+        }
+    }
+
     /// Optionally provide initial exchange asset `Balance`s.
     ///
     /// Useful for back-test scenarios where seeding EngineState with initial `Balance`s is
@@ -216,6 +228,7 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             engine_feed_mode,
             audit_mode,
             trading_state,
+            tear_sheet_history,
             balances,
         } = self;
 
@@ -241,6 +254,7 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         let state = EngineStateBuilder::new(instruments, global_data, instrument_data_init)
             .time_engine_start(clock.time())
             .trading_state(trading_state)
+            .tear_sheet_history(tear_sheet_history)
             .balances(
                 balances
                     .into_iter()
@@ -114,6 +114,9 @@ pub struct RiskLimits {
 
     /// Maximum proportion of account equity allocatable to an instrument.
     pub max_exposure_percent: Option<Decimal>,
+
+    /// Seconds after a position exit during which new orders for the instrument are refused.
+    pub exit_cooldown_secs: Option<Decimal>,
 }
 
 impl RiskLimits {
@@ -137,6 +140,7 @@ impl RiskLimits {
         ensure_positive(&self.max_position_notional, "max_position_notional")?;
         ensure_positive(&self.max_position_quantity, "max_position_quantity")?;
         ensure_positive(&self.max_leverage, "max_leverage")?;
+        ensure_positive(&self.exit_cooldown_secs, "exit_cooldown_secs")?;
 
         if let Some(exposure) = self.max_exposure_percent
             && (exposure <= Decimal::ZERO || exposure > Decimal::ONE)
@@ -164,6 +168,45 @@ pub enum RiskLimitsError {
     InstrumentIndexOutOfBounds { index: usize, total: usize },
 }
 
+/// Logical instrument grouping the same market traded under different symbols across venues.
+///
+/// For example, `binance_spot-btc_usdt` and `coinbase-btc_usd` may both be members of a
+/// `btc_usd` alias so that their results can be consolidated.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InstrumentAlias {
+    /// Logical instrument name shared by every member.
+    pub name: String,
+
+    /// [`InstrumentNameInternal`] of each member instrument.
+    pub instruments: Vec<InstrumentNameInternal>,
+}
+
+/// Errors that can occur while configuring instrument aliases.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum InstrumentAliasError {
+    /// Alias names must be non-empty.
+    #[error("instrument alias name must not be empty")]
+    EmptyName,
+
+    /// An alias must contain at least one member instrument.
+    #[error("instrument alias `{alias}` must contain at least one instrument")]
+    NoInstruments { alias: String },
+
+    /// Member instruments must be present in [`SystemConfig::instruments`].
+    #[error("instrument alias `{alias}` references unknown instrument `{instrument}`")]
+    UnknownInstrument {
+        alias: String,
+        instrument: InstrumentNameInternal,
+    },
+
+    /// An instrument can only be a member of a single alias.
+    #[error("instrument `{instrument}` is already a member of instrument alias `{existing}`")]
+    AlreadyAliased {
+        instrument: InstrumentNameInternal,
+        existing: String,
+    },
+}
+
 /// Top-level configuration for a full trading system.
 ///
 /// Contains configuration for all instruments and execution components.
@@ -178,6 +221,10 @@ pub struct SystemConfig {
     /// Optional risk manager configuration.
     #[serde(default)]
     pub risk: RiskConfiguration,
+
+    /// Optional logical instrument aliases used to consolidate results across venues.
+    #[serde(default)]
+    pub aliases: Vec<InstrumentAlias>,
 }
 
 impl SystemConfig {
@@ -219,6 +266,73 @@ impl SystemConfig {
     pub fn instrument_risk_limits(&self, index: usize) -> Option<&RiskLimits> {
         self.risk.instrument_limits(index)
     }
+
+    /// Access the configured instrument aliases.
+    pub fn instrument_aliases(&self) -> &[InstrumentAlias] {
+        &self.aliases
+    }
+
+    /// Define (or replace) the instrument alias `name` with the provided member instruments.
+    pub fn set_instrument_alias(
+        &mut self,
+        name: impl Into<String>,
+        instruments: Vec<InstrumentNameInternal>,
+    ) -> Result<(), InstrumentAliasError> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(InstrumentAliasError::EmptyName);
+        }
+        if instruments.is_empty() {
+            return Err(InstrumentAliasError::NoInstruments { alias: name });
+        }
+
+        for instrument in &instruments {
+            let configured = self
+                .instruments
+                .iter()
+                .any(|config| &config.name_internal() == instrument);
+            if !configured {
+                return Err(InstrumentAliasError::UnknownInstrument {
+                    alias: name,
+                    instrument: instrument.clone(),
+                });
+            }
+
+            if let Some(existing) = self.instrument_alias(instrument)
+                && existing != name
+            {
+                return Err(InstrumentAliasError::AlreadyAliased {
+                    instrument: instrument.clone(),
+                    existing: existing.to_string(),
+                });
+            }
+        }
+
+        let mut instruments = instruments;
+        instruments.sort();
+        instruments.dedup();
+
+        match self.aliases.iter_mut().find(|alias| alias.name == name) {
+            Some(existing) => existing.instruments = instruments,
+            None => self.aliases.push(InstrumentAlias { name, instruments }),
+        }
+
+        Ok(())
+    }
+
+    /// Remove the instrument alias `name`, returning it if it was configured.
+    pub fn remove_instrument_alias(&mut self, name: &str) -> Option<InstrumentAlias> {
+        let position = self.aliases.iter().position(|alias| alias.name == name)?;
+        Some(self.aliases.remove(position))
+    }
+
+    /// Retrieve the name of the alias the provided instrument is a member of, if any.
+    pub fn instrument_alias(&self, instrument: &InstrumentNameInternal) -> Option<&str> {
+        self.aliases
+            .iter()
+            .find(|alias| alias.instruments.contains(instrument))
+            .map(|alias| alias.name.as_str())
+    }
 }
 
 /// Convenient minimal instrument configuration, used to generate an [`Instrument`] on startup.
@@ -254,15 +368,22 @@ pub enum ExecutionConfig {
     Mock(MockExecutionConfig),
 }
 
+impl InstrumentConfig {
+    /// Internal name of the [`Instrument`] generated from this configuration.
+    pub fn name_internal(&self) -> InstrumentNameInternal {
+        InstrumentNameInternal::new_from_exchange_underlying(
+            self.exchange,
+            &self.underlying.base,
+            &self.underlying.quote,
+        )
+    }
+}
+
 impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
     fn from(value: InstrumentConfig) -> Self {
         Self {
             exchange: value.exchange,
-            name_internal: InstrumentNameInternal::new_from_exchange_underlying(
-                value.exchange,
-                &value.underlying.base,
-                &value.underlying.quote,
-            ),
+            name_internal: value.name_internal(),
             name_exchange: value.name_exchange,
             underlying: Underlying {
                 base: Asset::new_from_exchange(value.underlying.base),
@@ -330,6 +451,7 @@ mod tests {
             }],
             executions: Vec::new(),
             risk: RiskConfiguration::default(),
+            aliases: Vec::new(),
         }
     }
 
@@ -342,6 +464,7 @@ mod tests {
                 max_position_quantity: None,
                 max_leverage: Some(dec!(2)),
                 max_exposure_percent: Some(dec!(0.5)),
+                exit_cooldown_secs: None,
             }))
             .unwrap();
 
@@ -357,6 +480,7 @@ mod tests {
                 max_position_quantity: None,
                 max_leverage: None,
                 max_exposure_percent: Some(dec!(1.5)),
+                exit_cooldown_secs: None,
             }))
             .unwrap_err();
 
@@ -366,6 +490,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn rejects_non_positive_exit_cooldown() {
+        let mut config = sample_config();
+        let err = config
+            .set_global_risk_limits(Some(RiskLimits {
+                exit_cooldown_secs: Some(dec!(0)),
+                ..RiskLimits::default()
+            }))
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            RiskLimitsError::NonPositive {
+                field: "exit_cooldown_secs",
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn instrument_limits_round_trip() {
         let mut config = sample_config();
@@ -377,6 +520,7 @@ mod tests {
                     max_position_quantity: None,
                     max_leverage: None,
                     max_exposure_percent: None,
+                    exit_cooldown_secs: None,
                 }),
             )
             .unwrap();
@@ -399,6 +543,7 @@ mod tests {
                     max_position_quantity: None,
                     max_leverage: None,
                     max_exposure_percent: None,
+                    exit_cooldown_secs: None,
                 }),
             )
             .unwrap_err();
@@ -408,4 +553,44 @@ mod tests {
             RiskLimitsError::InstrumentIndexOutOfBounds { .. }
         ));
     }
+
+    #[test]
+    fn instrument_alias_round_trip() {
+        let mut config = sample_config();
+        let member = InstrumentNameInternal::new("binance_spot-btc_usdt");
+
+        config
+            .set_instrument_alias("btc_usd", vec![member.clone()])
+            .unwrap();
+        assert_eq!(config.instrument_alias(&member), Some("btc_usd"));
+
+        let removed = config.remove_instrument_alias("btc_usd").unwrap();
+        assert_eq!(removed.instruments, vec![member.clone()]);
+        assert!(config.instrument_alias(&member).is_none());
+    }
+
+    #[test]
+    fn instrument_alias_validation() {
+        let mut config = sample_config();
+        let member = InstrumentNameInternal::new("binance_spot-btc_usdt");
+
+        let err = config
+            .set_instrument_alias(
+                "btc_usd",
+                vec![InstrumentNameInternal::new("kraken-btc_usd")],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            InstrumentAliasError::UnknownInstrument { .. }
+        ));
+
+        config
+            .set_instrument_alias("btc_usd", vec![member.clone()])
+            .unwrap();
+        let err = config
+            .set_instrument_alias("bitcoin", vec![member])
+            .unwrap_err();
+        assert!(matches!(err, InstrumentAliasError::AlreadyAliased { .. }));
+    }
 }
@@ -15,6 +15,7 @@ use crate::{
     shutdown::{AsyncShutdown, Shutdown},
 };
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::instrument::InstrumentIndex;
 use barter_integration::{
     channel::{Tx, UnboundedRx, UnboundedTx},
     collection::one_or_many::OneOrMany,
@@ -160,6 +161,21 @@ where
         self.send(Command::CancelOrders(filter))
     }
 
+    /// Override the algorithmic `TradingState` of a single instrument, leaving the `Engine` wide
+    /// `TradingState` untouched.
+    ///
+    /// Only honoured by strategies that consult `InstrumentState::trading` before generating
+    /// algorithmic orders.
+    pub fn instrument_trading_state(&self, instrument: InstrumentIndex, trading_state: TradingState)
+    where
+        Event: From<Command>,
+    {
+        self.send(Command::SetInstrumentTradingState(
+            instrument,
+            trading_state,
+        ))
+    }
+
     /// Update the algorithmic `TradingState` of the `Engine`.
     pub fn trading_state(&self, trading_state: TradingState)
     where
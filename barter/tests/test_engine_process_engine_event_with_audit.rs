@@ -174,11 +174,11 @@ fn test_engine_process_engine_event_with_audit() {
 
     // Ensure ExecutionRequests were sent to ExecutionManager
     assert_eq!(
-        execution_rx.next().unwrap(),
+        next_order_request(&mut execution_rx),
         ExecutionRequest::Open(btc_usdt_buy_order)
     );
     assert_eq!(
-        execution_rx.next().unwrap(),
+        next_order_request(&mut execution_rx),
         ExecutionRequest::Open(eth_btc_buy_order)
     );
 
@@ -354,7 +354,7 @@ fn test_engine_process_engine_event_with_audit() {
 
     // Ensure ClosePositions ExecutionRequest was sent to ExecutionManager
     assert_eq!(
-        execution_rx.next().unwrap(),
+        next_order_request(&mut execution_rx),
         ExecutionRequest::Open(btc_usdt_sell_order)
     );
 
@@ -492,7 +492,7 @@ fn test_engine_process_engine_event_with_audit() {
 
     // Ensure ExecutionRequest for Sequence(21) Command::SendOpenRequests was sent to ExecutionManager
     assert_eq!(
-        execution_rx.next().unwrap(),
+        next_order_request(&mut execution_rx),
         ExecutionRequest::Open(eth_btc_sell_order)
     );
 
@@ -702,6 +702,17 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
     }
 }
 
+/// Returns the next non-[`ExecutionRequest::MarketPrice`] request sent to the ExecutionManager.
+///
+/// The Engine forwards the latest market price after every market event, so these are skipped.
+fn next_order_request(
+    execution_rx: &mut impl Iterator<Item = ExecutionRequest>,
+) -> ExecutionRequest {
+    execution_rx
+        .find(|request| !matches!(request, ExecutionRequest::MarketPrice { .. }))
+        .unwrap()
+}
+
 fn strategy_id() -> StrategyId {
     StrategyId::new("TestBuyAndHoldStrategy")
 }